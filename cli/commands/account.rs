@@ -15,8 +15,8 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{helpers::AccountModel, CurrentNetwork};
-use aleo_rust::account::Encryptor;
-use snarkvm::prelude::{Address, Ciphertext, PrivateKey, ViewKey};
+use aleo_rust::account::{Encryptor, Keystore, SigningDomain};
+use snarkvm::prelude::{Address, Ciphertext, PrivateKey, ProgramID, Signature, ViewKey};
 
 use anyhow::{bail, Result};
 use clap::Parser;
@@ -81,6 +81,78 @@ pub enum Account {
         #[clap(short = 'p', long)]
         password: Option<String>,
     },
+    /// Export a private key to a password protected keystore file
+    Export {
+        /// Provide private key plaintext to command line
+        #[clap(short = 'k', long)]
+        private_key: Option<PrivateKey<CurrentNetwork>>,
+        /// Get private key plaintext from file
+        #[clap(short = 'f', long)]
+        file: Option<String>,
+        /// Path of the keystore file to write
+        #[clap(short = 'o', long, default_value = "keystore.json")]
+        output: String,
+        /// password to encrypt the keystore
+        #[clap(short = 'p', long)]
+        password: Option<String>,
+    },
+    /// Import an account from a password protected keystore file
+    ImportKeystore {
+        /// Path of the keystore file
+        keystore: String,
+        /// Write key data to disk
+        #[clap(short = 'w', long)]
+        write: bool,
+        /// password to decrypt the keystore
+        #[clap(short = 'p', long)]
+        password: Option<String>,
+    },
+    /// Sign a message for an application domain so the signature cannot be replayed elsewhere
+    Sign {
+        /// Private key used to sign the message
+        #[clap(short = 'k', long)]
+        private_key: PrivateKey<CurrentNetwork>,
+        /// Message to sign
+        #[clap(short = 'm', long)]
+        message: String,
+        /// Origin of the application requesting the signature
+        #[clap(short = 'o', long)]
+        origin: String,
+        /// Program the signature is bound to
+        #[clap(long)]
+        program_id: Option<ProgramID<CurrentNetwork>>,
+        /// Nonce of the signing domain
+        #[clap(short = 'n', long, default_value = "0")]
+        nonce: u64,
+    },
+    /// Show the address of an account of a connected Ledger device
+    #[cfg(feature = "ledger")]
+    Ledger {
+        /// Derivation index of the account on the device
+        #[clap(short = 'a', long, default_value = "0")]
+        account: u32,
+    },
+    /// Verify a message signature produced for an application domain
+    Verify {
+        /// Address of the signer
+        #[clap(short = 'a', long)]
+        address: Address<CurrentNetwork>,
+        /// Signature to verify
+        #[clap(short = 's', long)]
+        signature: Signature<CurrentNetwork>,
+        /// Message that was signed
+        #[clap(short = 'm', long)]
+        message: String,
+        /// Origin of the application which requested the signature
+        #[clap(short = 'o', long)]
+        origin: String,
+        /// Program the signature is bound to
+        #[clap(long)]
+        program_id: Option<ProgramID<CurrentNetwork>>,
+        /// Nonce of the signing domain
+        #[clap(short = 'n', long, default_value = "0")]
+        nonce: u64,
+    },
 }
 
 impl Account {
@@ -148,23 +220,8 @@ impl Account {
                 Ok(format!("{key_output}{save_output}"))
             }
             Self::Encrypt { private_key, file, write, password } => {
-                // Check for ambiguous input
-                if private_key.is_some() && file.is_some() {
-                    bail!("❌ Please provide either a private key or a filepath, not both");
-                }
-
                 // Get private key from file or command line
-                let private_key = match file {
-                    Some(file) => {
-                        let mut file = File::open(file)?;
-                        let account_keys: AccountModel = serde_json::from_reader(&mut file)?;
-                        account_keys.private_key.ok_or_else(|| anyhow::anyhow!("❌ No private key found in file"))?
-                    }
-                    None => match private_key {
-                        Some(private_key) => private_key,
-                        None => bail!("❌ Please provide either a private key or a filepath"),
-                    },
-                };
+                let private_key = Self::load_private_key(private_key, file)?;
 
                 // Use the provided password or prompt for a password and encrypt private key
                 let private_key_ciphertext = Self::encrypt_with_password(&private_key, password)?;
@@ -232,16 +289,121 @@ impl Account {
 
                 Ok(format!("{key_output}{save_output}"))
             }
+            Self::Export { private_key, file, output, password } => {
+                // Get private key from file or command line
+                let private_key = Self::load_private_key(private_key, file)?;
+
+                // Refuse to overwrite an existing keystore
+                let path = std::env::current_dir()?.join(&output);
+                if path.exists() {
+                    bail!("❌ Keystore not written to disk, {output} already exists");
+                }
+
+                // Use the provided password or prompt for a password and write the keystore
+                let password = Self::new_password(password)?;
+                let keystore = Keystore::encrypt(&private_key, &password)?;
+                std::fs::write(path, keystore.to_json()?)?;
+
+                Ok(format!(
+                    "\n{:>12}\n\n {:>1}  {}\n\n{} {}",
+                    "✅ Account keystore successfully exported:".green().bold(),
+                    "Address".cyan().bold(),
+                    keystore.address,
+                    "✅ Keystore written to".green().bold(),
+                    output.green().bold()
+                ))
+            }
+            Self::ImportKeystore { keystore, write, password } => {
+                let keystore = Keystore::from_json(&std::fs::read_to_string(keystore)?)?;
+
+                // Use supplied password or prompt for the user for a password and attempt to decrypt the keystore
+                let secret = if let Some(password) = password {
+                    password
+                } else {
+                    rpassword::prompt_password("Enter keystore password: ")?
+                };
+                let private_key = keystore
+                    .decrypt::<CurrentNetwork>(&secret)
+                    .map_err(|_| anyhow::anyhow!("❌ Incorrect password or corrupted keystore"))?;
+
+                let view_key = ViewKey::try_from(&private_key)?;
+                let address = Address::try_from(&view_key)?;
+
+                // Print the imported Aleo account
+                let key_output = format!(
+                    "\n{:>12}\n\n {:>1}  {private_key}\n {:>1}  {view_key}\n {:>1}  {address}",
+                    "✅ Account keys successfully imported from keystore:".green().bold(),
+                    "Private Key".cyan().bold(),
+                    "View Key".cyan().bold(),
+                    "Address".cyan().bold(),
+                );
+
+                // Save output to file if specified
+                let save_output =
+                    Self::write_account_to_file(write, None, private_key.into(), view_key.into(), address.into())?;
+
+                Ok(format!("{key_output}{save_output}"))
+            }
+            Self::Sign { private_key, message, origin, program_id, nonce } => {
+                let domain = SigningDomain::new(&origin, program_id, nonce)?;
+                let signature = domain.sign(&private_key, message.as_bytes())?;
+
+                Ok(format!(
+                    "\n{:>12}\n\n {:>1}  {signature}",
+                    "✅ Message successfully signed:".green().bold(),
+                    "Signature".cyan().bold(),
+                ))
+            }
+            #[cfg(feature = "ledger")]
+            Self::Ledger { account } => {
+                use aleo_rust::{HidTransport, LedgerSigner, Signer};
+
+                let signer = LedgerSigner::<CurrentNetwork, _>::connect(HidTransport::connect()?, account)?;
+                Ok(format!(
+                    "\n{:>12}\n {:>1}  {}",
+                    "✅ Ledger account found:".green().bold(),
+                    "Address".cyan().bold(),
+                    signer.address()?
+                ))
+            }
+            Self::Verify { address, signature, message, origin, program_id, nonce } => {
+                let domain = SigningDomain::new(&origin, program_id, nonce)?;
+                if !domain.verify(&address, message.as_bytes(), &signature) {
+                    bail!("❌ The signature is not valid for this message, address, and domain");
+                }
+
+                Ok(format!("\n{:>12}", "✅ Signature successfully verified".green().bold()))
+            }
         }
     }
 
-    // Encrypt the private key with a password specified at the command line
-    fn encrypt_with_password(
-        private_key: &PrivateKey<CurrentNetwork>,
-        password: Option<String>,
-    ) -> Result<Ciphertext<CurrentNetwork>> {
+    // Get a private key from the command line or from an account file
+    fn load_private_key(
+        private_key: Option<PrivateKey<CurrentNetwork>>,
+        file: Option<String>,
+    ) -> Result<PrivateKey<CurrentNetwork>> {
+        // Check for ambiguous input
+        if private_key.is_some() && file.is_some() {
+            bail!("❌ Please provide either a private key or a filepath, not both");
+        }
+
+        match file {
+            Some(file) => {
+                let mut file = File::open(file)?;
+                let account_keys: AccountModel = serde_json::from_reader(&mut file)?;
+                account_keys.private_key.ok_or_else(|| anyhow::anyhow!("❌ No private key found in file"))
+            }
+            None => match private_key {
+                Some(private_key) => Ok(private_key),
+                None => bail!("❌ Please provide either a private key or a filepath"),
+            },
+        }
+    }
+
+    // Use the password specified at the command line or prompt for a new password
+    fn new_password(password: Option<String>) -> Result<String> {
         if let Some(password) = password {
-            Ok(Encryptor::encrypt_private_key_with_secret(private_key, &password)?)
+            Ok(password)
         } else {
             let password = rpassword::prompt_password("Enter encryption password: ")?;
             let password_confirm = rpassword::prompt_password("Confirm encryption password: ")?;
@@ -250,10 +412,19 @@ impl Account {
                 bail!("❌ Passwords do not match");
             }
 
-            Ok(Encryptor::encrypt_private_key_with_secret(private_key, &password)?)
+            Ok(password)
         }
     }
 
+    // Encrypt the private key with a password specified at the command line
+    fn encrypt_with_password(
+        private_key: &PrivateKey<CurrentNetwork>,
+        password: Option<String>,
+    ) -> Result<Ciphertext<CurrentNetwork>> {
+        let password = Self::new_password(password)?;
+        Ok(Encryptor::encrypt_private_key_with_secret(private_key, &password)?)
+    }
+
     // Write the account keys to a file or return if write flag is not specified
     fn write_account_to_file(
         write: bool,
@@ -528,4 +699,85 @@ mod tests {
             Account::Decrypt { ciphertext, file: Some("test.json".to_string()), write: false, password: None };
         assert!(account_ambiguous_inputs.parse().is_err());
     }
+
+    #[test]
+    fn test_account_export_and_import_keystore() {
+        let keystore_path = std::env::temp_dir().join("aleo-cli-test-keystore.json");
+        let _ = fs::remove_file(&keystore_path);
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut TestRng::default()).unwrap();
+
+        // Export the private key to a keystore
+        let export = Account::Export {
+            private_key: Some(private_key),
+            file: None,
+            output: keystore_path.to_str().unwrap().to_string(),
+            password: Some("mypassword".to_string()),
+        };
+        assert!(export.parse().unwrap().contains("Keystore written to"));
+        let keystore = Keystore::from_json(&fs::read_to_string(&keystore_path).unwrap()).unwrap();
+        assert_eq!(keystore.decrypt::<CurrentNetwork>("mypassword").unwrap(), private_key);
+
+        // Ensure an existing keystore is not overwritten
+        let export = Account::Export {
+            private_key: Some(private_key),
+            file: None,
+            output: keystore_path.to_str().unwrap().to_string(),
+            password: Some("mypassword".to_string()),
+        };
+        assert!(export.parse().is_err());
+
+        // Import the keystore with the correct and incorrect passwords
+        let import = Account::ImportKeystore {
+            keystore: keystore_path.to_str().unwrap().to_string(),
+            write: false,
+            password: Some("mypassword".to_string()),
+        };
+        assert!(import.parse().unwrap().contains(&private_key.to_string()));
+        let import = Account::ImportKeystore {
+            keystore: keystore_path.to_str().unwrap().to_string(),
+            write: false,
+            password: Some("wrong_password".to_string()),
+        };
+        assert!(import.parse().is_err());
+        fs::remove_file(&keystore_path).unwrap();
+    }
+
+    #[test]
+    fn test_account_sign_and_verify() {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut TestRng::default()).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let program_id = Some(ProgramID::from_str("credits.aleo").unwrap());
+        let domain = SigningDomain::new("https://app.aleo.org", program_id, 3).unwrap();
+        let signature = domain.sign(&private_key, b"hello").unwrap();
+
+        let sign = Account::Sign {
+            private_key,
+            message: "hello".to_string(),
+            origin: "https://app.aleo.org".to_string(),
+            program_id,
+            nonce: 3,
+        };
+        assert!(sign.parse().unwrap().contains("Message successfully signed"));
+
+        let verify = Account::Verify {
+            address,
+            signature,
+            message: "hello".to_string(),
+            origin: "https://app.aleo.org".to_string(),
+            program_id,
+            nonce: 3,
+        };
+        assert!(verify.parse().is_ok());
+
+        // Ensure verification fails in a different domain
+        let verify = Account::Verify {
+            address,
+            signature,
+            message: "hello".to_string(),
+            origin: "https://app.aleo.org".to_string(),
+            program_id,
+            nonce: 4,
+        };
+        assert!(verify.parse().is_err());
+    }
 }
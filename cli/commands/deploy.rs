@@ -27,7 +27,7 @@ use colored::Colorize;
 pub struct Deploy {
     /// The program identifier
     program_id: ProgramID<CurrentNetwork>,
-    /// Directory containing the program files
+    /// Directory containing the program files, or a Leo project when built with the leo feature
     #[clap(short, long)]
     directory: Option<std::path::PathBuf>,
     /// Aleo Network peer to broadcast the deployment to
@@ -80,7 +80,7 @@ impl Deploy {
                     println!("Using default peer: https://vm.aleo.org/api/testnet3");
                     Ok(AleoAPIClient::<CurrentNetwork>::testnet3())
                 },
-                |peer| AleoAPIClient::<CurrentNetwork>::new(&peer, "testnet3"),
+                |peer| AleoAPIClient::<CurrentNetwork>::for_network(&peer),
             )
             .map_err(|e| anyhow!("{:?}", e))?;
 
@@ -15,7 +15,7 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::CurrentNetwork;
-use aleo_rust::{AleoAPIClient, Encryptor, ProgramManager, RecordFinder};
+use aleo_rust::{AleoAPIClient, DiskKeyCache, Encryptor, ProgramManager, RecordFinder};
 use snarkvm::prelude::{Ciphertext, Identifier, Plaintext, PrivateKey, ProgramID, Record, Value};
 
 use anyhow::{anyhow, ensure, Result};
@@ -85,7 +85,7 @@ impl Execute {
                     println!("Using default peer: https://vm.aleo.org/api/testnet3");
                     Ok(AleoAPIClient::<CurrentNetwork>::testnet3())
                 },
-                |peer| AleoAPIClient::<CurrentNetwork>::new(&peer, "testnet3"),
+                |peer| AleoAPIClient::<CurrentNetwork>::for_network(&peer),
             )
             .map_err(|e| anyhow!("{:?}", e))?;
 
@@ -99,6 +99,11 @@ impl Execute {
         )?;
         program_manager.find_program(&self.program_id)?;
 
+        // Cache the keys of the function on disk, so they are only synthesized on the first run
+        if let Ok(key_cache) = DiskKeyCache::open_default() {
+            program_manager.set_key_provider(key_cache);
+        }
+
         // Find a fee record to pay the fee if necessary
         let fee_record = if self.record.is_none() {
             println!("Searching for a record to spend the execution fee from, this may take a while..");
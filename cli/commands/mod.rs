@@ -32,6 +32,9 @@ pub use execute::*;
 mod new;
 pub use new::*;
 
+mod params;
+pub use params::*;
+
 // mod node;
 // pub use node::*;
 
@@ -72,6 +75,8 @@ pub enum Command {
     Execute(Execute),
     #[clap(name = "new")]
     New(New),
+    #[clap(subcommand)]
+    Params(Params),
     #[clap(name = "run")]
     Run(Run),
     #[clap(name = "transfer")]
@@ -91,6 +96,7 @@ impl Command {
             Self::Execute(command) => command.parse(),
             Self::New(command) => command.parse(),
             // Self::Node(command) => command.parse(),
+            Self::Params(command) => command.parse(),
             Self::Run(command) => command.parse(),
             Self::Transfer(command) => command.parse(),
             Self::Update(command) => command.parse(),
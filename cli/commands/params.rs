@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use aleo_rust::{AleoNetwork, ParameterDownloader};
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use colored::*;
+use std::{io::Write, path::PathBuf, sync::Mutex};
+
+/// The credits.aleo functions whose keys are published on the parameter server
+const CREDITS_FUNCTIONS: [&str; 4] = ["fee", "join", "split", "transfer"];
+
+/// Commands to manage the parameters used to create and verify proofs
+#[derive(Debug, Parser)]
+pub enum Params {
+    /// Download parameter files, resuming interrupted downloads
+    Download {
+        /// The credits.aleo functions to download the keys of [default: all functions]
+        functions: Vec<String>,
+        /// Additional parameter files to download by name (i.e. a universal SRS file)
+        #[clap(short = 'f', long = "file")]
+        files: Vec<String>,
+        /// SHA-256 checksums of the additional files, in the order of the files. Required for
+        /// files whose name does not end with a checksum prefix
+        #[clap(short = 'c', long = "checksum")]
+        checksums: Vec<String>,
+        /// Parameter servers to download from, tried in order [default: the official server]
+        #[clap(short = 'm', long = "mirror")]
+        mirrors: Vec<String>,
+        /// The directory to download the files to [default: ~/.aleo/resources]
+        #[clap(short = 'd', long)]
+        directory: Option<PathBuf>,
+    },
+}
+
+impl Params {
+    pub fn parse(self) -> Result<String> {
+        match self {
+            Self::Download { functions, files, checksums, mirrors, directory } => {
+                ensure!(checksums.len() <= files.len(), "❌ More checksums than files were provided");
+                let last_progress = Mutex::new(None);
+                let downloader = match mirrors.is_empty() {
+                    true => ParameterDownloader::for_network(AleoNetwork::default()),
+                    false => ParameterDownloader::new(mirrors)?,
+                }
+                .with_progress(move |file, downloaded, total| {
+                    if let Some(line) = progress_line(&mut last_progress.lock().unwrap(), file, downloaded, total) {
+                        print!("{line}");
+                        let _ = std::io::stdout().flush();
+                    }
+                });
+                let directory = directory.map_or_else(ParameterDownloader::default_directory, Ok)?;
+
+                // Download the keys of every credits.aleo function unless only files were requested
+                let functions = match functions.is_empty() && files.is_empty() {
+                    true => CREDITS_FUNCTIONS.iter().map(|function| function.to_string()).collect(),
+                    false => functions,
+                };
+
+                let mut downloaded = vec![];
+                for function in functions {
+                    let (prover, verifier) = downloader.download_credits_keys(&function, &directory)?;
+                    downloaded.extend([prover, verifier]);
+                }
+                for (index, file) in files.iter().enumerate() {
+                    downloaded.push(downloader.download(file, &directory, checksums.get(index).map(String::as_str))?);
+                }
+
+                let paths = downloaded.iter().map(|path| path.display().to_string()).collect::<Vec<_>>();
+                Ok(format!("\n{}\n{}", "✅ Downloaded parameters:".green().bold(), paths.join("\n")))
+            }
+        }
+    }
+}
+
+/// Get the text redrawing the progress line of a download, if the whole percentage (or the
+/// number of megabytes when the size of the file is unknown) changed since the last report
+fn progress_line(last: &mut Option<(String, u64)>, file: &str, downloaded: u64, total: Option<u64>) -> Option<String> {
+    let (progress, status) = match total {
+        Some(total) if total > 0 => {
+            let percent = downloaded * 100 / total;
+            (percent, format!("{percent}%"))
+        }
+        _ => {
+            let megabytes = downloaded >> 20;
+            (megabytes, format!("{megabytes} MB"))
+        }
+    };
+    let previous = last.replace((file.to_string(), progress));
+    match previous {
+        Some((previous_file, previous_progress)) if previous_file == file => {
+            (previous_progress != progress).then(|| format!("\r{file}: {status}"))
+        }
+        // Keep the line of the previous file and start a new one
+        Some(_) => Some(format!("\n{file}: {status}")),
+        None => Some(format!("{file}: {status}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params_download_arguments() {
+        let params = Params::try_parse_from([
+            "aleo",
+            "download",
+            "fee",
+            "--file",
+            "universal.srs",
+            "--checksum",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            "--mirror",
+            "https://mirror.example.com",
+        ])
+        .unwrap();
+        let Params::Download { functions, files, checksums, mirrors, directory } = params;
+        assert_eq!(functions, ["fee"]);
+        assert_eq!(files, ["universal.srs"]);
+        assert_eq!(checksums, ["e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"]);
+        assert_eq!(mirrors, ["https://mirror.example.com"]);
+        assert!(directory.is_none());
+    }
+
+    #[test]
+    fn test_progress_line() {
+        let mut last = None;
+        assert_eq!(progress_line(&mut last, "fee.prover", 0, Some(1000)).unwrap(), "fee.prover: 0%");
+        // The line is only redrawn when the whole percentage changes
+        assert!(progress_line(&mut last, "fee.prover", 5, Some(1000)).is_none());
+        assert_eq!(progress_line(&mut last, "fee.prover", 10, Some(1000)).unwrap(), "\rfee.prover: 1%");
+        assert_eq!(progress_line(&mut last, "fee.verifier", 1 << 20, None).unwrap(), "\nfee.verifier: 1 MB");
+        assert!(progress_line(&mut last, "fee.verifier", (1 << 20) + 1, None).is_none());
+    }
+}
@@ -20,6 +20,7 @@ use aleo_rust::{
     AleoAPIClient,
     Ciphertext,
     Credits,
+    DiskKeyCache,
     Encryptor,
     Plaintext,
     PrivateKey,
@@ -103,17 +104,20 @@ impl Transfer {
                     println!("Using default peer: {}", "https://vm.aleo.org/api/testnet3".bright_blue().bold());
                     Ok(AleoAPIClient::<CurrentNetwork>::testnet3())
                 },
-                |peer| AleoAPIClient::<CurrentNetwork>::new(&peer, "testnet3"),
+                |peer| AleoAPIClient::<CurrentNetwork>::for_network(&peer),
             )
             .map_err(|e| anyhow!("{:?}", e))?;
 
-        // Create the program manager
-        let program_manager = ProgramManager::<CurrentNetwork>::new(
+        // Create the program manager, caching the keys of the transfer on disk between runs
+        let mut program_manager = ProgramManager::<CurrentNetwork>::new(
             self.private_key,
             self.ciphertext.clone(),
             Some(api_client.clone()),
             None,
         )?;
+        if let Ok(key_cache) = DiskKeyCache::open_default() {
+            program_manager.set_key_provider(key_cache);
+        }
 
         // Find the input records from the Aleo Network if not provided
         let private_key = if let Some(private_key) = self.private_key {
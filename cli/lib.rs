@@ -23,5 +23,11 @@ pub mod commands;
 pub mod errors;
 pub mod helpers;
 
+// The network targeted by the CLI is selected with a cargo feature
+#[cfg(feature = "testnet3")]
 pub type CurrentNetwork = snarkvm::prelude::Testnet3;
+#[cfg(feature = "testnet3")]
 pub type Aleo = snarkvm::circuit::AleoV0;
+
+#[cfg(not(feature = "testnet3"))]
+compile_error!("A network feature must be enabled, i.e. \"testnet3\"");
@@ -191,11 +191,11 @@ impl<N: Network> Rest<N> {
         // If no socket address was specified, use the default of 0.0.0.0:4040
         let socket_address = socket_address.unwrap_or_else(|| SocketAddr::from(([0, 0, 0, 0], 4040)));
 
-        // If no peer url was specified, use the default of https://vm.aleo.org/api
-        let peer = peer_url.unwrap_or("https://vm.aleo.org/api".to_string());
-
-        // Initialize an API client configured for the specified network.
-        let api_client = AleoAPIClient::new(&peer, "testnet3")?;
+        // If no peer url was specified, use the public API of the network
+        let api_client = match peer_url {
+            Some(peer) => AleoAPIClient::for_network(&peer)?,
+            None => AleoAPIClient::default_for_network()?,
+        };
         let record_finder = RecordFinder::new(api_client.clone());
 
         let key_warning = if private_key_ciphertext.is_some() {
@@ -82,7 +82,7 @@ impl<N: Network> Rest<N> {
     // If a separate peer url is provided in the request, use that instead of the one in the config
     fn get_api_client(api_client: AleoAPIClient<N>, peer_url: &Option<String>) -> Result<AleoAPIClient<N>, Rejection> {
         if let Some(peer_url) = peer_url {
-            AleoAPIClient::new(peer_url, "testnet3").or_reject()
+            AleoAPIClient::for_network(peer_url).or_reject()
         } else {
             Ok(api_client)
         }
@@ -0,0 +1,365 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Machine readable descriptions of Aleo programs
+//!
+//! A `ProgramAbi` lists the functions, structs, records and mappings of a program along with
+//! their types. It is serializable with serde, so the same description can be rendered by the
+//! CLI, handed to javascript by the wasm bindings or consumed by code generators.
+
+use super::*;
+
+use serde::{Deserialize, Serialize};
+use snarkvm_console::program::{EntryType, FinalizeType, PlaintextType, ValueType};
+use snarkvm_synthesizer::Program;
+
+/// The visibility of a value or record entry
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    /// A value fixed when the circuit is synthesized
+    Constant,
+    /// A value visible on chain
+    Public,
+    /// A value encrypted on chain
+    Private,
+}
+
+/// The kind of a type referenced by a program
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeKind {
+    /// A literal type such as `u64` or `address`
+    Literal,
+    /// A struct defined by the program
+    Struct,
+    /// A record defined by the program
+    Record,
+    /// A record defined by an imported program, named by its locator (i.e. `credits.aleo/credits`)
+    ExternalRecord,
+}
+
+/// A type referenced by a program
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TypeAbi {
+    /// The kind of the type
+    pub kind: TypeKind,
+    /// The name of the type
+    pub name: String,
+}
+
+impl TypeAbi {
+    fn new(kind: TypeKind, name: impl ToString) -> Self {
+        Self { kind, name: name.to_string() }
+    }
+}
+
+impl<N: Network> From<&PlaintextType<N>> for TypeAbi {
+    fn from(plaintext_type: &PlaintextType<N>) -> Self {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Self::new(TypeKind::Literal, literal_type),
+            PlaintextType::Struct(name) => Self::new(TypeKind::Struct, name),
+        }
+    }
+}
+
+/// An input or output of a function or an input of a finalize block
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValueAbi {
+    /// The visibility of the value, records are always private
+    pub visibility: Visibility,
+    /// The type of the value
+    #[serde(rename = "type")]
+    pub value_type: TypeAbi,
+}
+
+impl<N: Network> From<&ValueType<N>> for ValueAbi {
+    fn from(value_type: &ValueType<N>) -> Self {
+        let (visibility, value_type) = match value_type {
+            ValueType::Constant(plaintext_type) => (Visibility::Constant, plaintext_type.into()),
+            ValueType::Public(plaintext_type) => (Visibility::Public, plaintext_type.into()),
+            ValueType::Private(plaintext_type) => (Visibility::Private, plaintext_type.into()),
+            ValueType::Record(name) => (Visibility::Private, TypeAbi::new(TypeKind::Record, name)),
+            ValueType::ExternalRecord(locator) => {
+                (Visibility::Private, TypeAbi::new(TypeKind::ExternalRecord, locator))
+            }
+        };
+        Self { visibility, value_type }
+    }
+}
+
+impl<N: Network> From<&FinalizeType<N>> for ValueAbi {
+    fn from(finalize_type: &FinalizeType<N>) -> Self {
+        match finalize_type {
+            FinalizeType::Public(plaintext_type) => {
+                Self { visibility: Visibility::Public, value_type: plaintext_type.into() }
+            }
+        }
+    }
+}
+
+/// A named member of a struct or entry of a record
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemberAbi {
+    /// The name of the member
+    pub name: String,
+    /// The visibility of a record entry, struct members take the visibility of the struct
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+    /// The type of the member
+    #[serde(rename = "type")]
+    pub member_type: TypeAbi,
+}
+
+/// A struct defined by a program
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructAbi {
+    pub name: String,
+    pub members: Vec<MemberAbi>,
+}
+
+/// A record defined by a program
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordAbi {
+    pub name: String,
+    /// The visibility of the owner of the record
+    pub owner: Visibility,
+    /// The entries of the record other than its owner
+    pub entries: Vec<MemberAbi>,
+}
+
+/// A mapping stored on chain by a program
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MappingAbi {
+    pub name: String,
+    pub key: TypeAbi,
+    pub value: TypeAbi,
+}
+
+/// The finalize block of a function, which runs on chain once the execution is accepted
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalizeAbi {
+    pub inputs: Vec<ValueAbi>,
+}
+
+/// A function of a program
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionAbi {
+    pub name: String,
+    pub inputs: Vec<ValueAbi>,
+    pub outputs: Vec<ValueAbi>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finalize: Option<FinalizeAbi>,
+}
+
+/// A serializable description of the interface of a program
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramAbi {
+    /// The id of the program (i.e. `credits.aleo`)
+    pub program_id: String,
+    /// The ids of the programs imported by the program
+    pub imports: Vec<String>,
+    pub structs: Vec<StructAbi>,
+    pub records: Vec<RecordAbi>,
+    pub mappings: Vec<MappingAbi>,
+    pub functions: Vec<FunctionAbi>,
+}
+
+impl ProgramAbi {
+    /// Get the description of a function by name
+    pub fn function(&self, name: &str) -> Option<&FunctionAbi> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    /// Get the description of a struct by name
+    pub fn get_struct(&self, name: &str) -> Option<&StructAbi> {
+        self.structs.iter().find(|definition| definition.name == name)
+    }
+
+    /// Get the description of a record by name
+    pub fn record(&self, name: &str) -> Option<&RecordAbi> {
+        self.records.iter().find(|record| record.name == name)
+    }
+}
+
+impl<N: Network> From<&Program<N>> for ProgramAbi {
+    fn from(program: &Program<N>) -> Self {
+        let structs = program
+            .structs()
+            .iter()
+            .map(|(name, definition)| StructAbi {
+                name: name.to_string(),
+                members: definition
+                    .members()
+                    .iter()
+                    .map(|(name, member_type)| MemberAbi {
+                        name: name.to_string(),
+                        visibility: None,
+                        member_type: member_type.into(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        let records = program
+            .records()
+            .iter()
+            .map(|(name, record)| RecordAbi {
+                name: name.to_string(),
+                owner: if record.owner().is_public() { Visibility::Public } else { Visibility::Private },
+                entries: record.entries().iter().map(|(name, entry_type)| entry_abi(name, entry_type)).collect(),
+            })
+            .collect();
+        let mappings = program
+            .mappings()
+            .iter()
+            .map(|(name, mapping)| MappingAbi {
+                name: name.to_string(),
+                key: mapping.key().plaintext_type().into(),
+                value: mapping.value().plaintext_type().into(),
+            })
+            .collect();
+        let functions = program
+            .functions()
+            .iter()
+            .map(|(name, function)| FunctionAbi {
+                name: name.to_string(),
+                inputs: function.inputs().iter().map(|input| input.value_type().into()).collect(),
+                outputs: function.outputs().iter().map(|output| output.value_type().into()).collect(),
+                finalize: function.finalize().map(|(_, finalize)| FinalizeAbi {
+                    inputs: finalize.inputs().iter().map(|input| input.finalize_type().into()).collect(),
+                }),
+            })
+            .collect();
+
+        Self {
+            program_id: program.id().to_string(),
+            imports: program.imports().keys().map(|program_id| program_id.to_string()).collect(),
+            structs,
+            records,
+            mappings,
+            functions,
+        }
+    }
+}
+
+/// Describe an entry of a record
+fn entry_abi<N: Network>(name: &Identifier<N>, entry_type: &EntryType<N>) -> MemberAbi {
+    let (visibility, plaintext_type) = match entry_type {
+        EntryType::Constant(plaintext_type) => (Visibility::Constant, plaintext_type),
+        EntryType::Public(plaintext_type) => (Visibility::Public, plaintext_type),
+        EntryType::Private(plaintext_type) => (Visibility::Private, plaintext_type),
+    };
+    MemberAbi { name: name.to_string(), visibility: Some(visibility), member_type: plaintext_type.into() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    const TOKEN_PROGRAM: &str = r"program token_abi.aleo;
+
+struct metadata:
+    decimals as u8;
+    supply as u64;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    info as metadata.public;
+
+mapping balances:
+    key owner as address.public;
+    value amount as u64.public;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];
+
+function split:
+    input r0 as token.record;
+    input r1 as u64.private;
+    sub r0.amount r1 into r2;
+    cast r0.owner r1 r0.info into r3 as token.record;
+    cast r0.owner r2 r0.info into r4 as token.record;
+    output r3 as token.record;
+    output r4 as token.record;
+    output r0.info as metadata.public;
+";
+
+    #[test]
+    fn test_program_abi() {
+        let program = Program::<Testnet3>::from_str(TOKEN_PROGRAM).unwrap();
+        let abi = ProgramAbi::from(&program);
+        assert_eq!(abi.program_id, "token_abi.aleo");
+        assert!(abi.imports.is_empty());
+
+        let metadata = abi.get_struct("metadata").unwrap();
+        assert_eq!(metadata.members.len(), 2);
+        assert_eq!(metadata.members[1].member_type, TypeAbi::new(TypeKind::Literal, "u64"));
+
+        let token = abi.record("token").unwrap();
+        assert_eq!(token.owner, Visibility::Private);
+        assert_eq!(token.entries[1].visibility, Some(Visibility::Public));
+        assert_eq!(token.entries[1].member_type, TypeAbi::new(TypeKind::Struct, "metadata"));
+
+        assert_eq!(abi.mappings, vec![MappingAbi {
+            name: "balances".to_string(),
+            key: TypeAbi::new(TypeKind::Literal, "address"),
+            value: TypeAbi::new(TypeKind::Literal, "u64"),
+        }]);
+
+        let mint = abi.function("mint_public").unwrap();
+        assert_eq!(mint.inputs[1], ValueAbi {
+            visibility: Visibility::Public,
+            value_type: TypeAbi::new(TypeKind::Literal, "u64")
+        });
+        assert!(mint.outputs.is_empty());
+        assert_eq!(mint.finalize.as_ref().unwrap().inputs, mint.inputs);
+
+        let split = abi.function("split").unwrap();
+        assert_eq!(split.inputs[0], ValueAbi {
+            visibility: Visibility::Private,
+            value_type: TypeAbi::new(TypeKind::Record, "token")
+        });
+        assert_eq!(split.outputs.len(), 3);
+        assert_eq!(split.outputs[2].value_type.kind, TypeKind::Struct);
+        assert!(split.finalize.is_none());
+
+        // The description round trips through json
+        let json = serde_json::to_string(&abi).unwrap();
+        assert!(json.contains(r#""type":{"kind":"record","name":"token"}"#));
+        assert_eq!(serde_json::from_str::<ProgramAbi>(&json).unwrap(), abi);
+    }
+
+    #[test]
+    fn test_credits_abi() {
+        let abi = ProgramAbi::from(&Program::<Testnet3>::credits().unwrap());
+        assert_eq!(abi.program_id, "credits.aleo");
+        assert_eq!(abi.mappings[0].name, "account");
+        let transfer = abi.function("transfer_private").unwrap();
+        assert_eq!(transfer.inputs[0].value_type, TypeAbi::new(TypeKind::Record, "credits"));
+        assert_eq!(transfer.outputs.len(), 2);
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Tool for deterministically deriving a sequence of Aleo accounts from a single master private key
+///
+/// Each account is identified by an index, so wallets only need to store the master key (or the
+/// mnemonic it was recovered from) to regenerate every account they manage.
+pub struct KeyDerivation<N: Network> {
+    _phantom: std::marker::PhantomData<N>,
+}
+
+impl<N: Network> KeyDerivation<N> {
+    /// Derive the private key of the account at the given index from a master private key
+    pub fn derive_private_key(master_key: &PrivateKey<N>, index: u32) -> Result<PrivateKey<N>> {
+        let domain = Field::<N>::new_domain_separator("AleoAccountDerivation0");
+        let seed = N::hash_psd4(&[domain, master_key.seed(), Field::from_u32(index)])?;
+        PrivateKey::try_from(seed)
+    }
+
+    /// Derive the private keys of `count` consecutive accounts beginning at the `start` index
+    pub fn derive_private_keys(master_key: &PrivateKey<N>, start: u32, count: u32) -> Result<Vec<PrivateKey<N>>> {
+        let end = start.checked_add(count).ok_or_else(|| anyhow!("Account index range overflows a u32"))?;
+        (start..end).map(|index| Self::derive_private_key(master_key, index)).collect()
+    }
+
+    /// Derive the address of the account at the given index from a master private key
+    pub fn derive_address(master_key: &PrivateKey<N>, index: u32) -> Result<Address<N>> {
+        Address::try_from(Self::derive_private_key(master_key, index)?)
+    }
+}
+
+impl<N: Network> Mnemonic<N> {
+    /// Derive the private key of the account at the given index of the mnemonic's account tree
+    pub fn to_private_key_at_index(&self, passphrase: &str, index: u32) -> Result<PrivateKey<N>> {
+        KeyDerivation::derive_private_key(&self.to_private_key(passphrase)?, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use snarkvm_console::{network::Testnet3 as CurrentNetwork, prelude::TestRng};
+
+    #[test]
+    fn test_derivation_is_deterministic() {
+        let mut rng = TestRng::default();
+        let master_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+
+        let first = KeyDerivation::derive_private_key(&master_key, 0).unwrap();
+        assert_eq!(first, KeyDerivation::derive_private_key(&master_key, 0).unwrap());
+        assert_ne!(first, master_key);
+
+        // Ensure the batch derivation matches the individual derivation
+        let keys = KeyDerivation::derive_private_keys(&master_key, 0, 5).unwrap();
+        assert_eq!(keys.len(), 5);
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(key, &KeyDerivation::derive_private_key(&master_key, index as u32).unwrap());
+            assert_eq!(
+                Address::try_from(key).unwrap(),
+                KeyDerivation::derive_address(&master_key, index as u32).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_derivation_produces_distinct_accounts() {
+        let mut rng = TestRng::default();
+        let master_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let other_master_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+
+        let keys = KeyDerivation::derive_private_keys(&master_key, 0, 10).unwrap();
+        for (i, key) in keys.iter().enumerate() {
+            for other in keys.iter().skip(i + 1) {
+                assert_ne!(key, other);
+            }
+        }
+
+        // Ensure different master keys produce different accounts at the same index
+        assert_ne!(keys[0], KeyDerivation::derive_private_key(&other_master_key, 0).unwrap());
+
+        // Ensure ranges which overflow are rejected
+        assert!(KeyDerivation::derive_private_keys(&master_key, u32::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn test_mnemonic_account_derivation() {
+        let mnemonic = Mnemonic::<CurrentNetwork>::new(12).unwrap();
+        let recovered = Mnemonic::<CurrentNetwork>::from_phrase(&mnemonic.phrase()).unwrap();
+        for index in 0..3 {
+            assert_eq!(
+                mnemonic.to_private_key_at_index("", index).unwrap(),
+                recovered.to_private_key_at_index("", index).unwrap()
+            );
+        }
+        assert_ne!(mnemonic.to_private_key_at_index("", 0).unwrap(), mnemonic.to_private_key_at_index("", 1).unwrap());
+    }
+}
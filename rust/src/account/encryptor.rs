@@ -16,7 +16,21 @@
 
 use super::*;
 
-/// Tool for encrypting and decrypting Aleo key material into ciphertext
+use snarkvm_console::{
+    prelude::{FromBytes, FromFields, ToFields},
+    types::{Group, Scalar},
+};
+
+/// Number of message bytes packed into each field element of an encrypted message
+const MESSAGE_BYTES_PER_FIELD: usize = 31;
+
+/// Tool for encrypting and decrypting Aleo key material and messages into ciphertext
+///
+/// Private keys encrypted with a secret are returned as a standard Aleo `Ciphertext`, whose
+/// string form (`ciphertext1...`) is accepted by the CLI, the `ProgramManager` and the wasm
+/// `PrivateKeyCiphertext`, which all decrypt it with this encryptor. Accounts which need a
+/// password based key derivation should be stored in a [Keystore], which encrypts the private
+/// key with AES-256-GCM under a key derived from the password with scrypt.
 pub struct Encryptor<N: Network> {
     _phantom: std::marker::PhantomData<N>,
 }
@@ -33,6 +47,68 @@ impl<N: Network> Encryptor<N> {
         PrivateKey::try_from(seed)
     }
 
+    /// Encrypt an arbitrary message to an Aleo address
+    ///
+    /// The message is encrypted with the same scheme used for records: a random nonce is combined
+    /// with the recipient address via ECDH to derive a symmetric key, which is then used to mask
+    /// the message. Only the holder of the address's view key can decrypt the resulting ciphertext.
+    pub fn encrypt_message_to_address(address: &Address<N>, message: &[u8]) -> Result<Ciphertext<N>> {
+        // Generate a randomizer and the corresponding nonce
+        let mut rng = rand::thread_rng();
+        let randomizer = Scalar::<N>::rand(&mut rng);
+        let nonce = N::g_scalar_multiply(&randomizer);
+
+        // Derive the message view key from the recipient address
+        let message_view_key = (**address * randomizer).to_x_coordinate();
+
+        // Pack the message length and bytes into field elements
+        let mut fields = vec![Field::<N>::from_u64(message.len() as u64)];
+        for chunk in message.chunks(MESSAGE_BYTES_PER_FIELD) {
+            let mut bytes = [0u8; 32];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            fields.push(Field::<N>::from_bytes_le(&bytes)?);
+        }
+
+        // Mask the fields and prepend the nonce so the recipient can recover the message view key
+        let randomizers = N::hash_many_psd8(&[N::encryption_domain(), message_view_key], fields.len());
+        let ciphertext = std::iter::once(nonce.to_x_coordinate())
+            .chain(fields.iter().zip(randomizers).map(|(field, randomizer)| *field + randomizer))
+            .collect::<Vec<_>>();
+        Ciphertext::from_fields(&ciphertext)
+    }
+
+    /// Decrypt a message encrypted to an Aleo address using the address's view key
+    pub fn decrypt_message_with_view_key(ciphertext: &Ciphertext<N>, view_key: &ViewKey<N>) -> Result<Vec<u8>> {
+        let fields = ciphertext.to_fields()?;
+        ensure!(fields.len() >= 2, "The ciphertext is not an encrypted message");
+
+        // Recover the message view key from the nonce
+        let nonce = Group::<N>::from_x_coordinate(fields[0])?;
+        let message_view_key = (nonce * **view_key).to_x_coordinate();
+
+        // Unmask the fields
+        let randomizers = N::hash_many_psd8(&[N::encryption_domain(), message_view_key], fields.len() - 1);
+        let plaintext =
+            fields[1..].iter().zip(randomizers).map(|(field, randomizer)| *field - randomizer).collect::<Vec<_>>();
+
+        // Recover the message length, which will be invalid if the wrong view key was used
+        let length_bytes = plaintext[0].to_bytes_le()?;
+        ensure!(length_bytes[8..].iter().all(|byte| *byte == 0), "Failed to decrypt the message");
+        let length = u64::from_le_bytes(length_bytes[..8].try_into()?) as usize;
+        let capacity = (plaintext.len() - 1) * MESSAGE_BYTES_PER_FIELD;
+        ensure!(length <= capacity && length + MESSAGE_BYTES_PER_FIELD > capacity, "Failed to decrypt the message");
+
+        // Unpack the message bytes
+        let mut message = Vec::with_capacity(capacity);
+        for field in &plaintext[1..] {
+            let bytes = field.to_bytes_le()?;
+            ensure!(bytes[MESSAGE_BYTES_PER_FIELD..].iter().all(|byte| *byte == 0), "Failed to decrypt the message");
+            message.extend_from_slice(&bytes[..MESSAGE_BYTES_PER_FIELD]);
+        }
+        message.truncate(length);
+        Ok(message)
+    }
+
     // Encrypted a field element into a ciphertext representation
     fn encrypt_field(field: &Field<N>, secret: &str, domain: &str) -> Result<Ciphertext<N>> {
         // Derive the domain separators and the secret.
@@ -85,8 +161,35 @@ impl<N: Network> Encryptor<N> {
 mod tests {
     use super::*;
 
+    use rand::Rng;
     use snarkvm_console::{network::Testnet3 as CurrentNetwork, prelude::TestRng};
 
+    #[test]
+    fn test_encryptor_message_encrypt_and_decrypt() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+
+        for length in [0, 1, 30, 31, 32, 62, 100, 500] {
+            let message = (0..length).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let ciphertext = Encryptor::encrypt_message_to_address(&address, &message).unwrap();
+            let recovered = Encryptor::decrypt_message_with_view_key(&ciphertext, &view_key).unwrap();
+            assert_eq!(message, recovered);
+        }
+    }
+
+    #[test]
+    fn test_encryptor_message_wrong_view_key_doesnt_decrypt() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+
+        let ciphertext = Encryptor::encrypt_message_to_address(&address, b"hello aleo").unwrap();
+        assert!(Encryptor::decrypt_message_with_view_key(&ciphertext, &other_view_key).is_err());
+    }
+
     #[test]
     fn test_encryptor_encrypt_and_decrypt() {
         let mut rng = TestRng::default();
@@ -107,6 +210,20 @@ mod tests {
         assert!(recovered_private_key.is_err())
     }
 
+    #[test]
+    fn test_encryptor_ciphertext_string_round_trip() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let enc = Encryptor::<CurrentNetwork>::encrypt_private_key_with_secret(&private_key, "mypassword").unwrap();
+
+        // The ciphertext string is what the CLI and the wasm SDK exchange, so it must decrypt after parsing
+        let ciphertext_string = enc.to_string();
+        assert!(ciphertext_string.starts_with("ciphertext1"));
+        let parsed = Ciphertext::<CurrentNetwork>::from_str(&ciphertext_string).unwrap();
+        assert_eq!(parsed, enc);
+        assert_eq!(Encryptor::decrypt_private_key_with_secret(&parsed, "mypassword").unwrap(), private_key);
+    }
+
     #[test]
     fn test_encryptor_same_secret_doesnt_produce_same_ciphertext_on_different_runs() {
         let mut rng = TestRng::default();
@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm,
+    Nonce,
+};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use snarkvm_console::prelude::FromBytes;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Current version of the keystore file format
+pub const KEYSTORE_VERSION: u32 = 1;
+
+/// Default scrypt cost parameter (N = 2^15) used when creating keystores
+pub const KEYSTORE_DEFAULT_LOG_N: u8 = 15;
+
+const KEYSTORE_CIPHER: &str = "aes-256-gcm";
+const KEYSTORE_KDF: &str = "scrypt";
+const KEYSTORE_KEY_LENGTH: usize = 32;
+
+/// Password protected account file which can be exchanged between the CLI, the rust SDK, and the
+/// browser via the wasm SDK
+///
+/// The private key seed is encrypted with AES-256-GCM using a key derived from the password with
+/// scrypt. The account address is stored in the clear and is authenticated as associated data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    pub address: String,
+    pub crypto: KeystoreCrypto,
+}
+
+/// Encryption parameters and ciphertext of a keystore
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    pub cipher: String,
+    pub ciphertext: String,
+    pub nonce: String,
+    pub kdf: String,
+    pub kdfparams: KeystoreKdfParams,
+}
+
+/// Scrypt parameters used to derive the keystore encryption key from a password
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreKdfParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+    pub dklen: usize,
+    pub salt: String,
+}
+
+impl Keystore {
+    /// Encrypt a private key into a keystore protected by a password
+    pub fn encrypt<N: Network>(private_key: &PrivateKey<N>, password: &str) -> Result<Self> {
+        Self::encrypt_with_cost(private_key, password, KEYSTORE_DEFAULT_LOG_N)
+    }
+
+    /// Encrypt a private key into a keystore using a custom scrypt cost parameter (N = 2^log_n)
+    pub fn encrypt_with_cost<N: Network>(private_key: &PrivateKey<N>, password: &str, log_n: u8) -> Result<Self> {
+        let address = Address::try_from(private_key)?.to_string();
+
+        // Sample the salt and nonce
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; 32];
+        let mut nonce = [0u8; 12];
+        rng.fill_bytes(&mut salt);
+        rng.fill_bytes(&mut nonce);
+
+        // Derive the encryption key and encrypt the private key seed
+        let kdfparams = KeystoreKdfParams { log_n, r: 8, p: 1, dklen: KEYSTORE_KEY_LENGTH, salt: hex::encode(salt) };
+        let cipher = Self::cipher(password, &kdfparams)?;
+        let seed = private_key_seed_bytes(private_key)?;
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: &seed, aad: address.as_bytes() })
+            .map_err(|_| anyhow!("Failed to encrypt the private key"));
+
+        let crypto = KeystoreCrypto {
+            cipher: KEYSTORE_CIPHER.to_string(),
+            ciphertext: hex::encode(ciphertext?),
+            nonce: hex::encode(nonce),
+            kdf: KEYSTORE_KDF.to_string(),
+            kdfparams,
+        };
+        Ok(Self { version: KEYSTORE_VERSION, address, crypto })
+    }
+
+    /// Decrypt the private key stored in the keystore using its password
+    pub fn decrypt<N: Network>(&self, password: &str) -> Result<PrivateKey<N>> {
+        ensure!(self.version == KEYSTORE_VERSION, "Unsupported keystore version {}", self.version);
+        ensure!(self.crypto.cipher == KEYSTORE_CIPHER, "Unsupported keystore cipher {}", self.crypto.cipher);
+        ensure!(self.crypto.kdf == KEYSTORE_KDF, "Unsupported keystore key derivation function {}", self.crypto.kdf);
+
+        let nonce = hex::decode(&self.crypto.nonce)?;
+        ensure!(nonce.len() == 12, "The keystore nonce must be 12 bytes");
+        let ciphertext = hex::decode(&self.crypto.ciphertext)?;
+
+        // Derive the encryption key and decrypt the private key seed
+        let cipher = Self::cipher(password, &self.crypto.kdfparams)?;
+        let seed = Zeroizing::new(
+            cipher
+                .decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: self.address.as_bytes() })
+                .map_err(|_| anyhow!("Failed to decrypt the keystore, the password may be incorrect"))?,
+        );
+        let private_key = PrivateKey::try_from(Field::<N>::from_bytes_le(&seed)?)?;
+
+        // Ensure the decrypted key matches the address recorded in the keystore
+        ensure!(
+            Address::try_from(&private_key)?.to_string() == self.address,
+            "The keystore private key does not match its address"
+        );
+        Ok(private_key)
+    }
+
+    /// Get the address of the account stored in the keystore
+    pub fn address<N: Network>(&self) -> Result<Address<N>> {
+        Address::from_str(&self.address)
+    }
+
+    /// Serialize the keystore into its JSON representation
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Read a keystore from its JSON representation
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    // Derive the AES-GCM cipher from a password and the scrypt parameters
+    fn cipher(password: &str, kdfparams: &KeystoreKdfParams) -> Result<Aes256Gcm> {
+        ensure!(kdfparams.dklen == KEYSTORE_KEY_LENGTH, "The keystore key length must be {KEYSTORE_KEY_LENGTH} bytes");
+        let salt = hex::decode(&kdfparams.salt)?;
+        let params = scrypt::Params::new(kdfparams.log_n, kdfparams.r, kdfparams.p, kdfparams.dklen)
+            .map_err(|_| anyhow!("Invalid keystore scrypt parameters"))?;
+
+        let mut key = [0u8; KEYSTORE_KEY_LENGTH];
+        scrypt::scrypt(password.as_bytes(), &salt, &params, &mut key)
+            .map_err(|_| anyhow!("Failed to derive the keystore encryption key"))?;
+        let cipher = Aes256Gcm::new_from_slice(&key).map_err(|_| anyhow!("Invalid keystore encryption key"));
+        key.zeroize();
+        cipher
+    }
+}
+
+impl FromStr for Keystore {
+    type Err = Error;
+
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        Self::from_json(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use snarkvm_console::{network::Testnet3 as CurrentNetwork, prelude::TestRng};
+
+    // Use a low scrypt cost to keep the tests fast
+    const TEST_LOG_N: u8 = 10;
+
+    #[test]
+    fn test_keystore_encrypt_and_decrypt() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let keystore = Keystore::encrypt_with_cost(&private_key, "mypassword", TEST_LOG_N).unwrap();
+        assert_eq!(keystore.address::<CurrentNetwork>().unwrap(), Address::try_from(private_key).unwrap());
+
+        // Ensure the keystore survives a JSON round trip
+        let recovered = Keystore::from_json(&keystore.to_json().unwrap()).unwrap();
+        assert_eq!(recovered, keystore);
+        assert_eq!(recovered.decrypt::<CurrentNetwork>("mypassword").unwrap(), private_key);
+    }
+
+    #[test]
+    fn test_keystore_wrong_password_doesnt_decrypt() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let keystore = Keystore::encrypt_with_cost(&private_key, "mypassword", TEST_LOG_N).unwrap();
+        assert!(keystore.decrypt::<CurrentNetwork>("wrong_password").is_err());
+    }
+
+    #[test]
+    fn test_keystore_tampered_address_doesnt_decrypt() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let mut keystore = Keystore::encrypt_with_cost(&private_key, "mypassword", TEST_LOG_N).unwrap();
+        keystore.address = Address::try_from(other_private_key).unwrap().to_string();
+        assert!(keystore.decrypt::<CurrentNetwork>("mypassword").is_err());
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use rand::RngCore;
+use snarkvm_console::{network::Environment, prelude::PrimeField};
+use zeroize::Zeroize;
+
+/// BIP-39 mnemonic phrase from which an Aleo private key can be deterministically derived
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mnemonic<N: Network> {
+    mnemonic: bip39::Mnemonic,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> Mnemonic<N> {
+    /// Generate a new random mnemonic phrase with the specified number of words (12 or 24)
+    pub fn new(word_count: usize) -> Result<Self> {
+        let entropy_length = match word_count {
+            12 => 16,
+            24 => 32,
+            _ => bail!("Mnemonic phrases must be 12 or 24 words long, {word_count} words were requested"),
+        };
+        let mut entropy = vec![0u8; entropy_length];
+        rand::thread_rng().fill_bytes(&mut entropy);
+        let mnemonic = bip39::Mnemonic::from_entropy(&entropy).map_err(|error| anyhow!("{error}"));
+        entropy.zeroize();
+        Ok(Self { mnemonic: mnemonic?, _network: PhantomData })
+    }
+
+    /// Recover a mnemonic from an existing phrase, validating its words and checksum
+    pub fn from_phrase(phrase: &str) -> Result<Self> {
+        let normalized = phrase.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        let mnemonic =
+            bip39::Mnemonic::parse_normalized(&normalized).map_err(|error| anyhow!("Invalid mnemonic: {error}"))?;
+        ensure!(
+            matches!(mnemonic.word_count(), 12 | 24),
+            "Mnemonic phrases must be 12 or 24 words long, found {} words",
+            mnemonic.word_count()
+        );
+        Ok(Self { mnemonic, _network: PhantomData })
+    }
+
+    /// Get the words of the mnemonic phrase
+    pub fn phrase(&self) -> String {
+        self.mnemonic.to_string()
+    }
+
+    /// Get the number of words in the mnemonic phrase
+    pub fn word_count(&self) -> usize {
+        self.mnemonic.word_count()
+    }
+
+    /// Get the 64 byte BIP-39 seed of the mnemonic, optionally protected by a passphrase
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        self.mnemonic.to_seed_normalized(passphrase)
+    }
+
+    /// Deterministically derive the Aleo private key corresponding to the mnemonic and passphrase
+    pub fn to_private_key(&self, passphrase: &str) -> Result<PrivateKey<N>> {
+        let mut seed = self.to_seed(passphrase);
+        let field = Field::<N>::new(<N as Environment>::Field::from_bytes_le_mod_order(&seed));
+        seed.zeroize();
+        PrivateKey::try_from(field)
+    }
+}
+
+impl<N: Network> Drop for Mnemonic<N> {
+    fn drop(&mut self) {
+        self.mnemonic.zeroize();
+    }
+}
+
+impl<N: Network> FromStr for Mnemonic<N> {
+    type Err = Error;
+
+    fn from_str(phrase: &str) -> Result<Self, Self::Err> {
+        Self::from_phrase(phrase)
+    }
+}
+
+impl<N: Network> std::fmt::Display for Mnemonic<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.mnemonic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use snarkvm_console::network::Testnet3 as CurrentNetwork;
+
+    #[test]
+    fn test_mnemonic_generation_word_counts() {
+        let mnemonic = Mnemonic::<CurrentNetwork>::new(12).unwrap();
+        assert_eq!(mnemonic.word_count(), 12);
+        assert_eq!(mnemonic.phrase().split(' ').count(), 12);
+
+        let mnemonic = Mnemonic::<CurrentNetwork>::new(24).unwrap();
+        assert_eq!(mnemonic.word_count(), 24);
+        assert_eq!(mnemonic.phrase().split(' ').count(), 24);
+
+        // Ensure unsupported word counts are rejected
+        assert!(Mnemonic::<CurrentNetwork>::new(0).is_err());
+        assert!(Mnemonic::<CurrentNetwork>::new(15).is_err());
+    }
+
+    #[test]
+    fn test_mnemonic_recovers_same_private_key() {
+        let mnemonic = Mnemonic::<CurrentNetwork>::new(24).unwrap();
+        let private_key = mnemonic.to_private_key("").unwrap();
+
+        // Ensure the phrase recovers the same account
+        let recovered = Mnemonic::<CurrentNetwork>::from_phrase(&mnemonic.phrase()).unwrap();
+        assert_eq!(mnemonic, recovered);
+        assert_eq!(private_key, recovered.to_private_key("").unwrap());
+
+        // Ensure extra whitespace and capitalization do not change the recovered account
+        let messy_phrase = format!("  {}  ", mnemonic.phrase().to_uppercase().replace(' ', "   "));
+        let recovered = Mnemonic::<CurrentNetwork>::from_str(&messy_phrase).unwrap();
+        assert_eq!(private_key, recovered.to_private_key("").unwrap());
+    }
+
+    #[test]
+    fn test_mnemonic_passphrase_changes_private_key() {
+        let mnemonic = Mnemonic::<CurrentNetwork>::new(12).unwrap();
+        let private_key = mnemonic.to_private_key("").unwrap();
+        let private_key_with_passphrase = mnemonic.to_private_key("passphrase").unwrap();
+        assert_ne!(private_key, private_key_with_passphrase);
+        assert_eq!(private_key_with_passphrase, mnemonic.to_private_key("passphrase").unwrap());
+    }
+
+    #[test]
+    fn test_mnemonic_invalid_phrases_fail() {
+        // Ensure words outside the wordlist are rejected
+        assert!(Mnemonic::<CurrentNetwork>::from_phrase("not a real mnemonic phrase at all").is_err());
+
+        // Ensure a bad checksum is rejected
+        let bad_checksum = ["abandon"; 12].join(" ");
+        assert!(Mnemonic::<CurrentNetwork>::from_phrase(&bad_checksum).is_err());
+
+        // Ensure a valid phrase of an unsupported length is rejected
+        let fifteen_words = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon address";
+        assert!(Mnemonic::<CurrentNetwork>::from_phrase(fifteen_words).is_err());
+    }
+}
@@ -15,8 +15,29 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 //! Tools for working with Aleo Accounts
+//!
+//! The key types of snarkVM cannot be zeroized without unsafe code. Instead, [wipe_private_key]
+//! and [wipe_view_key] overwrite a key in place with a publicly known key, and serialized copies
+//! of a private key are held in buffers which are zeroized when dropped. Secrets handled by this
+//! module itself (mnemonic phrases, seeds and keystore encryption keys) are zeroized once they are
+//! no longer needed.
 
 use super::*;
 
+pub mod derivation;
+pub use derivation::*;
+
 pub mod encryptor;
 pub use encryptor::*;
+
+pub mod keystore;
+pub use keystore::*;
+
+pub mod mnemonic;
+pub use mnemonic::*;
+
+pub mod secrets;
+pub use secrets::*;
+
+pub mod typed_signing;
+pub use typed_signing::*;
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_console::prelude::{ToBytes, Zero};
+use zeroize::Zeroizing;
+
+/// Serialize the seed of a private key into a buffer which is zeroized when it is dropped
+///
+/// The private key can be recovered from its seed, so copies of the seed must not outlive the
+/// operation they are made for.
+pub fn private_key_seed_bytes<N: Network>(private_key: &PrivateKey<N>) -> Result<Zeroizing<Vec<u8>>> {
+    Ok(Zeroizing::new(private_key.seed().to_bytes_le()?))
+}
+
+/// Overwrite a private key in place with the publicly known key derived from the zero seed
+///
+/// The key types of snarkVM cannot be zeroized without unsafe code, so the key is replaced by
+/// another key stored at the same location instead. The private key must not be used afterwards.
+pub fn wipe_private_key<N: Network>(private_key: &mut PrivateKey<N>) -> Result<()> {
+    *private_key = PrivateKey::try_from(Field::zero())?;
+    // Prevent the compiler from eliding the write
+    std::hint::black_box(private_key);
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    Ok(())
+}
+
+/// Overwrite a view key in place with the view key of the zero seed private key
+///
+/// The view key must not be used afterwards.
+pub fn wipe_view_key<N: Network>(view_key: &mut ViewKey<N>) -> Result<()> {
+    *view_key = ViewKey::try_from(PrivateKey::try_from(Field::zero())?)?;
+    std::hint::black_box(view_key);
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use snarkvm_console::{
+        network::Testnet3 as CurrentNetwork,
+        prelude::{FromBytes, TestRng},
+    };
+
+    #[test]
+    fn test_private_key_seed_bytes() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let seed = private_key_seed_bytes(&private_key).unwrap();
+        assert_eq!(PrivateKey::try_from(Field::from_bytes_le(&seed).unwrap()).unwrap(), private_key);
+    }
+
+    #[test]
+    fn test_wipe_private_key() {
+        let mut rng = TestRng::default();
+        let mut private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let mut view_key = ViewKey::try_from(private_key).unwrap();
+        wipe_private_key(&mut private_key).unwrap();
+        wipe_view_key(&mut view_key).unwrap();
+
+        let zero_key = PrivateKey::<CurrentNetwork>::try_from(Field::zero()).unwrap();
+        assert_eq!(private_key, zero_key);
+        assert_eq!(view_key, ViewKey::try_from(zero_key).unwrap());
+    }
+}
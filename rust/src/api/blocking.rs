@@ -16,99 +16,153 @@
 
 use super::*;
 
-#[cfg(not(feature = "async"))]
+use std::thread;
+
+/// The maximum number of block requests sent at once by `get_blocks_in_range`
+const MAX_BLOCK_REQUESTS_IN_FLIGHT: usize = 8;
+
 #[allow(clippy::type_complexity)]
 impl<N: Network> AleoAPIClient<N> {
     pub fn latest_height(&self) -> Result<u32> {
-        let url = format!("{}/{}/latest/height", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(height) => Ok(height),
-            Err(error) => bail!("Failed to parse the latest block height: {error}"),
-        }
+        self.rpc.latest_height()
     }
 
     pub fn latest_hash(&self) -> Result<N::BlockHash> {
-        let url = format!("{}/{}/latest/hash", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(hash) => Ok(hash),
-            Err(error) => bail!("Failed to parse the latest block hash: {error}"),
-        }
+        self.rpc.latest_hash()
     }
 
     pub fn latest_block(&self) -> Result<Block<N>> {
-        let url = format!("{}/{}/latest/block", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(block) => Ok(block),
-            Err(error) => bail!("Failed to parse the latest block: {error}"),
-        }
+        self.rpc.latest_block()
     }
 
     pub fn get_block(&self, height: u32) -> Result<Block<N>> {
-        let url = format!("{}/{}/block/{height}", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(block) => Ok(block),
-            Err(error) => bail!("Failed to parse block {height}: {error}"),
-        }
+        self.rpc.get_block(height)
     }
 
     pub fn get_blocks(&self, start_height: u32, end_height: u32) -> Result<Vec<Block<N>>> {
-        if start_height >= end_height {
-            bail!("Start height must be less than end height");
-        } else if end_height - start_height > 50 {
-            bail!("Cannot request more than 50 blocks at a time");
-        }
+        self.rpc.get_blocks(start_height..end_height)
+    }
 
-        let url = format!("{}/{}/blocks?start={start_height}&end={end_height}", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(blocks) => Ok(blocks),
-            Err(error) => {
-                bail!("Failed to parse blocks {start_height} (inclusive) to {end_height} (exclusive): {error}")
-            }
+    /// Fetch the blocks in a range of heights of any size, ordered by height
+    ///
+    /// The range is split into requests of `MAX_BLOCKS_PER_REQUEST` blocks and at most 8 requests
+    /// are in flight at once, which keeps large ranges fast on slow endpoints without overwhelming
+    /// the node. Every client which follows the chain fetches its blocks through this method.
+    pub fn get_blocks_in_range(&self, start_height: u32, end_height: u32) -> Result<Vec<Block<N>>> {
+        ensure!(start_height < end_height, AleoSdkError::invalid_input("Start height must be less than end height"));
+        let batches = block_batches(start_height..end_height, MAX_BLOCKS_PER_REQUEST);
+        let workers = MAX_BLOCK_REQUESTS_IN_FLIGHT.min(batches.len());
+
+        // Worker i fetches batches i, i + workers, i + 2 * workers, ..
+        let mut results = thread::scope(|scope| {
+            let handles = (0..workers)
+                .map(|worker| {
+                    let batches = &batches;
+                    scope.spawn(move || {
+                        batches
+                            .iter()
+                            .enumerate()
+                            .skip(worker)
+                            .step_by(workers)
+                            .map(|(index, batch)| (index, self.get_blocks(batch.start, batch.end)))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().map_err(|_| anyhow!("A block request thread panicked")))
+                .collect::<Result<Vec<_>>>()
+        })?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        results.sort_by_key(|(index, _)| *index);
+        let mut blocks = Vec::with_capacity((end_height - start_height) as usize);
+        for (_, batch) in results {
+            blocks.extend(batch?);
         }
+        Ok(blocks)
     }
 
     pub fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
-        let url = format!("{}/{}/transaction/{transaction_id}", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(transaction) => Ok(transaction),
-            Err(error) => bail!("Failed to parse transaction '{transaction_id}': {error}"),
-        }
+        self.rpc.get_transaction(transaction_id)
     }
 
     pub fn get_memory_pool_transactions(&self) -> Result<Vec<Transaction<N>>> {
-        let url = format!("{}/{}/memoryPool/transactions", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(transactions) => Ok(transactions),
-            Err(error) => bail!("Failed to parse memory pool transactions: {error}"),
-        }
+        self.rpc.get_memory_pool_transactions()
     }
 
     pub fn get_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<Program<N>> {
-        // Prepare the program ID.
-        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
-        // Perform the request.
-        let url = format!("{}/{}/program/{program_id}", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(program) => Ok(program),
-            Err(error) => bail!("Failed to parse program {program_id}: {error}"),
+        self.rpc.get_program(program_id)
+    }
+
+    /// Get a deployed program, or `None` if no program with the id is deployed
+    pub fn find_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<Option<Program<N>>> {
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
+        // The node responds with 404 when the program is not deployed
+        self.rpc.find(&Endpoint::Program(program_id))
+    }
+
+    /// Get the names of the mappings of a deployed program
+    pub fn get_mapping_names(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<Vec<Identifier<N>>> {
+        self.rpc.get_mapping_names(program_id)
+    }
+
+    /// Get the value stored under a key in a mapping of a deployed program, or `None` if the key
+    /// is not present in the mapping
+    pub fn get_mapping_value(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        mapping_name: impl TryInto<Identifier<N>>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.rpc.get_mapping_value(program_id, mapping_name, key)
+    }
+
+    /// Get the public balance of an account in microcredits
+    pub fn get_public_balance(&self, address: &Address<N>) -> Result<u64> {
+        match self.get_mapping_value("credits.aleo", "account", &Plaintext::from_str(&address.to_string())?)? {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => Ok(*balance),
+            Some(value) => bail!("The public balance of {address} is not a u64: {value}"),
+            None => Ok(0),
         }
     }
 
     pub fn find_block_hash(&self, transaction_id: N::TransactionID) -> Result<N::BlockHash> {
-        let url = format!("{}/{}/find/blockHash/{transaction_id}", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(hash) => Ok(hash),
-            Err(error) => bail!("Failed to parse block hash: {error}"),
-        }
+        self.rpc.get(&Endpoint::FindBlockHash(transaction_id))
     }
 
     /// Returns the transition ID that contains the given `input ID` or `output ID`.
     pub fn find_transition_id(&self, input_or_output_id: Field<N>) -> Result<N::TransitionID> {
-        let url = format!("{}/{}/find/transitionID/{input_or_output_id}", self.base_url, self.network_id);
-        match self.client.get(&url).call()?.into_json() {
-            Ok(transition_id) => Ok(transition_id),
-            Err(error) => bail!("Failed to parse transition ID: {error}"),
-        }
+        self.rpc.get(&Endpoint::FindTransitionID(input_or_output_id))
+    }
+
+    /// Returns `true` if the serial number has been revealed on chain, meaning its record is spent.
+    pub fn is_serial_number_spent(&self, serial_number: Field<N>) -> Result<bool> {
+        // The node responds with 404 when no transition contains the serial number
+        let transition_id: Option<N::TransitionID> = self.rpc.find(&Endpoint::FindTransitionID(serial_number))?;
+        Ok(transition_id.is_some())
+    }
+
+    /// Determine which of a list of records owned by the private key have been spent on chain.
+    ///
+    /// The returned vector contains one entry for each record in the order given.
+    pub fn find_spent_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: &[Record<N, Plaintext<N>>],
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+    ) -> Result<Vec<bool>> {
+        records
+            .iter()
+            .map(|record| {
+                let serial_number = SpentRecords::serial_number(private_key, record, program_id, record_name)?;
+                self.is_serial_number_spent(serial_number)
+            })
+            .collect()
     }
 
     /// Scans the ledger for records that match the given view key.
@@ -119,7 +173,7 @@ impl<N: Network> AleoAPIClient<N> {
         max_records: Option<usize>,
     ) -> Result<Vec<(Field<N>, Record<N, Ciphertext<N>>)>> {
         // Prepare the view key.
-        let view_key = view_key.try_into().map_err(|_| anyhow!("Invalid view key"))?;
+        let view_key = view_key.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid view key"))?;
         // Compute the x-coordinate of the address.
         let address_x_coordinate = view_key.to_address().to_x_coordinate();
 
@@ -174,7 +228,7 @@ impl<N: Network> AleoAPIClient<N> {
 
         ensure!(
             block_heights.start < block_heights.end,
-            "The start block height must be less than the end block height"
+            AleoSdkError::invalid_input("The start block height must be less than the end block height")
         );
 
         // Initialize a vector for the records.
@@ -252,8 +306,8 @@ impl<N: Network> AleoAPIClient<N> {
 
     /// Broadcast a deploy or execute transaction to the Aleo network
     pub fn transaction_broadcast(&self, transaction: Transaction<N>) -> Result<String> {
-        let url = format!("{}/{}/transaction/broadcast", self.base_url, self.network_id);
-        match self.client.post(&url).send_json(&transaction) {
+        let url = self.rpc.url(&Endpoint::TransactionBroadcast);
+        match self.rpc.http().post_json(&url, &transaction) {
             Ok(response) => match response.into_string() {
                 Ok(success_response) => Ok(success_response),
                 Err(error) => bail!("❌ Transaction response was malformed {}", error),
@@ -282,10 +336,54 @@ impl<N: Network> AleoAPIClient<N> {
     }
 }
 
+/// Split a range of block heights into consecutive batches of at most `batch_size` blocks
+pub(crate) fn block_batches(block_heights: Range<u32>, batch_size: u32) -> Vec<Range<u32>> {
+    (block_heights.start..block_heights.end)
+        .step_by(batch_size as usize)
+        .map(|start| start..start.saturating_add(batch_size).min(block_heights.end))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_block_batches() {
+        assert_eq!(block_batches(0..120, 50), vec![0..50, 50..100, 100..120]);
+        assert_eq!(block_batches(10..60, 50), vec![10..60]);
+        assert_eq!(block_batches(5..6, 50), vec![5..6]);
+        assert!(block_batches(5..5, 50).is_empty());
+    }
+
+    #[test]
+    fn test_is_serial_number_spent_status() {
+        let serial_number = Field::<Testnet3>::from_u64(1);
+        let url = serve_responses(vec![
+            (200, "\"au1d2ckmh2gcg4aurvysmqxyw4qnw5jgjzy9a7ygc3sxzdfl4dmhvqsl4wc8w\""),
+            (404, "Not found"),
+            (400, "Bad request"),
+        ]);
+        let api_client = local_client(&url);
+
+        assert!(api_client.is_serial_number_spent(serial_number).unwrap());
+        assert!(!api_client.is_serial_number_spent(serial_number).unwrap());
+        // Statuses other than 404 are errors rather than an unspent record
+        let error = api_client.is_serial_number_spent(serial_number).unwrap_err();
+        assert!(matches!(AleoSdkError::find(&error), Some(AleoSdkError::Network { status: Some(400), .. })));
+    }
+
+    #[test]
+    fn test_find_program_status() {
+        let url = serve_responses(vec![(404, "Not found"), (400, "Bad request")]);
+        let api_client = local_client(&url);
+
+        assert!(api_client.find_program("hello.aleo").unwrap().is_none());
+        // Statuses other than 404 are errors rather than a missing program
+        let error = api_client.find_program("hello.aleo").unwrap_err();
+        assert!(matches!(AleoSdkError::find(&error), Some(AleoSdkError::Network { status: Some(400), .. })));
+    }
+
     #[test]
     fn test_api_get_blocks() {
         let client = AleoAPIClient::<Testnet3>::testnet3();
@@ -300,4 +398,29 @@ mod tests {
         assert_eq!(blocks[1].previous_hash(), blocks[0].hash());
         assert_eq!(blocks[2].previous_hash(), blocks[1].hash());
     }
+
+    #[test]
+    fn test_api_get_mappings() {
+        let client = AleoAPIClient::<Testnet3>::testnet3();
+        let mapping_names = client.get_mapping_names("credits.aleo").unwrap();
+        assert!(mapping_names.contains(&Identifier::from_str("account").unwrap()));
+
+        // Accounts which never received public credits have no entry in the account mapping
+        let private_key = PrivateKey::<Testnet3>::new(&mut rand::thread_rng()).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        assert!(client
+            .get_mapping_value("credits.aleo", "account", &Plaintext::from_str(&address.to_string()).unwrap())
+            .unwrap()
+            .is_none());
+        assert_eq!(client.get_public_balance(&address).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_api_get_blocks_in_range() {
+        let client = AleoAPIClient::<Testnet3>::testnet3();
+        let blocks = client.get_blocks_in_range(0, 120).unwrap();
+        assert_eq!(blocks.len(), 120);
+        assert!(blocks.iter().enumerate().all(|(height, block)| block.height() == height as u32));
+        assert!(client.get_blocks_in_range(5, 5).is_err());
+    }
 }
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The default interval between two checks of whether a transaction has been confirmed
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The status of a broadcast transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfirmationStatus<N: Network> {
+    /// The transaction was included in the block with this hash
+    Confirmed(N::BlockHash),
+    /// The transaction left the memory pool of the node without being included in a block
+    Rejected,
+    /// The transaction was not confirmed before the timeout
+    TimedOut,
+}
+
+/// Client which broadcasts transactions to an Aleo node and tracks their confirmation
+///
+/// Broadcasts which fail because of a transport error or a server side error (5xx or 429) are
+/// retried with an exponential backoff, while requests rejected by the node are not retried.
+#[derive(Clone, Debug)]
+pub struct BroadcastClient<N: Network> {
+    api_client: AleoAPIClient<N>,
+    retry_policy: RetryPolicy,
+    poll_interval: Duration,
+}
+
+impl<N: Network> BroadcastClient<N> {
+    /// Create a broadcast client which retries a failed broadcast up to `max_retries` times,
+    /// waiting `initial_backoff` before the first retry and doubling the wait after every retry
+    pub fn new(api_client: AleoAPIClient<N>, max_retries: u32, initial_backoff: Duration) -> Self {
+        let retry_policy = RetryPolicy::new(max_retries, initial_backoff);
+        Self { api_client, retry_policy, poll_interval: CONFIRMATION_POLL_INTERVAL }
+    }
+
+    /// Create a broadcast client which retries a failed broadcast according to the retry policy
+    /// of the API client
+    pub fn with_client_retry_policy(api_client: AleoAPIClient<N>) -> Self {
+        let retry_policy = api_client.config().retry_policy.clone();
+        Self { api_client, retry_policy, poll_interval: CONFIRMATION_POLL_INTERVAL }
+    }
+
+    /// Set the interval between two checks of whether a transaction has been confirmed, which
+    /// can be shortened for nodes producing blocks quickly (i.e. development nodes)
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Get the API client used by the broadcast client
+    pub fn api_client(&self) -> &AleoAPIClient<N> {
+        &self.api_client
+    }
+
+    /// Broadcast a transaction to the node and return the response of the node
+    pub fn broadcast(&self, transaction: &Transaction<N>) -> Result<String> {
+        let url = self.api_client.rpc().url(&Endpoint::TransactionBroadcast);
+        let _span = info_span!("broadcast", transaction = %transaction.id()).entered();
+        let mut attempt = 0;
+        loop {
+            let error = match self.api_client.rpc().http().request("POST", &url).send_json(transaction) {
+                Ok(response) => {
+                    return response
+                        .into_string()
+                        .map_err(|error| anyhow!("Transaction response was malformed {error}"));
+                }
+                Err(ureq::Error::Status(code, response)) if !is_transient_status(code) => {
+                    bail!(
+                        "Transaction {} was rejected (status code {code}: {:?})",
+                        transaction.id(),
+                        response.into_string()?
+                    )
+                }
+                Err(ureq::Error::Status(code, _)) => format!("status code {code}"),
+                Err(ureq::Error::Transport(error)) => error.to_string(),
+            };
+            if attempt >= self.retry_policy.max_retries {
+                bail!("Failed to broadcast transaction {} after {} attempts ({error})", transaction.id(), attempt + 1);
+            }
+            warn!(attempt, "Broadcast failed, retrying ({error})");
+            thread::sleep(self.retry_policy.delay(attempt));
+            attempt += 1;
+        }
+    }
+
+    /// Broadcast a transaction and wait until it is confirmed, rejected, or the timeout elapses
+    pub fn broadcast_and_wait(&self, transaction: &Transaction<N>, timeout: Duration) -> Result<ConfirmationStatus<N>> {
+        self.broadcast(transaction)?;
+        self.wait_for_confirmation(transaction.id(), timeout)
+    }
+
+    /// Poll the node until a transaction is included in a block, leaves the memory pool without
+    /// being included, or the timeout elapses
+    ///
+    /// A transaction is only reported as rejected once it has been seen in the memory pool and
+    /// then disappeared from it without being included in a block. Nodes which do not expose
+    /// their memory pool only report confirmed transactions and timeouts.
+    pub fn wait_for_confirmation(
+        &self,
+        transaction_id: N::TransactionID,
+        timeout: Duration,
+    ) -> Result<ConfirmationStatus<N>> {
+        let deadline = Instant::now() + timeout;
+        let mut seen_in_memory_pool = false;
+        loop {
+            if let Ok(block_hash) = self.api_client.find_block_hash(transaction_id) {
+                return Ok(ConfirmationStatus::Confirmed(block_hash));
+            }
+            if let Ok(transactions) = self.api_client.get_memory_pool_transactions() {
+                let in_memory_pool = transactions.iter().any(|transaction| transaction.id() == transaction_id);
+                if seen_in_memory_pool && !in_memory_pool {
+                    // The transaction may have been included between the two requests
+                    return match self.api_client.find_block_hash(transaction_id) {
+                        Ok(block_hash) => Ok(ConfirmationStatus::Confirmed(block_hash)),
+                        Err(_) => Ok(ConfirmationStatus::Rejected),
+                    };
+                }
+                seen_in_memory_pool |= in_memory_pool;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(ConfirmationStatus::TimedOut);
+            }
+            thread::sleep(self.poll_interval.min(deadline - now));
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The state of a transaction as seen by an Aleo node
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionStatus<N: Network> {
+    /// The transaction was included in the block with this hash
+    Confirmed(N::BlockHash),
+    /// The transaction is in the memory pool of the node, waiting to be included in a block
+    Pending,
+    /// The node does not know the transaction, it was never received or was dropped from the
+    /// memory pool without being included in a block
+    Unknown,
+}
+
+impl<N: Network> TransactionStatus<N> {
+    /// Check if the transaction was included in a block
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self, Self::Confirmed(_))
+    }
+
+    /// Check if the transaction is waiting in the memory pool
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+}
+
+impl<N: Network> AleoAPIClient<N> {
+    /// Get the ids of the transactions in the memory pool of the node
+    pub fn get_memory_pool_transaction_ids(&self) -> Result<Vec<N::TransactionID>> {
+        Ok(self.get_memory_pool_transactions()?.iter().map(|transaction| transaction.id()).collect())
+    }
+
+    /// Check if a transaction is in the memory pool of the node, waiting to be included in a block
+    pub fn is_transaction_pending(&self, transaction_id: N::TransactionID) -> Result<bool> {
+        Ok(self.get_memory_pool_transaction_ids()?.contains(&transaction_id))
+    }
+
+    /// Get the status of a transaction, i.e. to show pending transactions in a wallet or to avoid
+    /// broadcasting a transaction twice
+    ///
+    /// Only a 404 response to the block lookup is treated as the transaction not being confirmed,
+    /// any other failure to reach the node is returned as an error.
+    pub fn get_transaction_status(&self, transaction_id: N::TransactionID) -> Result<TransactionStatus<N>> {
+        if let Some(block_hash) = self.find_confirmed_block_hash(transaction_id)? {
+            return Ok(TransactionStatus::Confirmed(block_hash));
+        }
+        if self.is_transaction_pending(transaction_id)? {
+            return Ok(TransactionStatus::Pending);
+        }
+        // The transaction may have been included between the two requests
+        match self.find_confirmed_block_hash(transaction_id)? {
+            Some(block_hash) => Ok(TransactionStatus::Confirmed(block_hash)),
+            None => Ok(TransactionStatus::Unknown),
+        }
+    }
+
+    // Get the hash of the block containing a transaction, or `None` if the node responds with 404
+    fn find_confirmed_block_hash(&self, transaction_id: N::TransactionID) -> Result<Option<N::BlockHash>> {
+        match self.find_block_hash(transaction_id) {
+            Ok(block_hash) => Ok(Some(block_hash)),
+            Err(error)
+                if matches!(AleoSdkError::find(&error), Some(AleoSdkError::Network { status: Some(404), .. })) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_status_status_codes() {
+        let transaction_id = <Testnet3 as Network>::TransactionID::from(Field::from_u64(1));
+
+        // The transaction is neither in a block nor in the memory pool
+        let url = serve_responses(vec![(404, "Not found"), (200, "[]"), (404, "Not found")]);
+        assert_eq!(local_client(&url).get_transaction_status(transaction_id).unwrap(), TransactionStatus::Unknown);
+
+        // A failing node is an error rather than an unknown transaction
+        let url = serve_responses(vec![(500, "Internal server error")]);
+        let error = local_client(&url).get_transaction_status(transaction_id).unwrap_err();
+        assert!(matches!(AleoSdkError::find(&error), Some(AleoSdkError::Network { status: Some(500), .. })));
+    }
+
+    #[test]
+    fn test_api_transaction_status() {
+        let client = AleoAPIClient::<Testnet3>::testnet3();
+        let block = client.get_block(1).unwrap();
+        let transaction_id = block.transactions().iter().next().unwrap().id();
+        assert_eq!(client.get_transaction_status(transaction_id).unwrap(), TransactionStatus::Confirmed(block.hash()));
+        assert!(!client.is_transaction_pending(transaction_id).unwrap());
+    }
+}
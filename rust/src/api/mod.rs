@@ -18,31 +18,57 @@
 
 use super::*;
 
+use tracing::{info_span, warn};
+
 pub mod blocking;
 pub use blocking::*;
 
+pub mod broadcast;
+pub use broadcast::*;
+
+pub mod config;
+pub use config::*;
+
+pub mod mempool;
+pub use mempool::*;
+
+#[cfg(feature = "async")]
+pub mod nonblocking;
+#[cfg(feature = "async")]
+pub use nonblocking::*;
+
+pub mod rpc;
+pub use rpc::*;
+
 /// Aleo API client for interacting with the Aleo Beacon API
+///
+/// Requests are sent through an `AleoRpcClient`, which holds the connection settings and builds
+/// the url of every endpoint.
 #[derive(Clone, Debug)]
 pub struct AleoAPIClient<N: Network> {
-    client: ureq::Agent,
-    base_url: String,
-    network_id: String,
-    _network: PhantomData<N>,
+    rpc: AleoRpcClient<N>,
 }
 
 impl<N: Network> AleoAPIClient<N> {
     pub fn new(base_url: &str, chain: &str) -> Result<Self> {
-        let client = ureq::Agent::new();
-        ensure!(
-            base_url.starts_with("http://") || base_url.starts_with("https://"),
-            "specified url {base_url} invalid, the base url must start with or https:// (or http:// if doing local development)"
-        );
-        Ok(AleoAPIClient {
-            client,
-            base_url: base_url.to_string(),
-            network_id: chain.to_string(),
-            _network: PhantomData,
-        })
+        Self::with_config(base_url, chain, ClientConfig::default())
+    }
+
+    /// Create a client for a node which sends requests with the given timeouts, retry policy,
+    /// proxy and headers
+    pub fn with_config(base_url: &str, chain: &str, config: ClientConfig) -> Result<Self> {
+        Ok(AleoAPIClient { rpc: AleoRpcClient::with_config(base_url, chain, config)? })
+    }
+
+    /// Create a client for a node of the network of `N`, i.e. `AleoAPIClient::<Testnet3>::for_network("http://localhost:3030")`
+    pub fn for_network(base_url: &str) -> Result<Self> {
+        Self::new(base_url, AleoNetwork::of::<N>()?.name())
+    }
+
+    /// Create a client for the public API of the network of `N`
+    pub fn default_for_network() -> Result<Self> {
+        let network = AleoNetwork::of::<N>()?;
+        Self::new(network.default_api_url(), network.name())
     }
 
     pub fn testnet3() -> Self {
@@ -55,11 +81,21 @@ impl<N: Network> AleoAPIClient<N> {
 
     /// Get base URL
     pub fn base_url(&self) -> &str {
-        &self.base_url
+        self.rpc.base_url()
     }
 
     /// Get network ID being interacted with
     pub fn network_id(&self) -> &str {
-        &self.network_id
+        self.rpc.network_id()
+    }
+
+    /// Get the connection settings of the client
+    pub fn config(&self) -> &ClientConfig {
+        self.rpc.config()
+    }
+
+    /// Get the typed client the requests are sent through
+    pub fn rpc(&self) -> &AleoRpcClient<N> {
+        &self.rpc
     }
 }
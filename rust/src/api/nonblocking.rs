@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::time::Duration;
+use tokio::task;
+
+/// Run a blocking request on the blocking thread pool of the tokio runtime
+pub(crate) async fn spawn_request<T: Send + 'static>(
+    request: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    task::spawn_blocking(request).await?
+}
+
+/// Asynchronous Aleo API client for services running on a tokio runtime
+///
+/// Requests are sent by an `AleoAPIClient` on the blocking thread pool of the runtime, so the
+/// executor threads are never blocked while waiting for the node.
+#[derive(Clone, Debug)]
+pub struct AsyncAleoAPIClient<N: Network> {
+    client: AleoAPIClient<N>,
+}
+
+impl<N: Network> From<AleoAPIClient<N>> for AsyncAleoAPIClient<N> {
+    fn from(client: AleoAPIClient<N>) -> Self {
+        Self { client }
+    }
+}
+
+impl<N: Network> AsyncAleoAPIClient<N> {
+    /// Create a client for a node, i.e. `AsyncAleoAPIClient::<Testnet3>::new("http://localhost:3030", "testnet3")`
+    pub fn new(base_url: &str, chain: &str) -> Result<Self> {
+        Ok(Self { client: AleoAPIClient::new(base_url, chain)? })
+    }
+
+    /// Get the blocking client sending the requests
+    pub fn blocking(&self) -> &AleoAPIClient<N> {
+        &self.client
+    }
+
+    /// Send a request with a clone of the blocking client on the blocking thread pool
+    async fn request<T: Send + 'static>(
+        &self,
+        request: impl FnOnce(AleoAPIClient<N>) -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let client = self.client.clone();
+        spawn_request(move || request(client)).await
+    }
+
+    pub async fn latest_height(&self) -> Result<u32> {
+        self.request(|client| client.latest_height()).await
+    }
+
+    pub async fn latest_block(&self) -> Result<Block<N>> {
+        self.request(|client| client.latest_block()).await
+    }
+
+    pub async fn get_block(&self, height: u32) -> Result<Block<N>> {
+        self.request(move |client| client.get_block(height)).await
+    }
+
+    pub async fn get_blocks(&self, start_height: u32, end_height: u32) -> Result<Vec<Block<N>>> {
+        self.request(move |client| client.get_blocks(start_height, end_height)).await
+    }
+
+    /// Fetch the blocks in a range of heights of any size, ordered by height
+    pub async fn get_blocks_in_range(&self, start_height: u32, end_height: u32) -> Result<Vec<Block<N>>> {
+        self.request(move |client| client.get_blocks_in_range(start_height, end_height)).await
+    }
+
+    pub async fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
+        self.request(move |client| client.get_transaction(transaction_id)).await
+    }
+
+    /// Get the transactions in the memory pool of the node
+    pub async fn get_memory_pool_transactions(&self) -> Result<Vec<Transaction<N>>> {
+        self.request(|client| client.get_memory_pool_transactions()).await
+    }
+
+    /// Check if a transaction is in the memory pool of the node, waiting to be included in a block
+    pub async fn is_transaction_pending(&self, transaction_id: N::TransactionID) -> Result<bool> {
+        self.request(move |client| client.is_transaction_pending(transaction_id)).await
+    }
+
+    /// Get the status of a transaction
+    pub async fn get_transaction_status(&self, transaction_id: N::TransactionID) -> Result<TransactionStatus<N>> {
+        self.request(move |client| client.get_transaction_status(transaction_id)).await
+    }
+
+    pub async fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>> {
+        self.request(move |client| client.get_program(program_id)).await
+    }
+
+    /// Get the value stored under a key in a mapping of a deployed program, or `None` if the key
+    /// is not present in the mapping
+    pub async fn get_mapping_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.request(move |client| client.get_mapping_value(program_id, mapping_name, &key)).await
+    }
+
+    /// Get the public balance of an account in microcredits
+    pub async fn get_public_balance(&self, address: Address<N>) -> Result<u64> {
+        self.request(move |client| client.get_public_balance(&address)).await
+    }
+
+    pub async fn find_block_hash(&self, transaction_id: N::TransactionID) -> Result<N::BlockHash> {
+        self.request(move |client| client.find_block_hash(transaction_id)).await
+    }
+
+    /// Returns `true` if the serial number has been revealed on chain, meaning its record is spent.
+    pub async fn is_serial_number_spent(&self, serial_number: Field<N>) -> Result<bool> {
+        self.request(move |client| client.is_serial_number_spent(serial_number)).await
+    }
+
+    /// Scans the ledger for records that match the given view key.
+    pub async fn scan(
+        &self,
+        view_key: ViewKey<N>,
+        block_heights: Range<u32>,
+        max_records: Option<usize>,
+    ) -> Result<Vec<(Field<N>, Record<N, Ciphertext<N>>)>> {
+        self.request(move |client| client.scan(view_key, block_heights, max_records)).await
+    }
+
+    /// Search for unspent records in the ledger
+    pub async fn get_unspent_records(
+        &self,
+        private_key: PrivateKey<N>,
+        block_heights: Range<u32>,
+        max_gates: Option<u64>,
+        specified_amounts: Option<Vec<u64>>,
+    ) -> Result<Vec<(Field<N>, Record<N, Ciphertext<N>>)>> {
+        self.request(move |client| {
+            client.get_unspent_records(&private_key, block_heights, max_gates, specified_amounts.as_ref())
+        })
+        .await
+    }
+
+    pub async fn transaction_broadcast(&self, transaction: Transaction<N>) -> Result<String> {
+        self.request(move |client| client.transaction_broadcast(transaction)).await
+    }
+}
+
+impl<N: Network> BroadcastClient<N> {
+    /// Broadcast a transaction to the node from a tokio runtime and return the response of the node
+    pub async fn broadcast_async(&self, transaction: Transaction<N>) -> Result<String> {
+        let client = self.clone();
+        spawn_request(move || client.broadcast(&transaction)).await
+    }
+
+    /// Broadcast a transaction from a tokio runtime and wait until it is confirmed, rejected, or
+    /// the timeout elapses
+    pub async fn broadcast_and_wait_async(
+        &self,
+        transaction: Transaction<N>,
+        timeout: Duration,
+    ) -> Result<ConfirmationStatus<N>> {
+        let client = self.clone();
+        spawn_request(move || client.broadcast_and_wait(&transaction, timeout)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_spawn_request() {
+        assert_eq!(spawn_request(|| Ok(1u32)).await.unwrap(), 1);
+        assert!(spawn_request(|| -> Result<u32> { bail!("request failed") }).await.is_err());
+
+        // Requests to a node which cannot be reached fail without blocking the runtime
+        let client = AsyncAleoAPIClient::<Testnet3>::new("http://localhost:0", "testnet3").unwrap();
+        assert!(client.latest_height().await.is_err());
+    }
+}
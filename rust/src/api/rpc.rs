@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use serde::de::DeserializeOwned;
+
+/// Typed client for the REST API of an Aleo node
+///
+/// Every request goes through an `Endpoint` and the response is parsed into the snarkVM type of
+/// the endpoint, so callers never format urls themselves. Use `query` to obtain the network state
+/// query needed by the VM when building transactions.
+#[derive(Clone, Debug)]
+pub struct AleoRpcClient<N: Network> {
+    client: HttpClient,
+    base_url: String,
+    network_id: String,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> AleoRpcClient<N> {
+    /// Create a client for a node, the base url must start with http:// or https://
+    pub fn new(base_url: &str, network_id: &str) -> Result<Self> {
+        Self::with_config(base_url, network_id, ClientConfig::default())
+    }
+
+    /// Create a client for a node which sends requests with the given timeouts, retry policy,
+    /// proxy and headers
+    pub fn with_config(base_url: &str, network_id: &str, config: ClientConfig) -> Result<Self> {
+        ensure!(
+            base_url.starts_with("http://") || base_url.starts_with("https://"),
+            AleoSdkError::invalid_input(format!(
+                "specified url {base_url} invalid, the base url must start with or https:// (or http:// if doing local development)"
+            ))
+        );
+        Ok(Self {
+            client: HttpClient::new(config)?,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            network_id: network_id.to_string(),
+            _network: PhantomData,
+        })
+    }
+
+    /// Create a client for a node of the network of `N`
+    pub fn for_network(base_url: &str) -> Result<Self> {
+        Self::new(base_url, AleoNetwork::of::<N>()?.name())
+    }
+
+    /// Create a client for the public testnet3 API
+    pub fn testnet3() -> Self {
+        Self::new("https://vm.aleo.org/api", "testnet3").unwrap()
+    }
+
+    /// Get the base url of the node
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Get the id of the network
+    pub fn network_id(&self) -> &str {
+        &self.network_id
+    }
+
+    /// Get the connection settings of the client
+    pub fn config(&self) -> &ClientConfig {
+        self.client.config()
+    }
+
+    /// Get the HTTP client the requests are sent with
+    pub(crate) fn http(&self) -> &HttpClient {
+        &self.client
+    }
+
+    /// Get the full url of an endpoint
+    pub fn url(&self, endpoint: &Endpoint<N>) -> String {
+        endpoint.url(&self.base_url, &self.network_id)
+    }
+
+    /// Request an endpoint and parse the response
+    pub fn get<T: DeserializeOwned>(&self, endpoint: &Endpoint<N>) -> Result<T> {
+        let url = self.url(endpoint);
+        let response = self.client.fetch(&url)?;
+        response.into_json().map_err(|error| anyhow!("Failed to parse the response of {url}: {error}"))
+    }
+
+    /// Request an endpoint and parse the response, or return `None` if the node responds with 404
+    pub fn find<T: DeserializeOwned>(&self, endpoint: &Endpoint<N>) -> Result<Option<T>> {
+        let url = self.url(endpoint);
+        match self.client.get(&url) {
+            Ok(response) => response
+                .into_json()
+                .map(Some)
+                .map_err(|error| anyhow!("Failed to parse the response of {url}: {error}")),
+            Err(ureq::Error::Status(404, _)) => Ok(None),
+            Err(error) => Err(AleoSdkError::from_request(&url, error).into()),
+        }
+    }
+
+    /// Create a query of the network state of the node for use by the VM
+    pub fn query<B: BlockStorage<N>>(&self) -> Query<N, B> {
+        Query::from(self.base_url.as_str())
+    }
+
+    /// Get the height of the latest block
+    pub fn latest_height(&self) -> Result<u32> {
+        self.get(&Endpoint::LatestHeight)
+    }
+
+    /// Get the hash of the latest block
+    pub fn latest_hash(&self) -> Result<N::BlockHash> {
+        self.get(&Endpoint::LatestHash)
+    }
+
+    /// Get the latest block
+    pub fn latest_block(&self) -> Result<Block<N>> {
+        self.get(&Endpoint::LatestBlock)
+    }
+
+    /// Get the latest state root of the ledger
+    pub fn latest_state_root(&self) -> Result<N::StateRoot> {
+        self.get(&Endpoint::LatestStateRoot)
+    }
+
+    /// Get the block at a height
+    pub fn get_block(&self, height: u32) -> Result<Block<N>> {
+        self.get(&Endpoint::Block(height))
+    }
+
+    /// Get the blocks in a range of heights, which may hold at most `MAX_BLOCKS_PER_REQUEST` blocks
+    pub fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>> {
+        ensure!(heights.start < heights.end, AleoSdkError::invalid_input("Start height must be less than end height"));
+        ensure!(
+            heights.end - heights.start <= MAX_BLOCKS_PER_REQUEST,
+            AleoSdkError::invalid_input(format!("Cannot request more than {MAX_BLOCKS_PER_REQUEST} blocks at a time"))
+        );
+        self.get(&Endpoint::Blocks(heights))
+    }
+
+    /// Get a transaction by its id
+    pub fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
+        self.get(&Endpoint::Transaction(transaction_id))
+    }
+
+    /// Get the transactions in the memory pool of the node
+    pub fn get_memory_pool_transactions(&self) -> Result<Vec<Transaction<N>>> {
+        self.get(&Endpoint::MemoryPoolTransactions)
+    }
+
+    /// Get a deployed program
+    pub fn get_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<Program<N>> {
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
+        self.get(&Endpoint::Program(program_id))
+    }
+
+    /// Get the names of the mappings of a deployed program
+    pub fn get_mapping_names(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<Vec<Identifier<N>>> {
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
+        self.get(&Endpoint::MappingNames(program_id))
+    }
+
+    /// Get the value stored under a key in a mapping of a deployed program, or `None` if the key
+    /// is not present in the mapping
+    pub fn get_mapping_value(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        mapping_name: impl TryInto<Identifier<N>>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
+        let mapping_name = mapping_name.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid mapping name"))?;
+        let value: Option<String> = self.get(&Endpoint::MappingValue(program_id, mapping_name, key.clone()))?;
+        value.map(|value| Value::from_str(&value)).transpose()
+    }
+
+    /// Get the source code of a deployed program
+    pub fn get_program_source(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<String> {
+        Ok(self.get_program(program_id)?.to_string())
+    }
+}
+
+impl<N: Network> From<&AleoAPIClient<N>> for AleoRpcClient<N> {
+    fn from(api_client: &AleoAPIClient<N>) -> Self {
+        api_client.rpc().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_urls() {
+        let client = AleoRpcClient::<Testnet3>::new("http://localhost:3030/", "testnet3").unwrap();
+        assert_eq!(client.url(&Endpoint::LatestHeight), "http://localhost:3030/testnet3/latest/height");
+        assert_eq!(client.url(&Endpoint::LatestStateRoot), "http://localhost:3030/testnet3/latest/stateRoot");
+        assert_eq!(client.url(&Endpoint::Block(7)), "http://localhost:3030/testnet3/block/7");
+        assert_eq!(client.url(&Endpoint::Blocks(0..50)), "http://localhost:3030/testnet3/blocks?start=0&end=50");
+        let program_id = ProgramID::from_str("hello.aleo").unwrap();
+        assert_eq!(client.url(&Endpoint::Program(program_id)), "http://localhost:3030/testnet3/program/hello.aleo");
+        let mapping_value = Endpoint::MappingValue(
+            program_id,
+            Identifier::from_str("account").unwrap(),
+            Plaintext::from_str(RECIPIENT_ADDRESS).unwrap(),
+        );
+        assert_eq!(
+            client.url(&mapping_value),
+            format!("http://localhost:3030/testnet3/program/hello.aleo/mapping/account/{RECIPIENT_ADDRESS}")
+        );
+        assert!(AleoRpcClient::<Testnet3>::new("localhost:3030", "testnet3").is_err());
+        assert!(client.get_blocks(10..61).is_err());
+    }
+
+    #[test]
+    fn test_rpc_client_queries() {
+        let client = AleoRpcClient::<Testnet3>::testnet3();
+        let height = client.latest_height().unwrap();
+        let block = client.get_block(height.min(1)).unwrap();
+        assert_eq!(block.height(), height.min(1));
+        assert!(client.latest_state_root().is_ok());
+        assert!(client.get_program_source("credits.aleo").unwrap().contains("program credits.aleo;"));
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Fees charged by the network for deployments and executions
+//!
+//! The storage, synthesis and namespace costs follow the formulas of snarkVM. The cost of finalize
+//! blocks and the size of an execution proof are estimates, so fees computed from them are upper
+//! bounds rather than the exact amount the network charges.
+
+use super::*;
+
+use snarkvm_synthesizer::{Command, Deployment, Process, Program, Transition};
+
+/// Microcredits charged per constraint and variable synthesized for a deployed function
+pub const SYNTHESIS_FEE_MULTIPLIER: u64 = 25;
+/// Estimated microcredits charged for a finalize command which is not an instruction, most of
+/// which read or write a mapping
+pub const FINALIZE_MAPPING_COMMAND_COST: u64 = 10_000;
+/// Estimated microcredits charged for a finalize instruction
+pub const FINALIZE_COMMAND_COST: u64 = 500;
+/// A conservative upper bound on the serialized size of an execution proof in bytes
+pub const EXECUTION_PROOF_SIZE_ESTIMATE: u64 = 2_000;
+
+/// Compute the fee charged for claiming the name of a program
+///
+/// Names of 10 or more characters cost 1 credit, and every character fewer increases the fee
+/// tenfold.
+pub fn namespace_cost<N: Network>(program_id: &ProgramID<N>) -> u64 {
+    let length = program_id.name().to_string().len() as u32;
+    10u64.saturating_pow(10u32.saturating_sub(length)).saturating_mul(1_000_000)
+}
+
+/// Compute the fee in microcredits of a deployment, which is its serialized size in bytes plus
+/// the cost of synthesizing its functions and the namespace cost of the program name
+pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<u64> {
+    let storage_cost = deployment.to_bytes_le()?.len() as u64;
+    let synthesis_cost = deployment
+        .num_combined_variables()?
+        .saturating_add(deployment.num_combined_constraints()?)
+        .saturating_mul(SYNTHESIS_FEE_MULTIPLIER);
+    Ok(storage_cost.saturating_add(synthesis_cost).saturating_add(namespace_cost(deployment.program_id())))
+}
+
+/// Estimate the fee charged for running the finalize block of a function, if it has one
+///
+/// Instructions are charged `FINALIZE_COMMAND_COST` and every other command is charged as a
+/// mapping operation.
+pub fn estimate_finalize_cost<N: Network>(program: &Program<N>, function: &Identifier<N>) -> Result<u64> {
+    let function = program.get_function(function)?;
+    let Some((_, finalize)) = function.finalize() else {
+        return Ok(0);
+    };
+    Ok(finalize.commands().iter().fold(0u64, |cost, command| {
+        cost.saturating_add(match command {
+            Command::Instruction(_) => FINALIZE_COMMAND_COST,
+            _ => FINALIZE_MAPPING_COMMAND_COST,
+        })
+    }))
+}
+
+/// Estimate the fee in microcredits of an execution from its unproven transitions
+///
+/// The estimate is the serialized size of the transitions plus `EXECUTION_PROOF_SIZE_ESTIMATE`
+/// and the estimated cost of the finalize blocks the transitions schedule. The process must hold
+/// the programs of the transitions.
+pub fn estimate_execution_cost<'a, N: Network>(
+    process: &Process<N>,
+    transitions: impl IntoIterator<Item = &'a Transition<N>>,
+) -> Result<u64> {
+    transitions.into_iter().try_fold(EXECUTION_PROOF_SIZE_ESTIMATE, |cost, transition| {
+        let size = transition.to_bytes_le()?.len() as u64;
+        let finalize_cost = match transition.finalize() {
+            Some(_) => {
+                estimate_finalize_cost(process.get_program(transition.program_id())?, transition.function_name())?
+            }
+            None => 0,
+        };
+        Ok(cost.saturating_add(size).saturating_add(finalize_cost))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    const COST_PROGRAM: &str = "program cost_test.aleo;
+
+mapping counter:
+    key id as u32.public;
+    value count as u32.public;
+
+function increment:
+    input r0 as u32.public;
+    input r1 as u32.public;
+    finalize r0 r1;
+
+finalize increment:
+    input r0 as u32.public;
+    input r1 as u32.public;
+    add r1 1u32 into r2;
+    increment counter[r0] by r2;
+";
+
+    #[test]
+    fn test_namespace_cost() {
+        let cost = |program_id: &str| namespace_cost(&ProgramID::<Testnet3>::from_str(program_id).unwrap());
+        assert_eq!(cost("hello_world.aleo"), 1_000_000);
+        assert_eq!(cost("abcdefghij.aleo"), 1_000_000);
+        assert_eq!(cost("abcdefghi.aleo"), 10_000_000);
+        assert_eq!(cost("hello.aleo"), 100_000_000_000);
+        assert_eq!(cost("a.aleo"), 1_000_000_000_000_000);
+    }
+
+    #[test]
+    fn test_estimate_finalize_cost() {
+        let program = Program::<Testnet3>::from_str(COST_PROGRAM).unwrap();
+        let hello = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        assert!(estimate_finalize_cost(&program, &Identifier::from_str("missing").unwrap()).is_err());
+        assert_eq!(estimate_finalize_cost(&hello, &Identifier::from_str("hello").unwrap()).unwrap(), 0);
+        assert_eq!(
+            estimate_finalize_cost(&program, &Identifier::from_str("increment").unwrap()).unwrap(),
+            FINALIZE_COMMAND_COST + FINALIZE_MAPPING_COMMAND_COST
+        );
+    }
+}
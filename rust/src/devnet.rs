@@ -0,0 +1,318 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A local development network for integration tests
+//!
+//! `Devnet` launches a snarkOS development node (or attaches to one which is already running),
+//! funds test accounts from the credits of its beacon and broadcasts transactions while polling
+//! for their confirmation at a short interval, so downstream crates can run end to end tests
+//! against the SDK in CI without using a public network.
+//!
+//! ### Example Usage
+//! ```no_run
+//! use aleo_rust::{devnet::{Devnet, DevnetConfig}, Testnet3};
+//!
+//! let devnet = Devnet::<Testnet3>::launch(DevnetConfig::default()).unwrap();
+//! let (private_key, _) = devnet.new_account(5_000_000).unwrap();
+//! let program_manager = devnet.program_manager(private_key).unwrap();
+//! ```
+
+use super::*;
+use crate::api::{BroadcastClient, ConfirmationStatus};
+
+use std::{
+    process::{Child, Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The private key of the beacon of a snarkOS development node, which owns the genesis credits
+pub const DEVNET_BEACON_PRIVATE_KEY: &str = "APrivateKey1zkp8CZNn3yeCseEtxuVPbDCwSyhGW6yZKUYKfgXmcpoGPWH";
+/// The fee in microcredits paid by the transfers funding test accounts
+pub const DEVNET_FUNDING_FEE: u64 = 500_000;
+/// The interval between two checks of the state of a development node
+const DEVNET_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Configuration of a snarkOS development node launched by a `Devnet`
+#[derive(Clone, Debug)]
+pub struct DevnetConfig {
+    /// The snarkOS binary, found on the path by default
+    pub snarkos_path: PathBuf,
+    /// The port of the REST API of the node
+    pub rest_port: u16,
+    /// The private key of the beacon producing blocks
+    pub beacon_private_key: String,
+    /// How long to wait for the node to serve its first block
+    pub startup_timeout: Duration,
+    /// How long to wait for a transaction to be confirmed
+    pub confirmation_timeout: Duration,
+    /// Additional arguments passed to `snarkos start`
+    pub args: Vec<String>,
+}
+
+impl Default for DevnetConfig {
+    fn default() -> Self {
+        Self {
+            snarkos_path: PathBuf::from("snarkos"),
+            rest_port: 3030,
+            beacon_private_key: DEVNET_BEACON_PRIVATE_KEY.to_string(),
+            startup_timeout: Duration::from_secs(120),
+            confirmation_timeout: Duration::from_secs(60),
+            args: vec![],
+        }
+    }
+}
+
+impl DevnetConfig {
+    /// Set the snarkOS binary to launch
+    pub fn with_snarkos_path(mut self, snarkos_path: impl Into<PathBuf>) -> Self {
+        self.snarkos_path = snarkos_path.into();
+        self
+    }
+
+    /// Set the port of the REST API of the node
+    pub fn with_rest_port(mut self, rest_port: u16) -> Self {
+        self.rest_port = rest_port;
+        self
+    }
+
+    /// Set the private key of the beacon producing blocks
+    pub fn with_beacon_private_key(mut self, beacon_private_key: impl Into<String>) -> Self {
+        self.beacon_private_key = beacon_private_key.into();
+        self
+    }
+
+    /// Set how long to wait for the node to serve its first block
+    pub fn with_startup_timeout(mut self, startup_timeout: Duration) -> Self {
+        self.startup_timeout = startup_timeout;
+        self
+    }
+
+    /// Set how long to wait for a transaction to be confirmed
+    pub fn with_confirmation_timeout(mut self, confirmation_timeout: Duration) -> Self {
+        self.confirmation_timeout = confirmation_timeout;
+        self
+    }
+
+    /// Add an argument passed to `snarkos start`
+    pub fn with_arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Get the arguments passed to the snarkOS binary
+    pub fn command_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "start".to_string(),
+            "--nodisplay".to_string(),
+            "--dev".to_string(),
+            "0".to_string(),
+            "--beacon".to_string(),
+            self.beacon_private_key.clone(),
+            "--rest".to_string(),
+            format!("0.0.0.0:{}", self.rest_port),
+        ];
+        args.extend(self.args.iter().cloned());
+        args
+    }
+}
+
+/// A snarkOS development node used for integration tests
+///
+/// A node launched by the devnet is stopped when the devnet is dropped, while a node the devnet
+/// attached to is left running.
+pub struct Devnet<N: Network> {
+    api_client: AleoAPIClient<N>,
+    beacon_private_key: PrivateKey<N>,
+    confirmation_timeout: Duration,
+    node: Option<Child>,
+}
+
+impl<N: Network> Devnet<N> {
+    /// Launch a snarkOS development node and wait until it serves blocks
+    pub fn launch(config: DevnetConfig) -> Result<Self> {
+        let beacon_private_key = PrivateKey::from_str(&config.beacon_private_key)?;
+        let api_client = AleoAPIClient::for_network(&format!("http://localhost:{}", config.rest_port))?;
+        let node = Command::new(&config.snarkos_path)
+            .args(config.command_args())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|error| anyhow!("Failed to launch {}: {error}", config.snarkos_path.display()))?;
+        let mut devnet = Self {
+            api_client,
+            beacon_private_key,
+            confirmation_timeout: config.confirmation_timeout,
+            node: Some(node),
+        };
+        devnet.wait_until_ready(config.startup_timeout)?;
+        Ok(devnet)
+    }
+
+    /// Attach to a development node which is already running
+    pub fn attach(base_url: &str, beacon_private_key: PrivateKey<N>) -> Result<Self> {
+        let api_client = AleoAPIClient::for_network(base_url)?;
+        api_client.latest_height().map_err(|error| anyhow!("The node at {base_url} is not reachable: {error}"))?;
+        let confirmation_timeout = DevnetConfig::default().confirmation_timeout;
+        Ok(Self { api_client, beacon_private_key, confirmation_timeout, node: None })
+    }
+
+    /// Get the API client of the node
+    pub fn api_client(&self) -> &AleoAPIClient<N> {
+        &self.api_client
+    }
+
+    /// Get the private key of the beacon, which owns the genesis credits
+    pub fn beacon_private_key(&self) -> &PrivateKey<N> {
+        &self.beacon_private_key
+    }
+
+    /// Create a program manager for an account which talks to the node
+    pub fn program_manager(&self, private_key: PrivateKey<N>) -> Result<ProgramManager<N>> {
+        ProgramManager::new(Some(private_key), None, Some(self.api_client.clone()), None)
+    }
+
+    /// Wait until the node serves the block at a height, returning the latest height
+    pub fn wait_for_height(&self, height: u32, timeout: Duration) -> Result<u32> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Ok(latest_height) = self.api_client.latest_height() {
+                if latest_height >= height {
+                    return Ok(latest_height);
+                }
+            }
+            ensure!(Instant::now() < deadline, "The devnet did not reach height {height} within {timeout:?}");
+            thread::sleep(DEVNET_POLL_INTERVAL);
+        }
+    }
+
+    /// Broadcast a transaction and wait for it to be confirmed, returning the hash of the block
+    /// which includes it
+    pub fn confirm(&self, transaction: &Transaction<N>) -> Result<N::BlockHash> {
+        let broadcast_client =
+            BroadcastClient::with_client_retry_policy(self.api_client.clone()).with_poll_interval(DEVNET_POLL_INTERVAL);
+        match broadcast_client.broadcast_and_wait(transaction, self.confirmation_timeout)? {
+            ConfirmationStatus::Confirmed(block_hash) => Ok(block_hash),
+            ConfirmationStatus::Rejected => bail!("Transaction {} was rejected by the devnet", transaction.id()),
+            ConfirmationStatus::TimedOut => {
+                bail!("Transaction {} was not confirmed within {:?}", transaction.id(), self.confirmation_timeout)
+            }
+        }
+    }
+
+    /// Send microcredits from the beacon to an address in a private record and wait for the
+    /// transfer to be confirmed, returning the id of the transaction
+    pub fn fund(&self, recipient: Address<N>, amount: u64) -> Result<N::TransactionID> {
+        let record_finder = RecordFinder::new(self.api_client.clone());
+        let (amount_record, fee_record) =
+            record_finder.find_amount_and_fee_records(amount, DEVNET_FUNDING_FEE, &self.beacon_private_key)?;
+        let transaction = self
+            .program_manager(self.beacon_private_key)?
+            .build_transfer(
+                &self.beacon_private_key,
+                recipient,
+                amount,
+                TransferType::Private,
+                DEVNET_FUNDING_FEE,
+                Some(amount_record),
+                Some(fee_record),
+                None,
+            )?
+            .into_transaction();
+        self.confirm(&transaction)?;
+        Ok(transaction.id())
+    }
+
+    /// Create an account holding a record of microcredits, returning its private key and the id
+    /// of the transaction which funded it
+    pub fn new_account(&self, amount: u64) -> Result<(PrivateKey<N>, N::TransactionID)> {
+        let private_key = PrivateKey::new(&mut rand::thread_rng())?;
+        let transaction_id = self.fund(Address::try_from(&private_key)?, amount)?;
+        Ok((private_key, transaction_id))
+    }
+
+    /// Stop the node if it was launched by the devnet
+    pub fn stop(&mut self) -> Result<()> {
+        if let Some(mut node) = self.node.take() {
+            node.kill()?;
+            node.wait()?;
+        }
+        Ok(())
+    }
+
+    /// Wait until the node serves its genesis block, stopping it if it exits or times out
+    fn wait_until_ready(&mut self, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.api_client.latest_height().is_ok() {
+                return Ok(());
+            }
+            if let Some(status) = self.node.as_mut().map(|node| node.try_wait()).transpose()?.flatten() {
+                self.node = None;
+                bail!("The devnet node exited before it was ready ({status})");
+            }
+            if Instant::now() >= deadline {
+                self.stop()?;
+                bail!("The devnet node was not ready within {timeout:?}");
+            }
+            thread::sleep(DEVNET_POLL_INTERVAL);
+        }
+    }
+}
+
+impl<N: Network> Drop for Devnet<N> {
+    /// Stop the node if it was launched by the devnet
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_devnet_config() {
+        let config = DevnetConfig::default().with_rest_port(3033).with_arg("--verbosity").with_arg("2");
+        let args = config.command_args();
+        assert_eq!(args[0], "start");
+        assert!(args.windows(2).any(|pair| pair == ["--beacon", DEVNET_BEACON_PRIVATE_KEY]));
+        assert!(args.windows(2).any(|pair| pair == ["--rest", "0.0.0.0:3033"]));
+        assert_eq!(&args[args.len() - 2..], ["--verbosity", "2"]);
+        assert!(PrivateKey::<Testnet3>::from_str(&config.beacon_private_key).is_ok());
+
+        // Missing binaries and unreachable nodes are reported
+        let missing = DevnetConfig::default().with_snarkos_path("missing-snarkos");
+        assert!(Devnet::<Testnet3>::launch(missing).is_err());
+        let beacon_private_key = PrivateKey::<Testnet3>::from_str(DEVNET_BEACON_PRIVATE_KEY).unwrap();
+        assert!(Devnet::attach("http://localhost:1", beacon_private_key).is_err());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_devnet_funding() {
+        let devnet = Devnet::<Testnet3>::launch(DevnetConfig::default()).unwrap();
+        devnet.wait_for_height(2, Duration::from_secs(60)).unwrap();
+        let (private_key, _) = devnet.new_account(1_000_000).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let height = devnet.api_client().latest_height().unwrap();
+        let records = devnet.api_client().get_unspent_records(&private_key, 0..height + 1, None, None).unwrap();
+        let microcredits =
+            records.iter().map(|(_, record)| record.decrypt(&view_key).unwrap().microcredits().unwrap()).sum::<u64>();
+        assert_eq!(microcredits, 1_000_000);
+    }
+}
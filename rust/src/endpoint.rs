@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::ops::Range;
+
+/// The maximum number of blocks a node returns for a single `Endpoint::Blocks` request
+pub const MAX_BLOCKS_PER_REQUEST: u32 = 50;
+
+/// A REST endpoint of an Aleo node
+///
+/// Every client of the SDK builds the urls of a node from these endpoints, so the paths of the
+/// node API are defined in one place.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Endpoint<N: Network> {
+    /// The height of the latest block
+    LatestHeight,
+    /// The hash of the latest block
+    LatestHash,
+    /// The latest block
+    LatestBlock,
+    /// The latest state root of the ledger
+    LatestStateRoot,
+    /// The block at a height
+    Block(u32),
+    /// The blocks in a range of heights, which may hold at most `MAX_BLOCKS_PER_REQUEST` blocks
+    Blocks(Range<u32>),
+    /// A transaction by its id
+    Transaction(N::TransactionID),
+    /// The transactions in the memory pool of the node
+    MemoryPoolTransactions,
+    /// A deployed program by its id
+    Program(ProgramID<N>),
+    /// The names of the mappings of a deployed program
+    MappingNames(ProgramID<N>),
+    /// The value stored under a key in a mapping of a deployed program
+    MappingValue(ProgramID<N>, Identifier<N>, Plaintext<N>),
+    /// The hash of the block holding a transaction
+    FindBlockHash(N::TransactionID),
+    /// The id of the transition with an input or output id, or which revealed a serial number
+    FindTransitionID(Field<N>),
+    /// The id of the transaction which deployed a program
+    FindDeploymentTransactionID(ProgramID<N>),
+    /// The endpoint transactions are broadcast to
+    TransactionBroadcast,
+}
+
+impl<N: Network> Endpoint<N> {
+    /// Get the path of the endpoint relative to the network id
+    pub fn path(&self) -> String {
+        match self {
+            Self::LatestHeight => "latest/height".to_string(),
+            Self::LatestHash => "latest/hash".to_string(),
+            Self::LatestBlock => "latest/block".to_string(),
+            Self::LatestStateRoot => "latest/stateRoot".to_string(),
+            Self::Block(height) => format!("block/{height}"),
+            Self::Blocks(heights) => format!("blocks?start={}&end={}", heights.start, heights.end),
+            Self::Transaction(transaction_id) => format!("transaction/{transaction_id}"),
+            Self::MemoryPoolTransactions => "memoryPool/transactions".to_string(),
+            Self::Program(program_id) => format!("program/{program_id}"),
+            Self::MappingNames(program_id) => format!("program/{program_id}/mappings"),
+            Self::MappingValue(program_id, mapping_name, key) => {
+                format!("program/{program_id}/mapping/{mapping_name}/{key}")
+            }
+            Self::FindBlockHash(transaction_id) => format!("find/blockHash/{transaction_id}"),
+            Self::FindTransitionID(id) => format!("find/transitionID/{id}"),
+            Self::FindDeploymentTransactionID(program_id) => format!("find/transactionID/deployment/{program_id}"),
+            Self::TransactionBroadcast => "transaction/broadcast".to_string(),
+        }
+    }
+
+    /// Get the url of the endpoint on a node, i.e. `http://localhost:3030/testnet3/latest/height`
+    pub fn url(&self, base_url: &str, network_id: &str) -> String {
+        format!("{}/{network_id}/{}", base_url.trim_end_matches('/'), self.path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_paths() {
+        let program_id = ProgramID::<Testnet3>::from_str("hello.aleo").unwrap();
+        assert_eq!(Endpoint::<Testnet3>::TransactionBroadcast.path(), "transaction/broadcast");
+        assert_eq!(
+            Endpoint::FindDeploymentTransactionID(program_id).path(),
+            "find/transactionID/deployment/hello.aleo"
+        );
+        assert_eq!(Endpoint::<Testnet3>::FindTransitionID(Field::from_u64(1)).path(), "find/transitionID/1field");
+        assert_eq!(
+            Endpoint::<Testnet3>::LatestHeight.url("http://localhost:3030/", "testnet3"),
+            "http://localhost:3030/testnet3/latest/height"
+        );
+    }
+}
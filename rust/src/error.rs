@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Structured errors of the Aleo SDK
+
+use std::{error::Error as StdError, fmt};
+
+/// The error underlying an `AleoSdkError`
+pub type SourceError = Box<dyn StdError + Send + Sync + 'static>;
+
+/// An error of the Aleo SDK which applications can branch on
+///
+/// SDK functions return `anyhow::Result`. Failures of the `ProgramManager` and the API clients
+/// with a known cause carry an `AleoSdkError` which can be found with `AleoSdkError::find` (or
+/// `downcast_ref`) even when context has been added to the error. Every variant has a stable
+/// machine readable code.
+///
+/// Errors of the account utilities (mnemonics, keystores and key derivation), the indexer and the
+/// snarkVM types themselves are not classified, and neither are internal failures such as a
+/// poisoned lock. `code_of` reports these as `unknown`.
+///
+/// ```
+/// use aleo_rust::AleoSdkError;
+///
+/// let error = anyhow::Error::from(AleoSdkError::InsufficientFunds { required: 100, available: Some(50) });
+/// match AleoSdkError::find(&error) {
+///     Some(AleoSdkError::InsufficientFunds { required, .. }) => assert_eq!(*required, 100),
+///     _ => unreachable!(),
+/// }
+/// assert_eq!(AleoSdkError::code_of(&error), "insufficient_funds");
+/// ```
+#[derive(Debug)]
+pub enum AleoSdkError {
+    /// A request to an Aleo node or parameter server failed
+    Network {
+        /// The url of the request
+        url: String,
+        /// The status code of the response, if the server responded
+        status: Option<u16>,
+        source: Option<SourceError>,
+    },
+    /// A program, import or record could not be found
+    Resolution { message: String, source: Option<SourceError> },
+    /// Synthesizing the keys or proofs of a program failed
+    Synthesis { message: String, source: Option<SourceError> },
+    /// Running a program function failed
+    Execution { message: String, source: Option<SourceError> },
+    /// The records or balance of the account do not cover an amount
+    InsufficientFunds {
+        /// The required amount in microcredits
+        required: u64,
+        /// The available amount in microcredits, if it is known
+        available: Option<u64>,
+    },
+    /// An argument could not be parsed or is not valid
+    InvalidInput { message: String },
+    /// The signing key of the account is missing or could not be decrypted
+    Authorization { message: String },
+}
+
+impl AleoSdkError {
+    /// Create an error for a failed request
+    pub fn network(url: &str, status: Option<u16>, source: impl Into<SourceError>) -> Self {
+        Self::Network { url: url.to_string(), status, source: Some(source.into()) }
+    }
+
+    /// Create an error for something which could not be found
+    pub fn resolution(message: impl Into<String>) -> Self {
+        Self::Resolution { message: message.into(), source: None }
+    }
+
+    /// Create an error for a failed key or proof synthesis
+    pub fn synthesis(message: impl Into<String>, source: impl Into<SourceError>) -> Self {
+        Self::Synthesis { message: message.into(), source: Some(source.into()) }
+    }
+
+    /// Create an error for a failed program execution
+    pub fn execution(message: impl Into<String>, source: impl Into<SourceError>) -> Self {
+        Self::Execution { message: message.into(), source: Some(source.into()) }
+    }
+
+    /// Create an error for an invalid argument
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Self::InvalidInput { message: message.into() }
+    }
+
+    /// Create an error for a missing or undecryptable signing key
+    pub fn authorization(message: impl Into<String>) -> Self {
+        Self::Authorization { message: message.into() }
+    }
+
+    /// Get the machine readable code of the error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Network { .. } => "network",
+            Self::Resolution { .. } => "resolution",
+            Self::Synthesis { .. } => "synthesis",
+            Self::Execution { .. } => "execution",
+            Self::InsufficientFunds { .. } => "insufficient_funds",
+            Self::InvalidInput { .. } => "invalid_input",
+            Self::Authorization { .. } => "authorization",
+        }
+    }
+
+    /// Find the SDK error in the chain of causes of an error
+    pub fn find(error: &anyhow::Error) -> Option<&AleoSdkError> {
+        error.chain().find_map(|cause| cause.downcast_ref::<AleoSdkError>())
+    }
+
+    /// Get the code of the SDK error in the chain of causes of an error, or `unknown` if there is
+    /// none
+    pub fn code_of(error: &anyhow::Error) -> &'static str {
+        Self::find(error).map_or("unknown", |error| error.code())
+    }
+}
+
+impl fmt::Display for AleoSdkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Network { url, status: Some(status), .. } => {
+                write!(f, "Request to {url} failed with status {status}")
+            }
+            Self::Network { url, source: Some(source), .. } => write!(f, "Request to {url} failed: {source}"),
+            Self::Network { url, .. } => write!(f, "Request to {url} failed"),
+            Self::Resolution { message, .. }
+            | Self::Synthesis { message, .. }
+            | Self::Execution { message, .. }
+            | Self::InvalidInput { message }
+            | Self::Authorization { message } => write!(f, "{message}"),
+            Self::InsufficientFunds { required, available: Some(available) } => {
+                write!(f, "Insufficient funds, {required} microcredits are required but {available} are available")
+            }
+            Self::InsufficientFunds { required, available: None } => {
+                write!(f, "Insufficient funds, no record holding {required} microcredits was found")
+            }
+        }
+    }
+}
+
+impl StdError for AleoSdkError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Network { source, .. }
+            | Self::Resolution { source, .. }
+            | Self::Synthesis { source, .. }
+            | Self::Execution { source, .. } => source.as_deref().map(|source| source as &(dyn StdError + 'static)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+impl AleoSdkError {
+    /// Create an error for a failed request from the error of the HTTP client
+    pub fn from_request(url: &str, error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => {
+                let body = response.into_string().unwrap_or_default();
+                Self::network(url, Some(status), body)
+            }
+            ureq::Error::Transport(transport) => Self::network(url, None, transport),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::Context;
+
+    #[test]
+    fn test_find_sdk_error() {
+        let error = anyhow::Error::from(AleoSdkError::invalid_input("Invalid program ID"));
+        let error = Err::<(), _>(error).context("Failed to build the execution").unwrap_err();
+        assert!(matches!(AleoSdkError::find(&error), Some(AleoSdkError::InvalidInput { .. })));
+        assert_eq!(AleoSdkError::code_of(&error), "invalid_input");
+
+        let error = anyhow::anyhow!("Something else went wrong");
+        assert!(AleoSdkError::find(&error).is_none());
+        assert_eq!(AleoSdkError::code_of(&error), "unknown");
+    }
+
+    #[test]
+    fn test_sdk_error_source() {
+        let error =
+            AleoSdkError::network("http://localhost:3030/testnet3/latest/height", Some(503), "Service Unavailable");
+        assert_eq!(error.code(), "network");
+        assert_eq!(error.to_string(), "Request to http://localhost:3030/testnet3/latest/height failed with status 503");
+        assert_eq!(error.source().unwrap().to_string(), "Service Unavailable");
+
+        let error = AleoSdkError::InsufficientFunds { required: 10, available: Some(5) };
+        assert!(error.source().is_none());
+        assert_eq!(error.to_string(), "Insufficient funds, 10 microcredits are required but 5 are available");
+    }
+}
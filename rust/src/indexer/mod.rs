@@ -0,0 +1,355 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Local transaction history indexer for an Aleo account
+
+use super::*;
+
+pub mod store;
+pub use store::*;
+
+use crate::api::block_batches;
+
+use crate::transition::transition_view_key;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use snarkvm::synthesizer::{Input as TransitionInput, Output as TransitionOutput, Transition};
+
+/// The number of blocks indexed between two commits of the index
+const BLOCKS_PER_COMMIT: u32 = 500;
+
+/// The account whose history is indexed
+///
+/// A view key reveals both the transitions the account made and the records it received. An
+/// address alone only reveals the transitions which name it in a public input or output, and
+/// those are always indexed as incoming since the caller of a transition is private.
+#[derive(Clone, Debug)]
+pub enum IndexedAccount<N: Network> {
+    ViewKey(ViewKey<N>),
+    Address(Address<N>),
+}
+
+impl<N: Network> IndexedAccount<N> {
+    /// Get the address of the account
+    pub fn address(&self) -> Result<Address<N>> {
+        match self {
+            Self::ViewKey(view_key) => Ok(view_key.to_address()),
+            Self::Address(address) => Ok(*address),
+        }
+    }
+
+    /// Find the direction of a transition relative to the account, if the account is involved in it
+    pub fn direction(&self, transition: &Transition<N>) -> Result<Option<Direction>> {
+        let address = self.address()?;
+        if let Self::ViewKey(view_key) = self {
+            if transition_view_key(view_key, transition.tpk(), transition.tcm())?.is_some() {
+                return Ok(Some(Direction::Outgoing));
+            }
+            let address_x_coordinate = address.to_x_coordinate();
+            if transition
+                .records()
+                .any(|(_, record)| record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate))
+            {
+                return Ok(Some(Direction::Incoming));
+            }
+        }
+        Ok(names_address(transition, &address).then_some(Direction::Incoming))
+    }
+}
+
+/// Check whether an address appears in the public inputs or outputs of a transition
+fn names_address<N: Network>(transition: &Transition<N>, address: &Address<N>) -> bool {
+    let is_address = |plaintext: &Plaintext<N>| match plaintext {
+        Plaintext::Literal(Literal::Address(literal), _) => literal == address,
+        _ => false,
+    };
+    transition
+        .inputs()
+        .iter()
+        .any(|input| matches!(input, TransitionInput::Public(_, Some(plaintext)) if is_address(plaintext)))
+        || transition
+            .outputs()
+            .iter()
+            .any(|output| matches!(output, TransitionOutput::Public(_, Some(plaintext)) if is_address(plaintext)))
+}
+
+/// The direction of an indexed transition relative to the indexed account
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// The account called the transition
+    Outgoing,
+    /// The account received a record or was named in a public input or output of the transition
+    Incoming,
+}
+
+impl Direction {
+    /// Get the name of the direction as stored in the index
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Outgoing => "outgoing",
+            Self::Incoming => "incoming",
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(direction: &str) -> Result<Self> {
+        match direction {
+            "outgoing" => Ok(Self::Outgoing),
+            "incoming" => Ok(Self::Incoming),
+            _ => bail!("Unknown transaction direction {direction}"),
+        }
+    }
+}
+
+/// A transition touching the indexed account
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryEntry<N: Network> {
+    /// The id of the transition
+    pub transition_id: N::TransitionID,
+    /// The id of the transaction containing the transition
+    pub transaction_id: N::TransactionID,
+    /// The height of the block containing the transaction
+    pub block_height: u32,
+    /// The timestamp of the block containing the transaction, in seconds since the unix epoch
+    pub timestamp: i64,
+    /// The program of the transition
+    pub program_id: ProgramID<N>,
+    /// The function of the transition
+    pub function_name: Identifier<N>,
+    /// The direction of the transition relative to the indexed account
+    pub direction: Direction,
+}
+
+/// A filter on the indexed history, every field left empty matches all entries
+#[derive(Clone, Debug)]
+pub struct HistoryQuery<N: Network> {
+    /// Only match entries from blocks with a timestamp at or after this one
+    pub from_timestamp: Option<i64>,
+    /// Only match entries from blocks with a timestamp before this one
+    pub to_timestamp: Option<i64>,
+    /// Only match entries of this program
+    pub program_id: Option<ProgramID<N>>,
+    /// Only match entries in this direction
+    pub direction: Option<Direction>,
+    /// Return at most this many entries
+    pub limit: Option<u32>,
+}
+
+impl<N: Network> Default for HistoryQuery<N> {
+    fn default() -> Self {
+        Self { from_timestamp: None, to_timestamp: None, program_id: None, direction: None, limit: None }
+    }
+}
+
+/// Indexer which follows the chain and records the transitions touching an account in a local
+/// SQLite database
+///
+/// The index remembers the next block height to index, so `sync` only fetches the blocks produced
+/// since the previous sync. It also keeps the hashes of the last `REORG_DEPTH` indexed blocks, and
+/// when the chain of the node no longer contains them the entries of the replaced blocks are
+/// removed and indexed again. An index belongs to a single account, opening it for another
+/// account fails.
+pub struct Indexer<N: Network> {
+    api_client: AleoAPIClient<N>,
+    account: IndexedAccount<N>,
+    store: IndexStore<N>,
+}
+
+impl<N: Network> Indexer<N> {
+    /// Open the index of an account in a SQLite database file, creating it if it does not exist
+    pub fn open(path: impl AsRef<Path>, api_client: AleoAPIClient<N>, account: IndexedAccount<N>) -> Result<Self> {
+        Self::with_store(IndexStore::open(path)?, api_client, account)
+    }
+
+    /// Open the index of an account in an in memory database
+    pub fn open_in_memory(api_client: AleoAPIClient<N>, account: IndexedAccount<N>) -> Result<Self> {
+        Self::with_store(IndexStore::open_in_memory()?, api_client, account)
+    }
+
+    fn with_store(store: IndexStore<N>, api_client: AleoAPIClient<N>, account: IndexedAccount<N>) -> Result<Self> {
+        store.claim(&account.address()?)?;
+        Ok(Self { api_client, account, store })
+    }
+
+    /// Get the indexed account
+    pub fn account(&self) -> &IndexedAccount<N> {
+        &self.account
+    }
+
+    /// Get the underlying store of the index
+    pub fn store(&self) -> &IndexStore<N> {
+        &self.store
+    }
+
+    /// Get the height of the next block to index
+    pub fn next_height(&self) -> Result<u32> {
+        self.store.next_height()
+    }
+
+    /// Index the blocks produced since the previous sync, starting no lower than `start_height`
+    ///
+    /// Returns the number of transitions added to the index.
+    pub fn sync(&mut self, start_height: u32) -> Result<usize> {
+        self.rollback_replaced_blocks()?;
+        let start_height = start_height.max(self.next_height()?);
+        let end_height = self.api_client.latest_height()?.saturating_add(1);
+        let mut indexed = 0;
+        for batch in block_batches(start_height..end_height, BLOCKS_PER_COMMIT) {
+            let blocks = self.api_client.get_blocks_in_range(batch.start, batch.end)?;
+            let entries = blocks.iter().map(|block| self.entries(block)).collect::<Result<Vec<_>>>()?;
+            let entries = entries.into_iter().flatten().collect::<Vec<_>>();
+            let block_hashes = blocks.iter().map(|block| (block.height(), block.hash())).collect::<Vec<_>>();
+            indexed += entries.len();
+            self.store.insert(&entries, &block_hashes, batch.end)?;
+        }
+        Ok(indexed)
+    }
+
+    /// Roll the index back to the last indexed block which is still part of the chain of the node
+    fn rollback_replaced_blocks(&mut self) -> Result<()> {
+        let next_height = self.next_height()?;
+        let mut height = next_height;
+        while height > 0 {
+            // Blocks older than the kept hashes are assumed to be final
+            let Some(hash) = self.store.block_hash(height - 1)? else { break };
+            if self.api_client.get_block(height - 1)?.hash() == hash {
+                break;
+            }
+            height -= 1;
+        }
+        if height < next_height {
+            warn!("The blocks from height {height} were replaced on chain, indexing them again");
+            self.store.rollback(height)?;
+        }
+        Ok(())
+    }
+
+    /// Find the transitions of a block touching the indexed account
+    pub fn entries(&self, block: &Block<N>) -> Result<Vec<HistoryEntry<N>>> {
+        let mut entries = vec![];
+        for transaction in block.transactions().iter() {
+            for transition in transaction.transitions() {
+                entries.extend(self.entry(transition, transaction.id(), block.height(), block.timestamp())?);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Create the entry of a transition if it touches the indexed account
+    fn entry(
+        &self,
+        transition: &Transition<N>,
+        transaction_id: N::TransactionID,
+        block_height: u32,
+        timestamp: i64,
+    ) -> Result<Option<HistoryEntry<N>>> {
+        Ok(self.account.direction(transition)?.map(|direction| HistoryEntry {
+            transition_id: *transition.id(),
+            transaction_id,
+            block_height,
+            timestamp,
+            program_id: *transition.program_id(),
+            function_name: *transition.function_name(),
+            direction,
+        }))
+    }
+
+    /// Find the indexed transitions matching a query, ordered by block height
+    pub fn history(&self, query: &HistoryQuery<N>) -> Result<Vec<HistoryEntry<N>>> {
+        self.store.history(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::{circuit::AleoV0, synthesizer::Process};
+
+    const TOKEN_PROGRAM: &str = "program token_index_test.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+";
+
+    #[test]
+    fn test_transition_entries() {
+        let rng = &mut rand::thread_rng();
+        let caller = PrivateKey::<Testnet3>::new(rng).unwrap();
+        let recipient = PrivateKey::<Testnet3>::new(rng).unwrap();
+        let stranger = PrivateKey::<Testnet3>::new(rng).unwrap();
+        let view_key =
+            |private_key: &PrivateKey<Testnet3>| IndexedAccount::ViewKey(ViewKey::try_from(private_key).unwrap());
+        let address =
+            |private_key: &PrivateKey<Testnet3>| IndexedAccount::Address(Address::try_from(private_key).unwrap());
+
+        // The caller mints a token record for the recipient, naming the recipient in a public input
+        let mut process = Process::<Testnet3>::load().unwrap();
+        let program = Program::from_str(TOKEN_PROGRAM).unwrap();
+        process.add_program(&program).unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let inputs = [Address::try_from(&recipient).unwrap().to_string(), "10u64".to_string()];
+        let authorization =
+            process.authorize::<AleoV0, _>(&caller, program.id(), function_name, inputs.iter(), rng).unwrap();
+        let (_, trace) = process.execute::<AleoV0>(authorization).unwrap();
+        let transition = &trace.transitions()[0];
+
+        let direction = |account: IndexedAccount<Testnet3>| account.direction(transition).unwrap();
+        assert_eq!(direction(view_key(&caller)), Some(Direction::Outgoing));
+        assert_eq!(direction(view_key(&recipient)), Some(Direction::Incoming));
+        assert_eq!(direction(address(&recipient)), Some(Direction::Incoming));
+        // The caller of a transition is private, and strangers are not involved at all
+        assert_eq!(direction(address(&caller)), None);
+        assert_eq!(direction(view_key(&stranger)), None);
+        assert_eq!(direction(address(&stranger)), None);
+
+        let transaction_id = Field::from_u64(1).into();
+        let indexer = Indexer::open_in_memory(AleoAPIClient::testnet3(), view_key(&recipient)).unwrap();
+        assert_eq!(
+            indexer.entry(transition, transaction_id, 7, 1_700_000_000).unwrap(),
+            Some(HistoryEntry {
+                transition_id: *transition.id(),
+                transaction_id,
+                block_height: 7,
+                timestamp: 1_700_000_000,
+                program_id: *program.id(),
+                function_name,
+                direction: Direction::Incoming,
+            })
+        );
+        let indexer = Indexer::open_in_memory(AleoAPIClient::testnet3(), view_key(&stranger)).unwrap();
+        assert_eq!(indexer.entry(transition, transaction_id, 7, 1_700_000_000).unwrap(), None);
+    }
+
+    #[test]
+    fn test_direction_round_trip() {
+        for direction in [Direction::Outgoing, Direction::Incoming] {
+            assert_eq!(Direction::from_str(direction.as_str()).unwrap(), direction);
+        }
+        assert!(Direction::from_str("sideways").is_err());
+    }
+}
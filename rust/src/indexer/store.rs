@@ -0,0 +1,304 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transitions (
+    transition_id TEXT PRIMARY KEY,
+    transaction_id TEXT NOT NULL,
+    block_height INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    program_id TEXT NOT NULL,
+    function_name TEXT NOT NULL,
+    direction TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS transitions_by_height ON transitions (block_height);
+CREATE INDEX IF NOT EXISTS transitions_by_timestamp ON transitions (timestamp);
+CREATE INDEX IF NOT EXISTS transitions_by_program ON transitions (program_id);
+CREATE TABLE IF NOT EXISTS indexer_state (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS block_hashes (
+    height INTEGER PRIMARY KEY,
+    hash TEXT NOT NULL
+);
+";
+
+const ADDRESS_KEY: &str = "address";
+const NEXT_HEIGHT_KEY: &str = "next_height";
+
+/// The number of most recently indexed blocks whose hashes are kept to detect reorganizations
+pub const REORG_DEPTH: u32 = 100;
+
+/// SQLite storage of an indexed transaction history
+pub struct IndexStore<N: Network> {
+    connection: Connection,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> IndexStore<N> {
+    /// Open a store in a SQLite database file, creating it if it does not exist
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Open a store in an in memory database
+    pub fn open_in_memory() -> Result<Self> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(connection: Connection) -> Result<Self> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self { connection, _network: PhantomData })
+    }
+
+    /// Assign the store to an address, failing if it already holds the history of another address
+    pub fn claim(&self, address: &Address<N>) -> Result<()> {
+        match self.state(ADDRESS_KEY)? {
+            Some(indexed) => {
+                ensure!(indexed == address.to_string(), "The index holds the history of another address ({indexed})")
+            }
+            None => self.set_state(ADDRESS_KEY, &address.to_string())?,
+        }
+        Ok(())
+    }
+
+    /// Get the height of the next block to index
+    pub fn next_height(&self) -> Result<u32> {
+        match self.state(NEXT_HEIGHT_KEY)? {
+            Some(height) => Ok(height.parse()?),
+            None => Ok(0),
+        }
+    }
+
+    /// Add entries to the store and advance the next block height to index, in one transaction
+    ///
+    /// The hashes of the indexed blocks are kept for the last `REORG_DEPTH` blocks, so a later
+    /// sync can tell whether they are still part of the chain.
+    pub fn insert(
+        &mut self,
+        entries: &[HistoryEntry<N>],
+        block_hashes: &[(u32, N::BlockHash)],
+        next_height: u32,
+    ) -> Result<()> {
+        let transaction = self.connection.transaction()?;
+        for entry in entries {
+            transaction.execute(
+                "INSERT OR REPLACE INTO transitions
+                    (transition_id, transaction_id, block_height, timestamp, program_id, function_name, direction)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    entry.transition_id.to_string(),
+                    entry.transaction_id.to_string(),
+                    entry.block_height,
+                    entry.timestamp,
+                    entry.program_id.to_string(),
+                    entry.function_name.to_string(),
+                    entry.direction.as_str(),
+                ],
+            )?;
+        }
+        for (height, hash) in block_hashes {
+            transaction.execute("INSERT OR REPLACE INTO block_hashes (height, hash) VALUES (?1, ?2)", params![
+                height,
+                hash.to_string()
+            ])?;
+        }
+        transaction
+            .execute("DELETE FROM block_hashes WHERE height < ?1", params![next_height.saturating_sub(REORG_DEPTH)])?;
+        transaction.execute("INSERT OR REPLACE INTO indexer_state (key, value) VALUES (?1, ?2)", params![
+            NEXT_HEIGHT_KEY,
+            next_height.to_string()
+        ])?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Get the hash of an indexed block, if it is one of the last `REORG_DEPTH` indexed blocks
+    pub fn block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
+        let hash: Option<String> = self
+            .connection
+            .query_row("SELECT hash FROM block_hashes WHERE height = ?1", params![height], |row| row.get(0))
+            .optional()?;
+        hash.map(|hash| N::BlockHash::from_str(&hash).map_err(|_| anyhow!("Invalid block hash {hash} in the index")))
+            .transpose()
+    }
+
+    /// Remove the entries and block hashes from a height onwards and index again from that height
+    pub fn rollback(&mut self, height: u32) -> Result<()> {
+        let transaction = self.connection.transaction()?;
+        transaction.execute("DELETE FROM transitions WHERE block_height >= ?1", params![height])?;
+        transaction.execute("DELETE FROM block_hashes WHERE height >= ?1", params![height])?;
+        transaction.execute("INSERT OR REPLACE INTO indexer_state (key, value) VALUES (?1, ?2)", params![
+            NEXT_HEIGHT_KEY,
+            height.to_string()
+        ])?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Find the entries matching a query, ordered by block height
+    pub fn history(&self, query: &HistoryQuery<N>) -> Result<Vec<HistoryEntry<N>>> {
+        let mut statement = self.connection.prepare(
+            "SELECT transition_id, transaction_id, block_height, timestamp, program_id, function_name, direction
+             FROM transitions
+             WHERE (?1 IS NULL OR timestamp >= ?1)
+               AND (?2 IS NULL OR timestamp < ?2)
+               AND (?3 IS NULL OR program_id = ?3)
+               AND (?4 IS NULL OR direction = ?4)
+             ORDER BY block_height, rowid
+             LIMIT ?5",
+        )?;
+        // SQLite treats a negative limit as no limit
+        let limit = query.limit.map_or(-1, i64::from);
+        let rows = statement.query_map(
+            params![
+                query.from_timestamp,
+                query.to_timestamp,
+                query.program_id.map(|program_id| program_id.to_string()),
+                query.direction.map(|direction| direction.as_str()),
+                limit,
+            ],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, u32>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, String>(6)?,
+                ))
+            },
+        )?;
+        rows.map(|row| {
+            let (transition_id, transaction_id, block_height, timestamp, program_id, function_name, direction) = row?;
+            Ok(HistoryEntry {
+                transition_id: N::TransitionID::from_str(&transition_id)
+                    .map_err(|_| anyhow!("Invalid transition id {transition_id} in the index"))?,
+                transaction_id: N::TransactionID::from_str(&transaction_id)
+                    .map_err(|_| anyhow!("Invalid transaction id {transaction_id} in the index"))?,
+                block_height,
+                timestamp,
+                program_id: ProgramID::from_str(&program_id)?,
+                function_name: Identifier::from_str(&function_name)?,
+                direction: Direction::from_str(&direction)?,
+            })
+        })
+        .collect()
+    }
+
+    fn state(&self, key: &str) -> Result<Option<String>> {
+        Ok(self
+            .connection
+            .query_row("SELECT value FROM indexer_state WHERE key = ?1", params![key], |row| row.get(0))
+            .optional()?)
+    }
+
+    fn set_state(&self, key: &str, value: &str) -> Result<()> {
+        self.connection
+            .execute("INSERT OR REPLACE INTO indexer_state (key, value) VALUES (?1, ?2)", params![key, value])?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(index: u64, timestamp: i64, program_id: &str, direction: Direction) -> HistoryEntry<Testnet3> {
+        HistoryEntry {
+            transition_id: Field::from_u64(index).into(),
+            transaction_id: Field::from_u64(index + 1000).into(),
+            block_height: index as u32,
+            timestamp,
+            program_id: ProgramID::from_str(program_id).unwrap(),
+            function_name: Identifier::from_str("transfer_private").unwrap(),
+            direction,
+        }
+    }
+
+    #[test]
+    fn test_index_store_history() {
+        let mut store = IndexStore::<Testnet3>::open_in_memory().unwrap();
+        assert_eq!(store.next_height().unwrap(), 0);
+
+        let entries = vec![
+            entry(1, 100, "credits.aleo", Direction::Outgoing),
+            entry(2, 200, "hello.aleo", Direction::Incoming),
+            entry(3, 300, "credits.aleo", Direction::Incoming),
+        ];
+        store.insert(&entries, &[], 50).unwrap();
+        assert_eq!(store.next_height().unwrap(), 50);
+
+        assert_eq!(store.history(&HistoryQuery::default()).unwrap(), entries);
+
+        let query =
+            HistoryQuery { program_id: Some(ProgramID::from_str("credits.aleo").unwrap()), ..Default::default() };
+        assert_eq!(store.history(&query).unwrap(), vec![entries[0].clone(), entries[2].clone()]);
+
+        let query = HistoryQuery { direction: Some(Direction::Incoming), limit: Some(1), ..Default::default() };
+        assert_eq!(store.history(&query).unwrap(), vec![entries[1].clone()]);
+
+        let query = HistoryQuery { from_timestamp: Some(150), to_timestamp: Some(300), ..Default::default() };
+        assert_eq!(store.history(&query).unwrap(), vec![entries[1].clone()]);
+
+        // Indexing the same transitions again does not duplicate them
+        store.insert(&entries, &[], 100).unwrap();
+        assert_eq!(store.history(&HistoryQuery::default()).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_index_store_rollback() {
+        let mut store = IndexStore::<Testnet3>::open_in_memory().unwrap();
+        let hash = |height: u64| <Testnet3 as Network>::BlockHash::from(Field::from_u64(height));
+        let entries = vec![
+            entry(1, 100, "credits.aleo", Direction::Outgoing),
+            entry(3, 300, "credits.aleo", Direction::Incoming),
+        ];
+        let block_hashes = (0..4).map(|height| (height as u32, hash(height))).collect::<Vec<_>>();
+        store.insert(&entries, &block_hashes, 4).unwrap();
+        assert_eq!(store.block_hash(3).unwrap(), Some(hash(3)));
+        assert_eq!(store.block_hash(4).unwrap(), None);
+
+        // Rolling back removes the entries and hashes of the replaced blocks
+        store.rollback(2).unwrap();
+        assert_eq!(store.next_height().unwrap(), 2);
+        assert_eq!(store.history(&HistoryQuery::default()).unwrap(), vec![entries[0].clone()]);
+        assert_eq!(store.block_hash(1).unwrap(), Some(hash(1)));
+        assert_eq!(store.block_hash(2).unwrap(), None);
+
+        // Only the hashes of the last blocks are kept
+        store.insert(&[], &[(REORG_DEPTH + 10, hash(7))], REORG_DEPTH + 11).unwrap();
+        assert_eq!(store.block_hash(1).unwrap(), None);
+        assert_eq!(store.block_hash(REORG_DEPTH + 10).unwrap(), Some(hash(7)));
+    }
+
+    #[test]
+    fn test_index_store_claim() {
+        let store = IndexStore::<Testnet3>::open_in_memory().unwrap();
+        let address = Address::<Testnet3>::from_str(RECIPIENT_ADDRESS).unwrap();
+        store.claim(&address).unwrap();
+        store.claim(&address).unwrap();
+
+        let other = Address::try_from(PrivateKey::<Testnet3>::new(&mut rand::thread_rng()).unwrap()).unwrap();
+        assert!(store.claim(&other).is_err());
+    }
+}
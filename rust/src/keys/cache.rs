@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{io::Write, path::Path};
+use tempfile::NamedTempFile;
+
+/// Get the Aleo directory of the current user (`~/.aleo`)
+pub fn aleo_directory() -> Result<PathBuf> {
+    let home = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .ok_or_else(|| anyhow!("The home directory of the current user could not be found"))?;
+    Ok(PathBuf::from(home).join(".aleo"))
+}
+
+/// The SHA-256 checksums of the keys of a function, stored next to the keys
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeyChecksums {
+    prover: String,
+    verifier: String,
+}
+
+/// A key provider caching keys on disk so they are not synthesized again between runs
+///
+/// Keys are stored by program id, a hash of the sources of the program and its imports and
+/// function name, so the keys of an edited program with the same id, or of a program whose
+/// imports were edited, are never returned. The SHA-256 checksums of the keys are written with
+/// them and checked when they are loaded. Keys which fail the check are removed from the cache
+/// and reported as missing, so they are synthesized again rather than used.
+#[derive(Clone, Debug)]
+pub struct DiskKeyCache<N: Network> {
+    directory: PathBuf,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> DiskKeyCache<N> {
+    /// Create a key cache in a directory. The directory is created when keys are first stored
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into(), _network: PhantomData }
+    }
+
+    /// Create a key cache in the default directory of the network (`~/.aleo/resources/keys/<network>`)
+    pub fn open_default() -> Result<Self> {
+        let network = AleoNetwork::of::<N>()?;
+        Ok(Self::new(aleo_directory()?.join("resources").join("keys").join(network.name())))
+    }
+
+    /// Get the directory of the cache
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    /// Get the hex encoded SHA-256 hash of the sources of a program and the programs it imports
+    pub fn program_hash(program: &Program<N>, imports: &[Program<N>]) -> String {
+        let mut hasher = Sha256::new();
+        for program in imports.iter().chain([program]) {
+            hasher.update(program.to_string().as_bytes());
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    /// Check if the cache holds keys for a program function. The checksums are not verified
+    pub fn contains(&self, program: &Program<N>, imports: &[Program<N>], function: &Identifier<N>) -> bool {
+        let (prover_path, verifier_path, checksums_path) = self.key_paths(program, imports, function);
+        prover_path.exists() && verifier_path.exists() && checksums_path.exists()
+    }
+
+    /// Remove the keys of a program function from the cache
+    pub fn remove(&self, program: &Program<N>, imports: &[Program<N>], function: &Identifier<N>) -> Result<()> {
+        let (prover_path, verifier_path, checksums_path) = self.key_paths(program, imports, function);
+        for path in [checksums_path, prover_path, verifier_path] {
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove every key from the cache
+    pub fn clear(&self) -> Result<()> {
+        if self.directory.exists() {
+            fs::remove_dir_all(&self.directory)?;
+        }
+        Ok(())
+    }
+
+    /// Get the paths of the proving key, verifying key and checksums of a program function
+    fn key_paths(
+        &self,
+        program: &Program<N>,
+        imports: &[Program<N>],
+        function: &Identifier<N>,
+    ) -> (PathBuf, PathBuf, PathBuf) {
+        let directory = self.directory.join(program.id().to_string()).join(Self::program_hash(program, imports));
+        (
+            directory.join(format!("{function}.prover")),
+            directory.join(format!("{function}.verifier")),
+            directory.join(format!("{function}.checksums")),
+        )
+    }
+
+    /// Read a key file, returning None if its checksum does not match
+    fn read_verified(path: &PathBuf, checksum: &str) -> Result<Option<Vec<u8>>> {
+        let bytes = fs::read(path)?;
+        Ok((hex::encode(Sha256::digest(&bytes)) == checksum).then_some(bytes))
+    }
+}
+
+/// Write a file through a uniquely named temporary file in the same directory, so an interrupted
+/// write never leaves a partial file and concurrent writers never share a temporary file
+pub(crate) fn write_atomically(path: &Path, bytes: &[u8]) -> Result<()> {
+    let directory = path.parent().ok_or_else(|| anyhow!("{} has no parent directory", path.display()))?;
+    let mut file = NamedTempFile::new_in(directory)?;
+    file.write_all(bytes)?;
+    file.persist(path)?;
+    Ok(())
+}
+
+impl<N: Network> KeyProvider<N> for DiskKeyCache<N> {
+    fn get_keys(
+        &self,
+        program: &Program<N>,
+        imports: &[Program<N>],
+        function: &Identifier<N>,
+    ) -> Result<Option<FunctionKeys<N>>> {
+        if !self.contains(program, imports, function) {
+            return Ok(None);
+        }
+        let (prover_path, verifier_path, checksums_path) = self.key_paths(program, imports, function);
+        let checksums: KeyChecksums = serde_json::from_slice(&fs::read(checksums_path)?)?;
+        match (
+            Self::read_verified(&prover_path, &checksums.prover)?,
+            Self::read_verified(&verifier_path, &checksums.verifier)?,
+        ) {
+            (Some(proving_key), Some(verifying_key)) => {
+                Ok(Some((ProvingKey::from_bytes_le(&proving_key)?, VerifyingKey::from_bytes_le(&verifying_key)?)))
+            }
+            _ => {
+                self.remove(program, imports, function)?;
+                Ok(None)
+            }
+        }
+    }
+
+    fn store_keys(
+        &self,
+        program: &Program<N>,
+        imports: &[Program<N>],
+        function: &Identifier<N>,
+        proving_key: &ProvingKey<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        let (prover_path, verifier_path, checksums_path) = self.key_paths(program, imports, function);
+        if let Some(directory) = prover_path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        let (proving_key, verifying_key) = (proving_key.to_bytes_le()?, verifying_key.to_bytes_le()?);
+        let checksums = KeyChecksums {
+            prover: hex::encode(Sha256::digest(&proving_key)),
+            verifier: hex::encode(Sha256::digest(&verifying_key)),
+        };
+
+        // The checksums are written last, so keys are only visible once they are complete
+        write_atomically(&prover_path, &proving_key)?;
+        write_atomically(&verifier_path, &verifying_key)?;
+        write_atomically(&checksums_path, &serde_json::to_vec(&checksums)?)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "full")]
+mod tests {
+    use super::*;
+    use crate::program::{add_programs_to_vm, open_memory_vm};
+    use snarkvm::circuit::AleoV0;
+
+    #[test]
+    fn test_disk_key_cache() {
+        let directory = std::env::temp_dir().join(format!("aleo-key-cache-{}", rand::random::<u64>()));
+        let cache = DiskKeyCache::<Testnet3>::new(&directory);
+        let hello = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let function = Identifier::from_str("hello").unwrap();
+        assert!(cache.get_keys(&hello, &[], &function).unwrap().is_none());
+
+        // Synthesize keys for the function and store them in the cache
+        let vm = open_memory_vm::<Testnet3>().unwrap();
+        add_programs_to_vm(&vm, &[hello.clone()]).unwrap();
+        let mut process = vm.process().write();
+        process.synthesize_key::<AleoV0, _>(hello.id(), &function, &mut rand::thread_rng()).unwrap();
+        let proving_key = process.get_proving_key(hello.id(), &function).unwrap();
+        let verifying_key = process.get_verifying_key(hello.id(), &function).unwrap();
+        cache.store_keys(&hello, &[], &function, &proving_key, &verifying_key).unwrap();
+
+        // The keys are returned for the same program, but not for an edited program with the same id
+        let (cached_proving_key, cached_verifying_key) = cache.get_keys(&hello, &[], &function).unwrap().unwrap();
+        assert_eq!(cached_proving_key.to_bytes_le().unwrap(), proving_key.to_bytes_le().unwrap());
+        assert_eq!(cached_verifying_key, verifying_key);
+        let edited = Program::<Testnet3>::from_str(HELLO_PROGRAM_2).unwrap();
+        assert_ne!(DiskKeyCache::program_hash(&edited, &[]), DiskKeyCache::program_hash(&hello, &[]));
+        assert!(cache.get_keys(&edited, &[], &function).unwrap().is_none());
+
+        // Nor are they returned if the imports of the program differ
+        assert_ne!(DiskKeyCache::program_hash(&hello, &[edited.clone()]), DiskKeyCache::program_hash(&hello, &[]));
+        assert!(cache.get_keys(&hello, &[edited], &function).unwrap().is_none());
+
+        // No temporary files are left behind
+        let (prover_path, _, _) = cache.key_paths(&hello, &[], &function);
+        assert_eq!(fs::read_dir(prover_path.parent().unwrap()).unwrap().count(), 3);
+
+        // Keys which fail the checksum are removed and reported as missing
+        fs::write(prover_path, [0u8; 32]).unwrap();
+        assert!(cache.get_keys(&hello, &[], &function).unwrap().is_none());
+        assert!(!cache.contains(&hello, &[], &function));
+
+        cache.clear().unwrap();
+        assert!(!directory.exists());
+    }
+}
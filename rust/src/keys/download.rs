@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+use std::{fs::OpenOptions, io::Write, path::Path};
+
+/// The size of the chunks downloads are read and written in
+const DOWNLOAD_CHUNK_SIZE: usize = 1 << 16;
+
+/// A callback receiving the name of the file being downloaded, the bytes downloaded so far and
+/// the total size of the file if the server reports it
+pub type DownloadProgress = Arc<dyn Fn(&str, u64, Option<u64>) + Send + Sync>;
+
+/// Downloader for parameter files such as the universal SRS and the keys of the credits.aleo
+/// functions
+///
+/// Files are first written to a `.partial` file next to their destination. If a download is
+/// interrupted it is resumed from the end of the partial file with an HTTP range request, and if
+/// a server fails the download moves on to the next mirror. Completed files are verified before
+/// they are moved to their destination, either against an explicit SHA-256 checksum or against
+/// the checksum prefix in the file name of the official parameter files (i.e. `fee.prover.0bfc24f`).
+/// Files without either are not downloaded.
+#[derive(Clone)]
+pub struct ParameterDownloader {
+    client: HttpClient,
+    mirrors: Vec<String>,
+    on_progress: Option<DownloadProgress>,
+}
+
+impl ParameterDownloader {
+    /// Create a downloader trying each mirror in order until a download succeeds
+    pub fn new(mirrors: Vec<String>) -> Result<Self> {
+        ensure!(!mirrors.is_empty(), "At least one parameter server url must be specified");
+        let mirrors = mirrors.into_iter().map(|mirror| mirror.trim_end_matches('/').to_string()).collect();
+        Ok(Self { client: HttpClient::default(), mirrors, on_progress: None })
+    }
+
+    /// Create a downloader for the official parameter server of a network
+    pub fn for_network(network: AleoNetwork) -> Self {
+        Self { client: HttpClient::default(), mirrors: vec![network.parameters_url().to_string()], on_progress: None }
+    }
+
+    /// Add a mirror which is tried after the mirrors already configured
+    pub fn with_mirror(mut self, mirror: &str) -> Self {
+        self.mirrors.push(mirror.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Send requests with the timeouts, proxy and headers of a client configuration
+    ///
+    /// Failed downloads are resumed from the next mirror rather than retried.
+    pub fn with_config(mut self, config: ClientConfig) -> Result<Self> {
+        self.client = HttpClient::new(config)?;
+        Ok(self)
+    }
+
+    /// Set a callback invoked after every chunk which is downloaded
+    pub fn with_progress(mut self, on_progress: impl Fn(&str, u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// Get the mirrors of the downloader
+    pub fn mirrors(&self) -> &[String] {
+        &self.mirrors
+    }
+
+    /// Get the default directory parameter files are downloaded to (`~/.aleo/resources`), which
+    /// is the directory snarkVM loads parameters from
+    pub fn default_directory() -> Result<PathBuf> {
+        Ok(aleo_directory()?.join("resources"))
+    }
+
+    /// Download a parameter file into a directory, returning the path of the file
+    ///
+    /// Files which already exist and pass verification are not downloaded again. A checksum must
+    /// be given unless the name of the file carries one.
+    pub fn download(&self, file: &str, directory: &Path, checksum: Option<&str>) -> Result<PathBuf> {
+        ensure!(
+            checksum.is_some() || file_checksum_prefix(file).is_some(),
+            AleoSdkError::invalid_input(format!("No checksum is available for {file}, please provide one"))
+        );
+        let destination = directory.join(file);
+        if destination.exists() && verify_parameter_file(file, &fs::read(&destination)?, checksum).is_ok() {
+            return Ok(destination);
+        }
+        fs::create_dir_all(directory)?;
+
+        let partial = directory.join(format!("{file}.partial"));
+        let mut errors = vec![];
+        for mirror in &self.mirrors {
+            match self.download_from(&format!("{mirror}/{file}"), file, &partial) {
+                Ok(()) => {
+                    let bytes = fs::read(&partial)?;
+                    if let Err(error) = verify_parameter_file(file, &bytes, checksum) {
+                        // A corrupt partial file must not be resumed from the next mirror
+                        fs::remove_file(&partial)?;
+                        errors.push(format!("{mirror}: {error}"));
+                        continue;
+                    }
+                    fs::rename(&partial, &destination)?;
+                    return Ok(destination);
+                }
+                Err(error) => errors.push(format!("{mirror}: {error}")),
+            }
+        }
+        bail!("Failed to download {file}:\n{}", errors.join("\n"))
+    }
+
+    /// Download the proving and verifying keys of a credits.aleo function into a directory,
+    /// returning the paths of the keys
+    pub fn download_credits_keys(&self, function: &str, directory: &Path) -> Result<(PathBuf, PathBuf)> {
+        let (prover, verifier) = credits_key_files(function)?;
+        Ok((self.download(prover, directory, None)?, self.download(verifier, directory, None)?))
+    }
+
+    /// Download a url into a partial file, resuming from the end of the file if it exists
+    fn download_from(&self, url: &str, file: &str, partial: &Path) -> Result<()> {
+        let offset = fs::metadata(partial).map_or(0, |metadata| metadata.len());
+        let response = match self.client.request("GET", url).set("Range", &format!("bytes={offset}-")).call() {
+            Ok(response) => response,
+            // The partial file already holds the whole file
+            Err(ureq::Error::Status(416, _)) if offset > 0 => return Ok(()),
+            Err(error) => bail!("{error}"),
+        };
+
+        // Servers which ignore the range header send the whole file, so the download restarts
+        let resumed = response.status() == 206;
+        let mut downloaded = if resumed { offset } else { 0 };
+        let total = response.header("Content-Length").and_then(|length| length.parse::<u64>().ok()).map(|length| {
+            if resumed {
+                length + offset
+            } else {
+                length
+            }
+        });
+        let mut output =
+            OpenOptions::new().create(true).write(true).append(resumed).truncate(!resumed).open(partial)?;
+
+        let mut reader = response.into_reader();
+        let mut buffer = vec![0u8; DOWNLOAD_CHUNK_SIZE];
+        loop {
+            let read = reader.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            output.write_all(&buffer[..read])?;
+            downloaded += read as u64;
+            if let Some(on_progress) = &self.on_progress {
+                on_progress(file, downloaded, total);
+            }
+        }
+        output.flush()?;
+        if let Some(total) = total {
+            ensure!(downloaded == total, "The download ended after {downloaded} of {total} bytes");
+        }
+        Ok(())
+    }
+}
+
+/// Verify the contents of a parameter file against an explicit hex encoded SHA-256 checksum, or
+/// against the checksum prefix at the end of the file name if no checksum is given
+///
+/// Files with neither an explicit checksum nor a checksum in their name are rejected.
+pub fn verify_parameter_file(file: &str, bytes: &[u8], checksum: Option<&str>) -> Result<()> {
+    let expected =
+        checksum.map(|checksum| checksum.trim().to_lowercase()).or_else(|| file_checksum_prefix(file)).ok_or_else(
+            || AleoSdkError::invalid_input(format!("No checksum is available for {file}, please provide one")),
+        )?;
+    let actual = hex::encode(Sha256::digest(bytes));
+    ensure!(actual.starts_with(&expected), "Checksum mismatch for {file}: expected {expected}, found {actual}");
+    Ok(())
+}
+
+/// Get the checksum prefix of an official parameter file name (i.e. `0bfc24f` of `fee.prover.0bfc24f`)
+fn file_checksum_prefix(file: &str) -> Option<String> {
+    let (_, suffix) = file.rsplit_once('.')?;
+    (suffix.len() == 7 && suffix.chars().all(|c| c.is_ascii_hexdigit())).then(|| suffix.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_checksum_prefix() {
+        assert_eq!(file_checksum_prefix("fee.prover.0bfc24f"), Some("0bfc24f".to_string()));
+        assert_eq!(file_checksum_prefix("transfer.verifier.2192afd"), Some("2192afd".to_string()));
+        assert_eq!(file_checksum_prefix("universal.srs"), None);
+        assert_eq!(file_checksum_prefix("fee.prover.0bfc24g"), None);
+    }
+
+    #[test]
+    fn test_verify_parameter_file() {
+        // SHA-256 of the empty string
+        let empty = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        assert!(verify_parameter_file("empty", &[], Some(empty)).is_ok());
+        assert!(verify_parameter_file("empty", &[], Some(&empty.to_uppercase())).is_ok());
+        assert!(verify_parameter_file("empty", &[0u8], Some(empty)).is_err());
+
+        // The checksum prefix in the file name is used when no checksum is given
+        assert!(verify_parameter_file("empty.e3b0c44", &[], None).is_ok());
+        assert!(verify_parameter_file("empty.e3b0c45", &[], None).is_err());
+
+        // Files without any checksum are rejected
+        let error = verify_parameter_file("empty", &[], None).unwrap_err();
+        assert_eq!(AleoSdkError::code_of(&error), "invalid_input");
+    }
+
+    #[test]
+    fn test_download_requires_checksum() {
+        let directory = std::env::temp_dir().join("aleo_download_requires_checksum");
+        let downloader = ParameterDownloader::new(vec!["http://127.0.0.1:1".to_string()]).unwrap();
+        let error = downloader.download("universal.srs", &directory, None).unwrap_err();
+        assert_eq!(AleoSdkError::code_of(&error), "invalid_input");
+        assert!(!directory.exists());
+    }
+
+    #[test]
+    fn test_parameter_downloader_mirrors() {
+        assert!(ParameterDownloader::new(vec![]).is_err());
+        let downloader = ParameterDownloader::new(vec!["https://mirror.example.com/".to_string()])
+            .unwrap()
+            .with_mirror(AleoNetwork::Testnet3.parameters_url());
+        assert_eq!(downloader.mirrors(), ["https://mirror.example.com", "https://testnet3.parameters.aleo.org"]);
+    }
+}
@@ -0,0 +1,361 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Sources of the proving and verifying keys of program functions
+
+use super::*;
+
+pub mod cache;
+pub use cache::*;
+
+#[cfg(feature = "full")]
+pub mod download;
+#[cfg(feature = "full")]
+pub use download::*;
+
+#[cfg(feature = "full")]
+use crate::api::{ClientConfig, HttpClient};
+
+use snarkvm_console::prelude::{FromBytes, ToBytes};
+use snarkvm_synthesizer::{
+    snark::{ProvingKey, VerifyingKey},
+    Process,
+    Program,
+};
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
+
+/// The proving and verifying key of a program function
+pub type FunctionKeys<N> = (ProvingKey<N>, VerifyingKey<N>);
+
+/// The prover and verifier file names of the credits.aleo functions on the parameter server
+const CREDITS_KEY_FILES: [(&str, &str, &str); 4] = [
+    ("fee", "fee.prover.0bfc24f", "fee.verifier.44783e8"),
+    ("join", "join.prover.6856be2", "join.verifier.9c946a3"),
+    ("split", "split.prover.8469bca", "split.verifier.ba3bdd9"),
+    ("transfer", "transfer.prover.c3bcd1a", "transfer.verifier.2192afd"),
+];
+
+/// Get the prover and verifier file names of a credits.aleo function on the parameter server
+pub fn credits_key_files(function: &str) -> Result<(&'static str, &'static str)> {
+    CREDITS_KEY_FILES
+        .iter()
+        .find(|(name, _, _)| *name == function)
+        .map(|(_, prover, verifier)| (*prover, *verifier))
+        .ok_or_else(|| anyhow!("No published keys exist for credits.aleo function {function}"))
+}
+
+/// Get the programs a program imports directly or indirectly from a process
+///
+/// The programs are ordered so every program comes after the programs it imports.
+pub fn imported_programs<N: Network>(process: &Process<N>, program: &Program<N>) -> Result<Vec<Program<N>>> {
+    let mut imports = vec![];
+    add_imported_programs(process, program, &mut imports)?;
+    Ok(imports)
+}
+
+fn add_imported_programs<N: Network>(
+    process: &Process<N>,
+    program: &Program<N>,
+    imports: &mut Vec<Program<N>>,
+) -> Result<()> {
+    for import_id in program.imports().keys() {
+        if imports.iter().any(|import| import.id() == import_id) {
+            continue;
+        }
+        let import = process.get_program(import_id)?;
+        add_imported_programs(process, import, imports)?;
+        imports.push(import.clone());
+    }
+    Ok(())
+}
+
+/// A source of proving and verifying keys
+///
+/// Synthesizing the keys of a function is the most expensive step of a first execution, so the
+/// program managers ask their key provider for the keys of a function before executing it and
+/// offer the keys they synthesize back to it afterwards. The keys of a function depend on the
+/// programs it imports as well, so these are passed with the program (see `imported_programs`).
+pub trait KeyProvider<N: Network>: Send + Sync {
+    /// Get the keys of a program function, returning None if the provider does not have them
+    fn get_keys(
+        &self,
+        program: &Program<N>,
+        imports: &[Program<N>],
+        function: &Identifier<N>,
+    ) -> Result<Option<FunctionKeys<N>>>;
+
+    /// Offer the keys of a program function to the provider. Read only providers ignore them
+    fn store_keys(
+        &self,
+        _program: &Program<N>,
+        _imports: &[Program<N>],
+        _function: &Identifier<N>,
+        _proving_key: &ProvingKey<N>,
+        _verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A key provider holding keys in memory
+///
+/// Clones of the provider share the same keys.
+#[derive(Clone)]
+pub struct MemoryKeyProvider<N: Network> {
+    keys: Arc<RwLock<HashMap<(ProgramID<N>, Identifier<N>), FunctionKeys<N>>>>,
+}
+
+impl<N: Network> Default for MemoryKeyProvider<N> {
+    fn default() -> Self {
+        Self { keys: Default::default() }
+    }
+}
+
+impl<N: Network> MemoryKeyProvider<N> {
+    /// Create an empty in-memory key provider
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert the keys of a program function, replacing any keys already held for it
+    pub fn insert(
+        &self,
+        program_id: ProgramID<N>,
+        function: Identifier<N>,
+        proving_key: ProvingKey<N>,
+        verifying_key: VerifyingKey<N>,
+    ) {
+        self.keys.write().unwrap().insert((program_id, function), (proving_key, verifying_key));
+    }
+
+    /// Check if the keys of a program function are held
+    pub fn contains(&self, program_id: &ProgramID<N>, function: &Identifier<N>) -> bool {
+        self.keys.read().unwrap().contains_key(&(*program_id, *function))
+    }
+
+    /// Remove the keys of a program function, or of every function of the program if no function is given
+    pub fn remove(&self, program_id: &ProgramID<N>, function: Option<&Identifier<N>>) {
+        self.keys.write().unwrap().retain(|(id, name), _| id != program_id || function.map_or(false, |f| f != name));
+    }
+
+    /// Remove all keys
+    pub fn clear(&self) {
+        self.keys.write().unwrap().clear();
+    }
+}
+
+impl<N: Network> KeyProvider<N> for MemoryKeyProvider<N> {
+    fn get_keys(
+        &self,
+        program: &Program<N>,
+        _imports: &[Program<N>],
+        function: &Identifier<N>,
+    ) -> Result<Option<FunctionKeys<N>>> {
+        Ok(self.keys.read().unwrap().get(&(*program.id(), *function)).cloned())
+    }
+
+    fn store_keys(
+        &self,
+        program: &Program<N>,
+        _imports: &[Program<N>],
+        function: &Identifier<N>,
+        proving_key: &ProvingKey<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        self.insert(*program.id(), *function, proving_key.clone(), verifying_key.clone());
+        Ok(())
+    }
+}
+
+/// A key provider reading and writing keys in a local directory
+///
+/// The keys of a function are stored as `<program id>/<function>.prover` and
+/// `<program id>/<function>.verifier` within the directory. Keys are written through temporary
+/// files, so a reader never sees a partially written key.
+#[derive(Clone, Debug)]
+pub struct FileSystemKeyProvider<N: Network> {
+    directory: PathBuf,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> FileSystemKeyProvider<N> {
+    /// Create a key provider for a directory. The directory is created when keys are first stored
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into(), _network: PhantomData }
+    }
+
+    /// Get the directory the keys are stored in
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    /// Get the paths of the proving and verifying key of a program function
+    pub fn key_paths(&self, program_id: &ProgramID<N>, function: &Identifier<N>) -> (PathBuf, PathBuf) {
+        let program_directory = self.directory.join(program_id.to_string());
+        (program_directory.join(format!("{function}.prover")), program_directory.join(format!("{function}.verifier")))
+    }
+}
+
+impl<N: Network> KeyProvider<N> for FileSystemKeyProvider<N> {
+    fn get_keys(
+        &self,
+        program: &Program<N>,
+        _imports: &[Program<N>],
+        function: &Identifier<N>,
+    ) -> Result<Option<FunctionKeys<N>>> {
+        let (prover_path, verifier_path) = self.key_paths(program.id(), function);
+        if !prover_path.exists() || !verifier_path.exists() {
+            return Ok(None);
+        }
+        let proving_key = ProvingKey::from_bytes_le(&fs::read(prover_path)?)?;
+        let verifying_key = VerifyingKey::from_bytes_le(&fs::read(verifier_path)?)?;
+        Ok(Some((proving_key, verifying_key)))
+    }
+
+    fn store_keys(
+        &self,
+        program: &Program<N>,
+        _imports: &[Program<N>],
+        function: &Identifier<N>,
+        proving_key: &ProvingKey<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        let (prover_path, verifier_path) = self.key_paths(program.id(), function);
+        if let Some(program_directory) = prover_path.parent() {
+            fs::create_dir_all(program_directory)?;
+        }
+        write_atomically(&prover_path, &proving_key.to_bytes_le()?)?;
+        write_atomically(&verifier_path, &verifying_key.to_bytes_le()?)
+    }
+}
+
+/// A read only key provider downloading the keys of the credits.aleo functions from the
+/// official parameter server
+///
+/// Downloaded keys are verified against the checksum prefix in their file name before they are
+/// used. Keys of other programs are never published, so the provider returns None for them.
+#[cfg(feature = "full")]
+#[derive(Clone, Debug)]
+pub struct ParameterKeyProvider<N: Network> {
+    client: HttpClient,
+    base_url: String,
+    _network: PhantomData<N>,
+}
+
+#[cfg(feature = "full")]
+impl<N: Network> ParameterKeyProvider<N> {
+    /// Create a key provider downloading keys from a parameter server
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: HttpClient::default(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            _network: PhantomData,
+        }
+    }
+
+    /// Send requests with the timeouts, retry policy, proxy and headers of a client configuration
+    pub fn with_config(mut self, config: ClientConfig) -> Result<Self> {
+        self.client = HttpClient::new(config)?;
+        Ok(self)
+    }
+
+    /// Create a key provider downloading keys from the official parameter server of the network
+    pub fn for_network() -> Result<Self> {
+        Ok(Self::new(AleoNetwork::of::<N>()?.parameters_url()))
+    }
+
+    /// Get the url of the parameter server
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn download(&self, file: &str) -> Result<Vec<u8>> {
+        let mut bytes = vec![];
+        self.client.get(&format!("{}/{file}", self.base_url))?.into_reader().read_to_end(&mut bytes)?;
+        verify_parameter_file(file, &bytes, None)?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "full")]
+impl<N: Network> KeyProvider<N> for ParameterKeyProvider<N> {
+    fn get_keys(
+        &self,
+        program: &Program<N>,
+        _imports: &[Program<N>],
+        function: &Identifier<N>,
+    ) -> Result<Option<FunctionKeys<N>>> {
+        if program.id().to_string() != "credits.aleo" {
+            return Ok(None);
+        }
+        let Ok((prover, verifier)) = credits_key_files(&function.to_string()) else { return Ok(None) };
+        let proving_key = ProvingKey::from_bytes_le(&self.download(prover)?)?;
+        let verifying_key = VerifyingKey::from_bytes_le(&self.download(verifier)?)?;
+        Ok(Some((proving_key, verifying_key)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credits_key_files() {
+        assert_eq!(credits_key_files("fee").unwrap(), ("fee.prover.0bfc24f", "fee.verifier.44783e8"));
+        assert!(credits_key_files("mint").is_err());
+    }
+
+    #[test]
+    fn test_file_system_key_paths() {
+        let provider = FileSystemKeyProvider::<Testnet3>::new("keys");
+        let program = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let function = Identifier::from_str("hello").unwrap();
+        let (prover, verifier) = provider.key_paths(program.id(), &function);
+        assert_eq!(prover, PathBuf::from("keys/hello.aleo/hello.prover"));
+        assert_eq!(verifier, PathBuf::from("keys/hello.aleo/hello.verifier"));
+
+        // Missing keys are reported as absent rather than as an error
+        assert!(provider.get_keys(&program, &[], &function).unwrap().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "full")]
+    fn test_parameter_key_provider_rejects_corrupt_keys() {
+        let provider = ParameterKeyProvider::<Testnet3>::new(&serve_responses(vec![(200, "corrupt")]));
+        let credits = Program::<Testnet3>::credits().unwrap();
+        let fee = Identifier::from_str("fee").unwrap();
+        let error = provider.get_keys(&credits, &[], &fee).unwrap_err();
+        assert!(error.to_string().contains("Checksum mismatch for fee.prover.0bfc24f"));
+    }
+
+    #[test]
+    #[cfg(feature = "full")]
+    fn test_imported_programs() {
+        let vm = crate::program::open_memory_vm::<Testnet3>().unwrap();
+        let program = Program::<Testnet3>::from_str(IMPORT_PROGRAM).unwrap();
+        crate::program::add_programs_to_vm(&vm, &[program.clone()]).unwrap();
+        let process = vm.process().read();
+
+        let imports = imported_programs(&process, &program).unwrap();
+        assert_eq!(imports, vec![Program::credits().unwrap()]);
+        assert!(imported_programs(&process, &imports[0]).unwrap().is_empty());
+    }
+}
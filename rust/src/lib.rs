@@ -160,25 +160,105 @@
 //!   program_manager.transfer(amount, fee, recipient_address, Some("password"), amount_record, fee_record).unwrap();
 //!
 //!   ```
+//! # Profiling
+//!
+//! Transaction building is instrumented with [tracing](https://docs.rs/tracing) spans carrying the
+//! program and function being executed (`build_execution`, `load_keys`, `synthesis`, `execution`,
+//! `fee_proof`, `authorization`, `inclusion_proof`, `execution_proof`, `broadcast`, ...). Install a subscriber
+//! which reports span durations, i.e. `tracing_subscriber::fmt().with_span_events(FmtSpan::CLOSE)`,
+//! to see where the time of building a transaction goes.
+//!
 //! This API is currently under active development and is expected to change in the future in order
 //! to provide a more streamlined experience for program execution and deployment.
 //!
 
+#[cfg(feature = "abi")]
+pub mod abi;
+#[cfg(feature = "abi")]
+#[doc(inline)]
+pub use abi::ProgramAbi;
+
+#[cfg(feature = "abi")]
+pub mod cost;
+
 pub mod account;
 #[doc(inline)]
-pub use account::Encryptor;
+pub use account::{wipe_private_key, wipe_view_key, Encryptor, KeyDerivation, Keystore, Mnemonic, SigningDomain};
 
 #[cfg(feature = "full")]
 pub mod api;
+#[cfg(feature = "async")]
+#[doc(inline)]
+pub use api::AsyncAleoAPIClient;
 #[cfg(feature = "full")]
 #[doc(inline)]
-pub use api::AleoAPIClient;
+pub use api::{AleoAPIClient, AleoRpcClient, ClientConfig, RetryPolicy};
 
 #[cfg(feature = "full")]
 pub mod program;
+#[cfg(feature = "leo")]
+#[doc(inline)]
+pub use program::LeoBuilder;
+#[cfg(feature = "full")]
+#[doc(inline)]
+pub use program::{
+    DeploymentCheck,
+    DryRun,
+    OnChainProgramState,
+    Payout,
+    PrivateKeySigner,
+    ProgramManager,
+    RecordFinder,
+    RecordReservation,
+    ReservedTransaction,
+    Signer,
+    TransferType,
+};
+#[cfg(feature = "ledger")]
+#[doc(inline)]
+pub use program::{HidTransport, LedgerCommand, LedgerSigner, LedgerTransport};
+
+#[cfg(feature = "devnet")]
+pub mod devnet;
+#[cfg(feature = "devnet")]
+#[doc(inline)]
+pub use devnet::{Devnet, DevnetConfig};
+
+pub mod error;
+#[doc(inline)]
+pub use error::AleoSdkError;
+
+#[cfg(feature = "indexer")]
+pub mod indexer;
+#[cfg(feature = "indexer")]
+#[doc(inline)]
+pub use indexer::{Direction, HistoryEntry, HistoryQuery, IndexedAccount, Indexer};
+
+#[cfg(feature = "keys")]
+pub mod keys;
+#[cfg(feature = "keys")]
+#[doc(inline)]
+pub use keys::{imported_programs, DiskKeyCache, FileSystemKeyProvider, KeyProvider, MemoryKeyProvider};
 #[cfg(feature = "full")]
 #[doc(inline)]
-pub use program::{OnChainProgramState, ProgramManager, RecordFinder, TransferType};
+pub use keys::{ParameterDownloader, ParameterKeyProvider};
+
+pub mod networks;
+#[doc(inline)]
+pub use networks::AleoNetwork;
+
+pub mod endpoint;
+#[doc(inline)]
+pub use endpoint::{Endpoint, MAX_BLOCKS_PER_REQUEST};
+
+pub mod record;
+#[doc(inline)]
+pub use record::SpentRecords;
+
+pub mod transition;
+#[cfg(feature = "abi")]
+#[doc(inline)]
+pub use transition::{decrypt_transaction, decrypt_transition, DecryptedTransition};
 
 #[cfg(test)]
 #[cfg(feature = "full")]
@@ -189,6 +269,8 @@ pub use test_utils::*;
 
 pub mod snarkvm_types {
     //! Re-export of crucial types from the snarkVM crate
+    #[cfg(feature = "rocks")]
+    pub use snarkvm::synthesizer::store::helpers::rocksdb::ConsensusDB;
     #[cfg(feature = "full")]
     pub use snarkvm::synthesizer::{
         store::helpers::memory::ConsensusMemory,
@@ -208,7 +290,11 @@ pub mod snarkvm_types {
     };
 }
 #[cfg(feature = "full")]
-use snarkvm::{file::Manifest, package::Package};
+use snarkvm::{
+    file::Manifest,
+    package::Package,
+    synthesizer::store::{BlockStorage, ConsensusStorage},
+};
 
 pub use snarkvm_types::*;
 
@@ -217,7 +303,13 @@ use indexmap::IndexMap;
 use once_cell::sync::OnceCell;
 use snarkvm_console::program::Entry;
 #[cfg(feature = "full")]
-use std::{convert::TryInto, fs::File, io::Read, ops::Range, path::PathBuf};
+use std::{
+    convert::TryInto,
+    fs::File,
+    io::Read,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 use std::{iter::FromIterator, marker::PhantomData, str::FromStr};
 
 /// A trait providing convenient methods for accessing the amount of Aleo present in a record
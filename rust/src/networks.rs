@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Aleo networks supported by the SDK
+
+use super::*;
+
+use std::fmt;
+
+/// An Aleo network which can be selected at runtime
+///
+/// Types in the SDK are generic over the snarkVM `Network` trait, while node urls and
+/// configuration files refer to networks by name. This enum connects the two, so an application
+/// can select a network from its configuration and check it matches the network it was built for.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AleoNetwork {
+    /// The Aleo testnet3 network
+    #[default]
+    Testnet3,
+}
+
+impl AleoNetwork {
+    /// Every network supported by this version of the SDK
+    pub const ALL: [AleoNetwork; 1] = [AleoNetwork::Testnet3];
+
+    /// Get the name used for the network in node urls (i.e. "testnet3")
+    pub fn name(&self) -> &'static str {
+        match self {
+            AleoNetwork::Testnet3 => "testnet3",
+        }
+    }
+
+    /// Get the numeric id of the network
+    pub fn id(&self) -> u16 {
+        match self {
+            AleoNetwork::Testnet3 => <Testnet3 as Network>::ID,
+        }
+    }
+
+    /// Get the url of the public API of the network
+    pub fn default_api_url(&self) -> &'static str {
+        match self {
+            AleoNetwork::Testnet3 => "https://vm.aleo.org/api",
+        }
+    }
+
+    /// Get the url of the official server hosting the parameters of the network
+    pub fn parameters_url(&self) -> &'static str {
+        match self {
+            AleoNetwork::Testnet3 => "https://testnet3.parameters.aleo.org",
+        }
+    }
+
+    /// Get the network corresponding to a snarkVM network type
+    pub fn of<N: Network>() -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|network| network.id() == N::ID)
+            .ok_or_else(|| anyhow!("Network {} is not supported by this version of the SDK", N::ID))
+    }
+
+    /// Ensure the network is the network of a snarkVM network type
+    pub fn ensure_matches<N: Network>(&self) -> Result<()> {
+        let expected = Self::of::<N>()?;
+        ensure!(*self == expected, "The {self} network was selected but the SDK was built for the {expected} network");
+        Ok(())
+    }
+}
+
+impl FromStr for AleoNetwork {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|network| network.name().eq_ignore_ascii_case(name.trim()))
+            .ok_or_else(|| anyhow!("The {name} network is not supported by this version of the SDK"))
+    }
+}
+
+impl fmt::Display for AleoNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aleo_network() {
+        assert_eq!(AleoNetwork::from_str("testnet3").unwrap(), AleoNetwork::Testnet3);
+        assert_eq!(AleoNetwork::from_str("Testnet3").unwrap(), AleoNetwork::Testnet3);
+        assert!(AleoNetwork::from_str("mainnet").is_err());
+        assert_eq!(AleoNetwork::of::<Testnet3>().unwrap(), AleoNetwork::Testnet3);
+        assert!(AleoNetwork::Testnet3.ensure_matches::<Testnet3>().is_ok());
+        assert_eq!(AleoNetwork::default().to_string(), "testnet3");
+    }
+}
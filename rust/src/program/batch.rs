@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A credits.aleo transfer to one recipient within a batch of payouts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Payout<N: Network> {
+    /// The address receiving the credits
+    pub recipient: Address<N>,
+    /// The amount transferred in microcredits
+    pub amount: u64,
+    /// The credits.aleo function performing the transfer
+    pub transfer_type: TransferType,
+}
+
+impl<N: Network> Payout<N> {
+    /// Create a payout of an amount of microcredits to a recipient
+    pub fn new(recipient: Address<N>, amount: u64, transfer_type: TransferType) -> Self {
+        Self { recipient, amount, transfer_type }
+    }
+}
+
+/// Select one distinct record covering each amount, returned in the order of the amounts
+///
+/// The largest amounts are matched first, each with the smallest record still available which
+/// covers it.
+pub(crate) fn select_for_amounts<N: Network>(
+    candidates: Vec<Record<N, Plaintext<N>>>,
+    amounts: &[u64],
+) -> Result<Vec<Record<N, Plaintext<N>>>> {
+    let mut candidates = candidates
+        .into_iter()
+        .filter_map(|record| record.microcredits().ok().map(|microcredits| (microcredits, record)))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|(microcredits, _)| *microcredits);
+
+    let mut order = (0..amounts.len()).collect::<Vec<_>>();
+    order.sort_by_key(|index| std::cmp::Reverse(amounts[*index]));
+    let mut selected = vec![None; amounts.len()];
+    for index in order {
+        let amount = amounts[index];
+        let position = candidates
+            .iter()
+            .position(|(microcredits, _)| *microcredits >= amount)
+            .ok_or(AleoSdkError::InsufficientFunds { required: amount, available: None })?;
+        selected[index] = Some(candidates.remove(position).1);
+    }
+    Ok(selected.into_iter().flatten().collect())
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Pay a batch of recipients and broadcast the transactions, returning their ids
+    ///
+    /// The records of every broadcast transaction stay reserved. If a broadcast fails, the records
+    /// of that payout and of the payouts after it are released, and the transactions broadcast
+    /// before it are not rolled back.
+    pub fn transfer_batch(&self, payouts: &[Payout<N>], fee: u64, password: Option<&str>) -> Result<Vec<String>> {
+        let private_key = self.get_private_key(password)?;
+        let transactions = self.build_transfers(&private_key, payouts, fee, None)?;
+        transactions.into_iter().map(|transaction| self.broadcast_reserved(transaction)).collect()
+    }
+
+    /// Build the transactions paying a batch of recipients
+    ///
+    /// A transaction may only hold the transitions of one root function call and the calls it
+    /// makes, and credits.aleo has no function paying several recipients, so every payout is a
+    /// separate transaction paying its own fee. Building them as a batch still saves work: the
+    /// proving keys are loaded or synthesized once for every payout, and the amount and fee
+    /// records of all payouts are reserved together through the record resolver before proving
+    /// starts, so each payout spends distinct records and a shortfall is reported up front. Every
+    /// transaction holds the reservation of its own records until it is broadcast with
+    /// `broadcast_reserved`.
+    pub fn build_transfers(
+        &self,
+        private_key: &PrivateKey<N>,
+        payouts: &[Payout<N>],
+        fee: u64,
+        query: Option<&str>,
+    ) -> Result<Vec<ReservedTransaction<N>>> {
+        ensure!(!payouts.is_empty(), AleoSdkError::invalid_input("At least one payout must be specified"));
+        ensure!(fee > 0, AleoSdkError::invalid_input("Fee must be greater than 0"));
+        let _span = info_span!("build_transfers", payouts = payouts.len()).entered();
+
+        // Reserve the amount record (for private transfers) and the fee record of every payout
+        let amounts = payouts
+            .iter()
+            .flat_map(|payout| payout.transfer_type.requires_record().then_some(payout.amount).into_iter().chain([fee]))
+            .collect::<Vec<_>>();
+        let minimum = amounts.iter().copied().min().unwrap_or(fee);
+        let records = self.find_unspent_credits(private_key, minimum)?;
+        let mut reservation =
+            self.record_reservation.reserve_selected(records, |candidates| select_for_amounts(candidates, &amounts))?;
+
+        let vm = open_memory_vm()?;
+        let (credits, fee_name) = fee_function()?;
+        let mut functions = vec![(credits, fee_name)];
+        for payout in payouts {
+            let function = (credits, Identifier::from_str(payout.transfer_type.function_name())?);
+            if !functions.contains(&function) {
+                functions.push(function);
+            }
+        }
+        let synthesized = self.load_provided_keys(&vm, &functions)?;
+
+        let rng = &mut rand::thread_rng();
+        let mut transactions = Vec::with_capacity(payouts.len());
+        for payout in payouts {
+            let requires_record = payout.transfer_type.requires_record();
+            let records = reservation.split_first(if requires_record { 2 } else { 1 });
+            let mut inputs = records.records().iter().cloned();
+            let amount_record = if requires_record { inputs.next() } else { None };
+            let fee_record = inputs.next().ok_or_else(|| anyhow!("Missing fee record for payout"))?;
+            let (inputs, change) =
+                transfer_inputs(&payout.transfer_type, &payout.recipient, payout.amount, amount_record)?;
+            let function = Identifier::from_str(payout.transfer_type.function_name())?;
+            let state_query = self.network_query(query)?;
+            let transaction = info_span!("execute_and_prove", recipient = %payout.recipient)
+                .in_scope(|| {
+                    vm.execute(
+                        private_key,
+                        (credits, function),
+                        inputs.iter(),
+                        Some((fee_record, fee)),
+                        Some(state_query),
+                        rng,
+                    )
+                })
+                .map_err(|error| {
+                    self.execution_error(query, format!("Failed to execute credits.aleo/{function}"), error)
+                })?;
+            transactions.push(ReservedTransaction::new(transaction, Some(records)).with_change(change));
+        }
+        self.store_synthesized_keys(&vm, &synthesized);
+        Ok(transactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_select_for_amounts() {
+        let address = Address::<Testnet3>::from_str(RECIPIENT_ADDRESS).unwrap();
+        let records = [100, 500, 250, 50].map(|microcredits| credits_record(&address, microcredits)).to_vec();
+
+        // Every amount is matched with a distinct record, in the order of the amounts
+        let selected = select_for_amounts(records.clone(), &[40, 200, 90]).unwrap();
+        let selected = selected.iter().map(|record| record.microcredits().unwrap()).collect::<Vec<_>>();
+        assert_eq!(selected, vec![50, 250, 100]);
+
+        // Larger amounts are matched first so they are not starved by smaller ones
+        let selected = select_for_amounts(records.clone(), &[100, 400]).unwrap();
+        let selected = selected.iter().map(|record| record.microcredits().unwrap()).collect::<Vec<_>>();
+        assert_eq!(selected, vec![100, 500]);
+
+        // A record is never used for two amounts
+        assert!(select_for_amounts(records.clone(), &[300, 300]).is_err());
+        assert!(select_for_amounts(records, &[10, 10, 10, 10, 10]).is_err());
+    }
+}
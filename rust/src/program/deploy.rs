@@ -28,25 +28,28 @@ impl<N: Network> ProgramManager<N> {
         // Ensure a network client is configured, otherwise deployment is not possible
         ensure!(
             self.api_client.is_some(),
-            "❌ Network client not set, network config must be set before deployment in order to send transactions to the Aleo network"
+            AleoSdkError::invalid_input(
+                "❌ Network client not set, network config must be set before deployment in order to send transactions to the Aleo network"
+            )
         );
 
         // Ensure a fee is specified and the record has enough balance to pay for it
-        ensure!(fee > 0, "❌ Fee must be greater than zero in order to deploy a program");
+        ensure!(fee > 0, AleoSdkError::invalid_input("❌ Fee must be greater than zero in order to deploy a program"));
         let record_amount = fee_record.microcredits()?;
-        ensure!(
-            record_amount >= fee,
-            "❌ The record supplied has balance of {record_amount:?} microcredits which is insufficient to pay the specified fee of {fee:?} microcredits"
-        );
+        ensure!(record_amount >= fee, AleoSdkError::InsufficientFunds {
+            required: fee,
+            available: Some(record_amount)
+        });
 
         // Check program has a valid name
-        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
 
         // Check if program is already deployed on chain, cancel deployment if so
         ensure!(
-            self.api_client()?.get_program(program_id).is_err(),
-            "❌ Program {:?} already deployed on chain, cancelling deployment",
-            program_id
+            self.api_client()?.find_program(program_id)?.is_none(),
+            AleoSdkError::invalid_input(format!(
+                "❌ Program {program_id:?} already deployed on chain, cancelling deployment"
+            ))
         );
 
         // Get the program if it already exists, otherwise find it
@@ -77,7 +80,11 @@ impl<N: Network> ProgramManager<N> {
                 self.get_program(program_id)
             } else {
                 self.find_program(program_id)
-            }.map_err(|_| anyhow!("❌ Imported program {program_id:?} could not be found locally or on the Aleo Network"))?;
+            }
+            .map_err(|error| AleoSdkError::Resolution {
+                message: format!("❌ Imported program {program_id:?} could not be found locally or on the Aleo Network"),
+                source: Some(error.into()),
+            })?;
             let imported_program_id = imported_program.id();
             match self.on_chain_program_state(&imported_program)? {
                 OnChainProgramState::NotDeployed => {
@@ -117,11 +124,7 @@ impl<N: Network> ProgramManager<N> {
         let result = if usize::try_from(fee)? >= required_fee {
             self.broadcast_transaction(transaction)
         } else {
-            bail!(
-                "❌ Insufficient funds to pay for transaction fee, required fee: {}, fee specified in the record: {}",
-                required_fee,
-                fee
-            )
+            bail!(AleoSdkError::InsufficientFunds { required: required_fee as u64, available: Some(fee) })
         };
 
         // Notify the developer of the result
@@ -142,22 +145,35 @@ impl<N: Network> ProgramManager<N> {
         fee: u64,
         fee_record: Record<N, Plaintext<N>>,
         query: String,
+    ) -> Result<Transaction<N>> {
+        let vm = open_memory_vm()?;
+        Self::create_deploy_transaction_with_vm(&vm, program, imports, private_key, fee, fee_record, query)
+    }
+
+    /// Create a deploy transaction using an existing VM
+    ///
+    /// Reusing a VM (i.e. one opened with `open_persistent_vm`) across builds avoids initializing
+    /// a new VM and re-adding the imports for every transaction. Imports already held by the VM
+    /// are skipped.
+    pub fn create_deploy_transaction_with_vm<C: ConsensusStorage<N>>(
+        vm: &VM<N, C>,
+        program: &Program<N>,
+        imports: &[Program<N>],
+        private_key: &PrivateKey<N>,
+        fee: u64,
+        fee_record: Record<N, Plaintext<N>>,
+        query: String,
     ) -> Result<Transaction<N>> {
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
-        let query = Query::from(query);
-
-        // Attempt to add the programs to a local VM. This will fail if any imports are duplicated.
-        let store = ConsensusStore::<N, ConsensusMemory<N>>::open(None)?;
-        let vm = VM::<N, ConsensusMemory<N>>::from(store)?;
-        imports.iter().try_for_each(|imported_program| {
-            if imported_program.id().to_string() != "credits.aleo" {
-                vm.process().write().add_program(imported_program)?;
-            };
-            Ok::<_, Error>(())
-        })?;
+        let query = AleoRpcClient::<N>::for_network(&query)?.query();
+
+        // Attempt to add the imports to the VM
+        add_programs_to_vm(vm, imports)?;
 
-        vm.deploy(private_key, program, (fee_record, fee), Some(query), rng)
+        // The VM synthesizes the keys of the program, then builds and proves the fee
+        info_span!("build_deployment", program = %program.id())
+            .in_scope(|| vm.deploy(private_key, program, (fee_record, fee), Some(query), rng))
     }
 }
 
@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::cost::EXECUTION_PROOF_SIZE_ESTIMATE;
+use snarkvm::{
+    circuit::Aleo,
+    synthesizer::{Authorization, Function, Operand},
+};
+
+/// The finalize block a transition schedules when its execution is accepted
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeOperation<N: Network> {
+    /// The program of the transition
+    pub program_id: ProgramID<N>,
+    /// The function of the transition
+    pub function_name: Identifier<N>,
+    /// The inputs passed to the finalize block
+    pub inputs: Vec<Value<N>>,
+    /// The commands of the finalize block
+    pub commands: Vec<String>,
+}
+
+/// The result of running a program function without proving it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DryRun<N: Network> {
+    /// The outputs of the function
+    pub outputs: Vec<Value<N>>,
+    /// The finalize blocks the execution schedules, in the order they would run
+    pub finalize: Vec<FinalizeOperation<N>>,
+    /// The estimated fee in microcredits of executing the function on the network
+    pub estimated_fee: u64,
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Run a program function locally without creating a proof
+    ///
+    /// The function is authorized with the private key of the program manager and evaluated on a
+    /// process holding the program and its imports, which yields its outputs and the finalize
+    /// blocks it schedules. No circuits are synthesized, so no proving or verifying keys are
+    /// needed. The finalize blocks are not run, since they depend on the state of the network.
+    /// The estimated fee is the serialized size of the inputs and outputs of every call plus a
+    /// bound on the size of the proof and the estimated cost of the finalize blocks. It is lower
+    /// than the fee of the final execution, whose transitions also hold commitments and ciphertexts.
+    pub fn dry_run<A: Aleo<Network = N>>(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        password: Option<&str>,
+    ) -> Result<DryRun<N>> {
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
+        let function_name = function.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid function name"))?;
+        let private_key = self.get_private_key(password)?;
+        let _span = info_span!("dry_run", program = %program_id, function = %function_name).entered();
+
+        let vm = open_memory_vm()?;
+        add_programs_to_vm(&vm, &self.load_program_with_imports(&program_id)?)?;
+
+        let process = vm.process().read();
+        ensure!(
+            process.get_program(program_id)?.contains_function(&function_name),
+            AleoSdkError::invalid_input(format!(
+                "Program {program_id:?} does not contain function {function_name:?}, aborting dry run"
+            ))
+        );
+        let rng = &mut rand::thread_rng();
+        let authorization = info_span!("authorization")
+            .in_scope(|| process.authorize::<A, _>(&private_key, program_id, function_name, inputs, rng))?;
+
+        // Requests are ordered by call, so the requests from each one onwards authorize that call
+        // and the calls it makes. Evaluating them one at a time yields the outputs of every call.
+        let requests = Vec::from(authorization.to_vec_deque());
+        let mut outputs = vec![];
+        let mut finalize = vec![];
+        let mut estimated_fee = EXECUTION_PROOF_SIZE_ESTIMATE;
+        let _span = info_span!("evaluation").entered();
+        for (index, request) in requests.iter().enumerate() {
+            let response = process.evaluate::<A>(Authorization::new(&requests[index..])).map_err(|error| {
+                AleoSdkError::execution(
+                    format!("Failed to run {}/{}", request.program_id(), request.function_name()),
+                    error,
+                )
+            })?;
+            let program = process.get_program(request.program_id())?;
+            let function = program.get_function(request.function_name())?;
+            for value in request.inputs().iter().chain(response.outputs()) {
+                estimated_fee = estimated_fee.saturating_add(value.to_bytes_le()?.len() as u64);
+            }
+            if let Some((command, block)) = function.finalize() {
+                let inputs = command
+                    .operands()
+                    .iter()
+                    .map(|operand| finalize_input(function, request.inputs(), response.outputs(), operand))
+                    .collect::<Result<Vec<_>>>()?;
+                estimated_fee = estimated_fee.saturating_add(estimate_finalize_cost(program, request.function_name())?);
+                finalize.push(FinalizeOperation {
+                    program_id: *request.program_id(),
+                    function_name: *request.function_name(),
+                    inputs,
+                    commands: block.commands().iter().map(|command| command.to_string()).collect(),
+                });
+            }
+            if index == 0 {
+                outputs = response.outputs().to_vec();
+            }
+        }
+
+        Ok(DryRun { outputs, finalize, estimated_fee })
+    }
+}
+
+/// Resolve an operand of a finalize command from the inputs and outputs of its call
+fn finalize_input<N: Network>(
+    function: &Function<N>,
+    inputs: &[Value<N>],
+    outputs: &[Value<N>],
+    operand: &Operand<N>,
+) -> Result<Value<N>> {
+    match operand {
+        Operand::Literal(literal) => return Ok(Value::Plaintext(Plaintext::from(literal.clone()))),
+        Operand::Register(register) => {
+            if let Some(index) = function.inputs().iter().position(|input| input.register() == register) {
+                return Ok(inputs[index].clone());
+            }
+            let output = function.outputs().iter().position(|output| match output.operand() {
+                Operand::Register(output_register) => output_register == register,
+                _ => false,
+            });
+            if let Some(index) = output {
+                return Ok(outputs[index].clone());
+            }
+        }
+        _ => (),
+    }
+    bail!(
+        "The finalize operand {operand} of {} is neither an input nor an output of the function, which a dry run cannot resolve",
+        function.name()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cost::FINALIZE_MAPPING_COMMAND_COST, MemoryKeyProvider};
+    use snarkvm::circuit::AleoV0;
+    use snarkvm_console::network::Testnet3;
+
+    const COUNTER_PROGRAM: &str = "program counter_test.aleo;
+
+mapping counter:
+    key id as u32.public;
+    value count as u32.public;
+
+function increment:
+    input r0 as u32.public;
+    input r1 as u32.public;
+    finalize r0 r1;
+
+finalize increment:
+    input r0 as u32.public;
+    input r1 as u32.public;
+    increment counter[r0] by r1;
+";
+
+    #[test]
+    fn test_dry_run() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        program_manager.add_program(&Program::from_str(HELLO_PROGRAM).unwrap()).unwrap();
+        program_manager.add_program(&Program::from_str(COUNTER_PROGRAM).unwrap()).unwrap();
+        let provider = MemoryKeyProvider::new();
+        program_manager.set_key_provider(provider.clone());
+
+        let dry_run =
+            program_manager.dry_run::<AleoV0>("hello.aleo", "hello", ["5u32", "3u32"].into_iter(), None).unwrap();
+        assert_eq!(dry_run.outputs, vec![Value::from_str("8u32").unwrap()]);
+        assert!(dry_run.finalize.is_empty());
+        assert!(dry_run.estimated_fee > EXECUTION_PROOF_SIZE_ESTIMATE);
+
+        // The function is evaluated without synthesizing its keys
+        let hello = ProgramID::from_str("hello.aleo").unwrap();
+        assert!(!provider.contains(&hello, &Identifier::from_str("hello").unwrap()));
+
+        let dry_run = program_manager
+            .dry_run::<AleoV0>("counter_test.aleo", "increment", ["1u32", "2u32"].into_iter(), None)
+            .unwrap();
+        assert!(dry_run.outputs.is_empty());
+        assert_eq!(dry_run.finalize.len(), 1);
+        let operation = &dry_run.finalize[0];
+        assert_eq!(operation.function_name.to_string(), "increment");
+        assert_eq!(operation.inputs, vec![Value::from_str("1u32").unwrap(), Value::from_str("2u32").unwrap()]);
+        assert_eq!(operation.commands.len(), 1);
+        assert!(operation.commands[0].starts_with("increment counter[r0]"));
+        assert!(dry_run.estimated_fee > FINALIZE_MAPPING_COMMAND_COST);
+
+        // Functions which do not exist cannot be run
+        assert!(program_manager.dry_run::<AleoV0>("hello.aleo", "goodbye", ["5u32"].into_iter(), None).is_err());
+    }
+}
@@ -29,24 +29,33 @@ impl<N: Network> ProgramManager<N> {
         fee_record: Record<N, Plaintext<N>>,
         password: Option<&str>,
     ) -> Result<String> {
-        ensure!(fee > 0, "Fee must be greater than 0");
+        ensure!(fee > 0, AleoSdkError::invalid_input("Fee must be greater than 0"));
 
         // Ensure network config is set, otherwise execution is not possible
         ensure!(
             self.api_client.is_some(),
-            "❌ Network client not set. A network client must be set before execution in order to send an execution transaction to the Aleo network"
+            AleoSdkError::invalid_input(
+                "❌ Network client not set. A network client must be set before execution in order to send an execution transaction to the Aleo network"
+            )
         );
 
         // Check program and function has a valid name
-        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
-        let function_id = function.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
+        let function_id = function.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid function name"))?;
         let function_name = function_id.to_string();
 
         // Get the program from chain, error if it doesn't exist
         let program = self
             .api_client()?
             .get_program(program_id)
-            .map_err(|_| anyhow!("Program {program_id:?} does not exist on the Aleo Network. Try deploying the program first before executing."))?;
+            .map_err(|error| match AleoSdkError::find(&error) {
+                Some(AleoSdkError::Network { status: Some(404), .. }) | None => AleoSdkError::Resolution {
+                    message: format!("Program {program_id:?} does not exist on the Aleo Network. Try deploying the program first before executing."),
+                    source: Some(error.into()),
+                }
+                .into(),
+                Some(_) => error,
+            })?;
 
         // Try to get the private key configured in the program manager
         let private_key = self.get_private_key(password)?;
@@ -54,7 +63,11 @@ impl<N: Network> ProgramManager<N> {
         // Attempt to construct the execution transaction
         println!("Building transaction..");
         let query = self.api_client.as_ref().unwrap().base_url();
-        let transaction = Self::create_execute_transaction(
+        let vm = open_memory_vm()?;
+        add_programs_to_vm(&vm, std::slice::from_ref(&program))?;
+        let synthesized = self.load_provided_keys(&vm, &[(program_id, function_id), fee_function()?])?;
+        let transaction = Self::create_execute_transaction_with_vm(
+            &vm,
             &private_key,
             fee,
             inputs,
@@ -63,6 +76,7 @@ impl<N: Network> ProgramManager<N> {
             function_id,
             query.to_string(),
         )?;
+        self.store_synthesized_keys(&vm, &synthesized);
 
         // Broadcast the execution transaction to the network
         println!("Attempting to broadcast execution transaction for {program_id:?}");
@@ -78,6 +92,198 @@ impl<N: Network> ProgramManager<N> {
         execution
     }
 
+    /// Build an execution transaction which is ready to be broadcast to the Aleo network
+    ///
+    /// The program and its imports are loaded from the program manager, the local program
+    /// directory or the network. The function is authorized with the private key and executed,
+    /// and the proofs of the execution, its inclusion and the fee are created. If no query url is
+    /// given, the state of the network is queried from the API client of the program manager.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_execution(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        fee: u64,
+        fee_record: Record<N, Plaintext<N>>,
+        query: Option<&str>,
+    ) -> Result<Transaction<N>> {
+        ensure!(fee > 0, AleoSdkError::invalid_input("Fee must be greater than 0"));
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
+        let function_name = function.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid function name"))?;
+        let _span = info_span!("build_execution", program = %program_id, function = %function_name).entered();
+        let state_query = self.network_query(query)?;
+
+        // Load the program along with every program it depends on
+        let programs = self.load_program_with_imports(&program_id)?;
+        let program = programs
+            .last()
+            .ok_or_else(|| AleoSdkError::resolution(format!("Program {program_id:?} could not be loaded")))?;
+        ensure!(
+            program.contains_function(&function_name),
+            AleoSdkError::invalid_input(format!(
+                "Program {program_id:?} does not contain function {function_name:?}, aborting execution"
+            ))
+        );
+
+        // Create an ephemeral SnarkVM holding the program and its imports
+        let vm = open_memory_vm()?;
+        add_programs_to_vm(&vm, &programs)?;
+        let functions = [(program_id, function_name), fee_function()?];
+        let synthesized = self.load_provided_keys(&vm, &functions)?;
+
+        // Synthesize the missing keys, then authorize, execute and prove the function and the fee
+        let transaction = Self::execute_and_prove(
+            &vm,
+            private_key,
+            (program_id, function_name),
+            inputs,
+            (fee_record, fee),
+            &synthesized,
+            state_query,
+        )
+        .map_err(|error| {
+            self.execution_error(query, format!("Failed to execute {program_id}/{function_name}"), error)
+        })?;
+        self.store_synthesized_keys(&vm, &synthesized);
+        Ok(transaction)
+    }
+
+    /// Build an execution of a credits.aleo function paid for by a fee record
+    ///
+    /// The fee record reserved through the record resolver (if no fee record is given) stays
+    /// reserved by the returned transaction.
+    pub(crate) fn build_credits_execution(
+        &self,
+        private_key: &PrivateKey<N>,
+        function: &str,
+        inputs: Vec<Value<N>>,
+        fee: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        query: Option<&str>,
+    ) -> Result<ReservedTransaction<N>> {
+        ensure!(fee > 0, AleoSdkError::invalid_input("Fee must be greater than 0"));
+        let _span = info_span!("build_execution", program = "credits.aleo", function).entered();
+        let state_query = self.network_query(query)?;
+
+        // Hold the fee record reservation (if any) until the transaction is broadcast
+        let (fee_record, reservation) = self.resolve_fee_record(private_key, fee, fee_record)?;
+
+        let vm = open_memory_vm()?;
+        let (credits, fee_name) = fee_function()?;
+        let function_name = Identifier::from_str(function)
+            .map_err(|_| AleoSdkError::invalid_input(format!("Invalid credits.aleo function {function}")))?;
+        let functions = [(credits, function_name), (credits, fee_name)];
+        let synthesized = self.load_provided_keys(&vm, &functions)?;
+
+        let transaction = Self::execute_and_prove(
+            &vm,
+            private_key,
+            (credits, function_name),
+            inputs.iter(),
+            (fee_record, fee),
+            &synthesized,
+            state_query,
+        )
+        .map_err(|error| self.execution_error(query, format!("Failed to execute credits.aleo/{function}"), error))?;
+        self.store_synthesized_keys(&vm, &synthesized);
+        Ok(ReservedTransaction::new(transaction, reservation.map(ReservedFeeRecord::into_reservation)))
+    }
+
+    /// Execute a function and prove it along with the fee paying for it
+    ///
+    /// The keys of the `missing` functions are synthesized first, so key synthesis, the execution
+    /// and the fee proof are each traced in their own span.
+    fn execute_and_prove<C: ConsensusStorage<N>>(
+        vm: &VM<N, C>,
+        private_key: &PrivateKey<N>,
+        function: (ProgramID<N>, Identifier<N>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        (fee_record, fee): (Record<N, Plaintext<N>>, u64),
+        missing: &[(ProgramID<N>, Identifier<N>)],
+        state_query: Query<N, C::BlockStorage>,
+    ) -> Result<Transaction<N>> {
+        for (program_id, function_name) in missing {
+            info_span!("synthesis", program = %program_id, function = %function_name)
+                .in_scope(|| synthesize_key(vm, program_id, function_name))?;
+        }
+
+        let rng = &mut rand::thread_rng();
+        let transaction = info_span!("execution")
+            .in_scope(|| vm.execute(private_key, function, inputs, None, Some(state_query.clone()), rng))?;
+        let Transaction::Execute(_, execution, _) = transaction else {
+            bail!("Executing {}/{} did not create an execution", function.0, function.1);
+        };
+        let execution_id = execution.to_execution_id()?;
+        let (_, fee, _) = info_span!("fee_proof")
+            .in_scope(|| vm.execute_fee_raw(private_key, fee_record, fee, execution_id, Some(state_query), rng))?;
+        Transaction::from_execution(execution, Some(fee))
+    }
+
+    /// Create a query of the network state from a node url, or from the API client if no url is given
+    pub(crate) fn network_query<B: BlockStorage<N>>(&self, query: Option<&str>) -> Result<Query<N, B>> {
+        match query {
+            Some(query) => Ok(AleoRpcClient::for_network(query)?.query()),
+            None => Ok(self.api_client()?.rpc().query()),
+        }
+    }
+
+    /// Classify a failure of the VM to build a transaction
+    ///
+    /// The VM queries the state of the network while it executes, so a failed execution may be a
+    /// failed request. Failures which already carry an `AleoSdkError` keep it, failures while the
+    /// node of the query is unreachable are reported as network errors and all others as
+    /// execution errors.
+    pub(crate) fn execution_error(&self, query: Option<&str>, message: String, error: Error) -> Error {
+        if AleoSdkError::find(&error).is_some() {
+            return error.context(message);
+        }
+        let client = match query {
+            Some(query) => AleoRpcClient::<N>::for_network(query),
+            None => self.api_client().map(|api_client| api_client.rpc().clone()),
+        };
+        match client.and_then(|client| client.latest_state_root()) {
+            Err(network_error) if AleoSdkError::find(&network_error).is_some() => network_error.context(message),
+            _ => AleoSdkError::execution(message, error).into(),
+        }
+    }
+
+    /// Load a program and its imports, ordered so every program comes after the programs it
+    /// imports. credits.aleo is part of every VM and is not included.
+    pub(crate) fn load_program_with_imports(&self, program_id: &ProgramID<N>) -> Result<Vec<Program<N>>> {
+        let mut programs = IndexMap::new();
+        self.load_imports_recursively(program_id, &mut programs)?;
+        Ok(programs.into_values().collect())
+    }
+
+    fn load_imports_recursively(
+        &self,
+        program_id: &ProgramID<N>,
+        programs: &mut IndexMap<ProgramID<N>, Program<N>>,
+    ) -> Result<()> {
+        if programs.contains_key(program_id) || program_id.to_string() == "credits.aleo" {
+            return Ok(());
+        }
+        let program = match self.programs.get(program_id) {
+            Some(program) => program.clone(),
+            // Keep the cause, which may be a network error rather than a missing program
+            None => self.find_program(program_id).map_err(|error| match AleoSdkError::find(&error) {
+                Some(AleoSdkError::Network { .. }) => error,
+                _ => AleoSdkError::Resolution {
+                    message: format!("Program {program_id:?} could not be found"),
+                    source: Some(error.into()),
+                }
+                .into(),
+            })?,
+        };
+        for import_id in program.imports().keys() {
+            self.load_imports_recursively(import_id, programs)?;
+        }
+        programs.insert(*program_id, program);
+        Ok(())
+    }
+
     /// Create an execute transaction
     pub fn create_execute_transaction(
         private_key: &PrivateKey<N>,
@@ -87,24 +293,44 @@ impl<N: Network> ProgramManager<N> {
         program: &Program<N>,
         function: impl TryInto<Identifier<N>>,
         query: String,
+    ) -> Result<Transaction<N>> {
+        let vm = open_memory_vm()?;
+        Self::create_execute_transaction_with_vm(&vm, private_key, fee, inputs, fee_record, program, function, query)
+    }
+
+    /// Create an execute transaction using an existing VM
+    ///
+    /// Reusing a VM (i.e. one opened with `open_persistent_vm`) across builds avoids initializing
+    /// a new VM for every transaction. The program is added to the VM if it is not already held,
+    /// and its imports must already have been added.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_execute_transaction_with_vm<C: ConsensusStorage<N>>(
+        vm: &VM<N, C>,
+        private_key: &PrivateKey<N>,
+        fee: u64,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        fee_record: Record<N, Plaintext<N>>,
+        program: &Program<N>,
+        function: impl TryInto<Identifier<N>>,
+        query: String,
     ) -> Result<Transaction<N>> {
         // Initialize an RNG and query object for the transaction
         let rng = &mut rand::thread_rng();
-        let query = Query::from(query);
+        let query = AleoRpcClient::<N>::for_network(&query)?.query();
 
         // Check that the function exists in the program
-        let function_name = function.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        let function_name = function.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid function name"))?;
         let program_id = program.id();
         println!("Checking function {function_name:?} exists in {program_id:?}");
         ensure!(
             program.contains_function(&function_name),
-            "Program {program_id:?} does not contain function {function_name:?}, aborting execution"
+            AleoSdkError::invalid_input(format!(
+                "Program {program_id:?} does not contain function {function_name:?}, aborting execution"
+            ))
         );
 
-        // Create an ephemeral SnarkVM to store the programs
-        let store = ConsensusStore::<N, ConsensusMemory<N>>::open(None)?;
-        let vm = VM::<N, ConsensusMemory<N>>::from(store)?;
-        let _ = &vm.process().write().add_program(program);
+        // Add the program to the VM
+        add_programs_to_vm(vm, std::slice::from_ref(program))?;
 
         // Create an execution transaction
         vm.execute(private_key, (program_id, function_name), inputs, Some((fee_record, fee)), Some(query), rng)
@@ -119,6 +345,73 @@ mod tests {
     use snarkvm_console::network::Testnet3;
     use std::str::FromStr;
 
+    const CALL_HELLO_PROGRAM: &str = "import hello.aleo;
+
+program call_hello.aleo;
+
+function main:
+    input r0 as u32.public;
+    call hello.aleo/hello r0 r0 into r1;
+    output r1 as u32.private;
+";
+
+    #[test]
+    fn test_load_program_with_imports() {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rand::thread_rng()).unwrap();
+        let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        let hello = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let call_hello = Program::<Testnet3>::from_str(CALL_HELLO_PROGRAM).unwrap();
+        program_manager.add_program(&call_hello).unwrap();
+
+        // Imports which cannot be found are reported
+        assert!(program_manager.load_program_with_imports(call_hello.id()).is_err());
+
+        // Imports come before the programs importing them
+        program_manager.add_program(&hello).unwrap();
+        let programs = program_manager.load_program_with_imports(call_hello.id()).unwrap();
+        assert_eq!(programs, vec![hello, call_hello.clone()]);
+
+        // Functions which do not exist are rejected before anything is executed
+        let fee_record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(RECORD_5_MICROCREDITS).unwrap();
+        let execution = program_manager.build_execution(
+            &private_key,
+            call_hello.id(),
+            "missing",
+            ["5u32"].into_iter(),
+            1,
+            fee_record,
+            Some("https://vm.aleo.org/api"),
+        );
+        assert_eq!(AleoSdkError::code_of(&execution.unwrap_err()), "invalid_input");
+    }
+
+    #[test]
+    fn test_load_program_error_classification() {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rand::thread_rng()).unwrap();
+        let program_id = ProgramID::<Testnet3>::from_str("missing_program.aleo").unwrap();
+
+        // A program the node does not have cannot be resolved
+        let url = serve_responses(vec![(404, "Not found")]);
+        let program_manager =
+            ProgramManager::<Testnet3>::new(Some(private_key), None, Some(local_client(&url)), None).unwrap();
+        let error = program_manager.load_program_with_imports(&program_id).unwrap_err();
+        assert_eq!(AleoSdkError::code_of(&error), "resolution");
+
+        // A failing node is reported as a network error
+        let url = serve_responses(vec![(500, "Internal server error")]);
+        let program_manager =
+            ProgramManager::<Testnet3>::new(Some(private_key), None, Some(local_client(&url)), None).unwrap();
+        let error = program_manager.load_program_with_imports(&program_id).unwrap_err();
+        assert!(matches!(AleoSdkError::find(&error), Some(AleoSdkError::Network { status: Some(500), .. })));
+
+        // Fees must be positive
+        let fee_record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(RECORD_5_MICROCREDITS).unwrap();
+        let error = program_manager
+            .build_execution(&private_key, "hello.aleo", "hello", ["5u32"].into_iter(), 0, fee_record, None)
+            .unwrap_err();
+        assert_eq!(AleoSdkError::code_of(&error), "invalid_input");
+    }
+
     #[test]
     #[ignore]
     fn test_execution() {
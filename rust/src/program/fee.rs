@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{ops::Deref, sync::Arc};
+
+/// A fee record reserved by a `ProgramManager` while a transaction is being built
+///
+/// The record is not handed out again by the program manager's `RecordReservation` until the
+/// reservation is dropped, so keep the reservation alive until the transaction has been built and
+/// broadcast.
+pub struct ReservedFeeRecord<N: Network> {
+    reservation: ReservedRecords<N>,
+}
+
+impl<N: Network> ReservedFeeRecord<N> {
+    /// Get the reserved fee record
+    pub fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.reservation.records()[0]
+    }
+
+    /// Get the commitment of the reserved fee record
+    pub fn commitment(&self) -> Field<N> {
+        self.reservation.commitments()[0]
+    }
+
+    /// Leave the fee record reserved after this reservation is dropped, returning its commitment
+    pub fn keep(self) -> Field<N> {
+        self.reservation.keep()[0]
+    }
+
+    /// Get the reservation holding the fee record
+    pub(crate) fn into_reservation(self) -> ReservedRecords<N> {
+        self.reservation
+    }
+}
+
+impl<N: Network> Deref for ReservedFeeRecord<N> {
+    type Target = Record<N, Plaintext<N>>;
+
+    fn deref(&self) -> &Self::Target {
+        self.record()
+    }
+}
+
+/// Compute the commitment of a credits.aleo record
+pub(crate) fn credits_commitment<N: Network>(record: &Record<N, Plaintext<N>>) -> Result<Field<N>> {
+    record.to_commitment(&ProgramID::from_str("credits.aleo")?, &Identifier::from_str("credits")?)
+}
+
+/// Select the smallest unreserved record covering an amount on its own
+pub(crate) fn single_record<N: Network>(
+    candidates: Vec<Record<N, Plaintext<N>>>,
+    amount: u64,
+) -> Result<Vec<Record<N, Plaintext<N>>>> {
+    let selection = RecordSelector::new(SelectionStrategy::SmallestViable)
+        .select(candidates, amount)
+        .map_err(|_| AleoSdkError::InsufficientFunds { required: amount, available: None })?;
+    ensure!(selection.records.len() == 1, AleoSdkError::InsufficientFunds { required: amount, available: None });
+    Ok(selection.records)
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Set the resolver used to find fee records when one is not provided by the caller
+    pub fn set_record_resolver(&mut self, resolver: impl Resolver<N> + Send + Sync + 'static) {
+        self.record_resolver = Some(Arc::new(resolver));
+    }
+
+    /// Get the resolver used to find fee records, if one is set
+    pub fn record_resolver(&self) -> Option<&(dyn Resolver<N> + Send + Sync)> {
+        self.record_resolver.as_deref()
+    }
+
+    /// Find an unspent credits record owned by the private key holding at least the fee and reserve it
+    ///
+    /// The record resolver is queried for the account's unspent credits records and the smallest
+    /// record covering the fee which is not already reserved is selected. The reservation is
+    /// shared with clones of the program manager, so transactions built concurrently from the same
+    /// account never use the same fee record.
+    pub fn reserve_fee_record(&self, private_key: &PrivateKey<N>, fee: u64) -> Result<ReservedFeeRecord<N>> {
+        ensure!(fee > 0, AleoSdkError::invalid_input("Fee must be greater than 0"));
+        let records = self.find_unspent_credits(private_key, fee)?;
+        let reservation =
+            self.record_reservation.reserve_selected(records, |candidates| single_record(candidates, fee))?;
+        Ok(ReservedFeeRecord { reservation })
+    }
+
+    /// Use the fee record given by the caller or reserve one through the record resolver
+    ///
+    /// The returned reservation (if any) must be held until the transaction has been broadcast.
+    pub fn resolve_fee_record(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+    ) -> Result<(Record<N, Plaintext<N>>, Option<ReservedFeeRecord<N>>)> {
+        match fee_record {
+            Some(fee_record) => Ok((fee_record, None)),
+            None => {
+                let reservation = self.reserve_fee_record(private_key, fee)?;
+                Ok((reservation.record().clone(), Some(reservation)))
+            }
+        }
+    }
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Estimate the fee in microcredits required to deploy a program
+    ///
+    /// The estimate is the sum of the serialized size of the deployment in bytes, the cost of
+    /// synthesizing the circuits of its functions and the namespace cost of the program name.
+    /// The program's imports are loaded through the program manager and the deployment is built
+    /// locally, which synthesizes the keys of every function and may take some time.
+    pub fn estimate_deployment_fee(&self, program: &Program<N>) -> Result<u64> {
+        let vm = open_memory_vm()?;
+        for import_id in program.imports().keys() {
+            add_programs_to_vm(&vm, &self.load_program_with_imports(import_id)?)?;
+        }
+
+        let deployment = info_span!("synthesis", program = %program.id())
+            .in_scope(|| vm.deploy_raw(program, &mut rand::thread_rng()))
+            .map_err(|error| AleoSdkError::synthesis(format!("Failed to synthesize {}", program.id()), error))?;
+        ensure!(!deployment.program().functions().is_empty(), "Attempted to estimate the fee of an empty deployment");
+        deployment_cost(&deployment)
+    }
+
+    /// Estimate the fee in microcredits required to execute a program function
+    ///
+    /// The function is executed and proven locally without a fee, so the inputs must be valid for
+    /// the account of the program manager. The estimate is the serialized size of the execution
+    /// in bytes plus the estimated cost of the finalize blocks it schedules.
+    pub fn estimate_execution_fee(
+        &self,
+        program: &Program<N>,
+        function: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        password: Option<&str>,
+    ) -> Result<u64> {
+        let function = function.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid function name"))?;
+        ensure!(
+            program.contains_function(&function),
+            AleoSdkError::invalid_input(format!("Program {} does not contain function {function}", program.id()))
+        );
+        let private_key = self.get_private_key(password)?;
+        let state_query = self.network_query(None)?;
+
+        let vm = open_memory_vm()?;
+        for import_id in program.imports().keys() {
+            add_programs_to_vm(&vm, &self.load_program_with_imports(import_id)?)?;
+        }
+        add_programs_to_vm(&vm, std::slice::from_ref(program))?;
+
+        let execution = vm
+            .execute(&private_key, (program.id(), function), inputs, None, Some(state_query), &mut rand::thread_rng())
+            .map_err(|error| {
+                self.execution_error(None, format!("Failed to execute {}/{function}", program.id()), error)
+            })?;
+        let process = vm.process().read();
+        let mut cost = execution.to_bytes_le()?.len() as u64;
+        for transition in execution.transitions().filter(|transition| transition.finalize().is_some()) {
+            let program = process.get_program(transition.program_id())?;
+            cost = cost.saturating_add(estimate_finalize_cost(program, transition.function_name())?);
+        }
+        Ok(cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cost::SYNTHESIS_FEE_MULTIPLIER;
+    use snarkvm_console::{
+        network::{Network, Testnet3},
+        prelude::{TestRng, Zero},
+    };
+
+    #[test]
+    fn test_reserve_fee_record() {
+        let private_key = PrivateKey::<Testnet3>::new(&mut TestRng::default()).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let records = vec![credits_record(&address, 100), credits_record(&address, 300), credits_record(&address, 200)];
+        let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        assert!(program_manager.reserve_fee_record(&private_key, 150).is_err());
+        program_manager.set_record_resolver(MemoryResolver(records));
+
+        // The smallest record covering the fee is selected first
+        let first = program_manager.reserve_fee_record(&private_key, 150).unwrap();
+        assert_eq!(first.microcredits().unwrap(), 200);
+
+        // Reserved records are skipped, including by clones of the program manager
+        let second = program_manager.clone().reserve_fee_record(&private_key, 150).unwrap();
+        assert_eq!(second.microcredits().unwrap(), 300);
+        assert!(program_manager.reserve_fee_record(&private_key, 150).is_err());
+
+        // Dropping a reservation releases the record
+        drop(first);
+        let third = program_manager.reserve_fee_record(&private_key, 150).unwrap();
+        assert_eq!(third.microcredits().unwrap(), 200);
+
+        // A fee record given by the caller is used without a reservation
+        let (record, reservation) =
+            program_manager.resolve_fee_record(&private_key, 150, Some(second.record().clone())).unwrap();
+        assert_eq!(&record, second.record());
+        assert!(reservation.is_none());
+
+        // Records are reserved for the signing key rather than the program manager's own key
+        let other_key = PrivateKey::<Testnet3>::new(&mut rand::thread_rng()).unwrap();
+        assert!(program_manager.reserve_fee_record(&other_key, 150).is_err());
+    }
+
+    #[test]
+    fn test_estimate_deployment_fee() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        let program = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let fee = program_manager.estimate_deployment_fee(&program).unwrap();
+
+        // The fee is the size of the deployment, the cost of synthesizing `hello` and the 100,000
+        // credits charged for the five character name
+        let deployment = open_memory_vm::<Testnet3>().unwrap().deploy_raw(&program, &mut rand::thread_rng()).unwrap();
+        let synthesis_cost = (deployment.num_combined_variables().unwrap()
+            + deployment.num_combined_constraints().unwrap())
+            * SYNTHESIS_FEE_MULTIPLIER;
+        assert_eq!(fee, deployment.to_bytes_le().unwrap().len() as u64 + synthesis_cost + 100_000_000_000);
+    }
+
+    #[test]
+    fn test_estimate_execution_fee() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let state_root = <Testnet3 as Network>::StateRoot::from(Field::zero());
+        let state_root: &'static str = Box::leak(format!("\"{state_root}\"").into_boxed_str());
+        let url = serve_responses(vec![(200, state_root), (200, state_root)]);
+        let api_client = AleoAPIClient::<Testnet3>::new(&url, "testnet3").unwrap();
+        let program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, Some(api_client), None).unwrap();
+        let program = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let fee =
+            program_manager.estimate_execution_fee(&program, "hello", ["5u32", "3u32"].into_iter(), None).unwrap();
+
+        // `hello` has no finalize block, so the fee is the size of the execution
+        let vm = open_memory_vm::<Testnet3>().unwrap();
+        add_programs_to_vm(&vm, std::slice::from_ref(&program)).unwrap();
+        let inputs = ["5u32", "3u32"].into_iter();
+        let query = program_manager.network_query(None).unwrap();
+        let execution = vm
+            .execute(&private_key, (program.id(), "hello"), inputs, None, Some(query), &mut rand::thread_rng())
+            .unwrap();
+        assert_eq!(fee, execution.to_bytes_le().unwrap().len() as u64);
+
+        // Functions which do not exist are rejected before anything is executed
+        let error = program_manager
+            .estimate_execution_fee(&program, "goodbye", ["5u32", "3u32"].into_iter(), None)
+            .unwrap_err();
+        assert_eq!(AleoSdkError::code_of(&error), "invalid_input");
+        let error =
+            program_manager.estimate_execution_fee(&program, "1hello", ["5u32", "3u32"].into_iter(), None).unwrap_err();
+        assert_eq!(AleoSdkError::code_of(&error), "invalid_input");
+    }
+}
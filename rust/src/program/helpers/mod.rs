@@ -34,3 +34,20 @@ pub enum TransferType {
     /// Transfer credits from the public account mapping to a record for an Aleo account
     PublicToPrivate,
 }
+
+impl TransferType {
+    /// Get the name of the credits.aleo function performing the transfer
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            TransferType::Private => "transfer_private",
+            TransferType::PrivateToPublic => "transfer_private_to_public",
+            TransferType::Public => "transfer_public",
+            TransferType::PublicToPrivate => "transfer_public_to_private",
+        }
+    }
+
+    /// Determine whether the transfer spends a record holding the amount
+    pub fn requires_record(&self) -> bool {
+        matches!(self, TransferType::Private | TransferType::PrivateToPublic)
+    }
+}
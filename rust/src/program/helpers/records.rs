@@ -39,7 +39,11 @@ impl<N: Network> RecordFinder<N> {
         private_key: &PrivateKey<N>,
     ) -> Result<(Record<N, Plaintext<N>>, Record<N, Plaintext<N>>)> {
         let records = self.find_record_amounts(vec![amount, fee], private_key)?;
-        if records.len() < 2 { bail!("Insufficient funds") } else { Ok((records[0].clone(), records[1].clone())) }
+        if records.len() < 2 {
+            bail!(AleoSdkError::InsufficientFunds { required: amount.saturating_add(fee), available: None })
+        } else {
+            Ok((records[0].clone(), records[1].clone()))
+        }
     }
 
     /// Resolve a record with a specific value. If successful it will return a record with a gate
@@ -49,7 +53,7 @@ impl<N: Network> RecordFinder<N> {
         self.find_unspent_records_on_chain(Some(&amounts), None, private_key)?
             .into_iter()
             .find(|record| record.microcredits().unwrap_or(0) >= amount)
-            .ok_or_else(|| anyhow!("Insufficient funds"))
+            .ok_or_else(|| anyhow!(AleoSdkError::InsufficientFunds { required: amount, available: None }))
     }
 
     /// Attempt to resolve records with specific gate values specified as a vector of u64s. If the
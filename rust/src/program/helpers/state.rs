@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::*;
+
 /// The possible states of a program on chain as compared to the local program of the same name
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OnChainProgramState {
@@ -24,3 +26,90 @@ pub enum OnChainProgramState {
     /// Program not deployed,
     NotDeployed,
 }
+
+/// The result of checking whether a program can be deployed before building a deployment
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentCheck<N: Network> {
+    /// The id of the program being deployed
+    pub program_id: ProgramID<N>,
+    /// The state of the program id on chain compared to the local program
+    pub state: OnChainProgramState,
+    /// The imports of the program which are not deployed on chain
+    pub missing_imports: Vec<ProgramID<N>>,
+    /// The imports of the program which are deployed with different source code than the local import
+    pub mismatched_imports: Vec<ProgramID<N>>,
+}
+
+impl<N: Network> DeploymentCheck<N> {
+    /// Determine whether a program with the same id is already deployed
+    pub fn exists(&self) -> bool {
+        self.state != OnChainProgramState::NotDeployed
+    }
+
+    /// Determine whether the deployed program matches the local program
+    pub fn source_matches(&self) -> bool {
+        self.state == OnChainProgramState::Same
+    }
+
+    /// Determine whether a deployment of the program can succeed
+    pub fn can_deploy(&self) -> bool {
+        !self.exists() && self.missing_imports.is_empty() && self.mismatched_imports.is_empty()
+    }
+
+    /// Return an error describing why the program cannot be deployed, if it cannot be
+    pub fn ensure_deployable(&self) -> Result<()> {
+        let program_id = &self.program_id;
+        match self.state {
+            OnChainProgramState::Same => bail!("Program {program_id} is already deployed with the same source code"),
+            OnChainProgramState::Different => {
+                bail!("Program id {program_id} is already taken by a program with different source code")
+            }
+            OnChainProgramState::NotDeployed => (),
+        }
+        if !self.missing_imports.is_empty() {
+            bail!("Imports of {program_id} must be deployed first: {}", join_ids(&self.missing_imports));
+        }
+        if !self.mismatched_imports.is_empty() {
+            bail!(
+                "Imports of {program_id} do not match their deployed versions: {}",
+                join_ids(&self.mismatched_imports)
+            );
+        }
+        Ok(())
+    }
+}
+
+fn join_ids<N: Network>(program_ids: &[ProgramID<N>]) -> String {
+    program_ids.iter().map(|program_id| program_id.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_deployment_check() {
+        let program_id = ProgramID::<Testnet3>::from_str("hello.aleo").unwrap();
+        let import_id = ProgramID::<Testnet3>::from_str("token.aleo").unwrap();
+        let check = DeploymentCheck {
+            program_id,
+            state: OnChainProgramState::NotDeployed,
+            missing_imports: vec![],
+            mismatched_imports: vec![],
+        };
+        assert!(check.can_deploy());
+        assert!(!check.exists());
+        assert!(check.ensure_deployable().is_ok());
+
+        let taken = DeploymentCheck { state: OnChainProgramState::Different, ..check.clone() };
+        assert!(taken.exists());
+        assert!(!taken.source_matches());
+        assert!(!taken.can_deploy());
+        assert!(taken.ensure_deployable().unwrap_err().to_string().contains("already taken"));
+
+        let missing_import = DeploymentCheck { missing_imports: vec![import_id], ..check };
+        assert!(!missing_import.can_deploy());
+        assert!(missing_import.ensure_deployable().unwrap_err().to_string().contains("token.aleo"));
+    }
+}
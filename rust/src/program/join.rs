@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Pair up records for a single round of `credits.aleo/join` executions.
+///
+/// Joins within a round spend disjoint records, so they can all be built and broadcast at once.
+/// If the number of records is odd, the last record is returned as the leftover and should be
+/// carried into the next round along with the records produced by the joins.
+pub fn pair_records_for_join<T>(records: Vec<T>) -> (Vec<(T, T)>, Option<T>) {
+    let mut pairs = Vec::with_capacity(records.len() / 2);
+    let mut records = records.into_iter();
+    let mut leftover = None;
+    while let Some(first) = records.next() {
+        match records.next() {
+            Some(second) => pairs.push((first, second)),
+            None => leftover = Some(first),
+        }
+    }
+    (pairs, leftover)
+}
+
+/// Number of join rounds needed to consolidate `record_count` records into a single record.
+///
+/// Every join consumes two records and produces one, so `record_count - 1` joins are always
+/// required. Running the independent joins of each round in parallel brings the number of rounds
+/// down to `ceil(log2(record_count))`.
+pub fn join_rounds(record_count: usize) -> u32 {
+    match record_count {
+        0 | 1 => 0,
+        count => usize::BITS - (count - 1).leading_zeros(),
+    }
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Build a `credits.aleo/join` transaction combining two records into one record
+    ///
+    /// If no fee record is given, one is reserved through the record resolver and held by the
+    /// returned transaction until it is broadcast. If no query url is given, the network state is
+    /// queried from the API client.
+    pub fn join(
+        &self,
+        private_key: &PrivateKey<N>,
+        record_a: Record<N, Plaintext<N>>,
+        record_b: Record<N, Plaintext<N>>,
+        fee: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        query: Option<&str>,
+    ) -> Result<ReservedTransaction<N>> {
+        let inputs = vec![Value::Record(record_a), Value::Record(record_b)];
+        self.build_credits_execution(private_key, "join", inputs, fee, fee_record, query)
+    }
+
+    /// Consolidate several records by executing one round of `credits.aleo/join` transitions.
+    ///
+    /// The records are joined pairwise and one fee record must be provided for every pair. The
+    /// transaction ids of the broadcast joins are returned along with the leftover record, if the
+    /// number of records was odd. Records created by a join cannot be spent until the join is
+    /// confirmed, so once the transactions are included in a block, call this method again with
+    /// the new records (and the leftover) until a single record remains. Use `join_rounds` to
+    /// find how many rounds a set of records will take.
+    pub fn join_records(
+        &self,
+        records: Vec<Record<N, Plaintext<N>>>,
+        fee: u64,
+        fee_records: Vec<Record<N, Plaintext<N>>>,
+        password: Option<&str>,
+    ) -> Result<(Vec<String>, Option<Record<N, Plaintext<N>>>)> {
+        ensure!(records.len() > 1, AleoSdkError::invalid_input("At least two records must be provided to join"));
+        ensure!(fee > 0, AleoSdkError::invalid_input("Fee must be greater than 0"));
+
+        let (pairs, leftover) = pair_records_for_join(records);
+        ensure!(
+            fee_records.len() >= pairs.len(),
+            AleoSdkError::invalid_input(format!(
+                "{} joins are required but only {} fee records were provided",
+                pairs.len(),
+                fee_records.len()
+            ))
+        );
+
+        // Retrieve the private key.
+        let private_key = self.get_private_key(password)?;
+
+        let mut transaction_ids = Vec::with_capacity(pairs.len());
+        for ((record_a, record_b), fee_record) in pairs.into_iter().zip(fee_records) {
+            let execution = self.join(&private_key, record_a, record_b, fee, Some(fee_record), None)?;
+            transaction_ids.push(self.broadcast_reserved(execution)?);
+        }
+
+        Ok((transaction_ids, leftover))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pair_records_for_join() {
+        let (pairs, leftover) = pair_records_for_join(vec![1, 2, 3, 4, 5]);
+        assert_eq!(pairs, vec![(1, 2), (3, 4)]);
+        assert_eq!(leftover, Some(5));
+
+        let (pairs, leftover) = pair_records_for_join(vec![1, 2]);
+        assert_eq!(pairs, vec![(1, 2)]);
+        assert_eq!(leftover, None);
+
+        let (pairs, leftover) = pair_records_for_join(vec![1]);
+        assert!(pairs.is_empty());
+        assert_eq!(leftover, Some(1));
+    }
+
+    #[test]
+    fn test_join_rounds() {
+        assert_eq!(join_rounds(0), 0);
+        assert_eq!(join_rounds(1), 0);
+        assert_eq!(join_rounds(2), 1);
+        assert_eq!(join_rounds(3), 2);
+        assert_eq!(join_rounds(4), 2);
+        assert_eq!(join_rounds(5), 3);
+        assert_eq!(join_rounds(8), 3);
+        assert_eq!(join_rounds(9), 4);
+    }
+}
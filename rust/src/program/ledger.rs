@@ -0,0 +1,328 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_console::{
+    prelude::{FromBytes, ToBytes},
+    program::{Request, ValueType},
+};
+
+/// Class of the APDU commands of the Aleo Ledger application
+pub const LEDGER_CLA: u8 = 0xe0;
+/// Get the address of an account of the device
+pub const INS_GET_ADDRESS: u8 = 0x02;
+/// Sign a message of field elements
+pub const INS_SIGN_MESSAGE: u8 = 0x03;
+/// Sign the request of a call of a program function
+pub const INS_SIGN_REQUEST: u8 = 0x04;
+/// Get the next chunk of an answer which did not fit in one APDU
+pub const INS_GET_RESPONSE: u8 = 0x05;
+
+/// P1 of the first chunk of a payload
+pub const P1_FIRST: u8 = 0x00;
+/// P1 of the chunks following the first one
+pub const P1_MORE: u8 = 0x01;
+/// P2 of the last chunk of a payload
+pub const P2_LAST: u8 = 0x80;
+/// Maximum length of the data of an APDU command
+pub const MAX_CHUNK_LENGTH: usize = 255;
+
+/// The command completed successfully
+pub const SW_OK: u16 = 0x9000;
+/// The answer continues, its next chunk is fetched with `INS_GET_RESPONSE`
+pub const SW_MORE_DATA: u16 = 0x6100;
+/// The user rejected the command on the device
+pub const SW_REJECTED: u16 = 0x6985;
+
+/// An APDU command sent to the Aleo Ledger application
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerCommand {
+    pub ins: u8,
+    pub p1: u8,
+    pub p2: u8,
+    pub data: Vec<u8>,
+}
+
+/// A transport exchanging APDU commands with a Ledger device
+///
+/// Native applications use [`HidTransport`]. In the browser, where HID is not available, the
+/// same commands are exchanged through WebUSB with a javascript transport such as
+/// `@ledgerhq/hw-transport-webusb`, and the signed requests are handed to the SDK as
+/// authorizations.
+pub trait LedgerTransport: Send + Sync {
+    /// Send a command and return the data and status word of the answer
+    fn exchange(&self, command: &LedgerCommand) -> Result<(Vec<u8>, u16)>;
+}
+
+/// A transport reaching a Ledger device connected over USB HID
+pub struct HidTransport {
+    transport: ledger_transport_hid::TransportNativeHID,
+}
+
+impl HidTransport {
+    /// Connect to the first Ledger device found
+    pub fn connect() -> Result<Self> {
+        let api = ledger_transport_hid::hidapi::HidApi::new()?;
+        let transport = ledger_transport_hid::TransportNativeHID::new(&api)
+            .map_err(|error| anyhow!("Failed to connect to the Ledger device: {error}"))?;
+        Ok(Self { transport })
+    }
+}
+
+impl LedgerTransport for HidTransport {
+    fn exchange(&self, command: &LedgerCommand) -> Result<(Vec<u8>, u16)> {
+        let apdu = ledger_apdu::APDUCommand {
+            cla: LEDGER_CLA,
+            ins: command.ins,
+            p1: command.p1,
+            p2: command.p2,
+            data: command.data.as_slice(),
+        };
+        let answer = self
+            .transport
+            .exchange(&apdu)
+            .map_err(|error| anyhow!("Failed to exchange with the Ledger device: {error}"))?;
+        Ok((answer.data().to_vec(), answer.retcode()))
+    }
+}
+
+/// A signer authorizing calls with an account of a Ledger device
+///
+/// The signing key never leaves the device: messages and requests are sent to the Aleo Ledger
+/// application, which signs them once the user approves them on the device. Signatures and
+/// requests returned by the device are verified against the address of the account before use.
+pub struct LedgerSigner<N: Network, T: LedgerTransport> {
+    transport: T,
+    account: u32,
+    address: Address<N>,
+}
+
+impl<N: Network, T: LedgerTransport> LedgerSigner<N, T> {
+    /// Create a signer for an account of the device, identified by its derivation index
+    pub fn connect(transport: T, account: u32) -> Result<Self> {
+        let answer = exchange(&transport, INS_GET_ADDRESS, &account.to_le_bytes())?;
+        let address = Address::from_bytes_le(&answer)?;
+        Ok(Self { transport, account, address })
+    }
+
+    /// Get the derivation index of the account
+    pub fn account(&self) -> u32 {
+        self.account
+    }
+}
+
+impl<N: Network, T: LedgerTransport> Signer<N> for LedgerSigner<N, T> {
+    fn address(&self) -> Result<Address<N>> {
+        Ok(self.address)
+    }
+
+    fn sign(&self, message: &[Field<N>]) -> Result<Signature<N>> {
+        let mut payload = self.account.to_le_bytes().to_vec();
+        u16::try_from(message.len())?.write_le(&mut payload)?;
+        message.iter().try_for_each(|field| field.write_le(&mut payload))?;
+        let signature = Signature::from_bytes_le(&exchange(&self.transport, INS_SIGN_MESSAGE, &payload)?)?;
+        ensure!(
+            signature.verify(&self.address, message),
+            AleoSdkError::authorization("The Ledger device returned an invalid signature")
+        );
+        Ok(signature)
+    }
+
+    fn sign_request(
+        &self,
+        program_id: &ProgramID<N>,
+        function: &Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+    ) -> Result<Request<N>> {
+        ensure!(
+            inputs.len() == input_types.len(),
+            AleoSdkError::invalid_input(format!(
+                "{program_id}/{function} expects {} inputs, found {}",
+                input_types.len(),
+                inputs.len()
+            ))
+        );
+        let mut payload = self.account.to_le_bytes().to_vec();
+        program_id.write_le(&mut payload)?;
+        function.write_le(&mut payload)?;
+        u16::try_from(inputs.len())?.write_le(&mut payload)?;
+        inputs.iter().try_for_each(|input| input.write_le(&mut payload))?;
+        input_types.iter().try_for_each(|input_type| input_type.write_le(&mut payload))?;
+
+        let request = Request::from_bytes_le(&exchange(&self.transport, INS_SIGN_REQUEST, &payload)?)?;
+        ensure!(
+            request.signer() == &self.address
+                && request.program_id() == program_id
+                && request.function_name() == function
+                && request.inputs() == inputs
+                && request.verify(input_types),
+            AleoSdkError::authorization("The Ledger device returned an invalid request")
+        );
+        Ok(request)
+    }
+}
+
+/// Send a payload in chunks and collect the chunks of the answer
+fn exchange(transport: &impl LedgerTransport, ins: u8, payload: &[u8]) -> Result<Vec<u8>> {
+    let chunks = payload.chunks(MAX_CHUNK_LENGTH).collect::<Vec<_>>();
+    let mut answer = (vec![], SW_OK);
+    for (index, chunk) in chunks.iter().enumerate() {
+        let command = LedgerCommand {
+            ins,
+            p1: if index == 0 { P1_FIRST } else { P1_MORE },
+            p2: if index + 1 == chunks.len() { P2_LAST } else { 0 },
+            data: chunk.to_vec(),
+        };
+        answer = transport.exchange(&command)?;
+        if index + 1 < chunks.len() {
+            check_status(answer.1)?;
+        }
+    }
+
+    let (mut data, mut status) = answer;
+    while status == SW_MORE_DATA {
+        let (chunk, next_status) =
+            transport.exchange(&LedgerCommand { ins: INS_GET_RESPONSE, p1: 0, p2: 0, data: vec![] })?;
+        data.extend(chunk);
+        status = next_status;
+    }
+    check_status(status)?;
+    Ok(data)
+}
+
+fn check_status(status: u16) -> Result<()> {
+    match status {
+        SW_OK | SW_MORE_DATA => Ok(()),
+        SW_REJECTED => Err(AleoSdkError::authorization("The request was rejected on the Ledger device").into()),
+        status => bail!("The Ledger device returned status {status:#06x}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+    use std::sync::Mutex;
+
+    /// A device holding a private key which implements the Aleo Ledger application
+    struct MockDevice {
+        private_key: PrivateKey<Testnet3>,
+        reject: bool,
+        payload: Mutex<Vec<u8>>,
+        answer: Mutex<Vec<u8>>,
+    }
+
+    impl MockDevice {
+        fn new(reject: bool) -> Self {
+            let private_key = PrivateKey::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+            Self { private_key, reject, payload: Mutex::new(vec![]), answer: Mutex::new(vec![]) }
+        }
+
+        fn process(&self, ins: u8, payload: &[u8]) -> Result<Vec<u8>> {
+            let rng = &mut rand::thread_rng();
+            let mut payload = payload;
+            ensure!(u32::read_le(&mut payload)? == 0, "Unknown account");
+            match ins {
+                INS_GET_ADDRESS => Address::try_from(&self.private_key)?.to_bytes_le(),
+                INS_SIGN_MESSAGE => {
+                    let count = u16::read_le(&mut payload)?;
+                    let message = (0..count).map(|_| Field::read_le(&mut payload)).collect::<Result<Vec<_>, _>>()?;
+                    self.private_key.sign(&message, rng)?.to_bytes_le()
+                }
+                INS_SIGN_REQUEST => {
+                    let program_id = ProgramID::read_le(&mut payload)?;
+                    let function = Identifier::read_le(&mut payload)?;
+                    let count = u16::read_le(&mut payload)?;
+                    let inputs = (0..count).map(|_| Value::read_le(&mut payload)).collect::<Result<Vec<_>, _>>()?;
+                    let input_types =
+                        (0..count).map(|_| ValueType::read_le(&mut payload)).collect::<Result<Vec<_>, _>>()?;
+                    Request::sign(&self.private_key, program_id, function, inputs.into_iter(), &input_types, rng)?
+                        .to_bytes_le()
+                }
+                ins => bail!("Unknown instruction {ins}"),
+            }
+        }
+
+        fn next_chunk(&self) -> (Vec<u8>, u16) {
+            let mut answer = self.answer.lock().unwrap();
+            let length = answer.len().min(MAX_CHUNK_LENGTH);
+            let chunk = answer.drain(..length).collect();
+            (chunk, if answer.is_empty() { SW_OK } else { SW_MORE_DATA })
+        }
+    }
+
+    impl LedgerTransport for MockDevice {
+        fn exchange(&self, command: &LedgerCommand) -> Result<(Vec<u8>, u16)> {
+            if command.ins == INS_GET_RESPONSE {
+                return Ok(self.next_chunk());
+            }
+            let mut payload = self.payload.lock().unwrap();
+            if command.p1 == P1_FIRST {
+                payload.clear();
+            }
+            payload.extend(&command.data);
+            if command.p2 != P2_LAST {
+                return Ok((vec![], SW_OK));
+            }
+            if self.reject && command.ins != INS_GET_ADDRESS {
+                return Ok((vec![], SW_REJECTED));
+            }
+            *self.answer.lock().unwrap() = self.process(command.ins, &payload)?;
+            Ok(self.next_chunk())
+        }
+    }
+
+    #[test]
+    fn test_ledger_signer() {
+        let signer = LedgerSigner::<Testnet3, _>::connect(MockDevice::new(false), 0).unwrap();
+        let address = signer.address().unwrap();
+        assert_eq!(address.to_string(), RECIPIENT_ADDRESS);
+
+        // Long messages are sent in several chunks
+        let message = (0..16).map(Field::from_u64).collect::<Vec<_>>();
+        assert!(signer.sign(&message).unwrap().verify(&address, &message));
+
+        let program = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let function = program.get_function(&Identifier::from_str("hello").unwrap()).unwrap();
+        let inputs = [Value::from_str("5u32").unwrap(), Value::from_str("3u32").unwrap()];
+        let request = signer.sign_request(program.id(), function.name(), &inputs, &function.input_types()).unwrap();
+        assert_eq!(request.signer(), &address);
+        assert!(request.verify(&function.input_types()));
+
+        // Unknown accounts are reported by the device
+        assert!(LedgerSigner::<Testnet3, _>::connect(MockDevice::new(false), 1).is_err());
+    }
+
+    #[test]
+    fn test_ledger_authorize() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        program_manager.add_program(&Program::from_str(HELLO_PROGRAM).unwrap()).unwrap();
+        let signer = LedgerSigner::<Testnet3, _>::connect(MockDevice::new(false), 0).unwrap();
+
+        let authorization =
+            program_manager.authorize(&signer, "hello.aleo", "hello", ["5u32", "3u32"].into_iter()).unwrap();
+        assert_eq!(authorization.peek_next().unwrap().signer(), &signer.address().unwrap());
+
+        // Requests rejected on the device are authorization errors
+        let signer = LedgerSigner::<Testnet3, _>::connect(MockDevice::new(true), 0).unwrap();
+        let error =
+            program_manager.authorize(&signer, "hello.aleo", "hello", ["5u32", "3u32"].into_iter()).unwrap_err();
+        assert_eq!(AleoSdkError::code_of(&error), "authorization");
+    }
+}
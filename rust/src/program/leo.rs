@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Builds Leo projects into Aleo packages by invoking the Leo compiler
+///
+/// `leo build` compiles the `src` directory of a project into the `build` directory, which holds
+/// the `.aleo` bytecode of the program and its imports laid out as an Aleo package. Projects are
+/// only rebuilt when a source file is newer than the build output.
+#[derive(Clone, Debug)]
+pub struct LeoBuilder {
+    leo_path: PathBuf,
+}
+
+impl Default for LeoBuilder {
+    /// Use the `leo` binary found on the path
+    fn default() -> Self {
+        Self { leo_path: PathBuf::from("leo") }
+    }
+}
+
+impl LeoBuilder {
+    /// Create a builder invoking the Leo compiler at a path
+    pub fn new(leo_path: impl Into<PathBuf>) -> Self {
+        Self { leo_path: leo_path.into() }
+    }
+
+    /// Get the path of the Leo compiler
+    pub fn leo_path(&self) -> &Path {
+        &self.leo_path
+    }
+
+    /// Check if a directory is a Leo project
+    pub fn is_leo_project(directory: &Path) -> bool {
+        directory.join("program.json").is_file() && directory.join("src").join("main.leo").is_file()
+    }
+
+    /// Get the build directory of a Leo project, which is an Aleo package once built
+    pub fn build_directory(project_directory: &Path) -> PathBuf {
+        project_directory.join("build")
+    }
+
+    /// Build a Leo project if its sources changed since the last build and return its build
+    /// directory
+    pub fn build(&self, project_directory: &Path) -> Result<PathBuf> {
+        ensure!(
+            Self::is_leo_project(project_directory),
+            AleoSdkError::invalid_input(format!("{} is not a Leo project", project_directory.display()))
+        );
+        let build_directory = Self::build_directory(project_directory);
+        if !needs_build(project_directory)? {
+            return Ok(build_directory);
+        }
+
+        let _span = info_span!("leo_build", project = %project_directory.display()).entered();
+        let output = Command::new(&self.leo_path)
+            .arg("build")
+            .current_dir(project_directory)
+            .output()
+            .map_err(|error| anyhow!("Failed to run {}: {error}", self.leo_path.display()))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            bail!("Failed to build the Leo project at {}:\n{}{}", project_directory.display(), stdout, stderr);
+        }
+        ensure!(
+            build_directory.join("main.aleo").is_file(),
+            "The Leo compiler did not produce {}",
+            build_directory.join("main.aleo").display()
+        );
+        Ok(build_directory)
+    }
+
+    /// Build a Leo project and load its program
+    pub fn build_program<N: Network>(&self, project_directory: &Path) -> Result<Program<N>> {
+        let build_directory = self.build(project_directory)?;
+        let program_id = Manifest::<N>::open(&build_directory)?.program_id().to_owned();
+        FileSystemResolver::new(&build_directory)?.load_program(&program_id)
+    }
+}
+
+/// Check if any file in the `src` or `imports` directory of a project is newer than its compiled
+/// program
+fn needs_build(project_directory: &Path) -> Result<bool> {
+    let compiled = LeoBuilder::build_directory(project_directory).join("main.aleo");
+    let Ok(built_at) = compiled.metadata().and_then(|metadata| metadata.modified()) else { return Ok(true) };
+    let imports = project_directory.join("imports");
+    Ok(newest_modification(&project_directory.join("src"))? > built_at
+        || newest_modification(&project_directory.join("program.json"))? > built_at
+        || (imports.exists() && newest_modification(&imports)? > built_at))
+}
+
+/// Get the latest modification time of a file or of the files in a directory
+fn newest_modification(path: &Path) -> Result<SystemTime> {
+    let metadata = path.metadata()?;
+    if !metadata.is_dir() {
+        return Ok(metadata.modified()?);
+    }
+    let mut newest = UNIX_EPOCH;
+    for entry in std::fs::read_dir(path)? {
+        newest = newest.max(newest_modification(&entry?.path())?);
+    }
+    Ok(newest)
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Set the builder used to build Leo projects, i.e. to use a Leo compiler which is not on the path
+    pub fn set_leo_builder(&mut self, leo_builder: LeoBuilder) {
+        self.leo_builder = leo_builder;
+    }
+
+    /// Get the builder used to build Leo projects
+    pub fn leo_builder(&self) -> &LeoBuilder {
+        &self.leo_builder
+    }
+
+    /// Build a Leo project and add its program and the imports it was compiled with to the
+    /// program manager, returning the id of the program
+    pub fn add_leo_project(&mut self, project_directory: &Path) -> Result<ProgramID<N>> {
+        let build_directory = self.leo_builder.build(project_directory)?;
+        let resolver = FileSystemResolver::<N>::new(&build_directory)?;
+        let imports_directory = resolver.imports_directory();
+        if imports_directory.exists() {
+            for import in std::fs::read_dir(imports_directory)? {
+                let import = import?.path();
+                if import.extension().map_or(false, |extension| extension == "aleo") {
+                    self.update_program(&Program::from_str(&std::fs::read_to_string(import)?)?);
+                }
+            }
+        }
+        let program_id = Manifest::<N>::open(&build_directory)?.program_id().to_owned();
+        self.update_program(&resolver.load_program(&program_id)?);
+        Ok(program_id)
+    }
+
+    /// Build a Leo project and deploy its program to the network
+    pub fn deploy_leo_project(
+        &mut self,
+        project_directory: &Path,
+        fee: u64,
+        fee_record: Record<N, Plaintext<N>>,
+        password: Option<&str>,
+    ) -> Result<String> {
+        let program_id = self.add_leo_project(project_directory)?;
+        self.deploy_program(program_id, fee, fee_record, password)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leo_project_detection() {
+        let directory = setup_directory("aleo_test_leo_project", HELLO_PROGRAM, vec![]).unwrap();
+        let result = std::panic::catch_unwind(|| {
+            // An Aleo package is not a Leo project
+            assert!(!LeoBuilder::is_leo_project(&directory));
+            assert!(LeoBuilder::default().build(&directory).is_err());
+
+            // A project is rebuilt once a source is newer than the compiled program
+            std::fs::create_dir_all(directory.join("src")).unwrap();
+            std::fs::write(directory.join("src").join("main.leo"), "program hello.aleo {}").unwrap();
+            assert!(LeoBuilder::is_leo_project(&directory));
+            assert!(needs_build(&directory).unwrap());
+            let build_directory = LeoBuilder::build_directory(&directory);
+            std::fs::create_dir_all(&build_directory).unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            std::fs::write(build_directory.join("main.aleo"), HELLO_PROGRAM).unwrap();
+            assert!(!needs_build(&directory).unwrap());
+            assert_eq!(LeoBuilder::new("missing-leo").build(&directory).unwrap(), build_directory);
+
+            // Changes to local imports also require a rebuild
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            std::fs::create_dir_all(directory.join("imports")).unwrap();
+            std::fs::write(directory.join("imports").join("hello.aleo"), HELLO_PROGRAM).unwrap();
+            assert!(needs_build(&directory).unwrap());
+
+            // The program manager builds projects with its own builder
+            let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+            let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+            program_manager.set_leo_builder(LeoBuilder::new("missing-leo"));
+            assert_eq!(program_manager.leo_builder().leo_path(), Path::new("missing-leo"));
+            let error = program_manager.add_leo_project(&directory).unwrap_err();
+            assert!(error.to_string().contains("Failed to run missing-leo"));
+        });
+        teardown_directory(&directory);
+        result.unwrap();
+    }
+}
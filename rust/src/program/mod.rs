@@ -17,25 +17,74 @@
 //! Tools for deploying, executing, and managing programs on the Aleo network
 
 use super::*;
+use crate::cost::{deployment_cost, estimate_execution_cost, estimate_finalize_cost};
+
+use std::sync::Arc;
+use tracing::{debug, info_span};
+
+pub mod batch;
+pub use batch::*;
 
 pub mod deploy;
 pub use deploy::*;
 
+pub mod dry_run;
+pub use dry_run::*;
+
 pub mod execute;
 pub use execute::*;
 
+pub mod fee;
+pub use fee::*;
+
 pub mod helpers;
 pub use helpers::*;
 
+pub mod join;
+pub use join::*;
+
+#[cfg(feature = "leo")]
+pub mod leo;
+#[cfg(feature = "leo")]
+pub use leo::*;
+
+#[cfg(feature = "ledger")]
+pub mod ledger;
+#[cfg(feature = "ledger")]
+pub use ledger::*;
+
 pub mod network;
 pub use network::*;
 
+#[cfg(feature = "async")]
+pub mod nonblocking;
+#[cfg(feature = "async")]
+pub use nonblocking::*;
+
+pub mod reservation;
+pub use reservation::*;
+
 pub mod resolver;
 pub use resolver::*;
 
+pub mod resolvers;
+pub use resolvers::*;
+
+pub mod signer;
+pub use signer::*;
+
+pub mod split;
+pub use split::*;
+
 pub mod transfer;
 pub use transfer::*;
 
+pub mod verify;
+pub use verify::*;
+
+pub mod vm;
+pub use vm::*;
+
 /// Program management object for loading programs for building, execution, and deployment
 ///
 /// This object is meant to be a software abstraction that can be consumed by software like
@@ -48,6 +97,11 @@ pub struct ProgramManager<N: Network> {
     pub(crate) private_key_ciphertext: Option<Ciphertext<N>>,
     pub(crate) local_program_directory: Option<PathBuf>,
     pub(crate) api_client: Option<AleoAPIClient<N>>,
+    pub(crate) record_resolver: Option<Arc<dyn Resolver<N> + Send + Sync>>,
+    pub(crate) record_reservation: RecordReservation<N>,
+    pub(crate) key_provider: Option<Arc<dyn KeyProvider<N>>>,
+    #[cfg(feature = "leo")]
+    pub(crate) leo_builder: LeoBuilder,
 }
 
 impl<N: Network> ProgramManager<N> {
@@ -66,7 +120,18 @@ impl<N: Network> ProgramManager<N> {
             bail!("Must have either private key or private key ciphertext");
         }
         let programs = IndexMap::new();
-        Ok(Self { programs, private_key, private_key_ciphertext, local_program_directory, api_client })
+        Ok(Self {
+            programs,
+            private_key,
+            private_key_ciphertext,
+            local_program_directory,
+            api_client,
+            record_resolver: None,
+            record_reservation: Default::default(),
+            key_provider: None,
+            #[cfg(feature = "leo")]
+            leo_builder: LeoBuilder::default(),
+        })
     }
 
     /// Manually add a program to the program manager from memory if it does not already exist
@@ -86,13 +151,16 @@ impl<N: Network> ProgramManager<N> {
 
     /// Retrieve a program from the program manager if it exists
     pub fn get_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<Program<N>> {
-        let program_id = program_id.try_into().map_err(|_| anyhow!("invalid program id"))?;
-        self.programs.get(&program_id).map_or(Err(anyhow!("program not found")), |program| Ok(program.clone()))
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("invalid program id"))?;
+        self.programs
+            .get(&program_id)
+            .cloned()
+            .ok_or_else(|| AleoSdkError::resolution(format!("Program {program_id} not found")).into())
     }
 
     /// Determine if a program exists in the program manager
     pub fn contains_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<bool> {
-        let program_id = program_id.try_into().map_err(|_| anyhow!("invalid program id"))?;
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("invalid program id"))?;
         Ok(self.programs.contains_key(&program_id))
     }
 
@@ -100,7 +168,7 @@ impl<N: Network> ProgramManager<N> {
     /// password must be provided to decrypt it
     pub(super) fn get_private_key(&self, password: Option<&str>) -> Result<PrivateKey<N>> {
         if self.private_key.is_none() && self.private_key_ciphertext.is_none() {
-            bail!("Private key is not configured");
+            bail!(AleoSdkError::authorization("Private key is not configured"));
         };
         if let Some(private_key) = &self.private_key {
             if self.private_key_ciphertext.is_some() {
@@ -115,13 +183,25 @@ impl<N: Network> ProgramManager<N> {
                 bail!("Private key is already configured, cannot have both private key and private key ciphertext");
             }
 
-            let password = password.ok_or_else(|| anyhow!("Private key is encrypted, password is required"))?;
-            return Encryptor::<N>::decrypt_private_key_with_secret(ciphertext, password);
+            let password = password
+                .ok_or_else(|| AleoSdkError::authorization("Private key is encrypted, password is required"))?;
+            return Encryptor::<N>::decrypt_private_key_with_secret(ciphertext, password).map_err(|_| {
+                AleoSdkError::authorization("Private key could not be decrypted with the password").into()
+            });
         };
         bail!("Private key configuration error")
     }
 }
 
+impl<N: Network> Drop for ProgramManager<N> {
+    /// Wipe the private key held by the program manager so it does not linger in memory
+    fn drop(&mut self) {
+        if let Some(private_key) = self.private_key.as_mut() {
+            let _ = wipe_private_key(private_key);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -20,6 +20,7 @@ impl<N: Network> ProgramManager<N> {
     /// Broadcast a transaction to the network
     pub fn broadcast_transaction(&self, transaction: Transaction<N>) -> Result<String> {
         let transaction_type = if let Transaction::Deploy(..) = &transaction { "Deployment" } else { "Execute" };
+        let _span = info_span!("broadcast", transaction = %transaction.id()).entered();
         let api_client = self.api_client()?;
         let result = api_client.transaction_broadcast(transaction);
         if result.is_ok() {
@@ -30,24 +31,66 @@ impl<N: Network> ProgramManager<N> {
         result
     }
 
+    /// Broadcast a transaction built from reserved records
+    ///
+    /// Once the network accepts the transaction its records stay reserved, since they are spent
+    /// as soon as it is confirmed. If the broadcast fails the records are released so they can be
+    /// selected again.
+    pub fn broadcast_reserved(&self, transaction: ReservedTransaction<N>) -> Result<String> {
+        let transaction_id = self.broadcast_transaction(transaction.transaction().clone())?;
+        transaction.keep();
+        Ok(transaction_id)
+    }
+
     /// Get a reference to the configured API client
     pub fn api_client(&self) -> Result<&AleoAPIClient<N>> {
-        self.api_client.as_ref().ok_or_else(|| anyhow!("No API client found"))
+        self.api_client.as_ref().ok_or_else(|| AleoSdkError::invalid_input("No API client found"))
     }
 
     /// Check the on-chain version of a program to determine if it is deployed, and if so,
     /// if it is the same as the local version
     pub fn on_chain_program_state(&self, program: &Program<N>) -> Result<OnChainProgramState> {
-        let program_id = program.id();
-        Ok(self
-            .api_client()?
-            .get_program(program_id)
-            .map(
-                |chain_program| {
-                    if chain_program.eq(program) { OnChainProgramState::Same } else { OnChainProgramState::Different }
+        Ok(match self.api_client()?.find_program(program.id())? {
+            Some(chain_program) if chain_program.eq(program) => OnChainProgramState::Same,
+            Some(_) => OnChainProgramState::Different,
+            None => OnChainProgramState::NotDeployed,
+        })
+    }
+
+    /// Check whether a program can be deployed before paying for a deployment
+    ///
+    /// The program id must not be deployed yet and every import must already be deployed with
+    /// the same source code as the local import. Imports are loaded from the program manager, the
+    /// local program directory or the network.
+    pub fn check_deployment(&self, program: &Program<N>) -> Result<DeploymentCheck<N>> {
+        let mut missing_imports = vec![];
+        let mut mismatched_imports = vec![];
+        for import_id in program.imports().keys() {
+            if import_id.to_string() == "credits.aleo" {
+                continue;
+            }
+            let import = match self.programs.get(import_id) {
+                Some(import) => import.clone(),
+                None => match self.find_program(import_id) {
+                    Ok(import) => import,
+                    Err(_) => {
+                        missing_imports.push(*import_id);
+                        continue;
+                    }
                 },
-            )
-            .unwrap_or(OnChainProgramState::NotDeployed))
+            };
+            match self.on_chain_program_state(&import)? {
+                OnChainProgramState::Same => (),
+                OnChainProgramState::Different => mismatched_imports.push(*import_id),
+                OnChainProgramState::NotDeployed => missing_imports.push(*import_id),
+            }
+        }
+        Ok(DeploymentCheck {
+            program_id: *program.id(),
+            state: self.on_chain_program_state(program)?,
+            missing_imports,
+            mismatched_imports,
+        })
     }
 }
 
@@ -97,6 +140,12 @@ mod tests {
 
         assert!(matches!(state_mismatch, OnChainProgramState::Different));
 
+        // Test check_deployment() reports taken program ids and deployable programs
+        let check = program_manager.check_deployment(&wrong_hello_program).unwrap();
+        assert!(check.exists());
+        assert!(!check.can_deploy());
+        assert!(program_manager.check_deployment(&random_program).unwrap().can_deploy());
+
         let _ = std::fs::remove_dir_all(temp_dir);
     }
 }
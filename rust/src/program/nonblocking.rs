@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::api::spawn_request;
+
+use snarkvm::{circuit::Aleo, synthesizer::Authorization};
+
+impl<N: Network> ProgramManager<N> {
+    /// Build an execution transaction from a tokio runtime
+    ///
+    /// See `build_execution`. The program manager is cloned and the transaction is built on the
+    /// blocking thread pool of the runtime, since preparing the inclusion proof queries the
+    /// network and proving takes seconds to minutes.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn build_execution_async(
+        &self,
+        private_key: PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function: Identifier<N>,
+        inputs: Vec<Value<N>>,
+        fee: u64,
+        fee_record: Record<N, Plaintext<N>>,
+        query: Option<String>,
+    ) -> Result<Transaction<N>> {
+        let program_manager = self.clone();
+        spawn_request(move || {
+            program_manager.build_execution(
+                &private_key,
+                program_id,
+                function,
+                inputs.into_iter(),
+                fee,
+                fee_record,
+                query.as_deref(),
+            )
+        })
+        .await
+    }
+
+    /// Build a transfer transaction from a tokio runtime, see `build_transfer`
+    #[allow(clippy::too_many_arguments)]
+    pub async fn build_transfer_async(
+        &self,
+        private_key: PrivateKey<N>,
+        recipient_address: Address<N>,
+        amount: u64,
+        transfer_type: TransferType,
+        fee: u64,
+        amount_record: Option<Record<N, Plaintext<N>>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        query: Option<String>,
+    ) -> Result<ReservedTransaction<N>> {
+        let program_manager = self.clone();
+        spawn_request(move || {
+            program_manager.build_transfer(
+                &private_key,
+                recipient_address,
+                amount,
+                transfer_type,
+                fee,
+                amount_record,
+                fee_record,
+                query.as_deref(),
+            )
+        })
+        .await
+    }
+
+    /// Execute and prove an authorization from a tokio runtime, see `prove_authorization`
+    pub async fn prove_authorization_async<A: Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        signer: Arc<dyn Signer<N>>,
+        fee: u64,
+        fee_record: Record<N, Plaintext<N>>,
+        query: Option<String>,
+    ) -> Result<Transaction<N>> {
+        let program_manager = self.clone();
+        spawn_request(move || {
+            program_manager.prove_authorization::<A>(authorization, signer.as_ref(), fee, fee_record, query.as_deref())
+        })
+        .await
+    }
+
+    /// Broadcast a transaction to the network from a tokio runtime
+    pub async fn broadcast_transaction_async(&self, transaction: Transaction<N>) -> Result<String> {
+        let program_manager = self.clone();
+        spawn_request(move || program_manager.broadcast_transaction(transaction)).await
+    }
+
+    /// Broadcast a transaction built from reserved records from a tokio runtime, see
+    /// `broadcast_reserved`
+    pub async fn broadcast_reserved_async(&self, transaction: ReservedTransaction<N>) -> Result<String> {
+        let program_manager = self.clone();
+        spawn_request(move || program_manager.broadcast_reserved(transaction)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_build_execution_async_requires_api_client() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        program_manager.add_program(&Program::from_str(HELLO_PROGRAM).unwrap()).unwrap();
+
+        // Without an API client there is no node to prove the inclusion of the fee against
+        let fee_record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(RECORD_5_MICROCREDITS).unwrap();
+        let inputs = vec![Value::from_str("5u32").unwrap(), Value::from_str("3u32").unwrap()];
+        let error = program_manager
+            .build_execution_async(
+                private_key,
+                ProgramID::from_str("hello.aleo").unwrap(),
+                Identifier::from_str("hello").unwrap(),
+                inputs,
+                1,
+                fee_record,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(AleoSdkError::find(&error), Some(AleoSdkError::InvalidInput { .. })));
+        assert!(error.to_string().contains("No API client found"));
+    }
+}
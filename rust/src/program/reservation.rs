@@ -0,0 +1,323 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{
+    collections::HashSet,
+    ops::Deref,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// Records marked as in use while the transactions spending them are being built
+///
+/// Records are identified by their commitment. Clones share the same set of reservations, so a
+/// service building transactions from several threads (or through clones of a `ProgramManager`)
+/// never selects the same input record twice.
+#[derive(Clone, Default)]
+pub struct RecordReservation<N: Network> {
+    reserved: Arc<Mutex<HashSet<Field<N>>>>,
+}
+
+impl<N: Network> RecordReservation<N> {
+    /// Create an empty set of record reservations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if the record with a commitment is reserved
+    pub fn is_reserved(&self, commitment: &Field<N>) -> bool {
+        self.lock().map_or(false, |reserved| reserved.contains(commitment))
+    }
+
+    /// Get the number of reserved records
+    pub fn len(&self) -> usize {
+        self.lock().map_or(0, |reserved| reserved.len())
+    }
+
+    /// Check if no records are reserved
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reserve records given along with their commitments
+    ///
+    /// Either every record is reserved or, if any of them is already reserved, none are.
+    pub fn reserve(&self, records: Vec<(Field<N>, Record<N, Plaintext<N>>)>) -> Result<ReservedRecords<N>> {
+        let mut reserved = self.lock()?;
+        let commitments = records.iter().map(|(commitment, _)| *commitment).collect::<Vec<_>>();
+        let unique = commitments.iter().collect::<HashSet<_>>();
+        ensure!(unique.len() == commitments.len(), "The same record cannot be reserved twice");
+        if let Some(commitment) = commitments.iter().find(|commitment| reserved.contains(commitment)) {
+            bail!("Record {commitment} is already reserved");
+        }
+        reserved.extend(commitments.iter().copied());
+        let records = records.into_iter().map(|(_, record)| record).collect();
+        Ok(ReservedRecords { records, commitments, reservation: self.clone() })
+    }
+
+    /// Select credits records holding at least an amount from those which are not reserved and
+    /// reserve them
+    ///
+    /// Selection and reservation happen atomically, so concurrent callers always receive
+    /// disjoint records.
+    pub fn reserve_credits(
+        &self,
+        records: Vec<Record<N, Plaintext<N>>>,
+        amount: u64,
+        strategy: SelectionStrategy,
+    ) -> Result<ReservedRecords<N>> {
+        self.reserve_selected(records, |candidates| {
+            Ok(RecordSelector::new(strategy).select(candidates, amount)?.records)
+        })
+    }
+
+    /// Release the records with the given commitments
+    ///
+    /// Only needed for records kept reserved with `ReservedRecords::keep`.
+    pub fn release(&self, commitments: &[Field<N>]) {
+        if let Ok(mut reserved) = self.lock() {
+            commitments.iter().for_each(|commitment| {
+                reserved.remove(commitment);
+            });
+        }
+    }
+
+    /// Reserve the credits records chosen by a selection function from those not yet reserved
+    pub(crate) fn reserve_selected(
+        &self,
+        records: Vec<Record<N, Plaintext<N>>>,
+        select: impl FnOnce(Vec<Record<N, Plaintext<N>>>) -> Result<Vec<Record<N, Plaintext<N>>>>,
+    ) -> Result<ReservedRecords<N>> {
+        let mut reserved = self.lock()?;
+        let candidates = records
+            .into_iter()
+            .map(|record| Ok((credits_commitment(&record)?, record)))
+            .filter(|candidate| candidate.as_ref().map_or(true, |(commitment, _)| !reserved.contains(commitment)))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let records = select(candidates.into_values().collect())?;
+        let commitments = records.iter().map(credits_commitment).collect::<Result<Vec<_>>>()?;
+        reserved.extend(commitments.iter().copied());
+        Ok(ReservedRecords { records, commitments, reservation: self.clone() })
+    }
+
+    fn lock(&self) -> Result<MutexGuard<HashSet<Field<N>>>> {
+        self.reserved.lock().map_err(|_| anyhow!("Record reservations are poisoned"))
+    }
+}
+
+/// Records reserved through a `RecordReservation`
+///
+/// The records are released when the reservation is dropped, so a transaction which fails to
+/// build or broadcast frees its inputs automatically. Call `keep` once the transaction has been
+/// broadcast to leave the records reserved until they are known to be spent.
+pub struct ReservedRecords<N: Network> {
+    records: Vec<Record<N, Plaintext<N>>>,
+    commitments: Vec<Field<N>>,
+    reservation: RecordReservation<N>,
+}
+
+impl<N: Network> ReservedRecords<N> {
+    /// Get the reserved records
+    pub fn records(&self) -> &[Record<N, Plaintext<N>>] {
+        &self.records
+    }
+
+    /// Get the commitments of the reserved records
+    pub fn commitments(&self) -> &[Field<N>] {
+        &self.commitments
+    }
+
+    /// Leave the records reserved after this reservation is dropped, returning their commitments
+    /// so they can be released later with `RecordReservation::release`
+    pub fn keep(mut self) -> Vec<Field<N>> {
+        std::mem::take(&mut self.commitments)
+    }
+
+    /// Move the first records into a reservation of their own
+    pub(crate) fn split_first(&mut self, count: usize) -> ReservedRecords<N> {
+        let count = count.min(self.records.len());
+        let records = self.records.drain(..count).collect();
+        let commitments = self.commitments.drain(..count).collect();
+        ReservedRecords { records, commitments, reservation: self.reservation.clone() }
+    }
+}
+
+impl<N: Network> Deref for ReservedRecords<N> {
+    type Target = [Record<N, Plaintext<N>>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.records
+    }
+}
+
+impl<N: Network> Drop for ReservedRecords<N> {
+    /// Release the records so they can be selected again
+    fn drop(&mut self) {
+        self.reservation.release(&self.commitments);
+    }
+}
+
+/// A transaction along with the reservations of the records it spends
+///
+/// The records stay reserved while this value is alive, so other transactions built through the
+/// same `RecordReservation` cannot select them. Broadcast it with
+/// `ProgramManager::broadcast_reserved`, which leaves the records reserved once the transaction
+/// is accepted by the network and releases them if the broadcast fails.
+pub struct ReservedTransaction<N: Network> {
+    transaction: Transaction<N>,
+    reservations: Vec<ReservedRecords<N>>,
+    change: u64,
+}
+
+impl<N: Network> ReservedTransaction<N> {
+    /// Create a transaction holding the reservation of its fee record, if one was reserved
+    pub(crate) fn new(transaction: Transaction<N>, reservation: Option<ReservedRecords<N>>) -> Self {
+        Self { transaction, reservations: reservation.into_iter().collect(), change: 0 }
+    }
+
+    /// Hold further reservations of records spent by the transaction
+    pub(crate) fn with_reservations(mut self, reservations: impl IntoIterator<Item = ReservedRecords<N>>) -> Self {
+        self.reservations.extend(reservations);
+        self
+    }
+
+    /// Record the microcredits returned to the sender in a change record
+    pub(crate) fn with_change(mut self, change: u64) -> Self {
+        self.change = change;
+        self
+    }
+
+    /// Get the transaction
+    pub fn transaction(&self) -> &Transaction<N> {
+        &self.transaction
+    }
+
+    /// Get the microcredits returned to the sender in a change record by a private transfer
+    ///
+    /// The change record only becomes spendable once the transaction is confirmed. Transactions
+    /// which return no change report 0.
+    pub fn change_microcredits(&self) -> u64 {
+        self.change
+    }
+
+    /// Get the commitments of the records reserved for the transaction
+    pub fn commitments(&self) -> Vec<Field<N>> {
+        self.reservations.iter().flat_map(|reservation| reservation.commitments().iter().copied()).collect()
+    }
+
+    /// Leave the records reserved after the transaction is dropped, returning their commitments
+    /// so they can be released later with `RecordReservation::release`
+    pub fn keep(self) -> Vec<Field<N>> {
+        self.reservations.into_iter().flat_map(ReservedRecords::keep).collect()
+    }
+
+    /// Release the reserved records and return the transaction
+    pub fn into_transaction(self) -> Transaction<N> {
+        self.transaction
+    }
+}
+
+impl<N: Network> Deref for ReservedTransaction<N> {
+    type Target = Transaction<N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.transaction
+    }
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Get the record reservations shared by this program manager and its clones
+    pub fn record_reservation(&self) -> &RecordReservation<N> {
+        &self.record_reservation
+    }
+
+    /// Share record reservations with other program managers (or services) spending from the
+    /// same account
+    pub fn set_record_reservation(&mut self, record_reservation: RecordReservation<N>) {
+        self.record_reservation = record_reservation;
+    }
+
+    /// Find unspent credits records holding at least an amount through the record resolver and
+    /// reserve them
+    pub fn reserve_records(
+        &self,
+        amount: u64,
+        strategy: SelectionStrategy,
+        password: Option<&str>,
+    ) -> Result<ReservedRecords<N>> {
+        let private_key = self.get_private_key(password)?;
+        let records = self.find_unspent_credits(&private_key, amount)?;
+        self.record_reservation.reserve_credits(records, amount, strategy)
+    }
+
+    /// Query the record resolver for the unspent credits records of an account
+    pub(crate) fn find_unspent_credits(
+        &self,
+        private_key: &PrivateKey<N>,
+        min_microcredits: u64,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        let resolver = self.record_resolver().ok_or_else(|| AleoSdkError::invalid_input("Record resolver not set"))?;
+        let query = RecordQuery { min_microcredits: Some(min_microcredits), ..RecordQuery::unspent_credits()? };
+        resolver.find_owned_records(private_key, &query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_record_reservation() {
+        let address = Address::<Testnet3>::from_str(RECIPIENT_ADDRESS).unwrap();
+        let records = vec![credits_record(&address, 100), credits_record(&address, 300), credits_record(&address, 200)];
+        let reservation = RecordReservation::<Testnet3>::new();
+
+        // Concurrent selections receive disjoint records
+        let first = reservation.reserve_credits(records.clone(), 250, SelectionStrategy::LargestFirst).unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].microcredits().unwrap(), 300);
+        let second =
+            reservation.clone().reserve_credits(records.clone(), 250, SelectionStrategy::LargestFirst).unwrap();
+        assert_eq!(second.len(), 2);
+        assert!(reservation.reserve_credits(records.clone(), 1, SelectionStrategy::LargestFirst).is_err());
+        assert_eq!(reservation.len(), 3);
+
+        // Reserving an already reserved record fails without reserving the others
+        let commitment = credits_commitment(&records[0]).unwrap();
+        drop(second);
+        let conflicting =
+            vec![(commitment, records[0].clone()), (*first.commitments().first().unwrap(), records[1].clone())];
+        assert!(reservation.reserve(conflicting).is_err());
+        assert!(!reservation.is_reserved(&commitment));
+
+        // Kept records stay reserved until released
+        let kept = first.keep();
+        assert_eq!(reservation.len(), 1);
+        reservation.release(&kept);
+        assert!(reservation.is_empty());
+
+        // Records split into their own reservation are released independently
+        let mut all = reservation.reserve_credits(records, 600, SelectionStrategy::LargestFirst).unwrap();
+        let split = all.split_first(1);
+        assert_eq!(split.len(), 1);
+        assert_eq!(all.len(), 2);
+        drop(all);
+        assert_eq!(reservation.len(), 1);
+        assert!(reservation.is_reserved(&split.commitments()[0]));
+    }
+}
@@ -23,46 +23,20 @@ impl<N: Network> ProgramManager<N> {
     }
 
     /// Load a program from a local program directory
+    ///
+    /// With the `leo` feature, a directory holding a Leo project is built first and the program
+    /// is loaded from its build output.
     pub fn find_program_on_disk(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
-        let local_program_directory =
-            self.local_program_directory.as_ref().ok_or_else(|| anyhow!("Local program directory not set"))?;
-        let imports_directory = local_program_directory.join("imports");
-        // Ensure the directory path exists.
-        ensure!(local_program_directory.exists(), "The program directory does not exist");
-
-        ensure!(!Program::is_reserved_keyword(program_id.name()), "Program name is invalid (reserved): {program_id}");
-
-        ensure!(
-            Manifest::<N>::exists_at(local_program_directory),
-            "Please ensure that the manifest file exists in the Aleo program directory (missing '{}' at '{}')",
-            Manifest::<N>::file_name(),
-            local_program_directory.display()
-        );
-
-        // Open the manifest file.
-        let manifest = Manifest::<N>::open(local_program_directory)?;
-
-        // Ensure the program ID matches the manifest program ID, or that the program is a local import
-        if manifest.program_id() == program_id {
-            // Load the package.
-            let package = Package::open(local_program_directory)?;
-            // Load the main program.
-            Ok(package.program().clone())
-        } else {
-            let import_file = imports_directory.join(program_id.to_string());
-            ensure!(
-                import_file.exists(),
-                "No program named {program_id:?} found at {:?}",
-                local_program_directory.display()
-            );
-            println!("Attempting to load program {program_id:?} at {:?}", import_file.display());
-            let mut program_file = File::open(import_file)?;
-            let mut program_string = String::new();
-            program_file.read_to_string(&mut program_string).map_err(|err| anyhow::anyhow!(err.to_string()))?;
-            let program = Program::from_str(&program_string)?;
-            println!("Loaded program {program_id:?} successfully!");
-            Ok(program)
+        let local_program_directory = self
+            .local_program_directory
+            .as_ref()
+            .ok_or_else(|| AleoSdkError::invalid_input("Local program directory not set"))?;
+        #[cfg(feature = "leo")]
+        if LeoBuilder::is_leo_project(local_program_directory) {
+            let build_directory = self.leo_builder.build(local_program_directory)?;
+            return FileSystemResolver::new(&build_directory)?.load_program(program_id);
         }
+        FileSystemResolver::new(local_program_directory)?.load_program(program_id)
     }
 
     /// Load a program from the network
@@ -74,11 +48,11 @@ impl<N: Network> ProgramManager<N> {
     pub fn find_program_imports(&self, program: &Program<N>) -> Result<Vec<Program<N>>> {
         let mut imports = vec![];
         for program_id in program.imports().keys() {
-            if let Ok(program) = self.find_program(program_id) {
-                imports.push(program);
-            } else {
-                bail!("Could not find program import: {:?}", program_id);
-            }
+            let program = self.find_program(program_id).map_err(|error| AleoSdkError::Resolution {
+                message: format!("Could not find program import: {program_id:?}"),
+                source: Some(error.into()),
+            })?;
+            imports.push(program);
         }
         Ok(imports)
     }
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::{runtime::Handle, task};
+
+/// Asynchronous source of programs and records
+///
+/// The `Resolver` trait is synchronous, so a network backed resolver blocks the thread it is
+/// called from. Services running on an async executor should use this trait instead, which can
+/// be implemented directly or obtained from a synchronous resolver via `SpawnBlockingResolver`.
+#[async_trait]
+pub trait AsyncResolver<N: Network>: Send + Sync {
+    /// Load a program by its id
+    async fn load_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>>;
+
+    /// Load the programs imported by a program
+    async fn resolve_program_imports(&self, program: &Program<N>) -> Result<Vec<Program<N>>> {
+        let mut imports = Vec::with_capacity(program.imports().len());
+        for program_id in program.imports().keys() {
+            let import = self.load_program(program_id).await.map_err(|error| AleoSdkError::Resolution {
+                message: format!("Could not find program import: {program_id:?}"),
+                source: Some(error.into()),
+            })?;
+            imports.push(import);
+        }
+        Ok(imports)
+    }
+
+    /// Find the unspent records owned by a private key which match a query
+    async fn find_owned_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        record_query: &RecordQuery<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>>;
+}
+
+/// Asynchronous resolver for a local Aleo package
+pub type AsyncFileSystemResolver<N> = SpawnBlockingResolver<N, FileSystemResolver<N>>;
+
+/// Asynchronous resolver for the REST API of an Aleo node
+pub type AsyncNetworkResolver<N> = SpawnBlockingResolver<N, NetworkResolver<N>>;
+
+/// Adapter which makes a synchronous resolver asynchronous
+///
+/// Every call runs on the blocking thread pool of the tokio runtime, so the executor threads are
+/// never blocked by disk or network access.
+pub struct SpawnBlockingResolver<N: Network, R: Resolver<N>> {
+    resolver: Arc<R>,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network, R: Resolver<N>> SpawnBlockingResolver<N, R> {
+    /// Wrap a synchronous resolver
+    pub fn new(resolver: R) -> Self {
+        Self { resolver: Arc::new(resolver), _network: PhantomData }
+    }
+
+    /// Get the wrapped resolver
+    pub fn inner(&self) -> &R {
+        &self.resolver
+    }
+}
+
+impl<N: Network, R: Resolver<N>> Clone for SpawnBlockingResolver<N, R> {
+    fn clone(&self) -> Self {
+        Self { resolver: self.resolver.clone(), _network: PhantomData }
+    }
+}
+
+#[async_trait]
+impl<N: Network, R: Resolver<N> + Send + Sync + 'static> AsyncResolver<N> for SpawnBlockingResolver<N, R> {
+    async fn load_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        let (resolver, program_id) = (self.resolver.clone(), *program_id);
+        task::spawn_blocking(move || resolver.load_program(&program_id)).await?
+    }
+
+    async fn resolve_program_imports(&self, program: &Program<N>) -> Result<Vec<Program<N>>> {
+        let (resolver, program) = (self.resolver.clone(), program.clone());
+        task::spawn_blocking(move || resolver.resolve_program_imports(&program)).await?
+    }
+
+    async fn find_owned_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        record_query: &RecordQuery<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        let (resolver, private_key, record_query) = (self.resolver.clone(), *private_key, record_query.clone());
+        task::spawn_blocking(move || resolver.find_owned_records(&private_key, &record_query)).await?
+    }
+}
+
+/// Adapter which makes an asynchronous resolver synchronous
+///
+/// Calls block the current thread until the future completes on the given runtime. This must not
+/// be used from within an async task, since blocking an executor thread on the runtime it belongs
+/// to panics. Call it from a blocking thread instead (i.e. inside `spawn_blocking`).
+pub struct BlockOnResolver<N: Network, R: AsyncResolver<N>> {
+    resolver: R,
+    runtime: Handle,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network, R: AsyncResolver<N>> BlockOnResolver<N, R> {
+    /// Wrap an asynchronous resolver, driving its futures on the given runtime
+    pub fn new(resolver: R, runtime: Handle) -> Self {
+        Self { resolver, runtime, _network: PhantomData }
+    }
+
+    /// Get the wrapped resolver
+    pub fn inner(&self) -> &R {
+        &self.resolver
+    }
+}
+
+impl<N: Network, R: AsyncResolver<N>> Resolver<N> for BlockOnResolver<N, R> {
+    fn load_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.runtime.block_on(self.resolver.load_program(program_id))
+    }
+
+    fn resolve_program_imports(&self, program: &Program<N>) -> Result<Vec<Program<N>>> {
+        self.runtime.block_on(self.resolver.resolve_program_imports(program))
+    }
+
+    fn find_owned_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        record_query: &RecordQuery<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        self.runtime.block_on(self.resolver.find_owned_records(private_key, record_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{setup_directory, teardown_directory, IMPORT_PROGRAM};
+    use snarkvm_console::network::Testnet3;
+
+    use std::str::FromStr;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_async_file_system_resolver() {
+        let credits = Program::<Testnet3>::credits().unwrap().to_string();
+        let imports = vec![("credits.aleo", credits.as_str())];
+        let test_path = setup_directory("aleo_test_async_resolver", IMPORT_PROGRAM, imports).unwrap();
+
+        let resolver = AsyncFileSystemResolver::new(FileSystemResolver::<Testnet3>::new(&test_path).unwrap());
+        let program_id = ProgramID::<Testnet3>::from_str("aleo_test.aleo").unwrap();
+        let program = resolver.load_program(&program_id).await;
+        let imports = match &program {
+            Ok(program) => resolver.resolve_program_imports(program).await,
+            Err(_) => Ok(vec![]),
+        };
+
+        // Convert the asynchronous resolver back into a synchronous one on a blocking thread
+        let runtime = Handle::current();
+        let blocking_program = task::spawn_blocking(move || {
+            let resolver = BlockOnResolver::new(resolver, runtime);
+            resolver.load_program(&program_id)
+        })
+        .await
+        .unwrap();
+
+        teardown_directory(&test_path);
+        let expected_program = Program::<Testnet3>::from_str(IMPORT_PROGRAM).unwrap();
+        assert_eq!(program.unwrap(), expected_program);
+        assert_eq!(imports.unwrap(), vec![Program::<Testnet3>::credits().unwrap()]);
+        assert_eq!(blocking_program.unwrap(), expected_program);
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{
+    hash::Hash,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+/// Resolver which memoizes the programs and imports loaded by another resolver
+///
+/// Entries expire after a configurable time to live, and once the maximum number of entries is
+/// reached the oldest entry is evicted. This lets long-running services execute programs
+/// repeatedly without hitting the disk or the network every time. Records are not cached, since
+/// the unspent records of an account change with every block.
+pub struct CachingResolver<N: Network, R: Resolver<N>> {
+    resolver: R,
+    programs: TimedCache<ProgramID<N>, Program<N>>,
+    imports: TimedCache<ProgramID<N>, Vec<Program<N>>>,
+}
+
+impl<N: Network, R: Resolver<N>> CachingResolver<N, R> {
+    /// Wrap a resolver in a cache which holds at most `max_entries` programs (and as many import
+    /// lists). Entries are loaded again once they are older than `ttl`, or kept until they are
+    /// evicted if no time to live is specified.
+    pub fn new(resolver: R, ttl: Option<Duration>, max_entries: usize) -> Self {
+        Self { resolver, programs: TimedCache::new(ttl, max_entries), imports: TimedCache::new(ttl, max_entries) }
+    }
+
+    /// Get the wrapped resolver
+    pub fn inner(&self) -> &R {
+        &self.resolver
+    }
+
+    /// Remove all entries from the cache
+    pub fn clear(&self) {
+        self.programs.clear();
+        self.imports.clear();
+    }
+}
+
+impl<N: Network, R: Resolver<N>> Resolver<N> for CachingResolver<N, R> {
+    fn load_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        if let Some(program) = self.programs.get(program_id) {
+            return Ok(program);
+        }
+        let program = self.resolver.load_program(program_id)?;
+        self.programs.insert(*program_id, program.clone());
+        Ok(program)
+    }
+
+    fn resolve_program_imports(&self, program: &Program<N>) -> Result<Vec<Program<N>>> {
+        if let Some(imports) = self.imports.get(program.id()) {
+            return Ok(imports);
+        }
+        let imports = self.resolver.resolve_program_imports(program)?;
+        self.imports.insert(*program.id(), imports.clone());
+        Ok(imports)
+    }
+
+    fn find_owned_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        record_query: &RecordQuery<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        self.resolver.find_owned_records(private_key, record_query)
+    }
+}
+
+/// A bounded map whose entries expire after a time to live
+struct TimedCache<K, V> {
+    entries: RwLock<IndexMap<K, (Instant, V)>>,
+    ttl: Option<Duration>,
+    max_entries: usize,
+}
+
+impl<K: Hash + Eq, V: Clone> TimedCache<K, V> {
+    fn new(ttl: Option<Duration>, max_entries: usize) -> Self {
+        Self { entries: RwLock::new(IndexMap::new()), ttl, max_entries }
+    }
+
+    /// Get a clone of an entry if it has not expired
+    fn get(&self, key: &K) -> Option<V> {
+        let entries = self.entries.read().ok()?;
+        let (inserted, value) = entries.get(key)?;
+        match self.ttl {
+            Some(ttl) if inserted.elapsed() > ttl => None,
+            _ => Some(value.clone()),
+        }
+    }
+
+    /// Insert an entry, evicting the oldest entries if the cache is full
+    fn insert(&self, key: K, value: V) {
+        if self.max_entries == 0 {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.write() {
+            // Re-inserted keys move to the back of the eviction order
+            entries.shift_remove(&key);
+            while entries.len() >= self.max_entries {
+                entries.shift_remove_index(0);
+            }
+            entries.insert(key, (Instant::now(), value));
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::IMPORT_PROGRAM;
+    use snarkvm_console::network::Testnet3;
+
+    use std::{
+        str::FromStr,
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+    };
+
+    /// A resolver which counts how often it loads credits.aleo
+    #[derive(Default)]
+    struct CountingResolver {
+        loads: AtomicUsize,
+    }
+
+    impl CountingResolver {
+        fn loads(&self) -> usize {
+            self.loads.load(Ordering::SeqCst)
+        }
+    }
+
+    impl Resolver<Testnet3> for CountingResolver {
+        fn load_program(&self, program_id: &ProgramID<Testnet3>) -> Result<Program<Testnet3>> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            ensure!(program_id.to_string() == "credits.aleo", "Program {program_id} not found");
+            Program::credits()
+        }
+
+        fn find_owned_records(
+            &self,
+            _private_key: &PrivateKey<Testnet3>,
+            _record_query: &RecordQuery<Testnet3>,
+        ) -> Result<Vec<Record<Testnet3, Plaintext<Testnet3>>>> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_caching_resolver_memoizes_programs() {
+        let resolver = CachingResolver::new(CountingResolver::default(), None, 10);
+        let credits_id = ProgramID::<Testnet3>::from_str("credits.aleo").unwrap();
+
+        for _ in 0..3 {
+            assert_eq!(resolver.load_program(&credits_id).unwrap(), Program::<Testnet3>::credits().unwrap());
+        }
+        assert_eq!(resolver.inner().loads(), 1);
+
+        // Imports are memoized separately from the programs
+        let program = Program::<Testnet3>::from_str(IMPORT_PROGRAM).unwrap();
+        assert_eq!(resolver.resolve_program_imports(&program).unwrap().len(), 1);
+        assert_eq!(resolver.resolve_program_imports(&program).unwrap().len(), 1);
+        assert_eq!(resolver.inner().loads(), 2);
+
+        // Failed loads are not cached
+        let missing_id = ProgramID::<Testnet3>::from_str("missing.aleo").unwrap();
+        assert!(resolver.load_program(&missing_id).is_err());
+        assert!(resolver.load_program(&missing_id).is_err());
+        assert_eq!(resolver.inner().loads(), 4);
+
+        resolver.clear();
+        resolver.load_program(&credits_id).unwrap();
+        assert_eq!(resolver.inner().loads(), 5);
+    }
+
+    #[test]
+    fn test_caching_resolver_expires_entries() {
+        let resolver = CachingResolver::new(CountingResolver::default(), Some(Duration::from_millis(10)), 10);
+        let credits_id = ProgramID::<Testnet3>::from_str("credits.aleo").unwrap();
+
+        resolver.load_program(&credits_id).unwrap();
+        thread::sleep(Duration::from_millis(20));
+        resolver.load_program(&credits_id).unwrap();
+        assert_eq!(resolver.inner().loads(), 2);
+    }
+
+    #[test]
+    fn test_timed_cache_evicts_oldest_entries() {
+        let cache = TimedCache::new(None, 2);
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        cache.insert(3, "c");
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&2), Some("b"));
+        assert_eq!(cache.get(&3), Some("c"));
+
+        let disabled = TimedCache::new(None, 0);
+        disabled.insert(1, "a");
+        assert_eq!(disabled.get(&1), None);
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_console::program::Owner;
+
+/// Resolver which loads programs from a local Aleo package
+///
+/// The main program of the package is loaded from the package itself and any other program is
+/// loaded from the `imports` directory of the package. Records are stored as files in the
+/// `records/{program_id}` directories of the package, either as plaintext or as the ciphertext
+/// found on chain, which is decrypted with the view key of the account. Since these records carry no block
+/// height and the resolver has no access to the network, records are expected to be removed from
+/// the directory once spent and the block range of a query is not applied.
+#[derive(Clone, Debug)]
+pub struct FileSystemResolver<N: Network> {
+    local_program_directory: PathBuf,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> FileSystemResolver<N> {
+    /// Create a resolver for the Aleo package in a directory
+    pub fn new(local_program_directory: &Path) -> Result<Self> {
+        ensure!(local_program_directory.exists(), "The program directory does not exist");
+        Ok(Self { local_program_directory: local_program_directory.to_path_buf(), _network: PhantomData })
+    }
+
+    /// Get the directory of the Aleo package
+    pub fn local_program_directory(&self) -> &Path {
+        &self.local_program_directory
+    }
+
+    /// Get the directory holding the imports of the Aleo package
+    pub fn imports_directory(&self) -> PathBuf {
+        self.local_program_directory.join("imports")
+    }
+
+    /// Get the directory holding the records of the package
+    pub fn records_directory(&self) -> PathBuf {
+        self.local_program_directory.join("records")
+    }
+
+    /// Store a record of a program in the records directory of the package
+    pub fn store_record(&self, program_id: &ProgramID<N>, record: &Record<N, Plaintext<N>>) -> Result<PathBuf> {
+        let program_directory = self.records_directory().join(program_id.to_string());
+        std::fs::create_dir_all(&program_directory)?;
+        let record_file = program_directory.join(record.nonce().to_string());
+        std::fs::write(&record_file, record.to_string())?;
+        Ok(record_file)
+    }
+
+    /// Store an encrypted record of a program (i.e. one copied from a block) in the records
+    /// directory of the package
+    pub fn store_encrypted_record(
+        &self,
+        program_id: &ProgramID<N>,
+        record: &Record<N, Ciphertext<N>>,
+    ) -> Result<PathBuf> {
+        let program_directory = self.records_directory().join(program_id.to_string());
+        std::fs::create_dir_all(&program_directory)?;
+        let record_file = program_directory.join(record.nonce().to_string());
+        std::fs::write(&record_file, record.to_string())?;
+        Ok(record_file)
+    }
+
+    /// Find the records in the records directory owned by the account of a view key which match
+    /// a query
+    ///
+    /// Plaintext records are matched against the address of the view key and encrypted records
+    /// are decrypted if the view key owns them, whether their owner is public or private.
+    pub fn find_records_with_view_key(
+        &self,
+        view_key: &ViewKey<N>,
+        record_query: &RecordQuery<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        let address = view_key.to_address();
+        let records_directory = self.records_directory();
+        if !records_directory.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut records = vec![];
+        let mut total_microcredits = 0u64;
+        for program_directory in std::fs::read_dir(records_directory)? {
+            let program_directory = program_directory?.path();
+            let program_id = program_directory.file_name().and_then(|name| name.to_str()).map(ProgramID::<N>::from_str);
+            match program_id {
+                Some(Ok(program_id)) if program_directory.is_dir() && record_query.matches_program(&program_id) => {}
+                _ => continue,
+            }
+            for record_file in std::fs::read_dir(program_directory)? {
+                let record_string = std::fs::read_to_string(record_file?.path())?;
+                let record = match read_owned_record(record_string.trim(), view_key, &address)? {
+                    Some(record) if record_query.matches_amount(&record) => record,
+                    _ => continue,
+                };
+                total_microcredits = total_microcredits.saturating_add(record.microcredits().unwrap_or(0));
+                records.push(record);
+                if record_query.max_total_microcredits.map_or(false, |max| total_microcredits > max) {
+                    return record_query.select_amounts(records);
+                }
+            }
+        }
+        record_query.select_amounts(records)
+    }
+
+    /// Write a program into the imports directory of the package
+    pub fn write_import(&self, program: &Program<N>) -> Result<()> {
+        let imports_directory = self.imports_directory();
+        if !imports_directory.exists() {
+            std::fs::create_dir_all(&imports_directory)?;
+        }
+        std::fs::write(imports_directory.join(program.id().to_string()), program.to_string())?;
+        Ok(())
+    }
+}
+
+impl<N: Network> Resolver<N> for FileSystemResolver<N> {
+    fn load_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        let local_program_directory = &self.local_program_directory;
+        // Ensure the directory path exists.
+        ensure!(local_program_directory.exists(), "The program directory does not exist");
+
+        ensure!(!Program::is_reserved_keyword(program_id.name()), "Program name is invalid (reserved): {program_id}");
+
+        ensure!(
+            Manifest::<N>::exists_at(local_program_directory),
+            "Please ensure that the manifest file exists in the Aleo program directory (missing '{}' at '{}')",
+            Manifest::<N>::file_name(),
+            local_program_directory.display()
+        );
+
+        // Open the manifest file.
+        let manifest = Manifest::<N>::open(local_program_directory)?;
+
+        // Ensure the program ID matches the manifest program ID, or that the program is a local import
+        if manifest.program_id() == program_id {
+            // Load the package.
+            let package = Package::open(local_program_directory)?;
+            // Load the main program.
+            Ok(package.program().clone())
+        } else {
+            let import_file = self.imports_directory().join(program_id.to_string());
+            ensure!(
+                import_file.exists(),
+                "No program named {program_id:?} found at {:?}",
+                local_program_directory.display()
+            );
+            println!("Attempting to load program {program_id:?} at {:?}", import_file.display());
+            let mut program_file = File::open(import_file)?;
+            let mut program_string = String::new();
+            program_file.read_to_string(&mut program_string).map_err(|err| anyhow::anyhow!(err.to_string()))?;
+            let program = Program::from_str(&program_string)?;
+            println!("Loaded program {program_id:?} successfully!");
+            Ok(program)
+        }
+    }
+
+    fn find_owned_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        record_query: &RecordQuery<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        self.find_records_with_view_key(&ViewKey::try_from(private_key)?, record_query)
+    }
+}
+
+/// Parse a stored record, returning it if it is owned by the account of the view key
+///
+/// Encrypted records are only decrypted once the view key is known to own them.
+fn read_owned_record<N: Network>(
+    record_string: &str,
+    view_key: &ViewKey<N>,
+    address: &Address<N>,
+) -> Result<Option<Record<N, Plaintext<N>>>> {
+    if let Ok(record) = Record::<N, Plaintext<N>>::from_str(record_string) {
+        return Ok(is_owned_by(&record, address).then_some(record));
+    }
+    let record = Record::<N, Ciphertext<N>>::from_str(record_string)
+        .map_err(|_| anyhow!("Stored record is neither a plaintext nor an encrypted record"))?;
+    match record.is_owner(view_key) {
+        true => Ok(Some(record.decrypt(view_key)?)),
+        false => Ok(None),
+    }
+}
+
+/// Check if a record is owned by an address
+fn is_owned_by<N: Network>(record: &Record<N, Plaintext<N>>, address: &Address<N>) -> bool {
+    match record.owner() {
+        Owner::Public(owner) => owner == address,
+        Owner::Private(Plaintext::Literal(Literal::Address(owner), _)) => owner == address,
+        Owner::Private(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        setup_directory,
+        teardown_directory,
+        IMPORT_PROGRAM,
+        RECORD_2000000001_MICROCREDITS,
+        RECORD_5_MICROCREDITS,
+    };
+    use snarkvm_console::{network::Testnet3, prelude::Scalar};
+
+    use std::panic::catch_unwind;
+
+    const RECORD_OWNER: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+
+    // Create a record owned by an address from a test record
+    fn record_owned_by(record: &str, address: &Address<Testnet3>) -> Record<Testnet3, Plaintext<Testnet3>> {
+        Record::from_str(&record.replace(RECORD_OWNER, &address.to_string())).unwrap()
+    }
+
+    #[test]
+    fn test_file_system_resolver_records() {
+        let test_path = setup_directory("aleo_test_file_resolver_records", IMPORT_PROGRAM, vec![]).unwrap();
+
+        let result = catch_unwind(|| {
+            let resolver = FileSystemResolver::<Testnet3>::new(&test_path).unwrap();
+            let private_key = PrivateKey::<Testnet3>::new(&mut rand::thread_rng()).unwrap();
+            let address = Address::try_from(&private_key).unwrap();
+            let query = RecordQuery::default();
+            assert!(resolver.find_owned_records(&private_key, &query).unwrap().is_empty());
+
+            let credits_id = ProgramID::<Testnet3>::from_str("credits.aleo").unwrap();
+            let small_record = record_owned_by(RECORD_5_MICROCREDITS, &address);
+            let large_record = record_owned_by(RECORD_2000000001_MICROCREDITS, &address);
+            resolver.store_record(&credits_id, &small_record).unwrap();
+            resolver.store_record(&credits_id, &large_record).unwrap();
+            assert_eq!(resolver.find_owned_records(&private_key, &query).unwrap().len(), 2);
+
+            // Records are filtered by amount and program
+            let query = RecordQuery { min_microcredits: Some(10), ..Default::default() };
+            assert_eq!(resolver.find_owned_records(&private_key, &query).unwrap(), vec![large_record]);
+            let query =
+                RecordQuery { program_id: Some(ProgramID::from_str("hello.aleo").unwrap()), ..Default::default() };
+            assert!(resolver.find_owned_records(&private_key, &query).unwrap().is_empty());
+
+            // Records owned by other accounts are not returned
+            let other_private_key = PrivateKey::<Testnet3>::new(&mut rand::thread_rng()).unwrap();
+            assert!(resolver.find_owned_records(&other_private_key, &RecordQuery::default()).unwrap().is_empty());
+
+            // Encrypted records with a private owner are decrypted with the view key
+            let randomizer = Scalar::<Testnet3>::rand(&mut rand::thread_rng());
+            let nonce = Testnet3::g_scalar_multiply(&randomizer);
+            let encrypted_record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(&format!(
+                "{{ owner: {address}.private, microcredits: 300u64.private, _nonce: {nonce}.public }}"
+            ))
+            .unwrap();
+            resolver.store_encrypted_record(&credits_id, &encrypted_record.encrypt(randomizer).unwrap()).unwrap();
+            let query = RecordQuery { min_microcredits: Some(100), ..Default::default() };
+            let records = resolver.find_owned_records(&private_key, &query).unwrap();
+            assert_eq!(records.len(), 2);
+            assert!(records.contains(&encrypted_record));
+            let view_key = ViewKey::try_from(&other_private_key).unwrap();
+            assert!(resolver.find_records_with_view_key(&view_key, &query).unwrap().is_empty());
+        });
+        teardown_directory(&test_path);
+        result.unwrap();
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Resolver which loads programs from a local Aleo package and falls back to the network
+///
+/// Programs missing from the package are fetched from the network and can optionally be written
+/// into the `imports` directory of the package, so local development against programs deployed
+/// on-chain works without copying their source by hand. Records are always found on the network.
+#[derive(Clone, Debug)]
+pub struct HybridResolver<N: Network> {
+    file_resolver: FileSystemResolver<N>,
+    network_resolver: NetworkResolver<N>,
+    write_imports: bool,
+}
+
+impl<N: Network> HybridResolver<N> {
+    /// Create a hybrid resolver from a file system and a network resolver
+    ///
+    /// If `write_imports` is set, programs fetched from the network are written into the imports
+    /// directory of the package.
+    pub fn new(
+        file_resolver: FileSystemResolver<N>,
+        network_resolver: NetworkResolver<N>,
+        write_imports: bool,
+    ) -> Self {
+        Self { file_resolver, network_resolver, write_imports }
+    }
+
+    /// Get the file system resolver
+    pub fn file_resolver(&self) -> &FileSystemResolver<N> {
+        &self.file_resolver
+    }
+
+    /// Get the network resolver
+    pub fn network_resolver(&self) -> &NetworkResolver<N> {
+        &self.network_resolver
+    }
+}
+
+impl<N: Network> Resolver<N> for HybridResolver<N> {
+    fn load_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        if let Ok(program) = self.file_resolver.load_program(program_id) {
+            return Ok(program);
+        }
+        let program = self.network_resolver.load_program(program_id)?;
+        if self.write_imports {
+            self.file_resolver.write_import(&program)?;
+        }
+        Ok(program)
+    }
+
+    fn find_owned_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        record_query: &RecordQuery<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        self.network_resolver.find_owned_records(private_key, record_query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{setup_directory, teardown_directory, IMPORT_PROGRAM};
+    use snarkvm_console::network::Testnet3;
+
+    use std::{panic::catch_unwind, str::FromStr};
+
+    #[test]
+    fn test_hybrid_resolver_writes_network_imports() {
+        let test_path = setup_directory("aleo_test_hybrid_resolver", IMPORT_PROGRAM, vec![]).unwrap();
+
+        let result = catch_unwind(|| {
+            let file_resolver = FileSystemResolver::<Testnet3>::new(&test_path).unwrap();
+            let network_resolver = NetworkResolver::new(AleoAPIClient::testnet3());
+            let resolver = HybridResolver::new(file_resolver.clone(), network_resolver, true);
+
+            // The main program is loaded from disk
+            let program_id = ProgramID::<Testnet3>::from_str("aleo_test.aleo").unwrap();
+            let expected_program = Program::<Testnet3>::from_str(IMPORT_PROGRAM).unwrap();
+            assert_eq!(resolver.load_program(&program_id).unwrap(), expected_program);
+
+            // The missing import is fetched from the network and written into the imports directory
+            let credits_id = ProgramID::<Testnet3>::from_str("credits.aleo").unwrap();
+            assert!(file_resolver.load_program(&credits_id).is_err());
+            let imports = resolver.resolve_program_imports(&expected_program).unwrap();
+            assert_eq!(imports, vec![Program::<Testnet3>::credits().unwrap()]);
+            assert_eq!(file_resolver.load_program(&credits_id).unwrap(), Program::<Testnet3>::credits().unwrap());
+        });
+        teardown_directory(&test_path);
+        result.unwrap();
+    }
+}
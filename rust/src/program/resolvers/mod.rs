@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Pluggable sources of programs and records
+//!
+//! The ProgramManager resolves programs from its local program directory and API client. Services
+//! which need to control where programs and records come from (i.e. a database, a cache or a
+//! custom node) can implement the `Resolver` trait instead.
+
+use super::*;
+
+#[cfg(feature = "async")]
+pub mod async_resolver;
+#[cfg(feature = "async")]
+pub use async_resolver::*;
+
+pub mod caching;
+pub use caching::*;
+
+pub mod file;
+pub use file::*;
+
+pub mod hybrid;
+pub use hybrid::*;
+
+pub mod network;
+pub use network::*;
+
+pub mod query;
+pub use query::*;
+
+/// A source of programs and records
+pub trait Resolver<N: Network> {
+    /// Load a program by its id
+    fn load_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>>;
+
+    /// Load the programs imported by a program
+    fn resolve_program_imports(&self, program: &Program<N>) -> Result<Vec<Program<N>>> {
+        program
+            .imports()
+            .keys()
+            .map(|program_id| {
+                self.load_program(program_id).map_err(|error| {
+                    Error::from(AleoSdkError::Resolution {
+                        message: format!("Could not find program import: {program_id:?}"),
+                        source: Some(error.into()),
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// Find the unspent records owned by a private key which match a query
+    fn find_owned_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        record_query: &RecordQuery<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::IMPORT_PROGRAM;
+    use snarkvm_console::network::Testnet3;
+
+    use std::str::FromStr;
+
+    /// A resolver which only knows credits.aleo
+    struct CreditsResolver;
+
+    impl Resolver<Testnet3> for CreditsResolver {
+        fn load_program(&self, program_id: &ProgramID<Testnet3>) -> Result<Program<Testnet3>> {
+            ensure!(program_id.to_string() == "credits.aleo", "Program {program_id} not found");
+            Program::credits()
+        }
+
+        fn find_owned_records(
+            &self,
+            _private_key: &PrivateKey<Testnet3>,
+            _record_query: &RecordQuery<Testnet3>,
+        ) -> Result<Vec<Record<Testnet3, Plaintext<Testnet3>>>> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_resolve_program_imports() {
+        let program = Program::<Testnet3>::from_str(IMPORT_PROGRAM).unwrap();
+        let imports = CreditsResolver.resolve_program_imports(&program).unwrap();
+        assert_eq!(imports, vec![Program::<Testnet3>::credits().unwrap()]);
+
+        let bad_import_program =
+            Program::<Testnet3>::from_str(&format!("import missing.aleo;{IMPORT_PROGRAM}")).unwrap();
+        assert!(CreditsResolver.resolve_program_imports(&bad_import_program).is_err());
+    }
+}
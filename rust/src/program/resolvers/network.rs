@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{api::block_batches, record::RecordScanner};
+
+/// Resolver which loads programs and records from the REST API of an Aleo node
+///
+/// Programs are fetched from the `/program/{id}` endpoint and records are found by scanning the
+/// blocks of the node for records owned by the account. Without a block range in the query, the
+/// whole chain is scanned.
+#[derive(Clone, Debug)]
+pub struct NetworkResolver<N: Network> {
+    api_client: AleoAPIClient<N>,
+}
+
+impl<N: Network> NetworkResolver<N> {
+    /// Create a network resolver from an API client
+    pub fn new(api_client: AleoAPIClient<N>) -> Self {
+        Self { api_client }
+    }
+
+    /// Create a network resolver for the node at a base url (i.e. "https://vm.aleo.org/api")
+    pub fn from_url(base_url: &str, network_id: &str) -> Result<Self> {
+        Ok(Self::new(AleoAPIClient::new(base_url, network_id)?))
+    }
+
+    /// Get the API client used by the resolver
+    pub fn api_client(&self) -> &AleoAPIClient<N> {
+        &self.api_client
+    }
+}
+
+impl<N: Network> Resolver<N> for NetworkResolver<N> {
+    fn load_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.api_client.get_program(program_id)
+    }
+
+    fn find_owned_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        record_query: &RecordQuery<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        let scanner = RecordScanner::new(self.api_client.clone(), ViewKey::try_from(private_key)?);
+        let block_heights = match &record_query.block_range {
+            Some(block_range) => block_range.clone(),
+            None => 0..self.api_client.latest_height()?.saturating_add(1),
+        };
+        ensure!(
+            block_heights.start < block_heights.end,
+            AleoSdkError::invalid_input("The start block height must be less than the end block height")
+        );
+
+        let mut records = vec![];
+        let mut total_microcredits = 0u64;
+        for batch in block_batches(block_heights, MAX_BLOCKS_PER_REQUEST) {
+            for scanned in scanner.scan(batch)? {
+                if !record_query.matches_program(&scanned.program_id) || !record_query.matches_amount(&scanned.record) {
+                    continue;
+                }
+                if record_query.unspent_only {
+                    let serial_number = Record::<N, Ciphertext<N>>::serial_number(*private_key, scanned.commitment)?;
+                    if self.api_client.is_serial_number_spent(serial_number)? {
+                        continue;
+                    }
+                }
+                total_microcredits = total_microcredits.saturating_add(scanned.record.microcredits().unwrap_or(0));
+                records.push(scanned.record);
+            }
+            // Stop searching once enough credits have been found
+            if record_query.max_total_microcredits.map_or(false, |max| total_microcredits > max) {
+                break;
+            }
+        }
+        record_query.select_amounts(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_program_id, IMPORT_PROGRAM};
+    use snarkvm_console::network::Testnet3;
+
+    use std::str::FromStr;
+
+    #[test]
+    fn test_network_resolver_program_loading() {
+        let resolver = NetworkResolver::<Testnet3>::from_url("https://vm.aleo.org/api", "testnet3").unwrap();
+
+        let credits_id = ProgramID::<Testnet3>::from_str("credits.aleo").unwrap();
+        assert_eq!(resolver.load_program(&credits_id).unwrap(), Program::<Testnet3>::credits().unwrap());
+
+        let test_program = Program::<Testnet3>::from_str(IMPORT_PROGRAM).unwrap();
+        let imports = resolver.resolve_program_imports(&test_program).unwrap();
+        assert_eq!(imports, vec![Program::<Testnet3>::credits().unwrap()]);
+
+        let program_id = ProgramID::<Testnet3>::from_str(&random_program_id(16)).unwrap();
+        assert!(resolver.load_program(&program_id).is_err());
+    }
+
+    #[test]
+    fn test_network_resolver_rejects_invalid_urls() {
+        assert!(NetworkResolver::<Testnet3>::from_url("vm.aleo.org/api", "testnet3").is_err());
+    }
+}
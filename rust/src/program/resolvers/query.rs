@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Parameters of a search for records owned by an account
+///
+/// All filters are optional, and a default query finds every record owned by the account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordQuery<N: Network> {
+    /// Only find records created by this program
+    pub program_id: Option<ProgramID<N>>,
+    /// Only find records holding at least this many microcredits
+    pub min_microcredits: Option<u64>,
+    /// Only find records holding at most this many microcredits
+    pub max_microcredits: Option<u64>,
+    /// Only find records which have not been spent
+    pub unspent_only: bool,
+    /// Only search the blocks in this range of heights
+    pub block_range: Option<Range<u32>>,
+    /// Find one record holding at least each of these amounts of microcredits
+    pub amounts: Option<Vec<u64>>,
+    /// Stop searching once the records found hold more than this many microcredits in total
+    pub max_total_microcredits: Option<u64>,
+}
+
+impl<N: Network> Default for RecordQuery<N> {
+    fn default() -> Self {
+        Self {
+            program_id: None,
+            min_microcredits: None,
+            max_microcredits: None,
+            unspent_only: false,
+            block_range: None,
+            amounts: None,
+            max_total_microcredits: None,
+        }
+    }
+}
+
+impl<N: Network> RecordQuery<N> {
+    /// Create a query for the unspent credits.aleo records of an account
+    pub fn unspent_credits() -> Result<Self> {
+        Ok(Self { program_id: Some(ProgramID::from_str("credits.aleo")?), unspent_only: true, ..Default::default() })
+    }
+
+    /// Check if records of a program are included by the query
+    pub fn matches_program(&self, program_id: &ProgramID<N>) -> bool {
+        self.program_id.as_ref().map_or(true, |query_program_id| query_program_id == program_id)
+    }
+
+    /// Check if a record holds an amount of microcredits included by the query
+    ///
+    /// Records without microcredits are only included if no amount filter is set.
+    pub fn matches_amount(&self, record: &Record<N, Plaintext<N>>) -> bool {
+        if self.min_microcredits.is_none() && self.max_microcredits.is_none() {
+            return true;
+        }
+        match record.microcredits() {
+            Ok(microcredits) => {
+                self.min_microcredits.map_or(true, |min| microcredits >= min)
+                    && self.max_microcredits.map_or(true, |max| microcredits <= max)
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Select one record for each of the requested amounts, if any were requested
+    pub fn select_amounts(&self, mut records: Vec<Record<N, Plaintext<N>>>) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        let amounts = match &self.amounts {
+            Some(amounts) => amounts,
+            None => return Ok(records),
+        };
+        amounts
+            .iter()
+            .map(|amount| {
+                let position = records.iter().position(|record| record.microcredits().unwrap_or(0) >= *amount);
+                position
+                    .map(|index| records.remove(index))
+                    .ok_or_else(|| anyhow!(AleoSdkError::InsufficientFunds { required: *amount, available: None }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{RECORD_2000000001_MICROCREDITS, RECORD_5_MICROCREDITS};
+    use snarkvm_console::network::Testnet3;
+
+    fn records() -> Vec<Record<Testnet3, Plaintext<Testnet3>>> {
+        vec![
+            Record::from_str(RECORD_5_MICROCREDITS).unwrap(),
+            Record::from_str(RECORD_2000000001_MICROCREDITS).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_record_query_filters() {
+        let query = RecordQuery::<Testnet3>::unspent_credits().unwrap();
+        assert!(query.unspent_only);
+        assert!(query.matches_program(&ProgramID::from_str("credits.aleo").unwrap()));
+        assert!(!query.matches_program(&ProgramID::from_str("hello.aleo").unwrap()));
+        assert!(RecordQuery::<Testnet3>::default().matches_program(&ProgramID::from_str("hello.aleo").unwrap()));
+
+        let query = RecordQuery::<Testnet3> { min_microcredits: Some(10), ..Default::default() };
+        let matching = records().into_iter().filter(|record| query.matches_amount(record)).count();
+        assert_eq!(matching, 1);
+
+        let query = RecordQuery::<Testnet3> { max_microcredits: Some(10), ..Default::default() };
+        let matching = records().into_iter().filter(|record| query.matches_amount(record)).collect::<Vec<_>>();
+        assert_eq!(matching, vec![records()[0].clone()]);
+    }
+
+    #[test]
+    fn test_record_query_amount_selection() {
+        let query = RecordQuery::<Testnet3> { amounts: Some(vec![1_000_000, 5]), ..Default::default() };
+        let selected = query.select_amounts(records()).unwrap();
+        assert_eq!(selected, vec![records()[1].clone(), records()[0].clone()]);
+
+        let query = RecordQuery::<Testnet3> { amounts: Some(vec![1_000_000, 1_000_000]), ..Default::default() };
+        assert!(query.select_amounts(records()).is_err());
+
+        assert_eq!(RecordQuery::<Testnet3>::default().select_amounts(records()).unwrap(), records());
+    }
+}
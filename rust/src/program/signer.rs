@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm::{
+    circuit::Aleo,
+    synthesizer::{store::helpers::memory::BlockMemory, Authorization, Instruction, Process},
+};
+use snarkvm_console::program::{Request, ValueType};
+
+/// A signer holding the signing key of an Aleo account
+///
+/// Function calls are authorized by signing their requests, which is the only step of an
+/// execution requiring the signing key. Implementing this trait lets an HSM, a remote signing
+/// service or a hardware wallet authorize calls and fees, while the SDK executes and proves the
+/// authorization without ever holding the private key.
+pub trait Signer<N: Network>: Send + Sync {
+    /// Get the address of the account
+    fn address(&self) -> Result<Address<N>>;
+
+    /// Sign a message with the signing key of the account
+    fn sign(&self, message: &[Field<N>]) -> Result<Signature<N>>;
+
+    /// Sign the request of a call of a program function
+    ///
+    /// Besides the signature, the request holds the serial numbers of the records spent by the
+    /// call, which are also derived from the signing key.
+    fn sign_request(
+        &self,
+        program_id: &ProgramID<N>,
+        function: &Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+    ) -> Result<Request<N>>;
+
+    /// Authorize a call of a program function. The program and its imports have been added to
+    /// the process
+    ///
+    /// The default implementation signs the request of the call with `sign_request`, so it only
+    /// supports functions which do not call other functions. Signers able to sign the requests of
+    /// nested calls can override it.
+    fn authorize(
+        &self,
+        process: &Process<N>,
+        program_id: &ProgramID<N>,
+        function: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<Authorization<N>> {
+        let function = process.get_program(program_id)?.get_function(function)?;
+        ensure!(
+            !function.instructions().iter().any(|instruction| matches!(instruction, Instruction::Call(..))),
+            AleoSdkError::authorization(format!(
+                "{program_id}/{} calls other functions, which this signer cannot authorize",
+                function.name()
+            ))
+        );
+        let request = self.sign_request(program_id, function.name(), inputs, &function.input_types())?;
+        Ok(Authorization::new(&[request]))
+    }
+}
+
+/// A signer authorizing calls with a private key held in memory
+///
+/// The signer is generic over the circuit environment of the network (i.e. `AleoV0` for testnet3)
+/// which is used to build the requests.
+pub struct PrivateKeySigner<A: Aleo> {
+    private_key: PrivateKey<A::Network>,
+}
+
+impl<A: Aleo> PrivateKeySigner<A> {
+    /// Create a signer from a private key
+    pub fn new(private_key: PrivateKey<A::Network>) -> Self {
+        Self { private_key }
+    }
+}
+
+impl<A: Aleo> Signer<A::Network> for PrivateKeySigner<A> {
+    fn address(&self) -> Result<Address<A::Network>> {
+        Address::try_from(&self.private_key)
+    }
+
+    fn sign(&self, message: &[Field<A::Network>]) -> Result<Signature<A::Network>> {
+        self.private_key.sign(message, &mut rand::thread_rng())
+    }
+
+    fn sign_request(
+        &self,
+        program_id: &ProgramID<A::Network>,
+        function: &Identifier<A::Network>,
+        inputs: &[Value<A::Network>],
+        input_types: &[ValueType<A::Network>],
+    ) -> Result<Request<A::Network>> {
+        Request::sign(
+            &self.private_key,
+            *program_id,
+            *function,
+            inputs.iter().cloned(),
+            input_types,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Authorize a call along with the nested calls it makes, which are signed with the same key
+    fn authorize(
+        &self,
+        process: &Process<A::Network>,
+        program_id: &ProgramID<A::Network>,
+        function: &Identifier<A::Network>,
+        inputs: &[Value<A::Network>],
+    ) -> Result<Authorization<A::Network>> {
+        process.authorize::<A, _>(
+            &self.private_key,
+            program_id,
+            function,
+            inputs.iter().cloned(),
+            &mut rand::thread_rng(),
+        )
+    }
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Authorize a call of a program function with a signer
+    ///
+    /// The program and its imports are loaded from the program manager, the local program
+    /// directory or the network. The authorization can be proven with `prove_authorization`,
+    /// which does not require the signing key.
+    pub fn authorize(
+        &self,
+        signer: &dyn Signer<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+    ) -> Result<Authorization<N>> {
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
+        let function_name = function.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid function name"))?;
+        let inputs = inputs
+            .map(|input| input.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid input")))
+            .collect::<Result<Vec<Value<N>>, _>>()?;
+
+        let vm = open_memory_vm()?;
+        add_programs_to_vm(&vm, &self.load_program_with_imports(&program_id)?)?;
+        let process = vm.process().read();
+        ensure!(
+            process.get_program(program_id)?.contains_function(&function_name),
+            AleoSdkError::invalid_input(format!(
+                "Program {program_id:?} does not contain function {function_name:?}, aborting authorization"
+            ))
+        );
+        info_span!("authorization", program = %program_id, function = %function_name)
+            .in_scope(|| signer.authorize(&process, &program_id, &function_name, &inputs))
+    }
+
+    /// Authorize the payment of a fee for a deployment or execution with a signer
+    ///
+    /// The fee is paid from a record owned by the signer through `credits.aleo/fee`.
+    pub fn authorize_fee(
+        &self,
+        signer: &dyn Signer<N>,
+        fee_record: Record<N, Plaintext<N>>,
+        fee: u64,
+        id: Field<N>,
+    ) -> Result<Authorization<N>> {
+        ensure!(fee > 0, AleoSdkError::invalid_input("Fee must be greater than 0"));
+        let (credits, fee_name) = fee_function()?;
+        let input_types = Program::<N>::credits()?.get_function(&fee_name)?.input_types();
+        let inputs =
+            [Value::Record(fee_record), Value::from_str(&format!("{fee}u64"))?, Value::from_str(&id.to_string())?];
+        let request = info_span!("fee_authorization", fee)
+            .in_scope(|| signer.sign_request(&credits, &fee_name, &inputs, &input_types))?;
+        Ok(Authorization::new(&[request]))
+    }
+
+    /// Execute and prove an authorization and create an execution transaction from it
+    ///
+    /// Once the execution is proven, the fee paying for it is authorized by the signer with
+    /// `authorize_fee` and proven as well. If no query url is given, the state of the network is
+    /// queried from the API client of the program manager.
+    pub fn prove_authorization<A: Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        signer: &dyn Signer<N>,
+        fee: u64,
+        fee_record: Record<N, Plaintext<N>>,
+        query: Option<&str>,
+    ) -> Result<Transaction<N>> {
+        let request = authorization.peek_next()?;
+        let (program_id, function_name) = (*request.program_id(), *request.function_name());
+        let _span = info_span!("prove_authorization", program = %program_id, function = %function_name).entered();
+
+        let vm = open_memory_vm()?;
+        add_programs_to_vm(&vm, &self.load_program_with_imports(&program_id)?)?;
+        let synthesized = self.load_provided_keys(&vm, &[(program_id, function_name), fee_function()?])?;
+
+        // Execute the authorization and prove the execution
+        let process = vm.process().read();
+        let (_, mut trace) = info_span!("execution").in_scope(|| process.execute::<A>(authorization))?;
+        let state = self.network_query::<BlockMemory<N>>(query)?;
+        info_span!("inclusion_proof").in_scope(|| trace.prepare(state))?;
+        let locator = format!("{program_id}/{function_name}");
+        let execution = info_span!("execution_proof")
+            .in_scope(|| trace.prove_execution::<A, _>(&locator, &mut rand::thread_rng()))?;
+        info_span!("verification").in_scope(|| process.verify_execution(&execution))?;
+
+        // Authorize, execute and prove the fee paying for the execution
+        let execution_id = execution.to_execution_id()?;
+        let fee_authorization = self.authorize_fee(signer, fee_record, fee, execution_id)?;
+        let (_, mut fee_trace) = info_span!("fee").in_scope(|| process.execute::<A>(fee_authorization))?;
+        let state = self.network_query::<BlockMemory<N>>(query)?;
+        info_span!("inclusion_proof").in_scope(|| fee_trace.prepare(state))?;
+        let fee = info_span!("fee_proof").in_scope(|| fee_trace.prove_fee::<A, _>(&mut rand::thread_rng()))?;
+        info_span!("verification").in_scope(|| process.verify_fee(&fee, execution_id))?;
+        drop(process);
+
+        self.store_synthesized_keys(&vm, &synthesized);
+        Transaction::from_execution(execution, Some(fee))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::circuit::AleoV0;
+    use snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_private_key_signer() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let signer = PrivateKeySigner::<AleoV0>::new(private_key);
+        let address = signer.address().unwrap();
+        assert_eq!(address.to_string(), RECIPIENT_ADDRESS);
+
+        let message = [Field::from_u64(1), Field::from_u64(2)];
+        assert!(signer.sign(&message).unwrap().verify(&address, &message));
+    }
+
+    /// A signer which only signs requests, using the default authorization
+    struct RequestSigner(PrivateKeySigner<AleoV0>);
+
+    impl Signer<Testnet3> for RequestSigner {
+        fn address(&self) -> Result<Address<Testnet3>> {
+            self.0.address()
+        }
+
+        fn sign(&self, message: &[Field<Testnet3>]) -> Result<Signature<Testnet3>> {
+            self.0.sign(message)
+        }
+
+        fn sign_request(
+            &self,
+            program_id: &ProgramID<Testnet3>,
+            function: &Identifier<Testnet3>,
+            inputs: &[Value<Testnet3>],
+            input_types: &[ValueType<Testnet3>],
+        ) -> Result<Request<Testnet3>> {
+            self.0.sign_request(program_id, function, inputs, input_types)
+        }
+    }
+
+    const CALLER_PROGRAM: &str = "import hello.aleo;
+
+program caller.aleo;
+
+function call_hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    call hello.aleo/hello r0 r1 into r2;
+    output r2 as u32.private;
+";
+
+    #[test]
+    fn test_sign_request() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let signer = PrivateKeySigner::<AleoV0>::new(private_key);
+        let program = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let function = program.get_function(&Identifier::from_str("hello").unwrap()).unwrap();
+        let inputs = [Value::from_str("5u32").unwrap(), Value::from_str("3u32").unwrap()];
+
+        let request = signer.sign_request(program.id(), function.name(), &inputs, &function.input_types()).unwrap();
+        assert_eq!(request.signer(), &signer.address().unwrap());
+        assert!(request.verify(&function.input_types()));
+    }
+
+    #[test]
+    fn test_default_authorize() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        program_manager.add_program(&Program::from_str(HELLO_PROGRAM).unwrap()).unwrap();
+        program_manager.add_program(&Program::from_str(CALLER_PROGRAM).unwrap()).unwrap();
+        let signer = RequestSigner(PrivateKeySigner::new(private_key));
+
+        // Calls are authorized by signing their request
+        let authorization =
+            program_manager.authorize(&signer, "hello.aleo", "hello", ["5u32", "3u32"].into_iter()).unwrap();
+        assert_eq!(authorization.len(), 1);
+        assert_eq!(authorization.peek_next().unwrap().function_name().to_string(), "hello");
+
+        // Nested calls need a signer which can authorize them
+        let error =
+            program_manager.authorize(&signer, "caller.aleo", "call_hello", ["5u32", "3u32"].into_iter()).unwrap_err();
+        assert_eq!(AleoSdkError::code_of(&error), "authorization");
+        let signer = PrivateKeySigner::<AleoV0>::new(private_key);
+        let authorization =
+            program_manager.authorize(&signer, "caller.aleo", "call_hello", ["5u32", "3u32"].into_iter()).unwrap();
+        assert_eq!(authorization.len(), 2);
+    }
+
+    #[test]
+    fn test_authorize_fee() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        let signer = RequestSigner(PrivateKeySigner::new(private_key));
+        let fee_record = credits_record(&address, 1_000);
+        let id = Field::from_u64(7);
+
+        let authorization = program_manager.authorize_fee(&signer, fee_record.clone(), 500, id).unwrap();
+        let request = authorization.peek_next().unwrap();
+        assert_eq!(request.program_id().to_string(), "credits.aleo");
+        assert_eq!(request.function_name().to_string(), "fee");
+        assert_eq!(request.inputs()[1].to_string(), "500u64");
+        assert_eq!(request.inputs()[2], Value::from_str(&id.to_string()).unwrap());
+        assert!(program_manager.authorize_fee(&signer, fee_record, 0, id).is_err());
+    }
+
+    #[test]
+    fn test_authorize() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        program_manager.add_program(&Program::from_str(HELLO_PROGRAM).unwrap()).unwrap();
+        let signer = PrivateKeySigner::<AleoV0>::new(private_key);
+
+        let authorization =
+            program_manager.authorize(&signer, "hello.aleo", "hello", ["5u32", "3u32"].into_iter()).unwrap();
+        let request = authorization.peek_next().unwrap();
+        assert_eq!(request.program_id().to_string(), "hello.aleo");
+        assert_eq!(request.function_name().to_string(), "hello");
+        assert_eq!(request.signer(), &signer.address().unwrap());
+
+        // Functions which do not exist cannot be authorized
+        assert!(program_manager.authorize(&signer, "hello.aleo", "goodbye", ["5u32", "3u32"].into_iter()).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Compute the amounts of the two records created by splitting a record
+///
+/// The first record holds the requested amount and the second record holds the remainder.
+pub fn split_amounts<N: Network>(record: &Record<N, Plaintext<N>>, amount: u64) -> Result<(u64, u64)> {
+    ensure!(amount > 0, AleoSdkError::invalid_input("Split amount must be greater than 0"));
+    let available = record.microcredits()?;
+    ensure!(
+        amount < available,
+        AleoSdkError::invalid_input(format!(
+            "The record holds {available} microcredits, the split amount must be less than this amount"
+        ))
+    );
+    Ok((amount, available - amount))
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Build a `credits.aleo/split` transaction dividing a record into two records
+    ///
+    /// The first new record holds the requested amount and the second holds the remainder. If no
+    /// fee record is given, one is reserved through the record resolver and held by the returned
+    /// transaction until it is broadcast. If no query url is given, the network state is queried
+    /// from the API client.
+    pub fn split(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        amount: u64,
+        fee: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        query: Option<&str>,
+    ) -> Result<ReservedTransaction<N>> {
+        split_amounts(&record, amount)?;
+        let inputs = vec![Value::Record(record), Value::from_str(&format!("{amount}u64"))?];
+        self.build_credits_execution(private_key, "split", inputs, fee, fee_record, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_split_amounts() {
+        let record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(RECORD_5_MICROCREDITS).unwrap();
+        assert_eq!(split_amounts(&record, 2).unwrap(), (2, 3));
+        assert_eq!(split_amounts(&record, 4).unwrap(), (4, 1));
+        assert!(split_amounts(&record, 0).is_err());
+        assert!(split_amounts(&record, 5).is_err());
+        assert!(split_amounts(&record, 6).is_err());
+    }
+}
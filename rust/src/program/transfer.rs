@@ -16,6 +16,30 @@
 
 use super::*;
 
+/// Format the inputs of a credits.aleo transfer
+///
+/// Private transfers spend the amount record and return the remaining microcredits to the sender
+/// as a change record, so the amount record must hold at least the amount being transferred.
+/// Returns the inputs along with the change which will be returned to the sender.
+pub fn transfer_inputs<N: Network>(
+    transfer_type: &TransferType,
+    recipient: &Address<N>,
+    amount: u64,
+    amount_record: Option<Record<N, Plaintext<N>>>,
+) -> Result<(Vec<Value<N>>, u64)> {
+    ensure!(amount > 0, AleoSdkError::invalid_input("Amount must be greater than 0"));
+    let recipient = Value::from_str(&recipient.to_string())?;
+    let amount_value = Value::from_str(&format!("{amount}u64"))?;
+    if !transfer_type.requires_record() {
+        return Ok((vec![recipient, amount_value], 0));
+    }
+    let amount_record = amount_record
+        .ok_or_else(|| AleoSdkError::invalid_input("Amount record must be specified for private transfers"))?;
+    let available = amount_record.microcredits()?;
+    ensure!(available >= amount, AleoSdkError::InsufficientFunds { required: amount, available: Some(available) });
+    Ok((vec![Value::Record(amount_record), recipient, amount_value], available - amount))
+}
+
 impl<N: Network> ProgramManager<N> {
     /// Executes a transfer to the specified recipient_address with the specified amount and fee.
     /// Specify 0 for no fee.
@@ -29,78 +53,95 @@ impl<N: Network> ProgramManager<N> {
         amount_record: Option<Record<N, Plaintext<N>>>,
         fee_record: Record<N, Plaintext<N>>,
     ) -> Result<String> {
-        ensure!(amount > 0, "Amount must be greater than 0");
-        ensure!(fee > 0, "Fee must be greater than 0");
-
-        // Specify the network state query
-        let query = Query::from(self.api_client.as_ref().unwrap().base_url());
-
         // Retrieve the private key.
         let private_key = self.get_private_key(password)?;
 
         // Generate the execution transaction
-        let execution = {
-            let rng = &mut rand::thread_rng();
-
-            // Initialize a VM
-            let store = ConsensusStore::<N, ConsensusMemory<N>>::open(None)?;
-            let vm = VM::from(store)?;
-
-            // Prepare the inputs for a transfer.
-            let (transfer_function, inputs) = match transfer_type {
-                TransferType::Public => {
-                    let inputs = vec![
-                        Value::from_str(&recipient_address.to_string())?,
-                        Value::from_str(&format!("{}u64", amount))?,
-                    ];
-                    ("transfer_public", inputs)
-                }
-                TransferType::Private => {
-                    if amount_record.is_none() {
-                        bail!("Amount record must be specified for private transfers");
-                    } else {
-                        let inputs = vec![
-                            Value::Record(amount_record.unwrap()),
-                            Value::from_str(&recipient_address.to_string())?,
-                            Value::from_str(&format!("{}u64", amount))?,
-                        ];
-                        ("transfer_private", inputs)
-                    }
-                }
-                TransferType::PublicToPrivate => {
-                    let inputs = vec![
-                        Value::from_str(&recipient_address.to_string())?,
-                        Value::from_str(&format!("{}u64", amount))?,
-                    ];
-                    ("transfer_public_to_private", inputs)
-                }
-                TransferType::PrivateToPublic => {
-                    if amount_record.is_none() {
-                        bail!("Amount record must be specified for private transfers");
-                    } else {
-                        let inputs = vec![
-                            Value::Record(amount_record.unwrap()),
-                            Value::from_str(&recipient_address.to_string())?,
-                            Value::from_str(&format!("{}u64", amount))?,
-                        ];
-                        ("transfer_private_to_public", inputs)
-                    }
-                }
-            };
-
-            // Create a new transaction.
-            vm.execute(
-                &private_key,
-                ("credits.aleo", transfer_function),
-                inputs.iter(),
-                Some((fee_record, fee)),
-                Some(query),
-                rng,
-            )?
-        };
+        let execution = self.build_transfer(
+            &private_key,
+            recipient_address,
+            amount,
+            transfer_type,
+            fee,
+            amount_record,
+            Some(fee_record),
+            None,
+        )?;
+
+        self.broadcast_reserved(execution)
+    }
 
-        self.broadcast_transaction(execution)
+    /// Build a credits.aleo transfer transaction which is ready to be broadcast
+    ///
+    /// Private transfers and private to public transfers spend the amount record, and any
+    /// microcredits left in it are returned to the sender as a change record. Public transfers
+    /// and public to private transfers are paid from the sender's public balance and do not need
+    /// an amount record. If no amount record (for private transfers) or fee record is given, one is
+    /// reserved through the record resolver and held by the returned transaction until it is
+    /// broadcast with `broadcast_reserved`. If no query url is given, the network state is queried
+    /// from the API client. The change returned to the sender is reported by
+    /// `ReservedTransaction::change_microcredits`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_transfer(
+        &self,
+        private_key: &PrivateKey<N>,
+        recipient_address: Address<N>,
+        amount: u64,
+        transfer_type: TransferType,
+        fee: u64,
+        amount_record: Option<Record<N, Plaintext<N>>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        query: Option<&str>,
+    ) -> Result<ReservedTransaction<N>> {
+        let records =
+            self.resolve_transfer_records(private_key, &transfer_type, amount, fee, amount_record, fee_record)?;
+        let (inputs, change) = transfer_inputs(&transfer_type, &recipient_address, amount, records.amount_record)?;
+        let transaction = self.build_credits_execution(
+            private_key,
+            transfer_type.function_name(),
+            inputs,
+            fee,
+            Some(records.fee_record),
+            query,
+        )?;
+        Ok(transaction.with_reservations(records.reservations).with_change(change))
     }
+
+    /// Use the amount and fee records given by the caller or reserve them through the record
+    /// resolver
+    fn resolve_transfer_records(
+        &self,
+        private_key: &PrivateKey<N>,
+        transfer_type: &TransferType,
+        amount: u64,
+        fee: u64,
+        amount_record: Option<Record<N, Plaintext<N>>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+    ) -> Result<TransferRecords<N>> {
+        let mut reservations = Vec::new();
+        let amount_record = match amount_record {
+            None if transfer_type.requires_record() && self.record_resolver().is_some() => {
+                let records = self.find_unspent_credits(private_key, amount)?;
+                let reservation = self
+                    .record_reservation
+                    .reserve_selected(records, |candidates| single_record(candidates, amount))?;
+                let amount_record = reservation[0].clone();
+                reservations.push(reservation);
+                Some(amount_record)
+            }
+            amount_record => amount_record,
+        };
+        let (fee_record, reservation) = self.resolve_fee_record(private_key, fee, fee_record)?;
+        reservations.extend(reservation.map(ReservedFeeRecord::into_reservation));
+        Ok(TransferRecords { amount_record, fee_record, reservations })
+    }
+}
+
+/// The records spent by a transfer and the reservations of those found through the record resolver
+struct TransferRecords<N: Network> {
+    amount_record: Option<Record<N, Plaintext<N>>>,
+    fee_record: Record<N, Plaintext<N>>,
+    reservations: Vec<ReservedRecords<N>>,
 }
 
 #[cfg(test)]
@@ -111,6 +152,76 @@ mod tests {
 
     use std::{str::FromStr, thread};
 
+    #[test]
+    fn test_transfer_inputs() {
+        let recipient = Address::<Testnet3>::from_str(RECIPIENT_ADDRESS).unwrap();
+        let record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(RECORD_2000000001_MICROCREDITS).unwrap();
+
+        // Public transfers only take the recipient and amount
+        for transfer_type in [TransferType::Public, TransferType::PublicToPrivate] {
+            let (inputs, change) = transfer_inputs(&transfer_type, &recipient, 100, None).unwrap();
+            assert_eq!(inputs.len(), 2);
+            assert_eq!(inputs[1].to_string(), "100u64");
+            assert_eq!(change, 0);
+        }
+
+        // Private transfers spend the amount record and return the change
+        for transfer_type in [TransferType::Private, TransferType::PrivateToPublic] {
+            let error = transfer_inputs(&transfer_type, &recipient, 100, None).unwrap_err();
+            assert_eq!(AleoSdkError::code_of(&error), "invalid_input");
+            let (inputs, change) = transfer_inputs(&transfer_type, &recipient, 1, Some(record.clone())).unwrap();
+            assert_eq!(inputs.len(), 3);
+            assert_eq!(inputs[0], Value::Record(record.clone()));
+            assert_eq!(change, 2_000_000_000);
+            assert!(transfer_inputs(&transfer_type, &recipient, 2_000_000_002, Some(record.clone())).is_err());
+        }
+
+        assert!(transfer_inputs(&TransferType::Public, &recipient, 0, None).is_err());
+        assert_eq!(TransferType::PrivateToPublic.function_name(), "transfer_private_to_public");
+    }
+
+    #[test]
+    fn test_resolve_transfer_records() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let records = [100, 200, 300, 400, 500].map(|microcredits| credits_record(&address, microcredits)).to_vec();
+        let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        program_manager.set_record_resolver(MemoryResolver(records));
+
+        // Back to back transfers spend different records while the first is held
+        let first = program_manager
+            .resolve_transfer_records(&private_key, &TransferType::Private, 150, 50, None, None)
+            .unwrap();
+        let second = program_manager
+            .resolve_transfer_records(&private_key, &TransferType::Private, 150, 50, None, None)
+            .unwrap();
+        let microcredits = |records: &TransferRecords<Testnet3>| {
+            (
+                records.amount_record.as_ref().unwrap().microcredits().unwrap(),
+                records.fee_record.microcredits().unwrap(),
+            )
+        };
+        assert_eq!(microcredits(&first), (200, 100));
+        assert_eq!(microcredits(&second), (300, 400));
+        assert_eq!(program_manager.record_reservation().len(), 4);
+
+        // Public transfers only reserve a fee record
+        let public =
+            program_manager.resolve_transfer_records(&private_key, &TransferType::Public, 150, 50, None, None).unwrap();
+        assert!(public.amount_record.is_none());
+        assert_eq!(public.fee_record.microcredits().unwrap(), 500);
+        assert!(program_manager
+            .resolve_transfer_records(&private_key, &TransferType::Public, 1, 1, None, None)
+            .is_err());
+
+        // Dropping the records of a transfer releases them for the next one
+        drop(first);
+        let third = program_manager
+            .resolve_transfer_records(&private_key, &TransferType::Private, 150, 50, None, None)
+            .unwrap();
+        assert_eq!(microcredits(&third), (200, 100));
+    }
+
     #[test]
     #[ignore]
     fn test_private_transfer() {
@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::fmt;
+
+/// A kind of item declared in a program
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProgramItem {
+    /// An imported program
+    Import,
+    /// A mapping of public state
+    Mapping,
+    /// A struct type
+    Struct,
+    /// A record type
+    Record,
+    /// A closure, which can only be called by functions
+    Closure,
+    /// A function
+    Function,
+}
+
+impl fmt::Display for ProgramItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ProgramItem::Import => "import",
+            ProgramItem::Mapping => "mapping",
+            ProgramItem::Struct => "struct",
+            ProgramItem::Record => "record",
+            ProgramItem::Closure => "closure",
+            ProgramItem::Function => "function",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// How an item of a local program differs from the deployed program
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DifferenceKind {
+    /// The item is declared in both programs with different bytecode
+    Mismatched,
+    /// The item is only declared in the local program
+    MissingOnChain,
+    /// The item is only declared in the deployed program
+    MissingLocally,
+}
+
+/// A difference between an item of a local program and the deployed program
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemDifference {
+    /// The kind of the item
+    pub item: ProgramItem,
+    /// The name of the item
+    pub name: String,
+    /// How the item differs
+    pub kind: DifferenceKind,
+}
+
+impl fmt::Display for ItemDifference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            DifferenceKind::Mismatched => write!(f, "~ {} {} (bytecode differs)", self.item, self.name),
+            DifferenceKind::MissingOnChain => write!(f, "+ {} {} (only in the local source)", self.item, self.name),
+            DifferenceKind::MissingLocally => write!(f, "- {} {} (only on chain)", self.item, self.name),
+        }
+    }
+}
+
+/// The result of comparing the source code of a program with its deployed bytecode
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramVerification<N: Network> {
+    /// The id of the program
+    pub program_id: ProgramID<N>,
+    /// The items which differ between the local and deployed program, in declaration order
+    pub differences: Vec<ItemDifference>,
+}
+
+impl<N: Network> ProgramVerification<N> {
+    /// Compare a local program with the deployed program of the same id
+    ///
+    /// Both programs are compared item by item using their canonical bytecode, so differences
+    /// in formatting and comments of the local source are ignored.
+    pub fn compare(local: &Program<N>, on_chain: &Program<N>) -> Self {
+        let mut differences = vec![];
+        compare_items(&mut differences, ProgramItem::Import, local.imports(), on_chain.imports());
+        compare_items(&mut differences, ProgramItem::Mapping, local.mappings(), on_chain.mappings());
+        compare_items(&mut differences, ProgramItem::Struct, local.structs(), on_chain.structs());
+        compare_items(&mut differences, ProgramItem::Record, local.records(), on_chain.records());
+        compare_items(&mut differences, ProgramItem::Closure, local.closures(), on_chain.closures());
+        compare_items(&mut differences, ProgramItem::Function, local.functions(), on_chain.functions());
+        Self { program_id: *local.id(), differences }
+    }
+
+    /// Determine whether the local source matches the deployed bytecode
+    pub fn is_verified(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Get the names of the functions whose bytecode differs or which are only declared in one program
+    pub fn mismatched_functions(&self) -> Vec<&str> {
+        self.differences
+            .iter()
+            .filter(|difference| difference.item == ProgramItem::Function)
+            .map(|difference| difference.name.as_str())
+            .collect()
+    }
+}
+
+impl<N: Network> fmt::Display for ProgramVerification<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_verified() {
+            return write!(f, "{} matches the deployed bytecode", self.program_id);
+        }
+        write!(f, "{} does not match the deployed bytecode", self.program_id)?;
+        self.differences.iter().try_for_each(|difference| write!(f, "\n{difference}"))
+    }
+}
+
+/// Record the differences between two sets of program items
+fn compare_items<K: fmt::Display + Eq + std::hash::Hash, V: fmt::Display>(
+    differences: &mut Vec<ItemDifference>,
+    item: ProgramItem,
+    local: &IndexMap<K, V>,
+    on_chain: &IndexMap<K, V>,
+) {
+    for (name, local_item) in local {
+        let kind = match on_chain.get(name) {
+            Some(on_chain_item) if on_chain_item.to_string() == local_item.to_string() => continue,
+            Some(_) => DifferenceKind::Mismatched,
+            None => DifferenceKind::MissingOnChain,
+        };
+        differences.push(ItemDifference { item, name: name.to_string(), kind });
+    }
+    for name in on_chain.keys().filter(|name| !local.contains_key(*name)) {
+        differences.push(ItemDifference { item, name: name.to_string(), kind: DifferenceKind::MissingLocally });
+    }
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Compare the source code of a program with the bytecode deployed on chain
+    ///
+    /// The returned report lists every import, mapping, struct, record, closure and function
+    /// which differs, allowing "source verified" badges to be shown for deployed programs.
+    pub fn verify_program(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        local_source: &str,
+    ) -> Result<ProgramVerification<N>> {
+        let program_id = program_id.try_into().map_err(|_| AleoSdkError::invalid_input("Invalid program ID"))?;
+        let local = Program::<N>::from_str(local_source)?;
+        ensure!(
+            local.id() == &program_id,
+            AleoSdkError::invalid_input(format!("The local source declares {} instead of {program_id}", local.id()))
+        );
+        let on_chain = self
+            .api_client()?
+            .find_program(program_id)?
+            .ok_or_else(|| AleoSdkError::resolution(format!("Program {program_id} is not deployed on chain")))?;
+        Ok(ProgramVerification::compare(&local, &on_chain))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_compare_programs() {
+        let hello = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let verification = ProgramVerification::compare(&hello, &hello);
+        assert!(verification.is_verified());
+        assert_eq!(verification.to_string(), "hello.aleo matches the deployed bytecode");
+
+        // Formatting and comments are ignored
+        let reformatted = Program::<Testnet3>::from_str(&format!("// hello\n{HELLO_PROGRAM}\n\n")).unwrap();
+        assert!(ProgramVerification::compare(&reformatted, &hello).is_verified());
+
+        // Functions with different bytecode are reported
+        let hello_2 = Program::<Testnet3>::from_str(HELLO_PROGRAM_2).unwrap();
+        let verification = ProgramVerification::compare(&hello_2, &hello);
+        assert!(!verification.is_verified());
+        assert_eq!(verification.mismatched_functions(), vec!["hello"]);
+        assert_eq!(verification.differences[0].kind, DifferenceKind::Mismatched);
+
+        // Functions declared in only one of the programs are reported
+        let extended = Program::<Testnet3>::from_str(&format!("{HELLO_PROGRAM}{GENERIC_PROGRAM_BODY}")).unwrap();
+        let verification = ProgramVerification::compare(&extended, &hello);
+        assert_eq!(verification.differences, vec![ItemDifference {
+            item: ProgramItem::Function,
+            name: "fabulous".to_string(),
+            kind: DifferenceKind::MissingOnChain
+        }]);
+        let verification = ProgramVerification::compare(&hello, &extended);
+        assert_eq!(verification.differences[0].kind, DifferenceKind::MissingLocally);
+        assert!(verification.to_string().ends_with("- function fabulous (only on chain)"));
+    }
+
+    #[test]
+    fn test_verify_program() {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rand::thread_rng()).unwrap();
+        let api_client = AleoAPIClient::<Testnet3>::testnet3();
+        let program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, Some(api_client), None).unwrap();
+        let credits = Program::<Testnet3>::credits().unwrap().to_string();
+        assert!(program_manager.verify_program("credits.aleo", &credits).unwrap().is_verified());
+        assert!(program_manager.verify_program("hello.aleo", &credits).is_err());
+    }
+}
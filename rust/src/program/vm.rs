@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm::{circuit::AleoV0, synthesizer::Process};
+use snarkvm_console::network::Testnet3;
+
+use std::any::Any;
+
+/// Open a VM backed by an in-memory store, which is discarded when the VM is dropped
+pub fn open_memory_vm<N: Network>() -> Result<VM<N, ConsensusMemory<N>>> {
+    let store = ConsensusStore::<N, ConsensusMemory<N>>::open(None)?;
+    VM::from(store)
+}
+
+/// Open a VM backed by a persistent RocksDB store
+///
+/// Programs added to the VM are kept in the store, so a long running service can open the VM
+/// once and reuse it for every transaction it builds instead of initializing a new VM each time.
+/// The `dev` id selects a separate store for development networks, as in snarkOS.
+#[cfg(feature = "rocks")]
+pub fn open_persistent_vm<N: Network>(dev: Option<u16>) -> Result<VM<N, ConsensusDB<N>>> {
+    let store = ConsensusStore::<N, ConsensusDB<N>>::open(dev)?;
+    VM::from(store)
+}
+
+/// Add programs to a VM, skipping the programs it already holds
+///
+/// The programs must be ordered so every program comes after the programs it imports.
+pub fn add_programs_to_vm<N: Network, C: ConsensusStorage<N>>(vm: &VM<N, C>, programs: &[Program<N>]) -> Result<()> {
+    let mut process = vm.process().write();
+    for program in programs {
+        if !process.contains_program(program.id()) {
+            process.add_program(program)?;
+        }
+    }
+    Ok(())
+}
+
+impl<N: Network> ProgramManager<N> {
+    /// Set the key provider asked for the proving and verifying keys of the functions executed
+    /// by the program manager. Keys the provider does not have are synthesized and offered back
+    /// to it, so they are only synthesized once.
+    pub fn set_key_provider(&mut self, key_provider: impl KeyProvider<N> + 'static) {
+        self.key_provider = Some(Arc::new(key_provider));
+    }
+
+    /// Get the key provider of the program manager if one is set
+    pub fn key_provider(&self) -> Option<&dyn KeyProvider<N>> {
+        self.key_provider.as_deref()
+    }
+
+    /// Insert the keys the key provider holds for program functions into a VM, returning the
+    /// functions whose keys must still be synthesized
+    pub(crate) fn load_provided_keys<C: ConsensusStorage<N>>(
+        &self,
+        vm: &VM<N, C>,
+        functions: &[(ProgramID<N>, Identifier<N>)],
+    ) -> Result<Vec<(ProgramID<N>, Identifier<N>)>> {
+        let mut missing = vec![];
+        let mut process = vm.process().write();
+        for (program_id, function) in functions {
+            let _span = info_span!("load_keys", program = %program_id, function = %function).entered();
+            if process.get_stack(program_id)?.contains_proving_key(function) {
+                continue;
+            }
+            let program = process.get_program(program_id)?.clone();
+            let imports = imported_programs(&process, &program)?;
+            match self
+                .key_provider
+                .as_ref()
+                .map(|provider| provider.get_keys(&program, &imports, function))
+                .transpose()?
+            {
+                Some(Some((proving_key, verifying_key))) => {
+                    process.insert_proving_key(program_id, function, proving_key)?;
+                    process.insert_verifying_key(program_id, function, verifying_key)?;
+                }
+                _ => missing.push((*program_id, *function)),
+            }
+        }
+        debug!(missing = missing.len(), "Keys which must be synthesized");
+        Ok(missing)
+    }
+
+    /// Offer the keys a VM synthesized for program functions to the key provider
+    ///
+    /// The keys are a cache, so failing to store them does not fail the transaction they were
+    /// synthesized for.
+    pub(crate) fn store_synthesized_keys<C: ConsensusStorage<N>>(
+        &self,
+        vm: &VM<N, C>,
+        functions: &[(ProgramID<N>, Identifier<N>)],
+    ) {
+        let Some(provider) = self.key_provider.as_ref() else { return };
+        let process = vm.process().read();
+        for (program_id, function) in functions {
+            let _span = info_span!("store_keys", program = %program_id, function = %function).entered();
+            if let (Ok(program), Ok(proving_key), Ok(verifying_key)) = (
+                process.get_program(program_id),
+                process.get_proving_key(program_id, function),
+                process.get_verifying_key(program_id, function),
+            ) {
+                let Ok(imports) = imported_programs(&process, program) else { continue };
+                let _ = provider.store_keys(program, &imports, function, &proving_key, &verifying_key);
+            }
+        }
+    }
+}
+
+/// Synthesize the proving and verifying keys of a program function in a VM
+///
+/// The circuit environment of the network is selected from its type, as the VM does itself
+/// when it executes a function.
+pub(crate) fn synthesize_key<N: Network, C: ConsensusStorage<N>>(
+    vm: &VM<N, C>,
+    program_id: &ProgramID<N>,
+    function: &Identifier<N>,
+) -> Result<()> {
+    let mut process = vm.process().write();
+    let process: &mut dyn Any = &mut *process;
+    let (program_id, function): (&dyn Any, &dyn Any) = (program_id, function);
+    match (process.downcast_mut::<Process<Testnet3>>(), program_id.downcast_ref(), function.downcast_ref()) {
+        (Some(process), Some(program_id), Some(function)) => {
+            process.synthesize_key::<AleoV0, _>(program_id, function, &mut rand::thread_rng())
+        }
+        _ => bail!("Keys cannot be synthesized for network {}", N::ID),
+    }
+}
+
+/// Get the program and function identifiers of the credits.aleo `fee` function
+pub(crate) fn fee_function<N: Network>() -> Result<(ProgramID<N>, Identifier<N>)> {
+    Ok((ProgramID::from_str("credits.aleo")?, Identifier::from_str("fee")?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_add_programs_to_vm() {
+        let vm = open_memory_vm::<Testnet3>().unwrap();
+        let hello = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let credits = Program::<Testnet3>::credits().unwrap();
+
+        // Programs already held by the VM are skipped, so the VM can be reused across builds
+        add_programs_to_vm(&vm, &[credits, hello.clone()]).unwrap();
+        add_programs_to_vm(&vm, &[hello.clone()]).unwrap();
+        assert!(vm.process().read().contains_program(hello.id()));
+    }
+
+    #[test]
+    fn test_load_provided_keys() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let mut program_manager = ProgramManager::<Testnet3>::new(Some(private_key), None, None, None).unwrap();
+        let hello = Program::<Testnet3>::from_str(HELLO_PROGRAM).unwrap();
+        let function = Identifier::from_str("hello").unwrap();
+        let functions = [(*hello.id(), function)];
+
+        // Without a key provider the keys of the function must be synthesized
+        let vm = open_memory_vm::<Testnet3>().unwrap();
+        add_programs_to_vm(&vm, &[hello.clone()]).unwrap();
+        assert_eq!(program_manager.load_provided_keys(&vm, &functions).unwrap(), functions.to_vec());
+
+        // Keys synthesized by one VM are stored in the provider and inserted into the next VM
+        let provider = MemoryKeyProvider::new();
+        program_manager.set_key_provider(provider.clone());
+        synthesize_key(&vm, hello.id(), &function).unwrap();
+        program_manager.store_synthesized_keys(&vm, &functions);
+        assert!(provider.contains(hello.id(), &function));
+
+        let vm = open_memory_vm::<Testnet3>().unwrap();
+        add_programs_to_vm(&vm, &[hello.clone()]).unwrap();
+        assert!(program_manager.load_provided_keys(&vm, &functions).unwrap().is_empty());
+        assert!(vm.process().read().get_stack(hello.id()).unwrap().contains_proving_key(&function));
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+/// Progress of a record scan which can be persisted and resumed
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanCheckpoint {
+    /// The height of the next block to scan
+    pub next_height: u32,
+    /// The commitments of the records discovered so far
+    pub commitments: Vec<String>,
+}
+
+/// Storage for the checkpoint of a record scan
+pub trait CheckpointStore {
+    /// Load the last saved checkpoint, if one exists
+    fn load(&self) -> Result<Option<ScanCheckpoint>>;
+
+    /// Save a checkpoint, replacing the previous one
+    fn save(&self, checkpoint: &ScanCheckpoint) -> Result<()>;
+}
+
+/// Checkpoint store which keeps the checkpoint in a JSON file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonCheckpointStore {
+    path: PathBuf,
+}
+
+impl JsonCheckpointStore {
+    /// Create a checkpoint store backed by a JSON file, which is created on the first save
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Get the path of the checkpoint file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl CheckpointStore for JsonCheckpointStore {
+    fn load(&self) -> Result<Option<ScanCheckpoint>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        let json = std::fs::read_to_string(&self.path)?;
+        Ok(Some(serde_json::from_str(&json)?))
+    }
+
+    fn save(&self, checkpoint: &ScanCheckpoint) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file first so a crash never leaves a truncated checkpoint behind
+        let temporary_path = self.path.with_extension("tmp");
+        std::fs::write(&temporary_path, serde_json::to_string(checkpoint)?)?;
+        std::fs::rename(temporary_path, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_checkpoint_store() {
+        let directory = std::env::temp_dir().join("aleo_test_json_checkpoint_store");
+        let _ = std::fs::remove_dir_all(&directory);
+        let store = JsonCheckpointStore::new(directory.join("checkpoint.json"));
+        assert_eq!(store.load().unwrap(), None);
+
+        let checkpoint = ScanCheckpoint { next_height: 150, commitments: vec!["1field".to_string()] };
+        store.save(&checkpoint).unwrap();
+        assert_eq!(store.load().unwrap(), Some(checkpoint.clone()));
+
+        let checkpoint = ScanCheckpoint { next_height: 200, ..checkpoint };
+        store.save(&checkpoint).unwrap();
+        assert_eq!(store.load().unwrap(), Some(checkpoint));
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
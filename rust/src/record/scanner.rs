@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::api::block_batches;
+
+use rayon::prelude::*;
+
+/// The number of blocks scanned between two checkpoints of a resumable scan
+const BLOCKS_PER_CHECKPOINT: u32 = 500;
+
+/// A record found by a `RecordScanner`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedRecord<N: Network> {
+    /// The height of the block containing the record
+    pub block_height: u32,
+    /// The program which created the record
+    pub program_id: ProgramID<N>,
+    /// The commitment of the record
+    pub commitment: Field<N>,
+    /// The decrypted record
+    pub record: Record<N, Plaintext<N>>,
+}
+
+/// Scanner which discovers the records owned by a view key in the blocks of an Aleo node
+///
+/// Blocks are fetched with `AleoAPIClient::get_blocks_in_range`, which bounds the number of
+/// concurrent requests, and the fetched blocks are scanned in parallel.
+#[derive(Clone, Debug)]
+pub struct RecordScanner<N: Network> {
+    api_client: AleoAPIClient<N>,
+    view_key: ViewKey<N>,
+}
+
+impl<N: Network> RecordScanner<N> {
+    /// Create a scanner for the records of a view key
+    pub fn new(api_client: AleoAPIClient<N>, view_key: ViewKey<N>) -> Self {
+        Self { api_client, view_key }
+    }
+
+    /// Get the API client used by the scanner
+    pub fn api_client(&self) -> &AleoAPIClient<N> {
+        &self.api_client
+    }
+
+    /// Find the records owned by the view key in a range of block heights, ordered by height
+    pub fn scan(&self, block_heights: Range<u32>) -> Result<Vec<ScannedRecord<N>>> {
+        ensure!(
+            block_heights.start < block_heights.end,
+            "The start block height must be less than the end block height"
+        );
+        let blocks = self.api_client.get_blocks_in_range(block_heights.start, block_heights.end)?;
+        // Blocks are collected in order, so the records are ordered by block height
+        Ok(blocks.par_iter().flat_map_iter(|block| self.scan_block(block)).collect())
+    }
+
+    /// Find the records owned by the view key from a block height up to the latest block
+    pub fn scan_from(&self, start_height: u32) -> Result<Vec<ScannedRecord<N>>> {
+        let latest_height = self.api_client.latest_height()?;
+        if start_height > latest_height {
+            return Ok(vec![]);
+        }
+        self.scan(start_height..latest_height + 1)
+    }
+
+    /// Find the records owned by the view key, resuming from the checkpoint of a previous scan
+    ///
+    /// Scanning starts at the block after the last checkpoint (or at `start_height` if there is
+    /// none) and continues up to the end height, or the latest block if no end height is given.
+    /// The records found in every 500 blocks are passed to `accept`, and a checkpoint is only
+    /// saved once `accept` returns successfully. If `accept` fails the scan stops with its error,
+    /// and the next scan passes the same records again, so records are never lost by a crash
+    /// between scanning and storing them. Records already accepted are not passed twice, the
+    /// commitments of all accepted records are kept in the checkpoint. Returns the height of the
+    /// next block to scan.
+    pub fn scan_resumable(
+        &self,
+        store: &impl CheckpointStore,
+        start_height: u32,
+        end_height: Option<u32>,
+        accept: impl FnMut(Vec<ScannedRecord<N>>) -> Result<()>,
+    ) -> Result<u32> {
+        let end_height = match end_height {
+            Some(end_height) => end_height,
+            None => self.api_client.latest_height()?.saturating_add(1),
+        };
+        scan_with_checkpoints(store, start_height..end_height, |range| self.scan(range), accept)
+    }
+
+    /// Find the records owned by the view key in a range of block heights from a tokio runtime
+    ///
+    /// The scan runs on the blocking thread pool of the runtime, see `scan`.
+    #[cfg(feature = "async")]
+    pub async fn scan_async(&self, block_heights: Range<u32>) -> Result<Vec<ScannedRecord<N>>> {
+        let scanner = self.clone();
+        crate::api::spawn_request(move || scanner.scan(block_heights)).await
+    }
+
+    /// Find the records owned by the view key from a block height up to the latest block from a
+    /// tokio runtime
+    #[cfg(feature = "async")]
+    pub async fn scan_from_async(&self, start_height: u32) -> Result<Vec<ScannedRecord<N>>> {
+        let scanner = self.clone();
+        crate::api::spawn_request(move || scanner.scan_from(start_height)).await
+    }
+
+    /// Find the records owned by the view key in a block
+    pub fn scan_block(&self, block: &Block<N>) -> Vec<ScannedRecord<N>> {
+        let address_x_coordinate = self.view_key.to_address().to_x_coordinate();
+        block
+            .transitions()
+            .flat_map(|transition| {
+                transition.records().filter_map(move |(commitment, record)| {
+                    if !record.is_owner_with_address_x_coordinate(&self.view_key, &address_x_coordinate) {
+                        return None;
+                    }
+                    let record = record.decrypt(&self.view_key).ok()?;
+                    Some(ScannedRecord {
+                        block_height: block.height(),
+                        program_id: *transition.program_id(),
+                        commitment: *commitment,
+                        record,
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+/// Scan a range of blocks in checkpointed batches, saving each checkpoint only after the records
+/// of its batch have been accepted
+fn scan_with_checkpoints<N: Network>(
+    store: &impl CheckpointStore,
+    block_heights: Range<u32>,
+    scan: impl Fn(Range<u32>) -> Result<Vec<ScannedRecord<N>>>,
+    mut accept: impl FnMut(Vec<ScannedRecord<N>>) -> Result<()>,
+) -> Result<u32> {
+    let mut checkpoint =
+        store.load()?.unwrap_or_else(|| ScanCheckpoint { next_height: block_heights.start, ..Default::default() });
+    for range in block_batches(checkpoint.next_height..block_heights.end, BLOCKS_PER_CHECKPOINT) {
+        let next_height = range.end;
+        let records = scan(range)?
+            .into_iter()
+            .filter(|record| !checkpoint.commitments.contains(&record.commitment.to_string()))
+            .collect::<Vec<_>>();
+        let commitments = records.iter().map(|record| record.commitment.to_string()).collect::<Vec<_>>();
+        accept(records)?;
+        checkpoint.commitments.extend(commitments);
+        checkpoint.next_height = next_height;
+        store.save(&checkpoint)?;
+    }
+    Ok(checkpoint.next_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_scan_with_checkpoints_resumes_after_interruption() {
+        let directory = std::env::temp_dir().join("aleo_test_scan_with_checkpoints");
+        let _ = std::fs::remove_dir_all(&directory);
+        let store = JsonCheckpointStore::new(directory.join("checkpoint.json"));
+
+        // Every batch of blocks holds one record, identified by the height the batch starts at
+        let record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(RECORD_5_MICROCREDITS).unwrap();
+        let scan = |range: Range<u32>| -> Result<Vec<ScannedRecord<Testnet3>>> {
+            Ok(vec![ScannedRecord {
+                block_height: range.start,
+                program_id: ProgramID::from_str("credits.aleo")?,
+                commitment: Field::from_u64(u64::from(range.start)),
+                record: record.clone(),
+            }])
+        };
+
+        // The scan is interrupted while the records of the second batch are being stored
+        let mut accepted = vec![];
+        let result = scan_with_checkpoints(&store, 0..1200, scan, |records| {
+            ensure!(accepted.is_empty(), "Interrupted");
+            accepted.extend(records.into_iter().map(|record| record.block_height));
+            Ok(())
+        });
+        assert!(result.is_err());
+        assert_eq!(accepted, vec![0]);
+        assert_eq!(store.load().unwrap().unwrap().next_height, 500);
+
+        // Resuming passes the records of the interrupted batch again, without repeating the first
+        let next_height = scan_with_checkpoints(&store, 0..1200, scan, |records| {
+            accepted.extend(records.into_iter().map(|record| record.block_height));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(next_height, 1200);
+        assert_eq!(accepted, vec![0, 500, 1000]);
+        assert_eq!(store.load().unwrap().unwrap().commitments.len(), 3);
+
+        // A completed scan has nothing left to accept
+        assert_eq!(scan_with_checkpoints(&store, 0..1200, scan, |_| bail!("Nothing to accept")).unwrap(), 1200);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Strategy used by a `RecordSelector` to choose the records which pay for an amount
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Spend the largest records first, which minimizes the number of inputs
+    #[default]
+    LargestFirst,
+    /// Spend the smallest single record covering the amount, which keeps large records intact.
+    /// If no single record covers the amount the largest records are spent first.
+    SmallestViable,
+    /// Spend a single record holding exactly the amount, which avoids creating change.
+    /// If no record matches exactly the smallest viable selection is used instead.
+    ExactMatch,
+}
+
+/// Records chosen by a `RecordSelector` and the change left over after paying the target amount
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordSelection<N: Network> {
+    /// The records selected as inputs
+    pub records: Vec<Record<N, Plaintext<N>>>,
+    /// The total microcredits held by the selected records
+    pub total_microcredits: u64,
+    /// The microcredits which must be returned to the owner as change
+    pub change_microcredits: u64,
+}
+
+/// Coin selection for credits records
+///
+/// Given a target amount in microcredits and a set of owned records, the selector picks the
+/// records to spend according to its strategy and reports the change. Records which do not hold
+/// microcredits are ignored.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecordSelector {
+    strategy: SelectionStrategy,
+}
+
+impl RecordSelector {
+    /// Create a record selector using a selection strategy
+    pub fn new(strategy: SelectionStrategy) -> Self {
+        Self { strategy }
+    }
+
+    /// Get the selection strategy
+    pub fn strategy(&self) -> SelectionStrategy {
+        self.strategy
+    }
+
+    /// Select records holding at least the target amount of microcredits
+    pub fn select<N: Network>(
+        &self,
+        records: Vec<Record<N, Plaintext<N>>>,
+        target_microcredits: u64,
+    ) -> Result<RecordSelection<N>> {
+        ensure!(target_microcredits > 0, "The target amount must be greater than zero");
+        let mut records = records
+            .into_iter()
+            .filter_map(|record| record.microcredits().ok().map(|microcredits| (microcredits, record)))
+            .collect::<Vec<_>>();
+        let available = records.iter().fold(0u64, |total, (microcredits, _)| total.saturating_add(*microcredits));
+        ensure!(available >= target_microcredits, AleoSdkError::InsufficientFunds {
+            required: target_microcredits,
+            available: Some(available)
+        });
+
+        // Sort the records from largest to smallest
+        records.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        let single = match self.strategy {
+            SelectionStrategy::LargestFirst => None,
+            SelectionStrategy::SmallestViable => {
+                records.iter().rposition(|(microcredits, _)| *microcredits >= target_microcredits)
+            }
+            SelectionStrategy::ExactMatch => records
+                .iter()
+                .position(|(microcredits, _)| *microcredits == target_microcredits)
+                .or_else(|| records.iter().rposition(|(microcredits, _)| *microcredits >= target_microcredits)),
+        };
+        let selected = match single {
+            Some(index) => vec![records.swap_remove(index)],
+            None => {
+                let mut total = 0u64;
+                records
+                    .into_iter()
+                    .take_while(|(microcredits, _)| {
+                        let needed = total < target_microcredits;
+                        total = total.saturating_add(*microcredits);
+                        needed
+                    })
+                    .collect()
+            }
+        };
+
+        let total_microcredits = selected.iter().map(|(microcredits, _)| *microcredits).sum::<u64>();
+        Ok(RecordSelection {
+            records: selected.into_iter().map(|(_, record)| record).collect(),
+            total_microcredits,
+            change_microcredits: total_microcredits - target_microcredits,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "full")]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    fn records() -> Vec<Record<Testnet3, Plaintext<Testnet3>>> {
+        let address = Address::from_str(RECIPIENT_ADDRESS).unwrap();
+        [100, 500, 250, 1000].into_iter().map(|microcredits| credits_record(&address, microcredits)).collect()
+    }
+
+    fn amounts(selection: &RecordSelection<Testnet3>) -> Vec<u64> {
+        selection.records.iter().map(|record| record.microcredits().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_largest_first() {
+        let selector = RecordSelector::default();
+        let selection = selector.select(records(), 1200).unwrap();
+        assert_eq!(amounts(&selection), vec![1000, 500]);
+        assert_eq!(selection.total_microcredits, 1500);
+        assert_eq!(selection.change_microcredits, 300);
+
+        let selection = selector.select(records(), 200).unwrap();
+        assert_eq!(amounts(&selection), vec![1000]);
+        assert_eq!(selection.change_microcredits, 800);
+    }
+
+    #[test]
+    fn test_smallest_viable() {
+        let selector = RecordSelector::new(SelectionStrategy::SmallestViable);
+        let selection = selector.select(records(), 200).unwrap();
+        assert_eq!(amounts(&selection), vec![250]);
+        assert_eq!(selection.change_microcredits, 50);
+
+        // No single record covers the amount so the largest records are spent
+        let selection = selector.select(records(), 1600).unwrap();
+        assert_eq!(amounts(&selection), vec![1000, 500, 250]);
+        assert_eq!(selection.change_microcredits, 150);
+    }
+
+    #[test]
+    fn test_exact_match() {
+        let selector = RecordSelector::new(SelectionStrategy::ExactMatch);
+        let selection = selector.select(records(), 500).unwrap();
+        assert_eq!(amounts(&selection), vec![500]);
+        assert_eq!(selection.change_microcredits, 0);
+
+        let selection = selector.select(records(), 400).unwrap();
+        assert_eq!(amounts(&selection), vec![500]);
+        assert_eq!(selection.change_microcredits, 100);
+    }
+
+    #[test]
+    fn test_insufficient_funds() {
+        let selector = RecordSelector::default();
+        assert!(selector.select(records(), 1851).is_err());
+        assert!(selector.select(records(), 0).is_err());
+        assert!(selector.select(vec![], 1).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::collections::HashSet;
+
+/// Tracks the serial numbers of spent records so wallets can exclude spent records from balances
+///
+/// Serial numbers are revealed as transition inputs when a record is spent. They can be collected
+/// from scanned blocks or queried from a node, and then matched against the records owned by an
+/// account without revealing which records the account owns.
+#[derive(Clone, Debug)]
+pub struct SpentRecords<N: Network> {
+    serial_numbers: HashSet<Field<N>>,
+}
+
+impl<N: Network> SpentRecords<N> {
+    /// Create a new empty set of spent serial numbers
+    pub fn new() -> Self {
+        Self { serial_numbers: HashSet::new() }
+    }
+
+    /// Add the serial number of a spent record
+    pub fn insert(&mut self, serial_number: Field<N>) -> bool {
+        self.serial_numbers.insert(serial_number)
+    }
+
+    /// Get the number of spent serial numbers tracked
+    pub fn len(&self) -> usize {
+        self.serial_numbers.len()
+    }
+
+    /// Determine whether any spent serial numbers are tracked
+    pub fn is_empty(&self) -> bool {
+        self.serial_numbers.is_empty()
+    }
+
+    /// Determine whether a serial number has been spent
+    pub fn contains(&self, serial_number: &Field<N>) -> bool {
+        self.serial_numbers.contains(serial_number)
+    }
+
+    /// Compute the serial number of a record owned by the private key
+    pub fn serial_number(
+        private_key: &PrivateKey<N>,
+        record: &Record<N, Plaintext<N>>,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+    ) -> Result<Field<N>> {
+        let commitment = record.to_commitment(program_id, record_name)?;
+        Record::<N, Plaintext<N>>::serial_number(*private_key, commitment)
+    }
+
+    /// Determine whether a record owned by the private key has been spent
+    pub fn is_spent(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: &Record<N, Plaintext<N>>,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+    ) -> Result<bool> {
+        Ok(self.contains(&Self::serial_number(private_key, record, program_id, record_name)?))
+    }
+
+    /// Determine which of a list of records owned by the private key have been spent
+    ///
+    /// The returned vector contains one entry for each record in the order given
+    pub fn mark_spent(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: &[Record<N, Plaintext<N>>],
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+    ) -> Result<Vec<bool>> {
+        records.iter().map(|record| self.is_spent(private_key, record, program_id, record_name)).collect()
+    }
+
+    /// Filter a list of records owned by the private key down to the records which are unspent
+    pub fn unspent(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: Vec<Record<N, Plaintext<N>>>,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+    ) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        let spent = self.mark_spent(private_key, &records, program_id, record_name)?;
+        Ok(records.into_iter().zip(spent).filter_map(|(record, spent)| (!spent).then_some(record)).collect())
+    }
+}
+
+impl<N: Network> Default for SpentRecords<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> FromIterator<Field<N>> for SpentRecords<N> {
+    fn from_iter<I: IntoIterator<Item = Field<N>>>(serial_numbers: I) -> Self {
+        Self { serial_numbers: serial_numbers.into_iter().collect() }
+    }
+}
+
+impl<N: Network> Extend<Field<N>> for SpentRecords<N> {
+    fn extend<I: IntoIterator<Item = Field<N>>>(&mut self, serial_numbers: I) {
+        self.serial_numbers.extend(serial_numbers)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "full")]
+mod tests {
+    use super::*;
+
+    use snarkvm_console::{network::Testnet3 as CurrentNetwork, prelude::TestRng};
+
+    #[test]
+    fn test_spent_records() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let record_name = Identifier::from_str("credits").unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let records = vec![credits_record(&address, 1), credits_record(&address, 2)];
+
+        // Mark the first record as spent
+        let serial_number = SpentRecords::serial_number(&private_key, &records[0], &program_id, &record_name).unwrap();
+        let spent = SpentRecords::from_iter([serial_number]);
+        assert_eq!(spent.len(), 1);
+        assert!(spent.is_spent(&private_key, &records[0], &program_id, &record_name).unwrap());
+        assert!(!spent.is_spent(&private_key, &records[1], &program_id, &record_name).unwrap());
+        assert_eq!(spent.mark_spent(&private_key, &records, &program_id, &record_name).unwrap(), vec![true, false]);
+
+        let unspent = spent.unspent(&private_key, records.clone(), &program_id, &record_name).unwrap();
+        assert_eq!(unspent, vec![records[1].clone()]);
+        assert!(SpentRecords::<CurrentNetwork>::new().is_empty());
+    }
+}
@@ -14,17 +14,35 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AleoAPIClient, ProgramManager, RecordFinder, TransferType};
+use crate::{
+    program::{RecordQuery, Resolver},
+    AleoAPIClient,
+    ClientConfig,
+    ProgramManager,
+    RecordFinder,
+    RetryPolicy,
+    TransferType,
+};
 use snarkvm::file::Manifest;
 use snarkvm_console::{
-    account::{PrivateKey, ViewKey},
+    account::{Address, PrivateKey, ViewKey},
     network::Testnet3,
-    program::{Plaintext, Record},
+    program::{Plaintext, ProgramID, Record},
 };
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use snarkvm::synthesizer::Program;
-use std::{fs, fs::File, io::Write, ops::Add, panic::catch_unwind, path::PathBuf, str::FromStr, thread::sleep};
+use std::{
+    fs,
+    fs::File,
+    io::{Read, Write},
+    net::TcpListener,
+    ops::Add,
+    panic::catch_unwind,
+    path::PathBuf,
+    str::FromStr,
+    thread::sleep,
+};
 
 pub const RECIPIENT_PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
 pub const RECIPIENT_ADDRESS: &str = "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4";
@@ -108,6 +126,64 @@ pub const RECORD_5_MICROCREDITS: &str = r"{
   _nonce: 3700202890700295811197086261814785945731964545546334348117582517467189701159group.public
 }";
 
+/// A record resolver holding a fixed set of records
+pub struct MemoryResolver(pub Vec<Record<Testnet3, Plaintext<Testnet3>>>);
+
+impl Resolver<Testnet3> for MemoryResolver {
+    fn load_program(&self, _program_id: &ProgramID<Testnet3>) -> Result<Program<Testnet3>> {
+        bail!("Programs are not stored by this resolver")
+    }
+
+    fn find_owned_records(
+        &self,
+        private_key: &PrivateKey<Testnet3>,
+        record_query: &RecordQuery<Testnet3>,
+    ) -> Result<Vec<Record<Testnet3, Plaintext<Testnet3>>>> {
+        let address = Address::try_from(private_key)?;
+        Ok(self
+            .0
+            .iter()
+            .filter(|record| **record.owner() == address && record_query.matches_amount(record))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Create a credits record holding an amount of microcredits
+pub fn credits_record(owner: &Address<Testnet3>, microcredits: u64) -> Record<Testnet3, Plaintext<Testnet3>> {
+    Record::from_str(&format!(
+        "{{ owner: {owner}.private, microcredits: {microcredits}u64.private, _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public }}"
+    ))
+    .unwrap()
+}
+
+/// Serve canned responses to HTTP requests from a local port and return the url of the server
+///
+/// Every connection is answered with the next status and body in the list, then closed.
+pub fn serve_responses(responses: Vec<(u16, &'static str)>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for (status, body) in responses {
+            let Ok((mut stream, _)) = listener.accept() else { return };
+            let mut request = [0u8; 4096];
+            let _ = stream.read(&mut request);
+            let response = format!(
+                "HTTP/1.1 {status} Canned\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    url
+}
+
+/// Create a client for a local server which does not retry failed requests
+pub fn local_client(url: &str) -> AleoAPIClient<Testnet3> {
+    let config = ClientConfig::default().with_retry_policy(RetryPolicy::none());
+    AleoAPIClient::with_config(url, "testnet3", config).unwrap()
+}
+
 /// Get a random program id
 pub fn random_program_id(len: usize) -> String {
     use rand::Rng;
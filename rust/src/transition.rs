@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Decryption of the inputs and outputs of transitions with a view key
+
+use super::*;
+
+use snarkvm_console::{
+    prelude::{Group, ToBits},
+    types::U16,
+};
+#[cfg(feature = "abi")]
+use snarkvm_synthesizer::{Input, Output, Transaction, Transition};
+
+/// The inputs and outputs of a transition as seen by the holder of a view key
+///
+/// Public and constant values are visible to everyone. The private inputs and outputs can only be
+/// decrypted by the caller of the transition, and a record output only by its owner. Values the
+/// view key cannot decrypt, as well as spent records (of which only the serial number is public),
+/// are `None`.
+#[cfg(feature = "abi")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecryptedTransition<N: Network> {
+    /// The id of the transition
+    pub transition_id: N::TransitionID,
+    /// The program of the transition
+    pub program_id: ProgramID<N>,
+    /// The function of the transition
+    pub function_name: Identifier<N>,
+    /// Whether the account of the view key called the transition
+    pub is_caller: bool,
+    /// The inputs of the transition, in order
+    pub inputs: Vec<Option<Value<N>>>,
+    /// The outputs of the transition, in order
+    pub outputs: Vec<Option<Value<N>>>,
+}
+
+/// Decrypt the inputs and outputs of a transition which a view key is party to
+#[cfg(feature = "abi")]
+pub fn decrypt_transition<N: Network>(
+    transition: &Transition<N>,
+    view_key: &ViewKey<N>,
+) -> Result<DecryptedTransition<N>> {
+    let tvk = transition_view_key(view_key, transition.tpk(), transition.tcm())?;
+    // Derive the key a private value at an index of the transition was encrypted with
+    let key_at = |index: usize| -> Result<Option<Field<N>>> {
+        tvk.map(|tvk| value_key(tvk, transition.program_id(), transition.function_name(), index)).transpose()
+    };
+
+    let inputs = transition
+        .inputs()
+        .iter()
+        .enumerate()
+        .map(|(index, input)| match input {
+            Input::Constant(_, plaintext) | Input::Public(_, plaintext) => Ok(plaintext.clone().map(Value::Plaintext)),
+            Input::Private(_, Some(ciphertext)) => match key_at(index)? {
+                Some(key) => Ok(Some(Value::Plaintext(ciphertext.decrypt_symmetric(key)?))),
+                None => Ok(None),
+            },
+            Input::Private(_, None) | Input::Record(..) | Input::ExternalRecord(_) => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let address_x_coordinate = view_key.to_address().to_x_coordinate();
+    let outputs = transition
+        .outputs()
+        .iter()
+        .enumerate()
+        .map(|(index, output)| match output {
+            Output::Constant(_, plaintext) | Output::Public(_, plaintext) => {
+                Ok(plaintext.clone().map(Value::Plaintext))
+            }
+            Output::Private(_, Some(ciphertext)) => match key_at(transition.inputs().len() + index)? {
+                Some(key) => Ok(Some(Value::Plaintext(ciphertext.decrypt_symmetric(key)?))),
+                None => Ok(None),
+            },
+            Output::Record(_, _, Some(record)) => {
+                match record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) {
+                    true => Ok(Some(Value::Record(record.decrypt(view_key)?))),
+                    false => Ok(None),
+                }
+            }
+            Output::Private(_, None) | Output::Record(_, _, None) | Output::ExternalRecord(_) => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(DecryptedTransition {
+        transition_id: *transition.id(),
+        program_id: *transition.program_id(),
+        function_name: *transition.function_name(),
+        is_caller: tvk.is_some(),
+        inputs,
+        outputs,
+    })
+}
+
+/// Decrypt the inputs and outputs of every transition of a transaction which a view key is party to
+#[cfg(feature = "abi")]
+pub fn decrypt_transaction<N: Network>(
+    transaction: &Transaction<N>,
+    view_key: &ViewKey<N>,
+) -> Result<Vec<DecryptedTransition<N>>> {
+    transaction.transitions().map(|transition| decrypt_transition(transition, view_key)).collect()
+}
+
+/// Compute the transition view key of a transition if the account of the view key is its caller
+///
+/// The caller derives the transition view key from the transition public key and commits to it
+/// in the transition commitment, so the key is only returned if it matches the commitment.
+pub fn transition_view_key<N: Network>(
+    view_key: &ViewKey<N>,
+    tpk: &Group<N>,
+    tcm: &Field<N>,
+) -> Result<Option<Field<N>>> {
+    let tvk = (*tpk * **view_key).to_x_coordinate();
+    Ok((N::hash_psd2(&[tvk])? == *tcm).then_some(tvk))
+}
+
+/// Derive the symmetric key a private input or output of a transition is encrypted with
+///
+/// The `index` counts the inputs of the transition followed by its outputs, so the first output
+/// of a function with two inputs has index 2.
+pub fn value_key<N: Network>(
+    transition_view_key: Field<N>,
+    program_id: &ProgramID<N>,
+    function_name: &Identifier<N>,
+    index: usize,
+) -> Result<Field<N>> {
+    let function_id = function_id(program_id, function_name)?;
+    N::hash_psd4(&[function_id, transition_view_key, Field::from_u16(u16::try_from(index)?)])
+}
+
+/// Compute the id of a program function, which binds the keys of its private values to it
+fn function_id<N: Network>(program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<Field<N>> {
+    N::hash_bhp1024(&(U16::<N>::new(N::ID), program_id.name(), program_id.network(), function_name).to_bits_le())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::program::{add_programs_to_vm, open_memory_vm};
+    use snarkvm::circuit::AleoV0;
+    use snarkvm_console::{network::Testnet3, prelude::Scalar};
+
+    #[test]
+    fn test_decrypt_transition() {
+        let private_key = PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let other_view_key = ViewKey::try_from(PrivateKey::<Testnet3>::new(&mut rand::thread_rng()).unwrap()).unwrap();
+
+        // Run the hello function locally to obtain its transition
+        let vm = open_memory_vm::<Testnet3>().unwrap();
+        add_programs_to_vm(&vm, &[Program::from_str(HELLO_PROGRAM).unwrap()]).unwrap();
+        let process = vm.process().read();
+        let rng = &mut rand::thread_rng();
+        let authorization = process
+            .authorize::<AleoV0, _>(&private_key, "hello.aleo", "hello", ["5u32", "3u32"].into_iter(), rng)
+            .unwrap();
+        let (_, trace) = process.execute::<AleoV0>(authorization).unwrap();
+        let transition = &trace.transitions()[0];
+
+        // The caller sees every value
+        let decrypted = decrypt_transition(transition, &view_key).unwrap();
+        assert!(decrypted.is_caller);
+        assert_eq!(decrypted.function_name.to_string(), "hello");
+        assert_eq!(decrypted.inputs, vec![
+            Some(Value::from_str("5u32").unwrap()),
+            Some(Value::from_str("3u32").unwrap())
+        ]);
+        assert_eq!(decrypted.outputs, vec![Some(Value::from_str("8u32").unwrap())]);
+
+        // Anyone else only sees the public values
+        let decrypted = decrypt_transition(transition, &other_view_key).unwrap();
+        assert!(!decrypted.is_caller);
+        assert_eq!(decrypted.inputs, vec![Some(Value::from_str("5u32").unwrap()), None]);
+        assert_eq!(decrypted.outputs, vec![None]);
+    }
+
+    #[test]
+    fn test_transition_view_key() {
+        let rng = &mut rand::thread_rng();
+        let view_key =
+            ViewKey::<Testnet3>::try_from(PrivateKey::<Testnet3>::from_str(RECIPIENT_PRIVATE_KEY).unwrap()).unwrap();
+        let other_view_key = ViewKey::<Testnet3>::try_from(PrivateKey::<Testnet3>::new(rng).unwrap()).unwrap();
+
+        // Derive a transition public key and commitment the way the caller does
+        let randomizer = Scalar::<Testnet3>::rand(rng);
+        let tpk = Testnet3::g_scalar_multiply(&randomizer);
+        let tvk = (*view_key.to_address() * randomizer).to_x_coordinate();
+        let tcm = Testnet3::hash_psd2(&[tvk]).unwrap();
+
+        assert_eq!(transition_view_key(&view_key, &tpk, &tcm).unwrap(), Some(tvk));
+        assert_eq!(transition_view_key(&other_view_key, &tpk, &tcm).unwrap(), None);
+    }
+}
@@ -15,8 +15,8 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::{PrivateKey, Signature, ViewKey},
-    types::AddressNative,
+    account::{utils::validate_account_string, PrivateKey, Signature, ViewKey},
+    types::{AddressNative, Encryptor},
 };
 
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
@@ -28,10 +28,14 @@ pub struct Address(AddressNative);
 
 #[wasm_bindgen]
 impl Address {
+    /// Derive an address from a private key
     pub fn from_private_key(private_key: &PrivateKey) -> Self {
         Self(AddressNative::try_from(**private_key).unwrap())
     }
 
+    /// Derive an address from a view key
+    ///
+    /// This allows watch-only wallets which only hold a view key to display the account's address
     pub fn from_view_key(view_key: &ViewKey) -> Self {
         Self(AddressNative::try_from(**view_key).unwrap())
     }
@@ -40,14 +44,41 @@ impl Address {
         Self::from_str(address).unwrap()
     }
 
+    /// Check if a string is a valid address
+    #[wasm_bindgen(js_name = isValid)]
+    pub fn is_valid(address: &str) -> bool {
+        Self::validate(address).is_ok()
+    }
+
+    /// Check if a string is a valid address, returning a description of the problem if it is not
+    pub fn validate(address: &str) -> Result<(), String> {
+        validate_account_string::<Address>(address, "aleo1", "address")
+    }
+
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
         self.0.to_string()
     }
 
+    /// Verify a signature of a message was produced by the private key of this address
     pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
         signature.verify(self, message)
     }
+
+    /// Encrypt a message so that it can only be decrypted by the view key of this address
+    ///
+    /// The ciphertext is returned as a string and can be decrypted with `ViewKey.decryptMessage`
+    #[wasm_bindgen(js_name = encryptMessage)]
+    pub fn encrypt_message(&self, message: &[u8]) -> Result<String, String> {
+        let ciphertext = Encryptor::encrypt_message_to_address(&self.0, message).map_err(|error| error.to_string())?;
+        Ok(ciphertext.to_string())
+    }
+}
+
+impl From<AddressNative> for Address {
+    fn from(address: AddressNative) -> Self {
+        Self(address)
+    }
 }
 
 impl FromStr for Address {
@@ -81,6 +112,9 @@ mod tests {
 
     const ITERATIONS: u64 = 1_000;
 
+    const ALEO_VIEW_KEY: &str = "AViewKey1cxguxtKkjYnT9XDza9yTvVMxt6Ckb1Pv4ck1hppMzmCB";
+    const ALEO_ADDRESS: &str = "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4";
+
     #[wasm_bindgen_test]
     pub fn test_from_private_key() {
         for _ in 0..ITERATIONS {
@@ -93,4 +127,31 @@ mod tests {
             assert_eq!(expected, Address::from_view_key(&view_key));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_view_key() {
+        let view_key = ViewKey::from_string(ALEO_VIEW_KEY);
+        let address = Address::from_view_key(&view_key);
+        assert_eq!(ALEO_ADDRESS, address.to_string());
+        assert_eq!(address, view_key.to_address());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_validation() {
+        let address = PrivateKey::new().to_address().to_string();
+        assert!(Address::is_valid(&address));
+        assert!(Address::validate(&address).is_ok());
+
+        // Check empty strings, bad prefixes, and bad checksums are reported.
+        assert_eq!(Address::validate("").unwrap_err(), "The address is empty");
+        assert_eq!(
+            Address::validate(&address.replacen("aleo1", "aleo2", 1)).unwrap_err(),
+            "The address must begin with 'aleo1'"
+        );
+        let mut corrupted = address.clone();
+        let last = if corrupted.ends_with('q') { 'p' } else { 'q' };
+        corrupted.pop();
+        corrupted.push(last);
+        assert!(!Address::is_valid(&corrupted));
+    }
 }
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Address, Mnemonic, PrivateKey, Signature, ViewKey};
+use crate::record::{RecordCiphertext, RecordPlaintext};
+
+use wasm_bindgen::prelude::*;
+
+/// An Aleo account holding a private key along with its derived view key and address
+///
+/// This object bundles the key material of an account into a single handle so that JavaScript
+/// applications can sign messages and decrypt records without managing several wasm objects
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Account {
+    private_key: PrivateKey,
+    view_key: ViewKey,
+    address: Address,
+}
+
+#[wasm_bindgen]
+impl Account {
+    /// Generate a new account with a random private key
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self::from_private_key(&PrivateKey::new())
+    }
+
+    /// Create an account from an existing private key
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Self {
+        let view_key = private_key.to_view_key();
+        let address = view_key.to_address();
+        Self { private_key: private_key.clone(), view_key, address }
+    }
+
+    /// Create an account from a private key string
+    ///
+    /// This function will fail if the text is not a valid private key
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(private_key: &str) -> Result<Account, String> {
+        Ok(Self::from_private_key(&PrivateKey::from_string(private_key)?))
+    }
+
+    /// Recover an account from a BIP-39 mnemonic phrase and an optional passphrase
+    #[wasm_bindgen(js_name = fromMnemonic)]
+    pub fn from_mnemonic(phrase: &str, passphrase: Option<String>) -> Result<Account, String> {
+        Ok(Self::from_private_key(&Mnemonic::from_string(phrase)?.to_private_key(passphrase)?))
+    }
+
+    /// Get the private key of the account
+    ///
+    /// This function should be used very carefully as it exposes the private key
+    #[wasm_bindgen(js_name = privateKey)]
+    pub fn private_key(&self) -> PrivateKey {
+        self.private_key.clone()
+    }
+
+    /// Get the view key of the account
+    #[wasm_bindgen(js_name = viewKey)]
+    pub fn view_key(&self) -> ViewKey {
+        self.view_key.clone()
+    }
+
+    /// Get the address of the account
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Sign a message with the account's private key
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        self.private_key.sign(message)
+    }
+
+    /// Verify a signature of a message was produced by this account
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        self.address.verify(message, signature)
+    }
+
+    /// Decrypt a record ciphertext owned by the account
+    ///
+    /// This function will fail if the ciphertext is invalid or is not owned by the account
+    #[wasm_bindgen(js_name = decryptRecord)]
+    pub fn decrypt_record(&self, ciphertext: &str) -> Result<RecordPlaintext, String> {
+        RecordCiphertext::from_string(ciphertext)?.decrypt(&self.view_key)
+    }
+
+    /// Determine whether a record ciphertext is owned by the account
+    #[wasm_bindgen(js_name = ownsRecordCiphertext)]
+    pub fn owns_record_ciphertext(&self, ciphertext: &str) -> bool {
+        RecordCiphertext::from_string(ciphertext).map_or(false, |record| record.is_owner(&self.view_key))
+    }
+
+    /// Overwrite the key material of the account and release the memory it occupies
+    ///
+    /// The account can no longer be used from JavaScript after this method is called. Keys
+    /// obtained from it with `privateKey` and `viewKey` are copies which must be destroyed
+    /// separately.
+    pub fn destroy(self) {
+        drop(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const OWNER_PRIVATE_KEY: &str = "APrivateKey1zkpJkyYRGYtkeHDaFfwsKtUJzia7csiWhfBWPXWhXJzy9Ls";
+    const OWNER_ADDRESS: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+    const OWNER_CIPHERTEXT: &str = "record1qyqsqpe2szk2wwwq56akkwx586hkndl3r8vzdwve32lm7elvphh37rsyqyxx66trwfhkxun9v35hguerqqpqzqrtjzeu6vah9x2me2exkgege824sd8x2379scspmrmtvczs0d93qttl7y92ga0k0rsexu409hu3vlehe3yxjhmey3frh2z5pxm5cmxsv4un97q";
+    const OWNER_PLAINTEXT: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  microcredits: 1500000000000000u64.private,
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+
+    #[wasm_bindgen_test]
+    pub fn test_account_keys() {
+        let account = Account::from_string(OWNER_PRIVATE_KEY).unwrap();
+        assert_eq!(account.private_key().to_string(), OWNER_PRIVATE_KEY);
+        assert_eq!(account.view_key(), account.private_key().to_view_key());
+        assert_eq!(account.address().to_string(), OWNER_ADDRESS);
+        assert!(Account::from_string("not a private key").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_account_sign_and_verify() {
+        let account = Account::new();
+        let message = b"sign in with aleo";
+        let signature = account.sign(message);
+        assert!(account.verify(message, &signature));
+        assert!(!Account::new().verify(message, &signature));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_account_records() {
+        let account = Account::from_string(OWNER_PRIVATE_KEY).unwrap();
+        assert!(account.owns_record_ciphertext(OWNER_CIPHERTEXT));
+        assert_eq!(account.decrypt_record(OWNER_CIPHERTEXT).unwrap().to_string(), OWNER_PLAINTEXT);
+
+        // Ensure other accounts cannot decrypt the record
+        let other = Account::new();
+        assert!(!other.owns_record_ciphertext(OWNER_CIPHERTEXT));
+        assert!(other.decrypt_record(OWNER_CIPHERTEXT).is_err());
+        assert!(!account.owns_record_ciphertext("garbage"));
+    }
+}
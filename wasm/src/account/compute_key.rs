@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Address, PrivateKey};
+use crate::types::{ComputeKeyNative, FromBytes, ToBytes};
+
+use core::{convert::TryFrom, ops::Deref};
+use wasm_bindgen::prelude::*;
+
+/// The compute key of an Aleo account
+///
+/// The compute key can be used to authorize transitions on behalf of an account without revealing
+/// the private key, and can be used to derive the account's address
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComputeKey(ComputeKeyNative);
+
+#[wasm_bindgen]
+impl ComputeKey {
+    /// Derive the compute key from a private key
+    pub fn from_private_key(private_key: &PrivateKey) -> Self {
+        Self(ComputeKeyNative::try_from(**private_key).unwrap())
+    }
+
+    /// Create a compute key from its hex encoded byte representation
+    pub fn from_string(compute_key: &str) -> Result<ComputeKey, String> {
+        let bytes = hex::decode(compute_key).map_err(|_| "Invalid compute key encoding".to_string())?;
+        Self::from_bytes_le(&bytes)
+    }
+
+    /// Get the hex encoded byte representation of the compute key
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        hex::encode(self.to_bytes_le().unwrap_or_default())
+    }
+
+    /// Create a compute key from its little endian byte representation
+    #[wasm_bindgen(js_name = fromBytesLe)]
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<ComputeKey, String> {
+        Ok(Self(ComputeKeyNative::from_bytes_le(bytes).map_err(|_| "Invalid compute key".to_string())?))
+    }
+
+    /// Get the little endian byte representation of the compute key
+    #[wasm_bindgen(js_name = toBytesLe)]
+    pub fn to_bytes_le(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize compute key".to_string())
+    }
+
+    /// Get the address corresponding to the compute key
+    pub fn to_address(&self) -> Address {
+        Address::from(self.0.to_address())
+    }
+
+    /// Get the signature public key `pk_sig` of the compute key
+    pub fn pk_sig(&self) -> String {
+        self.0.pk_sig().to_string()
+    }
+
+    /// Get the signature public randomizer `pr_sig` of the compute key
+    pub fn pr_sig(&self) -> String {
+        self.0.pr_sig().to_string()
+    }
+
+    /// Get the PRF secret key `sk_prf` of the compute key
+    pub fn sk_prf(&self) -> String {
+        self.0.sk_prf().to_string()
+    }
+}
+
+impl From<ComputeKeyNative> for ComputeKey {
+    fn from(compute_key: ComputeKeyNative) -> Self {
+        Self(compute_key)
+    }
+}
+
+impl Deref for ComputeKey {
+    type Target = ComputeKeyNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const ITERATIONS: u64 = 100;
+
+    #[wasm_bindgen_test]
+    pub fn test_from_private_key() {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::new();
+            let compute_key = ComputeKey::from_private_key(&private_key);
+
+            // Check the compute key derives the same address as the private key.
+            assert_eq!(private_key.to_address(), compute_key.to_address());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_string_and_bytes_round_trip() {
+        let compute_key = ComputeKey::from_private_key(&PrivateKey::new());
+        assert_eq!(compute_key, ComputeKey::from_string(&compute_key.to_string()).unwrap());
+        assert_eq!(compute_key, ComputeKey::from_bytes_le(&compute_key.to_bytes_le().unwrap()).unwrap());
+        assert!(ComputeKey::from_string("not a compute key").is_err());
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{PrivateKey, ViewKey};
+use crate::types::{FromBytes, GraphKeyNative, ToBytes};
+
+use core::{convert::TryFrom, ops::Deref};
+use wasm_bindgen::prelude::*;
+
+/// The graph key of an Aleo account
+///
+/// The graph key is derived from the view key and is used to compute record tags, allowing a
+/// delegate to determine which records of an account have been spent without seeing their contents
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphKey(GraphKeyNative);
+
+#[wasm_bindgen]
+impl GraphKey {
+    /// Derive the graph key from a view key
+    pub fn from_view_key(view_key: &ViewKey) -> Self {
+        Self(GraphKeyNative::try_from(**view_key).unwrap())
+    }
+
+    /// Derive the graph key from a private key
+    pub fn from_private_key(private_key: &PrivateKey) -> Self {
+        Self::from_view_key(&private_key.to_view_key())
+    }
+
+    /// Create a graph key from its hex encoded byte representation
+    pub fn from_string(graph_key: &str) -> Result<GraphKey, String> {
+        let bytes = hex::decode(graph_key).map_err(|_| "Invalid graph key encoding".to_string())?;
+        Self::from_bytes_le(&bytes)
+    }
+
+    /// Get the hex encoded byte representation of the graph key
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        hex::encode(self.to_bytes_le().unwrap_or_default())
+    }
+
+    /// Create a graph key from its little endian byte representation
+    #[wasm_bindgen(js_name = fromBytesLe)]
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<GraphKey, String> {
+        Ok(Self(GraphKeyNative::from_bytes_le(bytes).map_err(|_| "Invalid graph key".to_string())?))
+    }
+
+    /// Get the little endian byte representation of the graph key
+    #[wasm_bindgen(js_name = toBytesLe)]
+    pub fn to_bytes_le(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize graph key".to_string())
+    }
+
+    /// Get the tag secret key `sk_tag` of the graph key
+    pub fn sk_tag(&self) -> String {
+        self.0.sk_tag().to_string()
+    }
+}
+
+impl From<GraphKeyNative> for GraphKey {
+    fn from(graph_key: GraphKeyNative) -> Self {
+        Self(graph_key)
+    }
+}
+
+impl Deref for GraphKey {
+    type Target = GraphKeyNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_from_view_key() {
+        let private_key = PrivateKey::new();
+        let graph_key = GraphKey::from_view_key(&private_key.to_view_key());
+        assert_eq!(graph_key, GraphKey::from_private_key(&private_key));
+        assert_ne!(graph_key, GraphKey::from_private_key(&PrivateKey::new()));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_string_and_bytes_round_trip() {
+        let graph_key = GraphKey::from_private_key(&PrivateKey::new());
+        assert_eq!(graph_key, GraphKey::from_string(&graph_key.to_string()).unwrap());
+        assert_eq!(graph_key, GraphKey::from_bytes_le(&graph_key.to_bytes_le().unwrap()).unwrap());
+        assert!(GraphKey::from_string("zz").is_err());
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account::PrivateKey, types::MnemonicNative};
+
+use core::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// BIP-39 mnemonic phrase used to deterministically generate and recover an Aleo private key
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mnemonic(MnemonicNative);
+
+#[wasm_bindgen]
+impl Mnemonic {
+    /// Generate a new random mnemonic phrase with 12 or 24 words
+    #[wasm_bindgen(constructor)]
+    pub fn new(word_count: usize) -> Result<Mnemonic, String> {
+        console_error_panic_hook::set_once();
+        Ok(Self(MnemonicNative::new(word_count).map_err(|error| error.to_string())?))
+    }
+
+    /// Recover a mnemonic from an existing phrase
+    ///
+    /// This function will fail if the phrase contains unknown words or has an invalid checksum
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(phrase: &str) -> Result<Mnemonic, String> {
+        Self::from_str(phrase).map_err(|error| error.to_string())
+    }
+
+    /// Get the words of the mnemonic phrase
+    ///
+    /// This function should be used very carefully as the phrase can be used to recover the private key
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Get the number of words in the mnemonic phrase
+    #[wasm_bindgen(js_name = wordCount)]
+    pub fn word_count(&self) -> usize {
+        self.0.word_count()
+    }
+
+    /// Derive the private key corresponding to the mnemonic and an optional passphrase
+    #[wasm_bindgen(js_name = toPrivateKey)]
+    pub fn to_private_key(&self, passphrase: Option<String>) -> Result<PrivateKey, String> {
+        let private_key =
+            self.0.to_private_key(passphrase.as_deref().unwrap_or_default()).map_err(|error| error.to_string())?;
+        Ok(PrivateKey::from(private_key))
+    }
+
+    /// Derive the private key of the account at the given index of the mnemonic's account tree
+    #[wasm_bindgen(js_name = toPrivateKeyAtIndex)]
+    pub fn to_private_key_at_index(&self, passphrase: Option<String>, index: u32) -> Result<PrivateKey, String> {
+        let private_key = self
+            .0
+            .to_private_key_at_index(passphrase.as_deref().unwrap_or_default(), index)
+            .map_err(|error| error.to_string())?;
+        Ok(PrivateKey::from(private_key))
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = anyhow::Error;
+
+    fn from_str(phrase: &str) -> Result<Self, Self::Err> {
+        Ok(Self(MnemonicNative::from_str(phrase)?))
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for Mnemonic {
+    type Target = MnemonicNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_mnemonic_recovery() {
+        for word_count in [12, 24] {
+            let mnemonic = Mnemonic::new(word_count).unwrap();
+            assert_eq!(mnemonic.word_count(), word_count);
+
+            // Ensure the phrase recovers the same private key
+            let expected = mnemonic.to_private_key(None).unwrap();
+            let recovered = PrivateKey::from_mnemonic(&mnemonic.to_string(), None).unwrap();
+            assert_eq!(expected, recovered);
+
+            // Ensure a passphrase produces a different private key
+            let with_passphrase = mnemonic.to_private_key(Some("passphrase".to_string())).unwrap();
+            assert_ne!(expected, with_passphrase);
+
+            // Ensure indexed accounts match the accounts derived from the master key
+            let child = mnemonic.to_private_key_at_index(None, 3).unwrap();
+            assert_eq!(child, expected.derive_child(3).unwrap());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_mnemonic_invalid_inputs() {
+        assert!(Mnemonic::new(13).is_err());
+        assert!(Mnemonic::from_string("not a real mnemonic phrase").is_err());
+        assert!(PrivateKey::from_mnemonic(&["abandon"; 12].join(" "), None).is_err());
+    }
+}
@@ -14,17 +14,34 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod aleo_account;
+pub use aleo_account::*;
+
 pub mod address;
 pub use address::*;
 
+pub mod compute_key;
+pub use compute_key::*;
+
+pub mod graph_key;
+pub use graph_key::*;
+
+pub mod mnemonic;
+pub use mnemonic::*;
+
 pub mod private_key;
 pub use private_key::*;
 
 pub mod signature;
 pub use signature::*;
 
+pub mod signing_domain;
+pub use signing_domain::*;
+
 pub mod view_key;
 pub use view_key::*;
 
 pub mod private_key_ciphertext;
 pub use private_key_ciphertext::*;
+
+pub(crate) mod utils;
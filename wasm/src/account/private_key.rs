@@ -15,16 +15,35 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::{Address, PrivateKeyCiphertext, Signature, ViewKey},
+    account::{
+        utils::validate_account_string,
+        Address,
+        ComputeKey,
+        GraphKey,
+        Mnemonic,
+        PrivateKeyCiphertext,
+        Signature,
+        ViewKey,
+    },
     record::RecordCiphertext,
-    types::{CurrentNetwork, Encryptor, Environment, FromBytes, PrimeField, PrivateKeyNative, ToBytes},
+    types::{
+        CurrentNetwork,
+        Encryptor,
+        Environment,
+        FromBytes,
+        KeyDerivationNative,
+        Keystore,
+        PrimeField,
+        PrivateKeyNative,
+        ToBytes,
+    },
 };
 
 use core::{convert::TryInto, fmt, ops::Deref, str::FromStr};
 use rand::{rngs::StdRng, SeedableRng};
 use wasm_bindgen::prelude::*;
 
-use aleo_rust::{Network, Plaintext, Record};
+use aleo_rust::{wipe_private_key, Network, Plaintext, Record};
 use serde::{Deserialize, Serialize};
 
 #[wasm_bindgen]
@@ -78,14 +97,29 @@ impl PrivateKey {
     }
 
     /// Get a private key from a series of unchecked bytes
-    pub fn from_seed_unchecked(seed: &[u8]) -> PrivateKey {
+    ///
+    /// The seed must be exactly 32 bytes long and should be sampled from a cryptographically secure
+    /// source of entropy, as the same seed will always produce the same private key
+    pub fn from_seed_unchecked(seed: &[u8]) -> Result<PrivateKey, String> {
         console_error_panic_hook::set_once();
         // Cast into a fixed-size byte array. Note: This is a **hard** requirement for security.
-        let seed: [u8; 32] = seed.try_into().unwrap();
+        let seed: [u8; 32] =
+            seed.try_into().map_err(|_| format!("The seed must be exactly 32 bytes, found {} bytes", seed.len()))?;
         // Recover the field element deterministically.
         let field = <CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&seed);
+        let bytes = field.to_bytes_le().map_err(|_| "Failed to serialize the seed".to_string())?;
+        let field: aleo_rust::Field<CurrentNetwork> =
+            FromBytes::read_le(&*bytes).map_err(|_| "Failed to recover the seed".to_string())?;
         // Cast and recover the private key from the seed.
-        Self(PrivateKeyNative::try_from(FromBytes::read_le(&*field.to_bytes_le().unwrap()).unwrap()).unwrap())
+        Ok(Self(PrivateKeyNative::try_from(field).map_err(|_| "Failed to derive a private key from the seed")?))
+    }
+
+    /// Recover a private key from a BIP-39 mnemonic phrase and an optional passphrase
+    ///
+    /// This function will fail if the phrase is not a valid 12 or 24 word mnemonic
+    #[wasm_bindgen(js_name = fromMnemonic)]
+    pub fn from_mnemonic(phrase: &str, passphrase: Option<String>) -> Result<PrivateKey, String> {
+        Mnemonic::from_string(phrase)?.to_private_key(passphrase)
     }
 
     /// Create a private key from a string representation
@@ -95,6 +129,17 @@ impl PrivateKey {
         Self::from_str(private_key).map_err(|_| "Invalid private key".to_string())
     }
 
+    /// Check if a string is a valid private key
+    #[wasm_bindgen(js_name = isValid)]
+    pub fn is_valid(private_key: &str) -> bool {
+        Self::validate(private_key).is_ok()
+    }
+
+    /// Check if a string is a valid private key, returning a description of the problem if it is not
+    pub fn validate(private_key: &str) -> Result<(), String> {
+        validate_account_string::<PrivateKey>(private_key, "APrivateKey1", "private key")
+    }
+
     /// Get a string representation of the private key
     ///
     /// This function should be used very carefully as it exposes the private key plaintext
@@ -108,11 +153,29 @@ impl PrivateKey {
         ViewKey::from_private_key(self)
     }
 
+    /// Get the compute key corresponding to the private key
+    pub fn to_compute_key(&self) -> ComputeKey {
+        ComputeKey::from_private_key(self)
+    }
+
+    /// Get the graph key corresponding to the private key
+    pub fn to_graph_key(&self) -> GraphKey {
+        GraphKey::from_private_key(self)
+    }
+
     /// Get the address corresponding to the private key
     pub fn to_address(&self) -> Address {
         Address::from_private_key(self)
     }
 
+    /// Derive the private key of the account at the given index, using this private key as the
+    /// master key of the account tree
+    #[wasm_bindgen(js_name = deriveChild)]
+    pub fn derive_child(&self, index: u32) -> Result<PrivateKey, String> {
+        let private_key = KeyDerivationNative::derive_private_key(self, index).map_err(|error| error.to_string())?;
+        Ok(Self::from(private_key))
+    }
+
     /// Sign a message with the private key
     pub fn sign(&self, message: &[u8]) -> Signature {
         Signature::sign(self, message)
@@ -147,6 +210,23 @@ impl PrivateKey {
         Ok(Self::from(private_key))
     }
 
+    /// Export the private key as a password protected JSON keystore
+    ///
+    /// The keystore format is shared with the Aleo CLI and rust SDK, so accounts exported in the
+    /// browser can be imported elsewhere and vice versa
+    #[wasm_bindgen(js_name = toKeystore)]
+    pub fn to_keystore(&self, password: &str) -> Result<String, String> {
+        let keystore = Keystore::encrypt(&self.0, password).map_err(|error| error.to_string())?;
+        keystore.to_json().map_err(|error| error.to_string())
+    }
+
+    /// Import a private key from a password protected JSON keystore
+    #[wasm_bindgen(js_name = fromKeystore)]
+    pub fn from_keystore(keystore: &str, password: &str) -> Result<PrivateKey, String> {
+        let keystore = Keystore::from_json(keystore).map_err(|error| error.to_string())?;
+        Ok(Self(keystore.decrypt(password).map_err(|error| error.to_string())?))
+    }
+
     #[wasm_bindgen(js_name = "decryptrecords")]
     pub fn decrypt_records(&self, recordstext: &str) -> Result<String, String> {
         let record_org_datas: Vec<RecordOrgData> = serde_json::from_str(recordstext).unwrap_or_default();
@@ -177,6 +257,14 @@ impl PrivateKey {
         }
         Ok(serde_json::to_string_pretty(&records).unwrap_or_default().replace("\\n", ""))
     }
+
+    /// Overwrite the private key and release the memory it occupies
+    ///
+    /// The private key can no longer be used from JavaScript after this method is called. Other
+    /// objects derived from it (view keys, accounts, etc.) must be destroyed separately.
+    pub fn destroy(self) {
+        drop(self)
+    }
 }
 
 impl From<PrivateKeyNative> for PrivateKey {
@@ -218,6 +306,12 @@ impl Deref for PrivateKey {
     }
 }
 
+impl Drop for PrivateKey {
+    fn drop(&mut self) {
+        let _ = wipe_private_key(&mut self.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +339,15 @@ mod tests {
         assert_eq!(ALEO_ADDRESS, private_key.to_address().to_string());
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_validation() {
+        assert!(PrivateKey::is_valid(ALEO_PRIVATE_KEY));
+        assert!(PrivateKey::validate(ALEO_PRIVATE_KEY).is_ok());
+        assert_eq!(PrivateKey::validate("").unwrap_err(), "The private key is empty");
+        assert_eq!(PrivateKey::validate(ALEO_VIEW_KEY).unwrap_err(), "The private key must begin with 'APrivateKey1'");
+        assert!(!PrivateKey::is_valid(&ALEO_PRIVATE_KEY[..40]));
+    }
+
     #[wasm_bindgen_test]
     pub fn test_new() {
         for _ in 0..ITERATIONS {
@@ -263,11 +366,20 @@ mod tests {
             let seed: [u8; 32] = StdRng::from_entropy().gen();
 
             // Ensure the private key is deterministically recoverable.
-            let expected = PrivateKey::from_seed_unchecked(&seed);
-            assert_eq!(expected, PrivateKey::from_seed_unchecked(&seed));
+            let expected = PrivateKey::from_seed_unchecked(&seed).unwrap();
+            assert_eq!(expected, PrivateKey::from_seed_unchecked(&seed).unwrap());
         }
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_from_seed_unchecked_invalid_length() {
+        // Ensure seeds which are not 32 bytes long produce an error instead of panicking.
+        assert!(PrivateKey::from_seed_unchecked(&[]).is_err());
+        assert!(PrivateKey::from_seed_unchecked(&[0u8; 31]).is_err());
+        assert!(PrivateKey::from_seed_unchecked(&[0u8; 33]).is_err());
+        assert!(PrivateKey::from_seed_unchecked(&[7u8; 32]).is_ok());
+    }
+
     #[wasm_bindgen_test]
     pub fn test_to_address() {
         for _ in 0..ITERATIONS {
@@ -297,6 +409,41 @@ mod tests {
         }
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_destroy() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let view_key = private_key.to_view_key();
+
+        // Ensure destroying a copy of a key does not affect the original
+        private_key.clone().destroy();
+        view_key.clone().destroy();
+        assert_eq!(private_key.to_string(), ALEO_PRIVATE_KEY);
+        assert_eq!(view_key.to_string(), ALEO_VIEW_KEY);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_keystore_roundtrip() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let keystore = private_key.to_keystore("mypassword").unwrap();
+        assert!(keystore.contains(ALEO_ADDRESS));
+        assert_eq!(PrivateKey::from_keystore(&keystore, "mypassword").unwrap(), private_key);
+        assert!(PrivateKey::from_keystore(&keystore, "wrong_password").is_err());
+        assert!(PrivateKey::from_keystore("{}", "mypassword").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_derive_child() {
+        let master_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let child_0 = master_key.derive_child(0).unwrap();
+        let child_1 = master_key.derive_child(1).unwrap();
+
+        // Ensure derivation is deterministic and produces distinct accounts
+        assert_eq!(child_0, master_key.derive_child(0).unwrap());
+        assert_ne!(child_0, child_1);
+        assert_ne!(child_0, master_key);
+        assert_ne!(child_0.to_address(), child_1.to_address());
+    }
+
     #[wasm_bindgen_test]
     fn test_private_key_ciphertext_encrypt_and_decrypt() {
         let private_key = PrivateKey::new();
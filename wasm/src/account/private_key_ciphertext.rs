@@ -19,7 +19,7 @@ use crate::{
     types::{CiphertextNative, Encryptor},
 };
 
-use std::{ops::Deref, str::FromStr};
+use std::{fmt, ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
 
 /// Private Key in ciphertext form
@@ -50,7 +50,7 @@ impl PrivateKeyCiphertext {
     }
 
     /// Returns the ciphertext string
-    #[allow(clippy::inherent_to_string)]
+    #[allow(clippy::inherent_to_string_shadow_display)]
     #[wasm_bindgen(js_name = toString)]
     pub fn to_string(&self) -> String {
         self.0.to_string()
@@ -69,6 +69,12 @@ impl From<CiphertextNative> for PrivateKeyCiphertext {
     }
 }
 
+impl From<PrivateKeyCiphertext> for CiphertextNative {
+    fn from(ciphertext: PrivateKeyCiphertext) -> Self {
+        ciphertext.0
+    }
+}
+
 impl TryFrom<String> for PrivateKeyCiphertext {
     type Error = String;
 
@@ -77,6 +83,20 @@ impl TryFrom<String> for PrivateKeyCiphertext {
     }
 }
 
+impl FromStr for PrivateKeyCiphertext {
+    type Err = anyhow::Error;
+
+    fn from_str(ciphertext: &str) -> Result<Self, Self::Err> {
+        Ok(Self(CiphertextNative::from_str(ciphertext)?))
+    }
+}
+
+impl fmt::Display for PrivateKeyCiphertext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl Deref for PrivateKeyCiphertext {
     type Target = CiphertextNative;
 
@@ -99,6 +119,11 @@ mod tests {
 
         // Assert the round trip to and from string journey results in the same key
         assert_eq!(private_key_ciphertext, private_key_ciphertext_2);
+
+        // Assert the native string conversions agree with the wasm bindings
+        let private_key_ciphertext_3 = PrivateKeyCiphertext::from_str(&private_key_ciphertext.to_string()).unwrap();
+        assert_eq!(private_key_ciphertext, private_key_ciphertext_3);
+        assert_eq!(format!("{private_key_ciphertext}"), private_key_ciphertext.to_string());
     }
 
     #[wasm_bindgen_test]
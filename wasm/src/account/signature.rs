@@ -24,22 +24,39 @@ use rand::{rngs::StdRng, SeedableRng};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Signature(SignatureNative);
 
 #[wasm_bindgen]
 impl Signature {
+    /// Sign a message with a private key
     pub fn sign(private_key: &PrivateKey, message: &[u8]) -> Self {
         Self(SignatureNative::sign_bytes(private_key, message, &mut StdRng::from_entropy()).unwrap())
     }
 
+    /// Verify a signature of a message with an address
     pub fn verify(&self, address: &Address, message: &[u8]) -> bool {
         self.0.verify_bytes(address, message)
     }
 
+    /// Get the address of the account which produced the signature
+    pub fn to_address(&self) -> Address {
+        Address::from(self.0.to_address())
+    }
+
     pub fn from_string(signature: &str) -> Self {
         Self::from_str(signature).unwrap()
     }
 
+    /// Create a signature from a string representation
+    ///
+    /// Unlike `from_string`, this function returns an error rather than panicking if the text is
+    /// not a valid signature
+    #[wasm_bindgen(js_name = tryFromString)]
+    pub fn try_from_string(signature: &str) -> Result<Signature, String> {
+        Self::from_str(signature).map_err(|_| "Invalid signature".to_string())
+    }
+
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
         self.0.to_string()
@@ -50,7 +67,13 @@ impl FromStr for Signature {
     type Err = anyhow::Error;
 
     fn from_str(signature: &str) -> Result<Self, Self::Err> {
-        Ok(Self(SignatureNative::from_str(signature).unwrap()))
+        Ok(Self(SignatureNative::from_str(signature)?))
+    }
+}
+
+impl From<SignatureNative> for Signature {
+    fn from(signature: SignatureNative) -> Self {
+        Self(signature)
     }
 }
 
@@ -93,6 +116,25 @@ mod tests {
             let bad_message: [u8; 32] = StdRng::from_entropy().gen();
             // Check the signature is invalid.
             assert!(!signature.verify(&private_key.to_address(), &bad_message));
+
+            // Check the signer can be recovered from the signature.
+            assert_eq!(signature.to_address(), private_key.to_address());
+            assert!(private_key.to_address().verify(&message, &signature));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_signature_string_round_trip() {
+        let private_key = PrivateKey::new();
+        let message: [u8; 32] = StdRng::from_entropy().gen();
+        let signature = Signature::sign(&private_key, &message);
+
+        // Check the signature survives a round trip through its string representation.
+        let recovered = Signature::try_from_string(&signature.to_string()).unwrap();
+        assert_eq!(signature, recovered);
+        assert!(recovered.verify(&private_key.to_address(), &message));
+
+        // Check invalid signature strings produce an error.
+        assert!(Signature::try_from_string("sign1invalid").is_err());
+    }
 }
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Address, PrivateKey, Signature};
+use crate::types::{ProgramIDNative, SigningDomainNative};
+
+use core::{ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// Domain used to sign structured messages so signatures cannot be replayed across applications
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningDomain(SigningDomainNative);
+
+#[wasm_bindgen]
+impl SigningDomain {
+    /// Create a signing domain for an application origin, an optional program id, and a nonce
+    #[wasm_bindgen(constructor)]
+    pub fn new(origin: &str, program_id: Option<String>, nonce: u64) -> Result<SigningDomain, String> {
+        let program_id = program_id
+            .map(|program_id| ProgramIDNative::from_str(&program_id))
+            .transpose()
+            .map_err(|error| error.to_string())?;
+        Ok(Self(SigningDomainNative::new(origin, program_id, nonce).map_err(|error| error.to_string())?))
+    }
+
+    /// Get the application origin of the domain
+    pub fn origin(&self) -> String {
+        self.0.origin().to_string()
+    }
+
+    /// Get the program id the domain is bound to, if any
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> Option<String> {
+        self.0.program_id().map(|program_id| program_id.to_string())
+    }
+
+    /// Get the nonce of the domain
+    pub fn nonce(&self) -> u64 {
+        self.0.nonce()
+    }
+
+    /// Get the canonical bytes which are signed for a message within this domain
+    #[wasm_bindgen(js_name = encodeMessage)]
+    pub fn encode_message(&self, message: &[u8]) -> Result<Vec<u8>, String> {
+        self.0.encode_message(message).map_err(|error| error.to_string())
+    }
+
+    /// Sign a message within this domain
+    pub fn sign(&self, private_key: &PrivateKey, message: &[u8]) -> Result<Signature, String> {
+        Ok(Signature::from(self.0.sign(private_key, message).map_err(|error| error.to_string())?))
+    }
+
+    /// Verify a signature over a message within this domain was produced by an address
+    pub fn verify(&self, address: &Address, message: &[u8], signature: &Signature) -> bool {
+        self.0.verify(address, message, signature)
+    }
+}
+
+impl Deref for SigningDomain {
+    type Target = SigningDomainNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_and_verify() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let domain = SigningDomain::new("https://app.aleo.org", Some("credits.aleo".to_string()), 7).unwrap();
+        assert_eq!(domain.program_id().unwrap(), "credits.aleo");
+
+        let signature = domain.sign(&private_key, b"login").unwrap();
+        assert!(domain.verify(&address, b"login", &signature));
+        assert!(!domain.verify(&address, b"logout", &signature));
+
+        // Ensure the signature does not verify in another domain or as a raw message signature
+        let other_domain = SigningDomain::new("https://app.aleo.org", None, 7).unwrap();
+        assert!(!other_domain.verify(&address, b"login", &signature));
+        assert!(!address.verify(b"login", &signature));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_invalid_domain() {
+        assert!(SigningDomain::new("", None, 0).is_err());
+        assert!(SigningDomain::new("https://app.aleo.org", Some("not a program".to_string()), 0).is_err());
+    }
+}
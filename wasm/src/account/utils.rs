@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::str::FromStr;
+
+/// Check that a string is a valid encoding of an account object (private key, view key, etc.)
+///
+/// A human readable error describing why the string is invalid is returned so that it can be
+/// displayed directly to users (i.e. in a web form)
+pub(crate) fn validate_account_string<T: FromStr>(value: &str, prefix: &str, name: &str) -> Result<(), String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(format!("The {name} is empty"));
+    }
+    if !value.starts_with(prefix) {
+        return Err(format!("The {name} must begin with '{prefix}'"));
+    }
+    if T::from_str(value).is_err() {
+        return Err(format!("The {name} is not correctly encoded, check that it was entered or copied correctly"));
+    }
+    Ok(())
+}
@@ -14,9 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{Address, PrivateKey};
-use crate::{record::RecordCiphertext, types::ViewKeyNative};
+use super::{utils::validate_account_string, Address, PrivateKey};
+use crate::{
+    record::RecordCiphertext,
+    types::{CiphertextNative, Encryptor, ViewKeyNative},
+};
 
+use aleo_rust::wipe_view_key;
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
 
@@ -34,6 +38,17 @@ impl ViewKey {
         Self::from_str(view_key).unwrap()
     }
 
+    /// Check if a string is a valid view key
+    #[wasm_bindgen(js_name = isValid)]
+    pub fn is_valid(view_key: &str) -> bool {
+        Self::validate(view_key).is_ok()
+    }
+
+    /// Check if a string is a valid view key, returning a description of the problem if it is not
+    pub fn validate(view_key: &str) -> Result<(), String> {
+        validate_account_string::<ViewKey>(view_key, "AViewKey1", "view key")
+    }
+
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
         self.0.to_string()
@@ -50,6 +65,20 @@ impl ViewKey {
             Err(error) => Err(error),
         }
     }
+
+    /// Decrypt a message that was encrypted to the address of this view key
+    #[wasm_bindgen(js_name = decryptMessage)]
+    pub fn decrypt_message(&self, ciphertext: &str) -> Result<Vec<u8>, String> {
+        let ciphertext = CiphertextNative::from_str(ciphertext).map_err(|error| error.to_string())?;
+        Encryptor::decrypt_message_with_view_key(&ciphertext, &self.0).map_err(|error| error.to_string())
+    }
+
+    /// Overwrite the view key and release the memory it occupies
+    ///
+    /// The view key can no longer be used from JavaScript after this method is called
+    pub fn destroy(self) {
+        drop(self)
+    }
 }
 
 impl FromStr for ViewKey {
@@ -74,6 +103,12 @@ impl Deref for ViewKey {
     }
 }
 
+impl Drop for ViewKey {
+    fn drop(&mut self) {
+        let _ = wipe_view_key(&mut self.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,6 +133,18 @@ mod tests {
         assert_eq!(given_view_key, view_key.to_string());
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_validation() {
+        assert!(ViewKey::is_valid(OWNER_VIEW_KEY));
+        assert!(ViewKey::validate(OWNER_VIEW_KEY).is_ok());
+        assert_eq!(ViewKey::validate("  ").unwrap_err(), "The view key is empty");
+        assert_eq!(
+            ViewKey::validate("APrivateKey1zkp4RyQ8Utj7aRcJgPQGEok8RMzWwUZzBhhgX6rhmBT8dcP").unwrap_err(),
+            "The view key must begin with 'AViewKey1'"
+        );
+        assert!(!ViewKey::is_valid(&OWNER_VIEW_KEY[..30]));
+    }
+
     #[wasm_bindgen_test]
     pub fn test_decrypt_success() {
         let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
@@ -107,6 +154,16 @@ mod tests {
         assert_eq!(RECORD_PLAINTEXT, plaintext.unwrap())
     }
 
+    #[wasm_bindgen_test]
+    pub fn test_message_encryption() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let message = b"private message to an aleo account";
+        let ciphertext = view_key.to_address().encrypt_message(message).unwrap();
+        assert_eq!(view_key.decrypt_message(&ciphertext).unwrap(), message);
+        assert!(ViewKey::from_string(NON_OWNER_VIEW_KEY).decrypt_message(&ciphertext).is_err());
+        assert!(view_key.decrypt_message("not a ciphertext").is_err());
+    }
+
     #[wasm_bindgen_test]
     pub fn test_decrypt_fails() {
         let ciphertext = RecordCiphertext::from_str(OWNER_CIPHERTEXT).map_err(|error| error.to_string()).unwrap();
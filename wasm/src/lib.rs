@@ -74,13 +74,18 @@
 //!
 //! ### 1. NodeJS Module
 //!
-//! This module has the features of the NodeJS environment built-in. It is single-threaded and unfortunately cannot yet be
-//! used to generate Aleo program executions or deployments due to current Aleo protocol limitations. It can however still
-//! be used to perform Aleo account, record, and program management tasks.
+//! This module has the features of the NodeJS environment built-in. It is single-threaded and can be used to perform
+//! Aleo account, record, and program management tasks as well as program executions and deployments with the same
+//! `ProgramManager` API as the browser modules.
+//!
+//! Executing programs requires NodeJS 18 or later (for the global `fetch` function used to build inclusion proofs). The
+//! snarkVM parameters are downloaded with a synchronous `XMLHttpRequest`, which NodeJS does not provide, so a polyfill
+//! supporting synchronous requests such as `xmlhttprequest-ssl` must be installed on `globalThis` before executing
+//! programs (`xhr2` only supports asynchronous requests and cannot be used).
 //!
 //! #### Build Instructions
 //! ```bash
-//! wasm-pack build --release --target nodejs -- --features "serial" --no-default-features
+//! wasm-pack build --release --target nodejs -- --features "serial, nodejs" --no-default-features
 //! ```
 //!
 //! ### 2. Single-Threaded browser module
@@ -133,6 +138,22 @@
 //! -- --features "parallel, browser" -Z build-std=panic_abort,std
 //! ```
 //!
+//! ### Wallet-only module
+//!
+//! Wallets which only manage accounts and records, or which delegate proving to a remote service,
+//! do not need the program execution machinery. Building without default features excludes the
+//! snarkVM synthesizer along with the `ProgramManager`, `Program`, `Transaction` and key types,
+//! which yields a much smaller `.wasm` file containing only accounts, records, signing and
+//! decryption.
+//!
+//! #### Build Instructions
+//! ```bash
+//! wasm-pack build --release --target web -- --no-default-features
+//! ```
+//!
+//! The `programs` feature adds the program types back without the `ProgramManager`, and the
+//! `browser` feature (enabled by default) adds the `ProgramManager` as well.
+//!
 //! ## Testing
 //!
 //! Run tests in NodeJS
@@ -155,12 +176,21 @@
 pub mod account;
 pub use account::*;
 
+pub mod network;
+pub use network::*;
+
+pub mod primitives;
+pub use primitives::*;
+
 pub mod programs;
 pub use programs::*;
 
 pub mod record;
 pub use record::*;
 
+pub mod thread_pool;
+pub use thread_pool::*;
+
 pub(crate) mod types;
 
 use wasm_bindgen::prelude::*;
@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{CurrentNetwork, IdentifierNative, Network, PlaintextNative, ProgramIDNative},
+    NetworkConfig,
+    Plaintext,
+};
+
+#[cfg(feature = "programs")]
+use crate::{Program, Transaction};
+
+use aleo_rust::{Endpoint, MAX_BLOCKS_PER_REQUEST};
+use js_sys::{Array, JSON};
+use std::str::FromStr;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+/// Client for the REST API of an Aleo node
+///
+/// This wraps the endpoints of the node with typed results so applications do not need to
+/// build urls and parse responses by hand.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct AleoNetworkClient {
+    network: NetworkConfig,
+}
+
+#[wasm_bindgen]
+impl AleoNetworkClient {
+    /// Create a new client for an Aleo node
+    ///
+    /// @param node_url The base url of the Aleo node (i.e. "https://vm.aleo.org/api")
+    /// @param network (optional) The network to target (default "testnet3")
+    #[wasm_bindgen(constructor)]
+    pub fn new(node_url: String, network: Option<String>) -> Result<AleoNetworkClient, String> {
+        Ok(Self { network: NetworkConfig::new(node_url, network, None)? })
+    }
+
+    /// Create a new client from a network configuration
+    #[wasm_bindgen(js_name = "fromNetworkConfig")]
+    pub fn from_network_config(network_config: &NetworkConfig) -> AleoNetworkClient {
+        Self { network: network_config.clone() }
+    }
+
+    /// Get the height of the latest block
+    #[wasm_bindgen(js_name = "getLatestHeight")]
+    pub async fn get_latest_height(&self) -> Result<u32, String> {
+        let response = self.get(&Endpoint::LatestHeight).await?;
+        response.trim().parse::<u32>().map_err(|_| format!("The node returned an invalid height: {response}"))
+    }
+
+    /// Get the block at a height as a javascript object
+    ///
+    /// @param height The height of the block
+    #[wasm_bindgen(js_name = "getBlock")]
+    pub async fn get_block(&self, height: u32) -> Result<JsValue, String> {
+        let response = self.get(&Endpoint::Block(height)).await?;
+        parse_json(&response, "block")
+    }
+
+    /// Get the blocks in a range of heights as an array of javascript objects
+    ///
+    /// @param start The height of the first block (inclusive)
+    /// @param end The height of the last block (exclusive), at most 50 blocks after the start
+    #[wasm_bindgen(js_name = "getBlockRange")]
+    pub async fn get_block_range(&self, start: u32, end: u32) -> Result<Array, String> {
+        validate_block_range(start, end)?;
+        let response = self.get(&Endpoint::Blocks(start..end)).await?;
+        parse_json(&response, "blocks").map(Array::from)
+    }
+
+    /// Get a confirmed transaction by its id
+    ///
+    /// @param transaction_id The id of the transaction (i.e. "at1...")
+    #[cfg(feature = "programs")]
+    #[wasm_bindgen(js_name = "getTransaction")]
+    pub async fn get_transaction(&self, transaction_id: String) -> Result<Transaction, String> {
+        let transaction_id = <CurrentNetwork as Network>::TransactionID::from_str(&transaction_id)
+            .map_err(|_| format!("Invalid transaction id {transaction_id}"))?;
+        let response = self.get(&Endpoint::Transaction(transaction_id)).await?;
+        Transaction::from_string(&response)
+    }
+
+    /// Get the source code of a program deployed on chain
+    ///
+    /// @param program_id The id of the program (i.e. "credits.aleo")
+    #[cfg(feature = "programs")]
+    #[wasm_bindgen(js_name = "getProgram")]
+    pub async fn get_program(&self, program_id: String) -> Result<Program, String> {
+        let program_id = Self::parse_program_id(&program_id)?;
+        let response = self.get(&Endpoint::Program(program_id)).await?;
+        let source = serde_json::from_str::<String>(&response).unwrap_or(response);
+        Program::from_string(&source)
+    }
+
+    /// Get the id of the transaction that deployed a program
+    ///
+    /// @param program_id The id of the program (i.e. "credits.aleo")
+    #[wasm_bindgen(js_name = "getDeploymentTransactionId")]
+    pub async fn get_deployment_transaction_id(&self, program_id: String) -> Result<String, String> {
+        let program_id = Self::parse_program_id(&program_id)?;
+        let response = self.get(&Endpoint::FindDeploymentTransactionID(program_id)).await?;
+        Ok(serde_json::from_str::<String>(&response).unwrap_or(response))
+    }
+
+    /// Get the transaction that deployed a program
+    ///
+    /// @param program_id The id of the program (i.e. "credits.aleo")
+    #[cfg(feature = "programs")]
+    #[wasm_bindgen(js_name = "getDeploymentTransaction")]
+    pub async fn get_deployment_transaction(&self, program_id: String) -> Result<Transaction, String> {
+        let transaction_id = self.get_deployment_transaction_id(program_id).await?;
+        self.get_transaction(transaction_id).await
+    }
+
+    /// Get the names of the mappings of a program deployed on chain
+    ///
+    /// @param program_id The id of the program (i.e. "credits.aleo")
+    #[wasm_bindgen(js_name = "getMappingNames")]
+    pub async fn get_mapping_names(&self, program_id: String) -> Result<Array, String> {
+        let program_id = Self::parse_program_id(&program_id)?;
+        let response = self.get(&Endpoint::MappingNames(program_id)).await?;
+        let names = serde_json::from_str::<Vec<String>>(&response)
+            .map_err(|_| format!("The node returned invalid mapping names: {response}"))?;
+        Ok(names.iter().map(|name| JsValue::from_str(name)).collect())
+    }
+
+    /// Get the value stored under a key in a mapping of a program deployed on chain
+    ///
+    /// Resolves with undefined if the key is not present in the mapping.
+    ///
+    /// @param program_id The id of the program (i.e. "credits.aleo")
+    /// @param mapping_name The name of the mapping (i.e. "account")
+    /// @param key The key as a plaintext string (i.e. "aleo1...")
+    #[wasm_bindgen(js_name = "getMappingValue")]
+    pub async fn get_mapping_value(
+        &self,
+        program_id: String,
+        mapping_name: String,
+        key: String,
+    ) -> Result<Option<Plaintext>, String> {
+        let program_id = Self::parse_program_id(&program_id)?;
+        let mapping_name =
+            IdentifierNative::from_str(&mapping_name).map_err(|_| format!("Invalid mapping name {mapping_name}"))?;
+        let key = PlaintextNative::from_str(&key).map_err(|_| format!("Invalid mapping key {key}"))?;
+
+        let response = self.get(&Endpoint::MappingValue(program_id, mapping_name, key)).await?;
+        match serde_json::from_str::<Option<String>>(&response) {
+            Ok(Some(value)) => Plaintext::from_string(&value).map(Some),
+            Ok(None) => Ok(None),
+            Err(_) => Err(format!("The node returned an invalid mapping value: {response}")),
+        }
+    }
+}
+
+impl AleoNetworkClient {
+    /// Send a GET request to an endpoint of the configured network and return the response body
+    pub(crate) async fn get(&self, endpoint: &Endpoint<CurrentNetwork>) -> Result<String, String> {
+        let url = self.network.endpoint(endpoint);
+        let response = reqwest::get(&url).await.map_err(|err| format!("Failed to reach {url}: {err}"))?;
+        let status = response.status();
+        let text = response.text().await.map_err(|err| err.to_string())?;
+        if !status.is_success() {
+            return Err(format!("The request to {url} failed with {status}: {text}"));
+        }
+        Ok(text)
+    }
+
+    // Parse and validate a program id
+    fn parse_program_id(program_id: &str) -> Result<ProgramIDNative, String> {
+        ProgramIDNative::from_str(program_id).map_err(|_| format!("Invalid program id {program_id}"))
+    }
+}
+
+/// Parse a JSON response into a javascript value
+fn parse_json(response: &str, name: &str) -> Result<JsValue, String> {
+    JSON::parse(response).map_err(|_| format!("The node returned an invalid {name} response"))
+}
+
+/// Ensure a block range is non-empty and within the limit of a single request
+fn validate_block_range(start: u32, end: u32) -> Result<(), String> {
+    if start >= end {
+        return Err("The start of the block range must be lower than the end".to_string());
+    }
+    if end - start > MAX_BLOCKS_PER_REQUEST {
+        return Err(format!("At most {MAX_BLOCKS_PER_REQUEST} blocks can be requested at once"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_validate_block_range() {
+        assert!(validate_block_range(0, 50).is_ok());
+        assert!(validate_block_range(10, 11).is_ok());
+        assert!(validate_block_range(10, 10).is_err());
+        assert!(validate_block_range(11, 10).is_err());
+        assert!(validate_block_range(0, 51).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_invalid_mapping_queries() {
+        let client = AleoNetworkClient::new("http://127.0.0.1:1".to_string(), None).unwrap();
+        let result = client.get_mapping_names("credits".to_string()).await;
+        assert_eq!(result.unwrap_err(), "Invalid program id credits");
+
+        let result =
+            client.get_mapping_value("credits.aleo".to_string(), "1account".to_string(), "1u8".to_string()).await;
+        assert_eq!(result.unwrap_err(), "Invalid mapping name 1account");
+
+        let result = client.get_mapping_value("credits.aleo".to_string(), "account".to_string(), "1".to_string()).await;
+        assert_eq!(result.unwrap_err(), "Invalid mapping key 1");
+
+        // Ensure unreachable nodes are reported
+        let result = client.get_mapping_names("credits.aleo".to_string()).await;
+        assert!(result
+            .unwrap_err()
+            .starts_with("Failed to reach http://127.0.0.1:1/testnet3/program/credits.aleo/mappings"));
+    }
+}
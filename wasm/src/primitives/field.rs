@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::{FieldNative, FromBytes, One, ToBytes, Zero};
+
+use core::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// An element of the base field of the Aleo curve, the `field` type of Aleo programs
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Field(FieldNative);
+
+#[wasm_bindgen]
+impl Field {
+    /// Parse a field element from a string (i.e. "1field")
+    #[wasm_bindgen(js_name = "fromString")]
+    pub fn from_string(field: &str) -> Result<Field, String> {
+        Self::from_str(field).map_err(|_| format!("Invalid field element {field}"))
+    }
+
+    /// Get the string representation of the field element
+    #[wasm_bindgen(js_name = "toString")]
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Create a field element from its little endian byte representation
+    #[wasm_bindgen(js_name = "fromBytesLe")]
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Field, String> {
+        FieldNative::from_bytes_le(bytes).map(Self).map_err(|e| e.to_string())
+    }
+
+    /// Get the little endian byte representation of the field element
+    #[wasm_bindgen(js_name = "toBytesLe")]
+    pub fn to_bytes_le(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|e| e.to_string())
+    }
+
+    /// Get the additive identity
+    pub fn zero() -> Field {
+        Self(FieldNative::zero())
+    }
+
+    /// Get the multiplicative identity
+    pub fn one() -> Field {
+        Self(FieldNative::one())
+    }
+
+    /// Add another field element to this one
+    pub fn add(&self, other: &Field) -> Field {
+        Self(self.0 + other.0)
+    }
+
+    /// Subtract another field element from this one
+    pub fn subtract(&self, other: &Field) -> Field {
+        Self(self.0 - other.0)
+    }
+
+    /// Multiply this field element by another one
+    pub fn multiply(&self, other: &Field) -> Field {
+        Self(self.0 * other.0)
+    }
+
+    /// Check if this field element is equal to another one
+    pub fn equals(&self, other: &Field) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<FieldNative> for Field {
+    fn from(field: FieldNative) -> Self {
+        Self(field)
+    }
+}
+
+impl From<Field> for FieldNative {
+    fn from(field: Field) -> Self {
+        field.0
+    }
+}
+
+impl FromStr for Field {
+    type Err = anyhow::Error;
+
+    fn from_str(field: &str) -> Result<Self, Self::Err> {
+        Ok(Self(FieldNative::from_str(field)?))
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for Field {
+    type Target = FieldNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_field_arithmetic() {
+        let two = Field::one().add(&Field::one());
+        assert_eq!(two.to_string(), "2field");
+        assert_eq!(two.multiply(&two), Field::from_string("4field").unwrap());
+        assert!(two.subtract(&two).equals(&Field::zero()));
+        assert!(Field::from_string("2scalar").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_field_bytes_roundtrip() {
+        let field = Field::from_string("12345field").unwrap();
+        let bytes = field.to_bytes_le().unwrap();
+        assert_eq!(Field::from_bytes_le(&bytes).unwrap(), field);
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{FromBytes, GroupNative, ToBytes, Zero},
+    Field,
+    Scalar,
+};
+
+use core::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// A point on the Aleo curve, the `group` type of Aleo programs
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Group(GroupNative);
+
+#[wasm_bindgen]
+impl Group {
+    /// Parse a group element from a string (i.e. "0group")
+    #[wasm_bindgen(js_name = "fromString")]
+    pub fn from_string(group: &str) -> Result<Group, String> {
+        Self::from_str(group).map_err(|_| format!("Invalid group element {group}"))
+    }
+
+    /// Get the string representation of the group element
+    #[wasm_bindgen(js_name = "toString")]
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Create a group element from its little endian byte representation
+    #[wasm_bindgen(js_name = "fromBytesLe")]
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Group, String> {
+        GroupNative::from_bytes_le(bytes).map(Self).map_err(|e| e.to_string())
+    }
+
+    /// Get the little endian byte representation of the group element
+    #[wasm_bindgen(js_name = "toBytesLe")]
+    pub fn to_bytes_le(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|e| e.to_string())
+    }
+
+    /// Get the identity element
+    pub fn zero() -> Group {
+        Self(GroupNative::zero())
+    }
+
+    /// Get the generator of the group
+    pub fn generator() -> Group {
+        Self(GroupNative::generator())
+    }
+
+    /// Add another group element to this one
+    pub fn add(&self, other: &Group) -> Group {
+        Self(self.0 + other.0)
+    }
+
+    /// Subtract another group element from this one
+    pub fn subtract(&self, other: &Group) -> Group {
+        Self(self.0 - other.0)
+    }
+
+    /// Multiply this group element by a scalar
+    #[wasm_bindgen(js_name = "scalarMultiply")]
+    pub fn scalar_multiply(&self, scalar: &Scalar) -> Group {
+        Self(self.0 * **scalar)
+    }
+
+    /// Get the x-coordinate of the group element
+    #[wasm_bindgen(js_name = "toXCoordinate")]
+    pub fn to_x_coordinate(&self) -> Field {
+        Field::from(self.0.to_x_coordinate())
+    }
+
+    /// Check if this group element is equal to another one
+    pub fn equals(&self, other: &Group) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<GroupNative> for Group {
+    fn from(group: GroupNative) -> Self {
+        Self(group)
+    }
+}
+
+impl From<Group> for GroupNative {
+    fn from(group: Group) -> Self {
+        group.0
+    }
+}
+
+impl FromStr for Group {
+    type Err = anyhow::Error;
+
+    fn from_str(group: &str) -> Result<Self, Self::Err> {
+        Ok(Self(GroupNative::from_str(group)?))
+    }
+}
+
+impl fmt::Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for Group {
+    type Target = GroupNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_group_arithmetic() {
+        let generator = Group::generator();
+        let two = Scalar::one().add(&Scalar::one());
+        assert_eq!(generator.scalar_multiply(&two), generator.add(&generator));
+        assert!(generator.subtract(&generator).equals(&Group::zero()));
+        assert_eq!(Group::zero().to_string(), "0group");
+
+        let bytes = generator.to_bytes_le().unwrap();
+        assert_eq!(Group::from_bytes_le(&bytes).unwrap(), generator);
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{CurrentNetwork, Network, ToBits, ToFields},
+    Field,
+    Plaintext,
+};
+
+use wasm_bindgen::prelude::*;
+
+// Define a hash function which hashes the field elements of a plaintext value, as done by the
+// `hash.psd*` instructions of Aleo programs
+macro_rules! poseidon_hash {
+    ($name:ident, $hash:ident, $instruction:literal) => {
+        #[doc = concat!("The Poseidon hash function used by the `", $instruction, "` instruction")]
+        #[wasm_bindgen]
+        pub struct $name;
+
+        #[wasm_bindgen]
+        impl $name {
+            #[doc = concat!("Hash a plaintext value as done by `", $instruction, "`")]
+            pub fn hash(input: &Plaintext) -> Result<Field, String> {
+                let fields = input.to_fields().map_err(|e| e.to_string())?;
+                CurrentNetwork::$hash(&fields).map(Field::from).map_err(|e| e.to_string())
+            }
+        }
+    };
+}
+
+// Define a hash function which hashes the bits of a plaintext value, as done by the `hash.bhp*`
+// instructions of Aleo programs
+macro_rules! bhp_hash {
+    ($name:ident, $hash:ident, $instruction:literal) => {
+        #[doc = concat!("The BHP hash function used by the `", $instruction, "` instruction")]
+        #[wasm_bindgen]
+        #[allow(clippy::upper_case_acronyms)]
+        pub struct $name;
+
+        #[wasm_bindgen]
+        impl $name {
+            #[doc = concat!("Hash a plaintext value as done by `", $instruction, "`")]
+            pub fn hash(input: &Plaintext) -> Result<Field, String> {
+                CurrentNetwork::$hash(&input.to_bits_le()).map(Field::from).map_err(|e| e.to_string())
+            }
+        }
+    };
+}
+
+poseidon_hash!(Poseidon2, hash_psd2, "hash.psd2");
+poseidon_hash!(Poseidon4, hash_psd4, "hash.psd4");
+poseidon_hash!(Poseidon8, hash_psd8, "hash.psd8");
+
+bhp_hash!(BHP256, hash_bhp256, "hash.bhp256");
+bhp_hash!(BHP512, hash_bhp512, "hash.bhp512");
+bhp_hash!(BHP768, hash_bhp768, "hash.bhp768");
+bhp_hash!(BHP1024, hash_bhp1024, "hash.bhp1024");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PrivateKey, ProgramManager};
+
+    use js_sys::Array;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::*;
+
+    const ADDRESS: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+
+    const HASH_PROGRAM: &str = r"program hash_vectors.aleo;
+
+function hashes:
+    input r0 as address.private;
+    hash.psd2 r0 into r1;
+    hash.psd4 r0 into r2;
+    hash.psd8 r0 into r3;
+    hash.bhp256 r0 into r4;
+    hash.bhp512 r0 into r5;
+    hash.bhp768 r0 into r6;
+    hash.bhp1024 r0 into r7;
+    output r1 as field.private;
+    output r2 as field.private;
+    output r3 as field.private;
+    output r4 as field.private;
+    output r5 as field.private;
+    output r6 as field.private;
+    output r7 as field.private;
+";
+
+    #[wasm_bindgen_test]
+    fn test_hashes_match_hash_instructions() {
+        // The digests computed by the hash instructions of the VM are the known answers
+        let inputs = Array::new();
+        inputs.push(&JsValue::from_str(ADDRESS));
+        let response = ProgramManager::new()
+            .execute_local(
+                PrivateKey::new(),
+                HASH_PROGRAM.to_string(),
+                "hashes".to_string(),
+                inputs,
+                false,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let expected =
+            response.get_outputs().to_vec().iter().map(|output| output.as_string().unwrap()).collect::<Vec<_>>();
+
+        let input = Plaintext::from_string(ADDRESS).unwrap();
+        let digests = [
+            Poseidon2::hash(&input).unwrap(),
+            Poseidon4::hash(&input).unwrap(),
+            Poseidon8::hash(&input).unwrap(),
+            BHP256::hash(&input).unwrap(),
+            BHP512::hash(&input).unwrap(),
+            BHP768::hash(&input).unwrap(),
+            BHP1024::hash(&input).unwrap(),
+        ];
+        assert_eq!(digests.iter().map(Field::to_string).collect::<Vec<_>>(), expected);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_hashes_are_deterministic() {
+        let input = Plaintext::from_string(ADDRESS).unwrap();
+        let other = Plaintext::from_string("1u64").unwrap();
+
+        let hashes: [fn(&Plaintext) -> Result<Field, String>; 7] = [
+            Poseidon2::hash,
+            Poseidon4::hash,
+            Poseidon8::hash,
+            BHP256::hash,
+            BHP512::hash,
+            BHP768::hash,
+            BHP1024::hash,
+        ];
+        for hash in hashes {
+            let digest = hash(&input).unwrap();
+            assert_eq!(digest, hash(&input).unwrap());
+            assert_ne!(digest, hash(&other).unwrap());
+        }
+        assert_ne!(Poseidon2::hash(&input).unwrap(), Poseidon4::hash(&input).unwrap());
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::{FromBytes, One, ScalarNative, ToBytes, Zero};
+
+use core::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// An element of the scalar field of the Aleo curve, the `scalar` type of Aleo programs
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Scalar(ScalarNative);
+
+#[wasm_bindgen]
+impl Scalar {
+    /// Parse a scalar from a string (i.e. "1scalar")
+    #[wasm_bindgen(js_name = "fromString")]
+    pub fn from_string(scalar: &str) -> Result<Scalar, String> {
+        Self::from_str(scalar).map_err(|_| format!("Invalid scalar {scalar}"))
+    }
+
+    /// Get the string representation of the scalar
+    #[wasm_bindgen(js_name = "toString")]
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Create a scalar from its little endian byte representation
+    #[wasm_bindgen(js_name = "fromBytesLe")]
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Scalar, String> {
+        ScalarNative::from_bytes_le(bytes).map(Self).map_err(|e| e.to_string())
+    }
+
+    /// Get the little endian byte representation of the scalar
+    #[wasm_bindgen(js_name = "toBytesLe")]
+    pub fn to_bytes_le(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|e| e.to_string())
+    }
+
+    /// Get the additive identity
+    pub fn zero() -> Scalar {
+        Self(ScalarNative::zero())
+    }
+
+    /// Get the multiplicative identity
+    pub fn one() -> Scalar {
+        Self(ScalarNative::one())
+    }
+
+    /// Add another scalar to this one
+    pub fn add(&self, other: &Scalar) -> Scalar {
+        Self(self.0 + other.0)
+    }
+
+    /// Subtract another scalar from this one
+    pub fn subtract(&self, other: &Scalar) -> Scalar {
+        Self(self.0 - other.0)
+    }
+
+    /// Multiply this scalar by another one
+    pub fn multiply(&self, other: &Scalar) -> Scalar {
+        Self(self.0 * other.0)
+    }
+
+    /// Check if this scalar is equal to another one
+    pub fn equals(&self, other: &Scalar) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl From<ScalarNative> for Scalar {
+    fn from(scalar: ScalarNative) -> Self {
+        Self(scalar)
+    }
+}
+
+impl From<Scalar> for ScalarNative {
+    fn from(scalar: Scalar) -> Self {
+        scalar.0
+    }
+}
+
+impl FromStr for Scalar {
+    type Err = anyhow::Error;
+
+    fn from_str(scalar: &str) -> Result<Self, Self::Err> {
+        Ok(Self(ScalarNative::from_str(scalar)?))
+    }
+}
+
+impl fmt::Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for Scalar {
+    type Target = ScalarNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_scalar_arithmetic() {
+        let two = Scalar::one().add(&Scalar::one());
+        assert_eq!(two.to_string(), "2scalar");
+        assert_eq!(two.multiply(&two), Scalar::from_string("4scalar").unwrap());
+        assert!(two.subtract(&two).equals(&Scalar::zero()));
+
+        let bytes = two.to_bytes_le().unwrap();
+        assert_eq!(Scalar::from_bytes_le(&bytes).unwrap(), two);
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::AuthorizationNative;
+
+use std::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Webassembly Representation of an Aleo function authorization
+///
+/// An authorization holds the signed requests needed to execute a function and contains no
+/// secrets, so it can be created on a signing device (i.e. an air-gapped machine holding the
+/// private key) and then transferred as a string to another device which generates the proof.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Authorization(AuthorizationNative);
+
+#[wasm_bindgen]
+impl Authorization {
+    /// Create an authorization from a string
+    #[wasm_bindgen(js_name = "fromString")]
+    pub fn from_string(authorization: &str) -> Result<Authorization, String> {
+        Self::from_str(authorization).map_err(|err| err.to_string())
+    }
+
+    /// Get the authorization as a string so it can be transferred to a proving device
+    #[wasm_bindgen(js_name = "toString")]
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Get the number of function calls (including calls to imported functions) authorized
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if the authorization contains no function calls
+    #[wasm_bindgen(js_name = "isEmpty")]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Deref for Authorization {
+    type Target = AuthorizationNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<AuthorizationNative> for Authorization {
+    fn from(authorization: AuthorizationNative) -> Self {
+        Self(authorization)
+    }
+}
+
+impl From<Authorization> for AuthorizationNative {
+    fn from(authorization: Authorization) -> Self {
+        authorization.0
+    }
+}
+
+impl FromStr for Authorization {
+    type Err = anyhow::Error;
+
+    fn from_str(authorization: &str) -> Result<Self, Self::Err> {
+        Ok(Self(AuthorizationNative::from_str(authorization)?))
+    }
+}
+
+impl fmt::Display for Authorization {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_invalid_authorization_string() {
+        assert!(Authorization::from_string("").is_err());
+        assert!(Authorization::from_string("not an authorization").is_err());
+    }
+}
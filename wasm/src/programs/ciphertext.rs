@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{CiphertextNative, FieldNative, IdentifierNative, ProgramIDNative},
+    Field,
+    Plaintext,
+};
+
+use aleo_rust::transition::value_key;
+use std::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Webassembly Representation of an Aleo ciphertext
+///
+/// Private inputs and outputs of transitions are stored on-chain as ciphertexts encrypted under a
+/// symmetric key derived from the transition view key. Parties to a transition which know this key
+/// can decrypt them without needing the view key of the owner.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ciphertext(CiphertextNative);
+
+#[wasm_bindgen]
+impl Ciphertext {
+    /// Create a ciphertext from a string (i.e. "ciphertext1...")
+    #[wasm_bindgen(js_name = "fromString")]
+    pub fn from_string(ciphertext: &str) -> Result<Ciphertext, String> {
+        Self::from_str(ciphertext).map_err(|_| "The ciphertext string provided was invalid".to_string())
+    }
+
+    /// Get the ciphertext as a string
+    #[wasm_bindgen(js_name = "toString")]
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Encrypt a private input or output of a transition the way the caller of the transition does
+    ///
+    /// The symmetric key is derived from the transition view key, the function of the transition
+    /// and the index of the value, which counts the inputs of the transition followed by its
+    /// outputs.
+    ///
+    /// @param plaintext The plaintext to encrypt
+    /// @param transition_view_key The transition view key of the transition
+    /// @param program_id The program of the transition (i.e. "hello.aleo")
+    /// @param function_name The function of the transition (i.e. "hello")
+    /// @param index The index of the value among the inputs and outputs of the transition
+    #[wasm_bindgen(js_name = "encryptSymmetric")]
+    pub fn encrypt_symmetric(
+        plaintext: &Plaintext,
+        transition_view_key: &Field,
+        program_id: &str,
+        function_name: &str,
+        index: u16,
+    ) -> Result<Ciphertext, String> {
+        let key = Self::value_key(transition_view_key, program_id, function_name, index)?;
+        plaintext.encrypt_symmetric(key).map(Self).map_err(|e| e.to_string())
+    }
+
+    /// Decrypt a private input or output of a transition with its transition view key
+    ///
+    /// @param transition_view_key The transition view key of the transition
+    /// @param program_id The program of the transition (i.e. "hello.aleo")
+    /// @param function_name The function of the transition (i.e. "hello")
+    /// @param index The index of the value among the inputs and outputs of the transition
+    #[wasm_bindgen(js_name = "decryptSymmetric")]
+    pub fn decrypt_symmetric(
+        &self,
+        transition_view_key: &Field,
+        program_id: &str,
+        function_name: &str,
+        index: u16,
+    ) -> Result<Plaintext, String> {
+        let key = Self::value_key(transition_view_key, program_id, function_name, index)?;
+        self.0.decrypt_symmetric(key).map(Plaintext::from).map_err(|e| e.to_string())
+    }
+}
+
+impl Ciphertext {
+    // Derive the symmetric key of the value at an index of a transition
+    fn value_key(
+        transition_view_key: &Field,
+        program_id: &str,
+        function_name: &str,
+        index: u16,
+    ) -> Result<FieldNative, String> {
+        let program_id =
+            ProgramIDNative::from_str(program_id).map_err(|_| "Invalid ProgramID specified".to_string())?;
+        let function_name = IdentifierNative::from_str(function_name)
+            .map_err(|_| "The function name provided was invalid".to_string())?;
+        value_key(**transition_view_key, &program_id, &function_name, usize::from(index)).map_err(|e| e.to_string())
+    }
+}
+
+impl Deref for Ciphertext {
+    type Target = CiphertextNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<CiphertextNative> for Ciphertext {
+    fn from(ciphertext: CiphertextNative) -> Self {
+        Self(ciphertext)
+    }
+}
+
+impl From<Ciphertext> for CiphertextNative {
+    fn from(ciphertext: Ciphertext) -> Self {
+        ciphertext.0
+    }
+}
+
+impl FromStr for Ciphertext {
+    type Err = anyhow::Error;
+
+    fn from_str(ciphertext: &str) -> Result<Self, Self::Err> {
+        Ok(Self(CiphertextNative::from_str(ciphertext)?))
+    }
+}
+
+impl fmt::Display for Ciphertext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const HELLO_PROGRAM: &str = "program hello.aleo;
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+";
+
+    #[wasm_bindgen_test]
+    fn test_symmetric_encryption_roundtrip() {
+        let plaintext = Plaintext::from_string("{ amount: 100u64, memo: 5field }").unwrap();
+        let key = Field::from_string("1234567field").unwrap();
+
+        let ciphertext = Ciphertext::encrypt_symmetric(&plaintext, &key, "hello.aleo", "hello", 1).unwrap();
+        let ciphertext = Ciphertext::from_string(&ciphertext.to_string()).unwrap();
+        assert_eq!(ciphertext.decrypt_symmetric(&key, "hello.aleo", "hello", 1).unwrap(), plaintext);
+
+        // Decrypting with a different key or index does not recover the plaintext
+        let other_key = Field::from_string("7654321field").unwrap();
+        assert_ne!(ciphertext.decrypt_symmetric(&other_key, "hello.aleo", "hello", 1).ok(), Some(plaintext.clone()));
+        assert_ne!(ciphertext.decrypt_symmetric(&key, "hello.aleo", "hello", 2).ok(), Some(plaintext));
+    }
+
+    #[cfg(feature = "programs")]
+    #[wasm_bindgen_test]
+    fn test_decrypt_transition_values() {
+        use crate::types::{CurrentAleo, PrivateKeyNative, ProcessNative, ProgramNative, ViewKeyNative};
+        use aleo_rust::transition::transition_view_key;
+        use rand::{rngs::StdRng, SeedableRng};
+        use snarkvm_synthesizer::{Input, Output};
+
+        let rng = &mut StdRng::from_entropy();
+        let private_key = PrivateKeyNative::new(rng).unwrap();
+        let view_key = ViewKeyNative::try_from(&private_key).unwrap();
+
+        // Execute the hello function to obtain a transition with a private input and output
+        let mut process = ProcessNative::load_web().unwrap();
+        let program = ProgramNative::from_str(HELLO_PROGRAM).unwrap();
+        process.add_program(&program).unwrap();
+        let function_name = IdentifierNative::from_str("hello").unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, program.id(), function_name, ["5u32", "3u32"].iter(), rng)
+            .unwrap();
+        let (_, trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        let transition = &trace.transitions()[0];
+        let tvk = Field::from(transition_view_key(&view_key, transition.tpk(), transition.tcm()).unwrap().unwrap());
+
+        let Input::Private(_, Some(input)) = &transition.inputs()[1] else { panic!("The input is not private") };
+        let input = Ciphertext::from(input.clone());
+        assert_eq!(input.decrypt_symmetric(&tvk, "hello.aleo", "hello", 1).unwrap().to_string(), "3u32");
+
+        // Outputs are indexed after the inputs
+        let Output::Private(_, Some(output)) = &transition.outputs()[0] else { panic!("The output is not private") };
+        let output = Ciphertext::from(output.clone());
+        assert_eq!(output.decrypt_symmetric(&tvk, "hello.aleo", "hello", 2).unwrap().to_string(), "8u32");
+    }
+}
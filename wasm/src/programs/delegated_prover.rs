@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Authorization, Transaction};
+
+use std::str::FromStr;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Client for a remote proving service
+///
+/// Devices which cannot generate proofs in a reasonable amount of time (i.e. mobile browsers)
+/// can create an `Authorization` locally with `ProgramManager.authorize` and send it to a remote
+/// prover, which executes and proves it and returns the completed transaction. Authorizations
+/// contain no secrets, so the private key never leaves the device.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct DelegatedProver {
+    url: String,
+    api_key: Option<String>,
+}
+
+#[wasm_bindgen]
+impl DelegatedProver {
+    /// Create a new delegated prover client
+    ///
+    /// @param url The url of the remote prover endpoint
+    /// @param api_key (optional) An API key sent to the prover as a bearer token
+    #[wasm_bindgen(constructor)]
+    pub fn new(url: String, api_key: Option<String>) -> Self {
+        Self { url, api_key }
+    }
+
+    /// Send an authorization to the remote prover and wait for the proven transaction
+    ///
+    /// The request body is a JSON object of the form
+    /// `{"authorization": <authorization>, "fee_authorization": <authorization or null>}` and
+    /// the prover is expected to respond with the JSON of the resulting transaction.
+    ///
+    /// @param authorization The authorization of the function execution
+    /// @param fee_authorization (optional) The authorization of the fee execution
+    pub async fn prove(
+        &self,
+        authorization: Authorization,
+        fee_authorization: Option<Authorization>,
+    ) -> Result<Transaction, String> {
+        let body = request_body(&authorization.to_string(), fee_authorization.map(|fee| fee.to_string()).as_deref())?;
+        let mut request = reqwest::Client::new().post(&self.url).header("Content-Type", "application/json").body(body);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request.send().await.map_err(|err| format!("Failed to reach the remote prover: {err}"))?;
+        let status = response.status();
+        let text = response.text().await.map_err(|err| err.to_string())?;
+        if !status.is_success() {
+            return Err(format!("The remote prover returned {status}: {text}"));
+        }
+        Transaction::from_str(&text).map_err(|err| format!("The remote prover returned an invalid transaction: {err}"))
+    }
+}
+
+/// Build the JSON body of a proving request
+fn request_body(authorization: &str, fee_authorization: Option<&str>) -> Result<String, String> {
+    let parse =
+        |authorization: &str| serde_json::from_str::<serde_json::Value>(authorization).map_err(|err| err.to_string());
+    let fee_authorization = fee_authorization.map(parse).transpose()?.unwrap_or(serde_json::Value::Null);
+    let body = serde_json::json!({
+        "authorization": parse(authorization)?,
+        "fee_authorization": fee_authorization,
+    });
+    Ok(body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_request_body() {
+        let body = request_body(r#"{"requests":[1]}"#, None).unwrap();
+        assert_eq!(body, r#"{"authorization":{"requests":[1]},"fee_authorization":null}"#);
+
+        let body = request_body(r#"{"requests":[1]}"#, Some(r#"{"requests":[2]}"#)).unwrap();
+        assert_eq!(body, r#"{"authorization":{"requests":[1]},"fee_authorization":{"requests":[2]}}"#);
+
+        assert!(request_body("not json", None).is_err());
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AleoNetwork, KeyPair, ProvingKey, VerifyingKey};
+
+use aleo_rust::keys::credits_key_files;
+
+use futures_util::StreamExt;
+use js_sys::Function;
+use sha2::{Digest, Sha256};
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+/// Downloader for the proving and verifying keys of the credits.aleo functions
+///
+/// Keys are streamed from the parameter server in chunks, an optional progress callback is
+/// invoked after each chunk, and the downloaded bytes can be checked against a SHA-256 checksum
+/// before they are deserialized.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct KeyDownloader {
+    base_url: String,
+    on_progress: Option<Function>,
+}
+
+#[wasm_bindgen]
+impl KeyDownloader {
+    /// Create a new key downloader
+    ///
+    /// @param base_url (optional) The url of the parameter server, defaults to the official Aleo
+    /// parameter server
+    /// @param on_progress (optional) A callback invoked as `onProgress(bytesDownloaded, totalBytes)`
+    /// after each chunk is received. `totalBytes` is undefined if the server does not report it
+    #[wasm_bindgen(constructor)]
+    pub fn new(base_url: Option<String>, on_progress: Option<Function>) -> Self {
+        let base_url = base_url
+            .unwrap_or_else(|| AleoNetwork::Testnet3.parameters_url().to_string())
+            .trim_end_matches('/')
+            .to_string();
+        Self { base_url, on_progress }
+    }
+
+    /// Download the raw bytes at a url, optionally verifying them against a SHA-256 checksum
+    ///
+    /// @param url The url to download
+    /// @param checksum (optional) The expected hex encoded SHA-256 checksum of the bytes
+    #[wasm_bindgen(js_name = "downloadBytes")]
+    pub async fn download_bytes(&self, url: String, checksum: Option<String>) -> Result<Vec<u8>, String> {
+        let response = reqwest::get(&url).await.map_err(|err| err.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("Failed to download {url}: {}", response.status()));
+        }
+
+        let total = response.content_length();
+        let mut bytes = Vec::with_capacity(total.unwrap_or_default() as usize);
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            bytes.extend_from_slice(&chunk.map_err(|err| err.to_string())?);
+            self.report_progress(bytes.len() as u64, total)?;
+        }
+
+        if let Some(checksum) = checksum {
+            verify_checksum(&bytes, &checksum)?;
+        }
+        Ok(bytes)
+    }
+
+    /// Download the proving key of a credits.aleo function
+    ///
+    /// @param function The name of the credits.aleo function (i.e. "transfer")
+    /// @param checksum (optional) The expected hex encoded SHA-256 checksum of the key
+    #[wasm_bindgen(js_name = "downloadProvingKey")]
+    pub async fn download_proving_key(&self, function: String, checksum: Option<String>) -> Result<ProvingKey, String> {
+        let (prover, _) = credits_key_files(&function).map_err(|err| err.to_string())?;
+        let bytes = self.download_bytes(format!("{}/{prover}", self.base_url), checksum).await?;
+        ProvingKey::from_bytes(&bytes)
+    }
+
+    /// Download the verifying key of a credits.aleo function
+    ///
+    /// @param function The name of the credits.aleo function (i.e. "transfer")
+    /// @param checksum (optional) The expected hex encoded SHA-256 checksum of the key
+    #[wasm_bindgen(js_name = "downloadVerifyingKey")]
+    pub async fn download_verifying_key(
+        &self,
+        function: String,
+        checksum: Option<String>,
+    ) -> Result<VerifyingKey, String> {
+        let (_, verifier) = credits_key_files(&function).map_err(|err| err.to_string())?;
+        let bytes = self.download_bytes(format!("{}/{verifier}", self.base_url), checksum).await?;
+        VerifyingKey::from_bytes(&bytes)
+    }
+
+    /// Download the proving and verifying keys of a credits.aleo function
+    ///
+    /// @param function The name of the credits.aleo function (i.e. "transfer")
+    /// @param proving_key_checksum (optional) The expected SHA-256 checksum of the proving key
+    /// @param verifying_key_checksum (optional) The expected SHA-256 checksum of the verifying key
+    #[wasm_bindgen(js_name = "downloadKeyPair")]
+    pub async fn download_key_pair(
+        &self,
+        function: String,
+        proving_key_checksum: Option<String>,
+        verifying_key_checksum: Option<String>,
+    ) -> Result<KeyPair, String> {
+        let proving_key = self.download_proving_key(function.clone(), proving_key_checksum).await?;
+        let verifying_key = self.download_verifying_key(function, verifying_key_checksum).await?;
+        Ok(KeyPair::new(proving_key, verifying_key))
+    }
+}
+
+impl KeyDownloader {
+    /// Invoke the progress callback if one was provided
+    fn report_progress(&self, loaded: u64, total: Option<u64>) -> Result<(), String> {
+        if let Some(on_progress) = &self.on_progress {
+            let total = total.map_or(JsValue::UNDEFINED, |total| JsValue::from_f64(total as f64));
+            on_progress
+                .call2(&JsValue::NULL, &JsValue::from_f64(loaded as f64), &total)
+                .map_err(|err| format!("Progress callback failed: {err:?}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Ensure the SHA-256 checksum of the bytes matches the expected hex encoded checksum
+fn verify_checksum(bytes: &[u8], checksum: &str) -> Result<(), String> {
+    let actual = hex::encode(Sha256::digest(bytes));
+    if !actual.eq_ignore_ascii_case(checksum.trim()) {
+        return Err(format!("Checksum mismatch: expected {checksum}, found {actual}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_verify_checksum() {
+        // SHA-256 of the empty string
+        let empty = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        assert!(verify_checksum(&[], empty).is_ok());
+        assert!(verify_checksum(&[], &empty.to_uppercase()).is_ok());
+        assert!(verify_checksum(&[0u8], empty).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_download_verifying_key() {
+        let downloader = KeyDownloader::new(None, None);
+        let bytes = downloader
+            .download_bytes(format!("{}/fee.verifier.44783e8", AleoNetwork::Testnet3.parameters_url()), None)
+            .await
+            .unwrap();
+        let checksum = hex::encode(Sha256::digest(&bytes));
+
+        // Ensure the key is returned when the checksum matches and rejected otherwise
+        let verifying_key = downloader.download_verifying_key("fee".to_string(), Some(checksum)).await.unwrap();
+        assert_eq!(verifying_key, VerifyingKey::from_bytes(&bytes).unwrap());
+        assert!(downloader.download_verifying_key("fee".to_string(), Some("00".to_string())).await.is_err());
+    }
+}
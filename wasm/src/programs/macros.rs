@@ -16,16 +16,7 @@
 
 #[macro_export]
 macro_rules! execute_program {
-    ($process: expr, $inputs:expr, $program_string:expr, $function_id_string:expr, $private_key:expr, $proving_key:expr, $verifying_key:expr) => {{
-        if (($proving_key.is_some() && $verifying_key.is_none())
-            || ($proving_key.is_none() && $verifying_key.is_some()))
-        {
-            return Err(
-                "If specifying a key for a program execution, both the proving and verifying key must be specified"
-                    .to_string(),
-            );
-        }
-
+    ($process: expr, $inputs:expr, $program_string:expr, $function_id_string:expr, $private_key:expr, $proving_key:expr, $verifying_key:expr, $on_progress:expr) => {{
         let mut inputs_native = vec![];
         log("parsing inputs");
         for input in $inputs.to_vec().iter() {
@@ -56,21 +47,11 @@ macro_rules! execute_program {
             }
         }
 
-        if let Some(proving_key) = $proving_key {
-            if Self::contains_key($process, program.id(), &function_name) {
-                log(&format!("Proving & verifying keys were specified for {program_id} - {function_name:?} but a key already exists in the cache. Using cached keys"));
-            } else {
-                log(&format!("Inserting externally provided proving and verifying keys for {program_id} - {function_name:?}"));
-                $process
-                    .insert_proving_key(program.id(), &function_name, ProvingKeyNative::from(proving_key))
-                    .map_err(|e| e.to_string())?;
-                if let Some(verifying_key) = $verifying_key {
-                    $process.insert_verifying_key(program.id(), &function_name, VerifyingKeyNative::from(verifying_key)).map_err(|e| e.to_string())?;
-                }
-            }
-        };
+        let provided_keys = Self::provided_keys(program.id(), &function_name, $proving_key, $verifying_key)?;
+        Self::load_provided_keys(&provided_keys, $process, program.id(), &function_name)?;
 
         log("Creating authorization");
+        $on_progress.report("authorization", 0.0)?;
         let authorization = $process
             .authorize::<CurrentAleo, _>(
                 &$private_key,
@@ -81,10 +62,21 @@ macro_rules! execute_program {
             )
             .map_err(|err| err.to_string())?;
 
+        $on_progress.report("authorization", 100.0)?;
+
         log("Executing program");
+        let synthesize = !Self::contains_key($process, program.id(), &function_name);
+        if synthesize {
+            $on_progress.report("synthesis", 0.0)?;
+        }
+        $on_progress.report("execution", 0.0)?;
         let result = $process
             .execute::<CurrentAleo>(authorization)
             .map_err(|err| err.to_string())?;
+        if synthesize {
+            $on_progress.report("synthesis", 100.0)?;
+        }
+        $on_progress.report("execution", 100.0)?;
 
         result
     }};
@@ -92,49 +84,51 @@ macro_rules! execute_program {
 
 #[macro_export]
 macro_rules! fee_inclusion_proof {
-    ($process:expr, $private_key:expr, $fee_record:expr, $fee_microcredits:expr, $submission_url:expr, $fee_proving_key:expr, $fee_verifying_key:expr, $execution_id:expr) => {{
-        if (($fee_proving_key.is_some() && $fee_verifying_key.is_none())
-            || ($fee_proving_key.is_none() && $fee_verifying_key.is_some()))
-        {
-            return Err(
-                 "Missing key - both the proving and verifying key must be specified for a program execution"
-                    .to_string(),
-            );
-        }
-
-        if let Some(fee_proving_key) = $fee_proving_key {
-            let credits = ProgramIDNative::from_str("credits.aleo").unwrap();
-            let fee = IdentifierNative::from_str("fee").unwrap();
-            if Self::contains_key($process, &credits, &fee) {
-                log("Fee proving & verifying keys were specified but a key already exists in the cache. Using cached keys");
-            } else {
-                log("Inserting externally provided fee proving and verifying keys");
-                $process
-                    .insert_proving_key(&credits, &fee, ProvingKeyNative::from(fee_proving_key)).map_err(|e| e.to_string())?;
-                if let Some(fee_verifying_key) = $fee_verifying_key {
-                    $process
-                        .insert_verifying_key(&credits, &fee, VerifyingKeyNative::from(fee_verifying_key))
-                        .map_err(|e| e.to_string())?;
-                }
+    ($process:expr, $private_key:expr, $fee_record:expr, $fee_microcredits:expr, $submission_url:expr, $execution_id:expr, $on_progress:expr) => {{
+        log("Executing fee program");
+        $on_progress.report("fee", 0.0)?;
+        let trace = match $fee_record {
+            Some(fee_record) => {
+                let fee_record_native = RecordPlaintextNative::from_str(&fee_record.to_string()).unwrap();
+                let (_, _, trace) = $process
+                    .execute_fee::<CurrentAleo, _>(
+                        &$private_key,
+                        fee_record_native,
+                        $fee_microcredits,
+                        $execution_id,
+                        &mut StdRng::from_entropy(),
+                    )
+                    .map_err(|err| err.to_string())?;
+                trace
+            }
+            None => {
+                // Pay the fee from the public balance, which requires the credits.aleo program of
+                // the process to provide fee_public. Otherwise fail before anything is executed.
+                let (credits_id, _) = Self::fee_function()?;
+                let credits = $process.get_program(&credits_id).map_err(|e| e.to_string())?.clone();
+                let (function, inputs) = Self::public_fee_inputs(&credits, $fee_microcredits, &$execution_id)?;
+                let authorization = $process
+                    .authorize::<CurrentAleo, _>(
+                        &$private_key,
+                        credits.id(),
+                        function,
+                        inputs.iter(),
+                        &mut StdRng::from_entropy(),
+                    )
+                    .map_err(|err| err.to_string())?;
+                let (_, mut trace) = $process.execute::<CurrentAleo>(authorization).map_err(|err| err.to_string())?;
+                trace.prepare_async::<CurrentBlockMemory, _>(&$submission_url).await.map_err(|err| err.to_string())?;
+                trace
             }
         };
 
-        log("Executing fee program");
-        let fee_record_native = RecordPlaintextNative::from_str(&$fee_record.to_string()).unwrap();
-        let (_, _, trace) = $process
-            .execute_fee::<CurrentAleo, _>(
-                &$private_key,
-                fee_record_native,
-                $fee_microcredits,
-                $execution_id,
-                &mut StdRng::from_entropy(),
-            )
-            .map_err(|err| err.to_string())?;
-
-        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e|e.to_string())?;
+        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e| e.to_string())?;
+        $on_progress.report("fee", 100.0)?;
 
         log("Verifying fee execution");
+        $on_progress.report("verification", 0.0)?;
         $process.verify_fee(&fee, $execution_id).map_err(|e| e.to_string())?;
+        $on_progress.report("verification", 100.0)?;
 
         fee
     }};
@@ -142,13 +136,13 @@ macro_rules! fee_inclusion_proof {
 
 #[macro_export]
 macro_rules! get_process {
-    ($self:expr, $cache:expr, $new_process:expr) => {
+    ($self:expr, $cache:expr, $new_process:expr) => {{
+        Self::check_environment()?;
         if $cache {
             &mut $self.process
         } else {
-            let new_process = ProcessNative::load_web().map_err(|err| err.to_string())?;
-            $new_process = Some(new_process);
+            $new_process = Some($self.base_process.clone());
             $new_process.as_mut().unwrap()
         }
-    };
+    }};
 }
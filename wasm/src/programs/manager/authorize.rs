@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    get_process,
+    log,
+    types::{CurrentAleo, CurrentBlockMemory, IdentifierNative, ProcessNative, ProgramNative, TransactionNative},
+    Authorization,
+    PrivateKey,
+    Transaction,
+};
+
+use js_sys::{Array, Object};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{ops::Add, str::FromStr};
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Authorize a function call without executing it
+    ///
+    /// This only requires the private key and the program, so it can be run on a signing device
+    /// which never generates proofs. The returned authorization can be serialized with `toString`
+    /// and passed to `proveAuthorization` on another device.
+    ///
+    /// @param private_key The private key of the caller
+    /// @param program The source code of the program containing the function
+    /// @param function The name of the function to authorize
+    /// @param inputs A javascript array of inputs to the function
+    /// @param imports (optional) A javascript object holding the source code of any imported
+    /// programs in the form {"program_name1": "program_source_code", ..}
+    /// @param cache Cache the program and its imports in the ProgramManager's memory
+    #[wasm_bindgen]
+    pub fn authorize(
+        &mut self,
+        private_key: PrivateKey,
+        program: String,
+        function: String,
+        inputs: Array,
+        imports: Option<Object>,
+        cache: bool,
+    ) -> Result<Authorization, String> {
+        log(&format!("Authorizing function: {function}"));
+        let inputs = inputs
+            .to_vec()
+            .iter()
+            .map(|input| {
+                input
+                    .as_string()
+                    .ok_or_else(|| "Invalid input - all inputs must be a string specifying the type".to_string())
+            })
+            .collect::<Result<Vec<String>, String>>()?;
+        let function_name =
+            IdentifierNative::from_str(&function).map_err(|_| "The function name provided was invalid".to_string())?;
+
+        let mut new_process;
+        let process = get_process!(self, cache, new_process);
+        let program = Self::load_program(process, &program, imports.as_ref())?;
+
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                program.id(),
+                function_name,
+                inputs.iter(),
+                &mut StdRng::from_entropy(),
+            )
+            .map_err(|err| err.to_string())?;
+        Ok(Authorization::from(authorization))
+    }
+
+    /// Execute and prove an authorization created with `authorize` and create an execution
+    /// transaction from it
+    ///
+    /// This does not require the private key of the caller, which allows the expensive proof to be
+    /// delegated to a separate device. The resulting transaction does not include a fee.
+    ///
+    /// @param authorization The authorization to prove
+    /// @param program The source code of the program containing the authorized function
+    /// @param url (optional) The url of the Aleo network node used to build the inclusion proofs. If
+    /// not provided, the node of the ProgramManager's network config is used
+    /// @param imports (optional) A javascript object holding the source code of any imported
+    /// programs in the form {"program_name1": "program_source_code", ..}
+    /// @param cache Cache the program and the proving and verifying keys in the ProgramManager's
+    /// memory
+    /// @param proving_key (optional) Provide a proving key to use for the function execution
+    /// @param verifying_key (optional) Provide a verifying key to use for the function execution
+    #[wasm_bindgen(js_name = "proveAuthorization")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn prove_authorization(
+        &mut self,
+        authorization: Authorization,
+        program: String,
+        url: Option<String>,
+        imports: Option<Object>,
+        cache: bool,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+    ) -> Result<Transaction, String> {
+        let url = self.network.resolve_node_url(url);
+        let function_name = *authorization.peek_next().map_err(|err| err.to_string())?.function_name();
+        log(&format!("Proving authorization for function: {function_name}"));
+
+        let on_progress = self.progress.clone();
+        let mut new_process;
+        let process = get_process!(self, cache, new_process);
+        let program = Self::load_program(process, &program, imports.as_ref())?;
+
+        let provided_keys = Self::provided_keys(program.id(), &function_name, proving_key, verifying_key)?;
+        Self::load_provided_keys(&provided_keys, process, program.id(), &function_name)?;
+
+        let synthesize = !Self::contains_key(process, program.id(), &function_name);
+        if synthesize {
+            on_progress.report("synthesis", 0.0)?;
+        }
+        on_progress.report("execution", 0.0)?;
+        let (_, mut trace) = process.execute::<CurrentAleo>(authorization.into()).map_err(|err| err.to_string())?;
+        if synthesize {
+            on_progress.report("synthesis", 100.0)?;
+        }
+        on_progress.report("execution", 100.0)?;
+
+        // Prepare the inclusion proofs for the execution
+        on_progress.report("inclusion", 0.0)?;
+        trace.prepare_async::<CurrentBlockMemory, _>(&url).await.map_err(|err| err.to_string())?;
+        on_progress.report("inclusion", 100.0)?;
+
+        // Prove the execution
+        on_progress.report("proving", 0.0)?;
+        let locator = program.id().to_string().add("/").add(&function_name.to_string());
+        let execution = trace
+            .prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
+            .map_err(|e| e.to_string())?;
+        on_progress.report("proving", 100.0)?;
+
+        // Verify the execution
+        on_progress.report("verification", 0.0)?;
+        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+        on_progress.report("verification", 100.0)?;
+
+        log("Creating execution transaction");
+        let transaction = TransactionNative::from_execution(execution, None).map_err(|err| err.to_string())?;
+        Ok(Transaction::from(transaction))
+    }
+}
+
+impl ProgramManager {
+    /// Parse a program and add it and its imports to a process, ensuring a program with the same
+    /// name already in the process has the same source
+    pub(crate) fn load_program(
+        process: &mut ProcessNative,
+        program: &str,
+        imports: Option<&Object>,
+    ) -> Result<ProgramNative, String> {
+        let program =
+            ProgramNative::from_str(program).map_err(|_| "The program ID provided was invalid".to_string())?;
+        if &program.id().to_string() != "credits.aleo" {
+            if let Ok(stored_program) = process.get_program(program.id()) {
+                if stored_program != &program {
+                    return Err("The program provided does not match the program stored in the cache, please clear the cache before proceeding".to_string());
+                }
+            } else {
+                Self::resolve_imports(process, &program, imports)?;
+                process.add_program(&program).map_err(|e| e.to_string())?;
+            }
+        }
+        Ok(program)
+    }
+}
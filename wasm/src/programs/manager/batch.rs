@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::PrivateKey;
+
+use super::worker::{optional_record, required_string, worker_param};
+use js_sys::Array;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Execute several Aleo functions on-chain and create a transaction for each of them
+    ///
+    /// All executions share the ProgramManager's cached process, so programs are only loaded and
+    /// keys are only synthesized once for the whole batch instead of once per execution. The
+    /// programs and keys used remain in the cache afterwards.
+    ///
+    /// @param private_key The private key of the sender
+    /// @param requests A javascript array of execution requests in the form
+    /// {program, function, inputs, feeCredits, feeRecord}. Each request must spend a different fee
+    /// record
+    /// @param url (optional) The url of the Aleo network node used to build the inclusion proofs
+    #[wasm_bindgen(js_name = "executeBatch")]
+    pub async fn execute_batch(
+        &mut self,
+        private_key: PrivateKey,
+        requests: Array,
+        url: Option<String>,
+    ) -> Result<Array, String> {
+        let transactions = Array::new();
+        for (index, request) in requests.iter().enumerate() {
+            log(&format!("Executing batch request {} of {}", index + 1, requests.length()));
+            let transaction = self
+                .execute(
+                    private_key.clone(),
+                    required_string(&request, "program")?,
+                    required_string(&request, "function")?,
+                    Array::from(&worker_param(&request, "inputs")),
+                    worker_param(&request, "feeCredits")
+                        .as_f64()
+                        .ok_or_else(|| "Missing parameter feeCredits".to_string())?,
+                    optional_record(&request, "feeRecord")?,
+                    url.clone(),
+                    true,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .map_err(|err| format!("Batch request {index} failed: {err}"))?;
+            transactions.push(&transaction.into());
+        }
+        Ok(transactions)
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{NetworkConfig, PrivateKey, Transaction};
+
+use futures_util::future::{select, Either};
+use js_sys::{Array, Promise, Reflect};
+use wasm_bindgen_futures::JsFuture;
+
+/// The default number of times a broadcast is retried after a failed attempt
+const DEFAULT_BROADCAST_RETRIES: u32 = 3;
+/// The default time in milliseconds to wait for the node to respond to a broadcast
+const DEFAULT_BROADCAST_TIMEOUT_MS: u32 = 30_000;
+/// The delay in milliseconds before the first retry, doubled after each subsequent attempt
+const BROADCAST_BACKOFF_MS: u32 = 500;
+
+/// The result of successfully broadcasting a transaction to the Aleo Network
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BroadcastResult {
+    transaction_id: String,
+    attempts: u32,
+}
+
+#[wasm_bindgen]
+impl BroadcastResult {
+    /// Get the id of the transaction accepted by the node
+    #[wasm_bindgen(getter, js_name = "transactionId")]
+    pub fn transaction_id(&self) -> String {
+        self.transaction_id.clone()
+    }
+
+    /// Get the number of attempts it took to broadcast the transaction
+    #[wasm_bindgen(getter)]
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Broadcast a transaction to the Aleo Network
+    ///
+    /// Broadcasts which fail because the node could not be reached, did not respond in time or
+    /// returned a server error are retried with exponential backoff. Broadcasts rejected by the
+    /// node (i.e. because the transaction is invalid) are not retried.
+    ///
+    /// @param transaction The transaction to broadcast
+    /// @param url (optional) The url of the Aleo network node to send the transaction to. If not
+    /// provided, the broadcast url of the ProgramManager's network config is used
+    /// @param retries (optional) The number of times to retry a failed broadcast (default 3)
+    /// @param timeout_ms (optional) The time in milliseconds to wait for each attempt (default 30000)
+    #[wasm_bindgen]
+    pub async fn broadcast(
+        &self,
+        transaction: Transaction,
+        url: Option<String>,
+        retries: Option<u32>,
+        timeout_ms: Option<u32>,
+    ) -> Result<BroadcastResult, String> {
+        let endpoint = match url {
+            Some(url) => NetworkConfig::new(url, Some(self.network.network()), None)?.broadcast_url(),
+            None => self.network.broadcast_url(),
+        };
+        let retries = retries.unwrap_or(DEFAULT_BROADCAST_RETRIES);
+        let timeout_ms = timeout_ms.unwrap_or(DEFAULT_BROADCAST_TIMEOUT_MS);
+        let body = transaction.to_string();
+
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            log(&format!("Broadcasting transaction {} (attempt {attempts})", transaction.transaction_id()));
+            let error = match Self::broadcast_once(&endpoint, &body, timeout_ms).await {
+                Ok(transaction_id) => return Ok(BroadcastResult { transaction_id, attempts }),
+                Err((error, false)) => return Err(error),
+                Err((error, true)) => error,
+            };
+            if attempts > retries {
+                return Err(format!("Failed to broadcast transaction after {attempts} attempts: {error}"));
+            }
+            log(&format!("Broadcast failed, retrying: {error}"));
+            sleep(BROADCAST_BACKOFF_MS.saturating_mul(2u32.saturating_pow(attempts - 1))).await?;
+        }
+    }
+
+    /// Execute an Aleo function on-chain and broadcast the resulting transaction
+    ///
+    /// This accepts the same arguments as `execute` followed by the url to broadcast to and the
+    /// same optional retry settings as `broadcast`.
+    #[wasm_bindgen(js_name = "executeAndBroadcast")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn execute_and_broadcast(
+        &mut self,
+        private_key: PrivateKey,
+        program: String,
+        function: String,
+        inputs: Array,
+        fee_credits: f64,
+        fee_record: Option<RecordPlaintext>,
+        url: Option<String>,
+        cache: bool,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+        fee_proving_key: Option<ProvingKey>,
+        fee_verifying_key: Option<VerifyingKey>,
+        retries: Option<u32>,
+        timeout_ms: Option<u32>,
+    ) -> Result<BroadcastResult, String> {
+        let transaction = self
+            .execute(
+                private_key,
+                program,
+                function,
+                inputs,
+                fee_credits,
+                fee_record,
+                url.clone(),
+                cache,
+                proving_key,
+                verifying_key,
+                fee_proving_key,
+                fee_verifying_key,
+            )
+            .await?;
+        self.broadcast(transaction, url, retries, timeout_ms).await
+    }
+}
+
+impl ProgramManager {
+    /// Make a single broadcast attempt, returning the transaction id on success or the error and
+    /// whether the attempt can be retried on failure
+    async fn broadcast_once(endpoint: &str, body: &str, timeout_ms: u32) -> Result<String, (String, bool)> {
+        let request = reqwest::Client::new()
+            .post(endpoint)
+            .header("Content-Type", "application/json")
+            .body(body.to_string())
+            .send();
+        let response = match select(Box::pin(request), Box::pin(sleep(timeout_ms))).await {
+            Either::Left((response, _)) => {
+                response.map_err(|err| (format!("Failed to reach the node: {err}"), true))?
+            }
+            Either::Right(_) => return Err((format!("The node did not respond within {timeout_ms}ms"), true)),
+        };
+
+        let status = response.status();
+        let text = response.text().await.map_err(|err| (err.to_string(), true))?;
+        if !status.is_success() {
+            return Err((format!("The node returned {status}: {text}"), status.is_server_error()));
+        }
+        Ok(parse_transaction_id(&text))
+    }
+}
+
+/// Read the transaction id from the body of a successful broadcast response
+fn parse_transaction_id(text: &str) -> String {
+    serde_json::from_str::<String>(text).unwrap_or_else(|_| text.trim().to_string())
+}
+
+/// Wait for the given number of milliseconds using the javascript `setTimeout` function
+async fn sleep(ms: u32) -> Result<(), String> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let set_timeout = Reflect::get(&js_sys::global(), &"setTimeout".into()).map(Function::from);
+        match set_timeout {
+            Ok(set_timeout) => {
+                let _ = set_timeout.call2(&JsValue::NULL, &resolve, &ms.into());
+            }
+            Err(error) => {
+                let _ = reject.call1(&JsValue::NULL, &error);
+            }
+        }
+    });
+    JsFuture::from(promise).await.map(|_| ()).map_err(|_| "setTimeout is not available".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_parse_transaction_id() {
+        let id = "at1pkw4ms8yuw29k8lfqdqkcdaffd6hngnzkw3j8f8j0aht0egkz5fq4h652c";
+        assert_eq!(parse_transaction_id(&format!("\"{id}\"")), id);
+        assert_eq!(parse_transaction_id(&format!("{id}\n")), id);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_broadcast_unreachable_node() {
+        let transaction = Transaction::from_string(crate::programs::transaction::tests::TRANSACTION_STRING).unwrap();
+        let result = ProgramManager::new()
+            .broadcast(transaction, Some("http://127.0.0.1:1".to_string()), Some(1), Some(1_000))
+            .await;
+        assert!(result.unwrap_err().contains("after 2 attempts"));
+    }
+}
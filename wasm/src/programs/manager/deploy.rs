@@ -22,9 +22,9 @@ use crate::{
     log,
     types::{
         CurrentAleo,
+        CurrentBlockMemory,
         CurrentNetwork,
         ProcessNative,
-        ProgramIDNative,
         ProgramNative,
         ProgramOwnerNative,
         RecordPlaintextNative,
@@ -55,8 +55,11 @@ impl ProgramManager {
     /// Note that all imported programs must be deployed on chain before the main program in order
     /// for the deployment to succeed
     /// @param fee_credits The amount of credits to pay as a fee
-    /// @param fee_record The record to spend the fee from
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param fee_record (optional) The record to spend the fee from. If not provided, the fee is
+    /// paid from the public balance, which requires the network to support `credits.aleo/fee_public`
+    /// @param url (optional) The url of the Aleo network node to send the transaction to. If not
+    /// provided, the node of the ProgramManager's network config is used
+    /// @param cache Cache the programs and keys used for the deployment in wasm memory for reuse
     /// @param fee_proving_key (optional) Provide a proving key to use for the fee execution
     /// @param fee_verifying_key (optional) Provide a verifying key to use for the fee execution
     #[wasm_bindgen]
@@ -67,51 +70,36 @@ impl ProgramManager {
         program: String,
         imports: Option<Object>,
         fee_credits: f64,
-        fee_record: RecordPlaintext,
-        _url: String,
+        fee_record: Option<RecordPlaintext>,
+        url: Option<String>,
         cache: bool,
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
+        let url = self.network.resolve_node_url(url);
         log("Creating deployment transaction");
-        // Convert fee to microcredits and check that the fee record has enough credits to pay it
-        let fee_microcredits = Self::validate_amount(fee_credits, &fee_record, true)?;
-        if fee_record.microcredits() < fee_microcredits {
-            return Err("Fee record does not have enough credits to pay the specified fee".to_string());
-        }
+        // Convert fee to microcredits and check that the fee record (if any) has enough credits to pay it
+        let fee_microcredits = Self::validate_fee(fee_credits, fee_record.as_ref())?;
 
+        let on_progress = self.progress.clone();
         let mut new_process;
         let process = get_process!(self, cache, new_process);
+        Self::load_fee_keys(&self.fee_keys, process, fee_proving_key, fee_verifying_key)?;
 
         log("Check program has a valid name");
         let program = ProgramNative::from_str(&program).map_err(|err| err.to_string())?;
 
         log("Check program imports are valid");
-        if let Some(imports) = imports {
-            program
-                .imports()
-                .keys()
-                .try_for_each(|program_id| {
-                    let program_id =
-                        program_id.to_string().parse::<ProgramIDNative>().map_err(|err| err.to_string())?.to_string();
-                    if let Some(import_string) = Reflect::get(&imports, &program_id.into())
-                        .map_err(|_| "Import not found".to_string())?
-                        .as_string()
-                    {
-                        let import = ProgramNative::from_str(&import_string).map_err(|err| err.to_string())?;
-                        process.add_program(&import).map_err(|err| err.to_string())?;
-                    }
-                    Ok::<(), String>(())
-                })
-                .map_err(|_| "Import resolution failed".to_string())?;
-        }
+        Self::resolve_imports(process, &program, imports.as_ref())?;
 
         log("Create and validate deployment");
+        on_progress.report("synthesis", 0.0)?;
         let deployment =
             process.deploy::<CurrentAleo, _>(&program, &mut StdRng::from_entropy()).map_err(|err| err.to_string())?;
         if deployment.program().functions().is_empty() {
             return Err("Attempted to create an empty transaction deployment".to_string());
         }
+        on_progress.report("synthesis", 100.0)?;
 
         log("Ensure the fee is sufficient to pay for the deployment");
         let deployment_fee = deployment.to_bytes_le().map_err(|err| err.to_string())?.len();
@@ -120,22 +108,17 @@ impl ProgramManager {
         }
 
         log("Verify the deployment and fees");
+        on_progress.report("verification", 0.0)?;
         process
             .verify_deployment::<CurrentAleo, _>(&deployment, &mut StdRng::from_entropy())
             .map_err(|err| err.to_string())?;
+        on_progress.report("verification", 100.0)?;
 
         let deployment_id = deployment.to_deployment_id().map_err(|e| e.to_string())?;
 
-        let fee = fee_inclusion_proof!(
-            process,
-            private_key,
-            fee_record,
-            fee_microcredits,
-            _url,
-            fee_proving_key,
-            fee_verifying_key,
-            deployment_id
-        );
+        let fee =
+            fee_inclusion_proof!(process, private_key, fee_record, fee_microcredits, url, deployment_id, &on_progress);
+        Self::store_fee_keys(&self.fee_keys, process)?;
 
         log("Create the deployment transaction");
         TransactionNative::check_deployment_size(&deployment).map_err(|err| err.to_string())?;
@@ -171,3 +154,29 @@ impl ProgramManager {
         ))
     }
 }
+
+impl ProgramManager {
+    /// Add the imports of a program to a process, skipping any programs the process already
+    /// contains and returning an error if an import was not provided
+    pub(crate) fn resolve_imports(
+        process: &mut ProcessNative,
+        program: &ProgramNative,
+        imports: Option<&Object>,
+    ) -> Result<(), String> {
+        program
+            .imports()
+            .keys()
+            .try_for_each(|program_id| {
+                if process.contains_program(program_id) {
+                    return Ok(());
+                }
+                let import_string = imports
+                    .and_then(|imports| Reflect::get(imports, &program_id.to_string().into()).ok())
+                    .and_then(|import| import.as_string())
+                    .ok_or_else(|| format!("Import {program_id} was not provided"))?;
+                let import = ProgramNative::from_str(&import_string).map_err(|err| err.to_string())?;
+                process.add_program(&import).map_err(|err| err.to_string())
+            })
+            .map_err(|err| format!("Import resolution failed: {err}"))
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    execute_program,
+    get_process,
+    log,
+    types::{CurrentAleo, IdentifierNative, ProcessNative, ProgramNative},
+    PrivateKey,
+};
+
+use aleo_rust::cost::{deployment_cost, estimate_execution_cost};
+
+use js_sys::{Array, Object};
+use rand::{rngs::StdRng, SeedableRng};
+use std::str::FromStr;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Estimate the fee in microcredits required to execute a program function
+    ///
+    /// The function is authorized and run locally to determine the transitions it produces, but no
+    /// proof is generated. The estimate is the serialized size of those transitions plus a
+    /// conservative bound on the size of the execution proof and the estimated cost of the finalize
+    /// blocks they schedule. If the keys for the function are not cached they will be synthesized,
+    /// which may take some time for large functions.
+    ///
+    /// @param private_key The private key of the sender
+    /// @param program The source code of the program being executed
+    /// @param function The name of the function to execute
+    /// @param inputs A javascript array of inputs to the function
+    /// @param cache Cache the synthesized keys in the ProgramManager's memory for reuse
+    #[wasm_bindgen(js_name = "estimateExecutionFee")]
+    pub fn estimate_execution_fee(
+        &mut self,
+        private_key: PrivateKey,
+        program: String,
+        function: String,
+        inputs: Array,
+        cache: bool,
+    ) -> Result<u64, String> {
+        log(&format!("Estimating the execution fee for function: {function}"));
+        let on_progress = self.progress.clone();
+        let mut new_process;
+        let process = get_process!(self, cache, new_process);
+
+        let (proving_key, verifying_key): (Option<ProvingKey>, Option<VerifyingKey>) = (None, None);
+        let (_, trace) =
+            execute_program!(process, inputs, program, function, private_key, proving_key, verifying_key, &on_progress);
+
+        estimate_execution_cost(process, trace.transitions()).map_err(|err| err.to_string())
+    }
+
+    /// Estimate the fee in microcredits required to deploy a program
+    ///
+    /// The deployment is created locally without a fee execution. The fee is its serialized size in
+    /// bytes plus the cost of synthesizing its functions and the namespace cost of the program
+    /// name, which is the minimum fee the network will accept for it.
+    ///
+    /// @param program The source code of the program being deployed
+    /// @param imports A javascript object holding the source code of any imported programs in the
+    /// form {"program_name1": "program_source_code", "program_name2": "program_source_code", ..}
+    /// @param cache Cache the imported programs in the ProgramManager's memory for reuse
+    #[wasm_bindgen(js_name = "estimateDeploymentFee")]
+    pub fn estimate_deployment_fee(
+        &mut self,
+        program: String,
+        imports: Option<Object>,
+        cache: bool,
+    ) -> Result<u64, String> {
+        log("Estimating the deployment fee");
+        let mut new_process;
+        let process = get_process!(self, cache, new_process);
+
+        let program = ProgramNative::from_str(&program).map_err(|err| err.to_string())?;
+        Self::resolve_imports(process, &program, imports.as_ref())?;
+
+        let deployment =
+            process.deploy::<CurrentAleo, _>(&program, &mut StdRng::from_entropy()).map_err(|err| err.to_string())?;
+        if deployment.program().functions().is_empty() {
+            return Err("Attempted to create an empty transaction deployment".to_string());
+        }
+
+        deployment_cost(&deployment).map_err(|err| err.to_string())
+    }
+}
@@ -21,13 +21,25 @@ use crate::{
     execute_program,
     get_process,
     log,
-    types::{CurrentAleo, CurrentBlockMemory, IdentifierNative, ProcessNative, ProgramNative, TransactionNative},
+    types::{
+        CurrentAleo,
+        CurrentBlockMemory,
+        IdentifierNative,
+        ProcessNative,
+        ProgramNative,
+        TransactionNative,
+        TransitionNative,
+        ValueNative,
+    },
     ExecutionResponse,
     PrivateKey,
     RecordPlaintext,
     Transaction,
+    ViewKey,
 };
 
+use aleo_rust::decrypt_transition;
+
 use js_sys::Array;
 use rand::{rngs::StdRng, SeedableRng};
 use std::str::FromStr;
@@ -51,6 +63,9 @@ impl ProgramManager {
     /// keys will be deallocated from memory after the transaction is executed.
     /// @param proving_key (optional) Provide a verifying key to use for the function execution
     /// @param verifying_key (optional) Provide a verifying key to use for the function execution
+    /// @param debug (optional) Record the inputs and outputs of every function call made during the
+    /// execution, which can be retrieved with `ExecutionResponse.getTrace`. Private values are
+    /// decrypted with the view key of the sender
     #[wasm_bindgen]
     #[allow(clippy::too_many_arguments)]
     pub fn execute_local(
@@ -62,22 +77,32 @@ impl ProgramManager {
         cache: bool,
         proving_key: Option<ProvingKey>,
         verifying_key: Option<VerifyingKey>,
+        debug: Option<bool>,
     ) -> Result<ExecutionResponse, String> {
         log(&format!("Executing local function: {function}"));
         let inputs = inputs.to_vec();
 
+        let on_progress = self.progress.clone();
         let mut new_process;
         let process: &mut ProcessNative = get_process!(self, cache, new_process);
 
-        let (response, _) =
-            execute_program!(process, inputs, program, function, private_key, proving_key, verifying_key);
+        let (response, trace) =
+            execute_program!(process, inputs, program, function, private_key, proving_key, verifying_key, &on_progress);
 
         log("Creating execution response");
-        let outputs = js_sys::Array::new_with_length(response.outputs().len() as u32);
-        for (i, output) in response.outputs().iter().enumerate() {
-            outputs.set(i as u32, wasm_bindgen::JsValue::from_str(&output.to_string()));
+        let program = ProgramNative::from_str(&program).map_err(|err| err.to_string())?;
+        let function_name = IdentifierNative::from_str(&function).map_err(|err| err.to_string())?;
+        let response = ExecutionResponse::new(response, &program, function_name)?;
+        if debug.unwrap_or(false) {
+            let view_key = ViewKey::from_private_key(&private_key);
+            let calls = trace
+                .transitions()
+                .iter()
+                .map(|transition| Self::describe_call(transition, &view_key))
+                .collect::<Result<_, _>>()?;
+            return Ok(response.with_trace(calls));
         }
-        Ok(ExecutionResponse::from(response))
+        Ok(response)
     }
 
     /// Execute Aleo function and create an Aleo execution transaction
@@ -87,8 +112,10 @@ impl ProgramManager {
     /// @param function The name of the function to execute
     /// @param inputs A javascript array of inputs to the function
     /// @param fee_credits The amount of credits to pay as a fee
-    /// @param fee_record The record to spend the fee from
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param fee_record (optional) The record to spend the fee from. If not provided, the fee is
+    /// paid from the public balance, which requires the network to support `credits.aleo/fee_public`
+    /// @param url (optional) The url of the Aleo network node to send the transaction to. If not
+    /// provided, the node of the ProgramManager's network config is used
     /// @param cache Cache the proving and verifying keys in the ProgramManager's memory.
     /// If this is set to 'true' the keys synthesized (or passed in as optional parameters via the
     /// `proving_key` and `verifying_key` arguments) will be stored in the ProgramManager's memory
@@ -107,53 +134,126 @@ impl ProgramManager {
         function: String,
         inputs: Array,
         fee_credits: f64,
-        fee_record: RecordPlaintext,
-        url: String,
+        fee_record: Option<RecordPlaintext>,
+        url: Option<String>,
         cache: bool,
         proving_key: Option<ProvingKey>,
         verifying_key: Option<VerifyingKey>,
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
+        let url = self.network.resolve_node_url(url);
         log(&format!("Executing function: {function} on-chain"));
-        Self::validate_amount(fee_credits, &fee_record, true)?;
+        Self::validate_fee(fee_credits, fee_record.as_ref())?;
+
+        let credits = ProgramNative::credits().map_err(|e| e.to_string())?;
+        if cache {
+            let program = ProgramNative::from_str(&program).map_err(|err| err.to_string())?;
+            self.rehydrate_keys(&program, &function).await;
+            self.rehydrate_keys(&credits, "fee").await;
+        }
 
+        let on_progress = self.progress.clone();
         let mut new_process;
         let process = get_process!(self, cache, new_process);
-        let stack = process.get_stack("credits.aleo").map_err(|e| e.to_string())?;
-        let fee_identifier = IdentifierNative::from_str("fee").map_err(|e| e.to_string())?;
-        if !stack.contains_proving_key(&fee_identifier) && fee_proving_key.is_some() && fee_verifying_key.is_some() {
-            let fee_proving_key = fee_proving_key.unwrap();
-            let fee_verifying_key = fee_verifying_key.unwrap();
-            stack
-                .insert_proving_key(&fee_identifier, ProvingKeyNative::from(fee_proving_key))
-                .map_err(|e| e.to_string())?;
-            stack
-                .insert_verifying_key(&fee_identifier, VerifyingKeyNative::from(fee_verifying_key))
-                .map_err(|e| e.to_string())?;
-        }
+        Self::load_fee_keys(&self.fee_keys, process, fee_proving_key, fee_verifying_key)?;
 
         let (_, mut trace) =
-            execute_program!(process, inputs, program, function, private_key, proving_key, verifying_key);
+            execute_program!(process, inputs, program, function, private_key, proving_key, verifying_key, &on_progress);
 
         // Prepare the inclusion proofs for the fee & execution
+        on_progress.report("inclusion", 0.0)?;
         trace.prepare_async::<CurrentBlockMemory, _>(&url).await.map_err(|err| err.to_string())?;
+        on_progress.report("inclusion", 100.0)?;
 
         // Prove the execution and fee
         let program = ProgramNative::from_str(&program).map_err(|err| err.to_string())?;
         let locator = program.id().to_string().add("/").add(&function);
-        let execution = trace
-            .prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
-            .map_err(|e| e.to_string())?;
-        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e| e.to_string())?;
+        let (execution, fee) = Self::prove_execution_and_fee(&trace, &locator, &on_progress)?;
+        Self::store_fee_keys(&self.fee_keys, process)?;
         let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
 
         // Verify the execution and fee
+        on_progress.report("verification", 0.0)?;
         process.verify_execution(&execution).map_err(|err| err.to_string())?;
         process.verify_fee(&fee, execution_id).map_err(|err| err.to_string())?;
+        on_progress.report("verification", 100.0)?;
+
+        if cache {
+            self.persist_keys(&program, &function).await;
+            self.persist_keys(&credits, "fee").await;
+        }
 
         log("Creating execution transaction");
         let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
         Ok(Transaction::from(transaction))
     }
 }
+
+impl ProgramManager {
+    /// Describe a function call of a debug execution as a JSON object
+    ///
+    /// Public and constant values are shown as they are, private values and records are decrypted
+    /// with the view key of the caller, and values the view key cannot decrypt are null.
+    fn describe_call(transition: &TransitionNative, view_key: &ViewKey) -> Result<String, String> {
+        let call = decrypt_transition(transition, &**view_key).map_err(|err| err.to_string())?;
+        let values = |values: &[Option<ValueNative>]| {
+            values.iter().map(|value| value.as_ref().map(|value| value.to_string())).collect::<Vec<_>>()
+        };
+        Ok(serde_json::json!({
+            "id": call.transition_id.to_string(),
+            "program": call.program_id.to_string(),
+            "function": call.function_name.to_string(),
+            "inputs": values(&call.inputs),
+            "outputs": values(&call.outputs),
+        })
+        .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const HELLO_PROGRAM: &str = "program hello.aleo;
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+";
+
+    #[wasm_bindgen_test]
+    fn test_describe_call() {
+        let rng = &mut StdRng::from_entropy();
+        let private_key = PrivateKey::new();
+
+        let mut process = ProcessNative::load_web().unwrap();
+        let program = ProgramNative::from_str(HELLO_PROGRAM).unwrap();
+        process.add_program(&program).unwrap();
+        let function_name = IdentifierNative::from_str("hello").unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, program.id(), function_name, ["5u32", "3u32"].iter(), rng)
+            .unwrap();
+        let (_, trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        let transition = &trace.transitions()[0];
+
+        // The caller sees the private values of the call in plaintext
+        let call = ProgramManager::describe_call(transition, &private_key.to_view_key()).unwrap();
+        let call: serde_json::Value = serde_json::from_str(&call).unwrap();
+        assert_eq!(call["program"], "hello.aleo");
+        assert_eq!(call["function"], "hello");
+        assert_eq!(call["inputs"], serde_json::json!(["5u32", "3u32"]));
+        assert_eq!(call["outputs"], serde_json::json!(["8u32"]));
+        assert!(!call.to_string().contains("ciphertext"));
+
+        // Other accounts only see its public values
+        let call = ProgramManager::describe_call(transition, &PrivateKey::new().to_view_key()).unwrap();
+        let call: serde_json::Value = serde_json::from_str(&call).unwrap();
+        assert_eq!(call["inputs"], serde_json::json!(["5u32", null]));
+        assert_eq!(call["outputs"], serde_json::json!([null]));
+    }
+}
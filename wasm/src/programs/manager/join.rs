@@ -40,7 +40,8 @@ impl ProgramManager {
     /// @param record_2 The second record to combine
     /// @param fee_credits The amount of credits to pay as a fee
     /// @param fee_record The record to spend the fee from
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param url (optional) The url of the Aleo network node to send the transaction to. If not
+    /// provided, the node of the ProgramManager's network config is used
     /// @param cache Cache the proving and verifying keys in the ProgramManager memory. If this is
     /// set to `true` the keys synthesized (or passed in as optional parameters via the
     /// `join_proving_key` and `join_verifying_key` arguments) will be stored in the
@@ -59,13 +60,14 @@ impl ProgramManager {
         record_2: RecordPlaintext,
         fee_credits: f64,
         fee_record: RecordPlaintext,
-        url: String,
+        url: Option<String>,
         cache: bool,
         join_proving_key: Option<ProvingKey>,
         join_verifying_key: Option<VerifyingKey>,
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
+        let url = self.network.resolve_node_url(url);
         log("Executing join program");
         Self::validate_amount(fee_credits, &fee_record, true)?;
 
@@ -75,40 +77,126 @@ impl ProgramManager {
         inputs.set(0u32, wasm_bindgen::JsValue::from_str(&record_1.to_string()));
         inputs.set(1u32, wasm_bindgen::JsValue::from_str(&record_2.to_string()));
 
+        let credits = ProgramNative::credits().map_err(|e| e.to_string())?;
+        if cache {
+            self.rehydrate_keys(&credits, "join").await;
+            self.rehydrate_keys(&credits, "fee").await;
+        }
+
+        let on_progress = self.progress.clone();
         let mut new_process;
         let process = get_process!(self, cache, new_process);
-        let stack = process.get_stack("credits.aleo").map_err(|e| e.to_string())?;
-        let fee_identifier = IdentifierNative::from_str("fee").map_err(|e| e.to_string())?;
-        if !stack.contains_proving_key(&fee_identifier) && fee_proving_key.is_some() && fee_verifying_key.is_some() {
-            let fee_proving_key = fee_proving_key.unwrap();
-            let fee_verifying_key = fee_verifying_key.unwrap();
-            stack
-                .insert_proving_key(&fee_identifier, ProvingKeyNative::from(fee_proving_key))
-                .map_err(|e| e.to_string())?;
-            stack
-                .insert_verifying_key(&fee_identifier, VerifyingKeyNative::from(fee_verifying_key))
-                .map_err(|e| e.to_string())?;
-        }
+        Self::load_fee_keys(&self.fee_keys, process, fee_proving_key, fee_verifying_key)?;
 
-        let (_, mut trace) =
-            execute_program!(process, inputs, program, "join", private_key, join_proving_key, join_verifying_key);
+        let (_, mut trace) = execute_program!(
+            process,
+            inputs,
+            program,
+            "join",
+            private_key,
+            join_proving_key,
+            join_verifying_key,
+            &on_progress
+        );
 
         // Prepare the inclusion proofs for the fee & execution
+        on_progress.report("inclusion", 0.0)?;
         trace.prepare_async::<CurrentBlockMemory, _>(&url).await.map_err(|err| err.to_string())?;
+        on_progress.report("inclusion", 100.0)?;
 
         // Prove the execution and fee
-        let execution = trace
-            .prove_execution::<CurrentAleo, _>("credits.aleo/join", &mut StdRng::from_entropy())
-            .map_err(|e| e.to_string())?;
-        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e| e.to_string())?;
+        let (execution, fee) = Self::prove_execution_and_fee(&trace, "credits.aleo/join", &on_progress)?;
+        Self::store_fee_keys(&self.fee_keys, process)?;
         let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
 
         // Verify the execution and fee
+        on_progress.report("verification", 0.0)?;
         process.verify_execution(&execution).map_err(|err| err.to_string())?;
         process.verify_fee(&fee, execution_id).map_err(|err| err.to_string())?;
+        on_progress.report("verification", 100.0)?;
+
+        if cache {
+            self.persist_keys(&credits, "join").await;
+            self.persist_keys(&credits, "fee").await;
+        }
 
         log("Creating execution transaction for join");
         let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
         Ok(Transaction::from(transaction))
     }
+
+    /// Consolidate several records by creating one round of join transactions
+    ///
+    /// The records are joined pairwise, so every transaction of a round spends different records
+    /// and the whole round can be broadcast at once. If an odd number of records is provided, the
+    /// last record is not joined in this round. Records created by a join can only be spent once
+    /// the join is confirmed on-chain, so after the transactions are accepted call this method
+    /// again with the new records (obtainable via `Transaction.outputRecords(viewKey)`) and the
+    /// leftover record, until a single record remains. Consolidating n records takes ceil(log2(n))
+    /// rounds and n - 1 joins in total.
+    ///
+    /// @param private_key The private key of the sender
+    /// @param records A javascript array of the record strings to combine
+    /// @param fee_credits The amount of credits to pay as a fee for each join
+    /// @param fee_records A javascript array of record strings to spend the fees from. One fee
+    /// record is required for each pair of records
+    /// @param url (optional) The url of the Aleo network node used to build the inclusion proofs
+    /// @param cache Cache the join and fee proving and verifying keys in the ProgramManager memory
+    /// @returns A javascript array of the join transactions for this round
+    #[wasm_bindgen(js_name = "joinMany")]
+    pub async fn join_many(
+        &mut self,
+        private_key: PrivateKey,
+        records: Array,
+        fee_credits: f64,
+        fee_records: Array,
+        url: Option<String>,
+        cache: bool,
+    ) -> Result<Array, String> {
+        let records = records_from_array(&records)?;
+        if records.len() < 2 {
+            return Err("At least two records must be provided to join".to_string());
+        }
+        let fee_records = records_from_array(&fee_records)?;
+        let joins = records.len() / 2;
+        if fee_records.len() < joins {
+            return Err(format!("{joins} joins are required but only {} fee records were provided", fee_records.len()));
+        }
+
+        let transactions = Array::new();
+        for (index, (pair, fee_record)) in records.chunks_exact(2).zip(fee_records).enumerate() {
+            log(&format!("Executing join {} of {joins}", index + 1));
+            let transaction = self
+                .join(
+                    private_key.clone(),
+                    pair[0].clone(),
+                    pair[1].clone(),
+                    fee_credits,
+                    fee_record,
+                    url.clone(),
+                    cache,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .map_err(|err| format!("Join {index} failed: {err}"))?;
+            transactions.push(&transaction.into());
+        }
+        Ok(transactions)
+    }
+}
+
+/// Parse a javascript array of record strings
+pub(crate) fn records_from_array(records: &Array) -> Result<Vec<RecordPlaintext>, String> {
+    records
+        .iter()
+        .map(|record| {
+            record
+                .as_string()
+                .ok_or_else(|| "Records must be provided as strings".to_string())
+                .and_then(|record| RecordPlaintext::from_string(&record))
+        })
+        .collect()
 }
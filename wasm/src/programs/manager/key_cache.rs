@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::log;
+
+use js_sys::{Promise, Reflect, Uint8Array};
+use sha2::{Digest, Sha256};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbFactory, IdbObjectStore, IdbRequest, IdbTransactionMode};
+
+/// The name of the object store holding the serialized keys
+const KEY_STORE: &str = "keys";
+
+/// A persistent cache of proving and verifying keys backed by IndexedDB
+///
+/// Keys are stored by program id, function name, and a hash of the program source, so keys
+/// belonging to an edited program with the same name are never returned. A cache can be attached
+/// to a ProgramManager with `setPersistentKeyCache`, after which keys used by executions with
+/// caching enabled are persisted and rehydrated automatically across page loads.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct IndexedDbKeyCache {
+    database: IdbDatabase,
+}
+
+#[wasm_bindgen]
+impl IndexedDbKeyCache {
+    /// Open (or create) an IndexedDB key cache
+    ///
+    /// @param name (optional) The name of the IndexedDB database, defaults to "aleo-keys"
+    pub async fn open(name: Option<String>) -> Result<IndexedDbKeyCache, String> {
+        let name = name.unwrap_or_else(|| "aleo-keys".to_string());
+        let factory: IdbFactory = Reflect::get(&js_sys::global(), &"indexedDB".into())
+            .ok()
+            .and_then(|factory| factory.dyn_into().ok())
+            .ok_or_else(|| "IndexedDB is not available in this environment".to_string())?;
+        let request = factory.open_with_u32(&name, 1).map_err(|err| format!("{err:?}"))?;
+
+        // Create the object store the first time the database is opened
+        let upgrade_request = request.clone();
+        let on_upgrade = Closure::once_into_js(move |_event: JsValue| {
+            if let Some(database) = upgrade_request.result().ok().and_then(|db| db.dyn_into::<IdbDatabase>().ok()) {
+                if !database.object_store_names().contains(KEY_STORE) {
+                    let _ = database.create_object_store(KEY_STORE);
+                }
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade.unchecked_ref()));
+
+        let database =
+            request_result(&request).await?.dyn_into().map_err(|_| "Failed to open IndexedDB".to_string())?;
+        Ok(Self { database })
+    }
+
+    /// Store the proving and verifying key of a program function
+    ///
+    /// @param program The source code of the program containing the function
+    /// @param function The name of the function the keys belong to
+    /// @param proving_key The proving key of the function
+    /// @param verifying_key The verifying key of the function
+    pub async fn store(
+        &self,
+        program: String,
+        function: String,
+        proving_key: ProvingKey,
+        verifying_key: VerifyingKey,
+    ) -> Result<(), String> {
+        let program = ProgramNative::from_str(&program).map_err(|err| err.to_string())?;
+        self.store_keys(&program, &function, &proving_key, &verifying_key).await
+    }
+
+    /// Load the proving and verifying key of a program function, returning undefined if the
+    /// keys are not in the cache
+    ///
+    /// @param program The source code of the program containing the function
+    /// @param function The name of the function the keys belong to
+    pub async fn load(&self, program: String, function: String) -> Result<Option<KeyPair>, String> {
+        let program = ProgramNative::from_str(&program).map_err(|err| err.to_string())?;
+        self.load_keys(&program, &function).await
+    }
+
+    /// Remove all keys from the cache
+    pub async fn clear(&self) -> Result<(), String> {
+        request_result(&self.object_store(IdbTransactionMode::Readwrite)?.clear().map_err(|err| format!("{err:?}"))?)
+            .await?;
+        Ok(())
+    }
+}
+
+impl IndexedDbKeyCache {
+    /// Store the keys of a function of a parsed program
+    pub(crate) async fn store_keys(
+        &self,
+        program: &ProgramNative,
+        function: &str,
+        proving_key: &ProvingKey,
+        verifying_key: &VerifyingKey,
+    ) -> Result<(), String> {
+        let key = Self::cache_key(program, function);
+        self.put(&format!("{key}/prover"), &proving_key.to_bytes()?).await?;
+        self.put(&format!("{key}/verifier"), &verifying_key.to_bytes()?).await
+    }
+
+    /// Load the keys of a function of a parsed program
+    pub(crate) async fn load_keys(&self, program: &ProgramNative, function: &str) -> Result<Option<KeyPair>, String> {
+        let key = Self::cache_key(program, function);
+        let (Some(proving_key), Some(verifying_key)) =
+            (self.get(&format!("{key}/prover")).await?, self.get(&format!("{key}/verifier")).await?)
+        else {
+            return Ok(None);
+        };
+        Ok(Some(KeyPair::new(ProvingKey::from_bytes(&proving_key)?, VerifyingKey::from_bytes(&verifying_key)?)))
+    }
+
+    /// Check if the keys of a function of a parsed program are stored
+    pub(crate) async fn contains_keys(&self, program: &ProgramNative, function: &str) -> Result<bool, String> {
+        let key = Self::cache_key(program, function);
+        let mut contains = true;
+        for suffix in ["prover", "verifier"] {
+            let request = self
+                .object_store(IdbTransactionMode::Readonly)?
+                .count_with_key(&format!("{key}/{suffix}").into())
+                .map_err(|err| format!("{err:?}"))?;
+            contains &= request_result(&request).await?.as_f64().unwrap_or_default() > 0.0;
+        }
+        Ok(contains)
+    }
+
+    /// Build the key under which the keys of a function are stored
+    fn cache_key(program: &ProgramNative, function: &str) -> String {
+        let program_hash = hex::encode(Sha256::digest(program.to_string().as_bytes()));
+        format!("{}/{function}/{program_hash}", program.id())
+    }
+
+    fn object_store(&self, mode: IdbTransactionMode) -> Result<IdbObjectStore, String> {
+        self.database
+            .transaction_with_str_and_mode(KEY_STORE, mode)
+            .and_then(|transaction| transaction.object_store(KEY_STORE))
+            .map_err(|err| format!("{err:?}"))
+    }
+
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<(), String> {
+        let value = Uint8Array::from(bytes);
+        let request = self
+            .object_store(IdbTransactionMode::Readwrite)?
+            .put_with_key(&value, &key.into())
+            .map_err(|err| format!("{err:?}"))?;
+        request_result(&request).await?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+        let request =
+            self.object_store(IdbTransactionMode::Readonly)?.get(&key.into()).map_err(|err| format!("{err:?}"))?;
+        let value = request_result(&request).await?;
+        Ok(value.dyn_into::<Uint8Array>().ok().map(|bytes| bytes.to_vec()))
+    }
+}
+
+/// Wait for an IndexedDB request to complete and return its result
+async fn request_result(request: &IdbRequest) -> Result<JsValue, String> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    JsFuture::from(promise).await.map_err(|_| {
+        request.error().ok().flatten().map_or("IndexedDB request failed".to_string(), |error| error.message())
+    })?;
+    request.result().map_err(|err| format!("{err:?}"))
+}
+
+impl ProgramManager {
+    /// Insert keys from the persistent key cache into the ProgramManager's memory if they are not
+    /// already cached. Failures are logged rather than returned since the cache is an optimization
+    pub(crate) async fn rehydrate_keys(&mut self, program: &ProgramNative, function: &str) {
+        let Some(key_cache) = self.key_cache.clone() else { return };
+        let Ok(function_id) = IdentifierNative::from_str(function) else { return };
+        if Self::contains_key(&self.process, program.id(), &function_id) {
+            return;
+        }
+
+        match key_cache.load_keys(program, function).await {
+            Ok(Some(mut key_pair)) => {
+                let (proving_key, verifying_key) = (key_pair.proving_key(), key_pair.verifying_key());
+                if let (Ok(proving_key), Ok(verifying_key)) = (proving_key, verifying_key) {
+                    if let Err(err) =
+                        self.cache_keypair_in_wasm_memory(&program.to_string(), function, proving_key, verifying_key)
+                    {
+                        log(&format!("Failed to rehydrate keys for {} - {function}: {err}", program.id()));
+                    }
+                }
+            }
+            Ok(None) => {}
+            Err(err) => log(&format!("Failed to load keys for {} - {function}: {err}", program.id())),
+        }
+    }
+
+    /// Write the keys of a function from the ProgramManager's memory to the persistent key cache
+    /// if they are not already stored. Failures are logged rather than returned
+    pub(crate) async fn persist_keys(&self, program: &ProgramNative, function: &str) {
+        let Some(key_cache) = self.key_cache.as_ref() else { return };
+        let Ok(function_id) = IdentifierNative::from_str(function) else { return };
+        if !Self::contains_key(&self.process, program.id(), &function_id)
+            || key_cache.contains_keys(program, function).await.unwrap_or(false)
+        {
+            return;
+        }
+
+        let result = match Self::get_keypair(&self.process, program.id(), &function_id) {
+            Ok(mut key_pair) => match (key_pair.proving_key(), key_pair.verifying_key()) {
+                (Ok(proving_key), Ok(verifying_key)) => {
+                    key_cache.store_keys(program, function, &proving_key, &verifying_key).await
+                }
+                _ => Ok(()),
+            },
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            log(&format!("Failed to persist keys for {} - {function}: {err}", program.id()));
+        }
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::types::ToBytes;
+
+use js_sys::{ArrayBuffer, WebAssembly};
+use std::io::{self, Write};
+use wasm_bindgen::JsCast;
+
+/// A summary of the keys held in the memory of a ProgramManager
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    programs: u32,
+    proving_keys: u32,
+    verifying_keys: u32,
+    key_bytes: f64,
+    wasm_memory_bytes: f64,
+}
+
+#[wasm_bindgen]
+impl MemoryStats {
+    /// Get the number of programs loaded into the ProgramManager, including credits.aleo
+    #[wasm_bindgen(getter)]
+    pub fn programs(&self) -> u32 {
+        self.programs
+    }
+
+    /// Get the number of cached proving keys, including the fee proving key
+    #[wasm_bindgen(getter, js_name = "provingKeys")]
+    pub fn proving_keys(&self) -> u32 {
+        self.proving_keys
+    }
+
+    /// Get the number of cached verifying keys, including the fee verifying key
+    #[wasm_bindgen(getter, js_name = "verifyingKeys")]
+    pub fn verifying_keys(&self) -> u32 {
+        self.verifying_keys
+    }
+
+    /// Get the approximate number of bytes used by the cached keys
+    #[wasm_bindgen(getter, js_name = "keyBytes")]
+    pub fn key_bytes(&self) -> f64 {
+        self.key_bytes
+    }
+
+    /// Get the number of bytes of memory allocated to the wasm module. Wasm memory cannot shrink,
+    /// so this is the peak memory usage of the module rather than its current usage
+    #[wasm_bindgen(getter, js_name = "wasmMemoryBytes")]
+    pub fn wasm_memory_bytes(&self) -> f64 {
+        self.wasm_memory_bytes
+    }
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Get the number of cached keys and the approximate memory they use
+    ///
+    /// Long-running applications can use this to decide when to evict keys with `clearKeyCache`
+    /// before the wasm memory limit of 4GB is reached.
+    #[wasm_bindgen(js_name = "memoryStats")]
+    pub fn memory_stats(&self) -> Result<MemoryStats, String> {
+        let mut stats = MemoryStats::default();
+        for program_id in self.process.program_ids() {
+            let stack = self.process.get_stack(program_id).map_err(|e| e.to_string())?;
+            stats.programs += 1;
+            for function_name in stack.program().functions().keys() {
+                if let Ok(proving_key) = stack.get_proving_key(function_name) {
+                    stats.proving_keys += 1;
+                    stats.key_bytes += serialized_size(&proving_key)?;
+                }
+                if let Ok(verifying_key) = stack.get_verifying_key(function_name) {
+                    stats.verifying_keys += 1;
+                    stats.key_bytes += serialized_size(&verifying_key)?;
+                }
+            }
+        }
+        let credits = ProgramNative::credits().map_err(|e| e.to_string())?;
+        let (_, fee) = Self::fee_function()?;
+        if let Some((proving_key, verifying_key)) =
+            self.fee_keys.get_keys(&credits, &[], &fee).map_err(|e| e.to_string())?
+        {
+            stats.proving_keys += 1;
+            stats.verifying_keys += 1;
+            stats.key_bytes += serialized_size(&proving_key)? + serialized_size(&verifying_key)?;
+        }
+        let memory = wasm_bindgen::memory().unchecked_into::<WebAssembly::Memory>();
+        stats.wasm_memory_bytes = memory.buffer().unchecked_into::<ArrayBuffer>().byte_length() as f64;
+        Ok(stats)
+    }
+}
+
+impl ProgramManager {
+    /// Remove the cached keys of a program, or of a single function of a program
+    pub(crate) fn evict_keys(&mut self, program_id: &str, function_name: Option<&str>) -> Result<(), String> {
+        let program_id =
+            ProgramIDNative::from_str(program_id).map_err(|_| format!("Invalid program id {program_id}"))?;
+        let stack = match self.process.get_stack(program_id) {
+            Ok(stack) => stack,
+            Err(_) => return Ok(()),
+        };
+        let function_names = match function_name {
+            Some(function_name) => vec![IdentifierNative::from_str(function_name)
+                .map_err(|_| format!("Invalid function name {function_name}"))?],
+            None => stack.program().functions().keys().copied().collect(),
+        };
+        for function_name in function_names {
+            stack.remove_proving_key(&function_name);
+            stack.remove_verifying_key(&function_name);
+        }
+        Ok(())
+    }
+}
+
+/// Count the bytes of the serialized form of a key without allocating a copy of it
+fn serialized_size(key: &impl ToBytes) -> Result<f64, String> {
+    let mut counter = ByteCounter(0);
+    key.write_le(&mut counter).map_err(|e| e.to_string())?;
+    Ok(counter.0 as f64)
+}
+
+/// A writer which only counts the bytes written to it
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const HELLO_PROGRAM: &str = "program hello.aleo;
+
+function main:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+";
+
+    const ARITHMETIC_PROGRAM: &str = "program arithmetic.aleo;
+
+function sum:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+
+function difference:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    sub r0 r1 into r2;
+    output r2 as u32.private;
+";
+
+    #[wasm_bindgen_test]
+    fn test_byte_counter() {
+        let mut counter = ByteCounter(0);
+        counter.write_all(&[0u8; 10]).unwrap();
+        counter.write_all(&[1u8; 5]).unwrap();
+        assert_eq!(counter.0, 15);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_memory_stats_and_eviction() {
+        let mut program_manager = ProgramManager::new();
+        let initial = program_manager.memory_stats().unwrap();
+
+        // Fill the cache with the keys of three functions of two programs
+        program_manager.synthesize_key_pair(HELLO_PROGRAM, "main", None, true).unwrap();
+        program_manager.synthesize_key_pair(ARITHMETIC_PROGRAM, "sum", None, true).unwrap();
+        program_manager.synthesize_key_pair(ARITHMETIC_PROGRAM, "difference", None, true).unwrap();
+        let filled = program_manager.memory_stats().unwrap();
+        assert_eq!(filled.programs(), initial.programs() + 2);
+        assert_eq!(filled.proving_keys(), initial.proving_keys() + 3);
+        assert_eq!(filled.verifying_keys(), initial.verifying_keys() + 3);
+        assert!(filled.key_bytes() > initial.key_bytes());
+        assert!(filled.wasm_memory_bytes() > 0.0);
+
+        // Evict a single function of a program
+        program_manager.clear_key_cache(Some("arithmetic.aleo".to_string()), Some("sum".to_string())).unwrap();
+        let evicted_function = program_manager.memory_stats().unwrap();
+        assert_eq!(evicted_function.proving_keys(), initial.proving_keys() + 2);
+        assert_eq!(evicted_function.verifying_keys(), initial.verifying_keys() + 2);
+        assert!(evicted_function.key_bytes() < filled.key_bytes());
+        assert!(!program_manager.key_exists("arithmetic.aleo", "sum").unwrap());
+        assert!(program_manager.key_exists("arithmetic.aleo", "difference").unwrap());
+
+        // Evict the remaining keys of the program, the keys of other programs are kept
+        program_manager.clear_key_cache(Some("arithmetic.aleo".to_string()), None).unwrap();
+        let evicted_program = program_manager.memory_stats().unwrap();
+        assert_eq!(evicted_program.programs(), filled.programs());
+        assert_eq!(evicted_program.proving_keys(), initial.proving_keys() + 1);
+        assert_eq!(evicted_program.verifying_keys(), initial.verifying_keys() + 1);
+        assert!(evicted_program.key_bytes() < evicted_function.key_bytes());
+        assert!(program_manager.key_exists("hello.aleo", "main").unwrap());
+
+        // A function can't be evicted without its program
+        assert!(program_manager.clear_key_cache(None, Some("main".to_string())).is_err());
+    }
+}
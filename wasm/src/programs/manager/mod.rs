@@ -14,11 +14,26 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod authorize;
+pub use authorize::*;
+
+pub mod batch;
+pub use batch::*;
+
+pub mod broadcast;
+pub use broadcast::*;
+
 pub mod deploy;
 
 pub use deploy::*;
 use rand::{rngs::StdRng, SeedableRng};
-use std::str::FromStr;
+use std::{
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+pub mod estimate;
+pub use estimate::*;
 
 pub mod execute;
 pub use execute::*;
@@ -26,6 +41,12 @@ pub use execute::*;
 pub mod join;
 pub use join::*;
 
+pub mod key_cache;
+pub use key_cache::*;
+
+pub mod memory;
+pub use memory::*;
+
 pub mod split;
 pub use split::*;
 
@@ -34,35 +55,165 @@ pub use transfer::*;
 
 pub mod utils;
 
+pub mod verify;
+pub use verify::*;
+
+pub mod worker;
+pub use worker::*;
+
 use crate::{
+    get_process,
+    log,
     types::{
         CurrentAleo,
+        CurrentNetwork,
+        ExecutionNative,
+        FeeNative,
+        FieldNative,
         IdentifierNative,
         ProcessNative,
         ProgramIDNative,
         ProgramNative,
         ProvingKeyNative,
+        TraceNative,
         VerifyingKeyNative,
     },
     KeyPair,
+    NetworkConfig,
     ProvingKey,
     RecordPlaintext,
     VerifyingKey,
 };
 
-use wasm_bindgen::prelude::wasm_bindgen;
+use aleo_rust::{imported_programs, AleoSdkError, KeyProvider, MemoryKeyProvider};
+use js_sys::{Function, Object};
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+use web_sys::AbortSignal;
 
 #[wasm_bindgen]
-#[derive(Clone)]
 pub struct ProgramManager {
     process: ProcessNative,
+    base_process: ProcessNative,
+    key_cache: Option<IndexedDbKeyCache>,
+    progress: ProgressListener,
+    network: NetworkConfig,
+    fee_keys: MemoryKeyProvider<CurrentNetwork>,
 }
 
 #[wasm_bindgen]
 impl ProgramManager {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self { process: ProcessNative::load_web().unwrap() }
+        let process = ProcessNative::load_web().unwrap();
+        Self {
+            process: process.clone(),
+            base_process: process,
+            key_cache: None,
+            progress: ProgressListener::default(),
+            network: NetworkConfig::default(),
+            fee_keys: MemoryKeyProvider::new(),
+        }
+    }
+
+    /// Set the network configuration used by methods which are not given an explicit url
+    ///
+    /// @param network_config The network configuration to use
+    #[wasm_bindgen(js_name = "setNetworkConfig")]
+    pub fn set_network_config(&mut self, network_config: NetworkConfig) {
+        self.network = network_config;
+    }
+
+    /// Get the network configuration used by the ProgramManager
+    #[wasm_bindgen(js_name = "getNetworkConfig")]
+    pub fn get_network_config(&self) -> NetworkConfig {
+        self.network.clone()
+    }
+
+    /// Attach a persistent IndexedDB key cache to the ProgramManager
+    ///
+    /// When a cache is attached, executions with caching enabled will first look for the keys
+    /// they need in the persistent cache and will store any keys synthesized during execution in
+    /// it, so keys survive page reloads. Pass undefined to detach the cache.
+    ///
+    /// @param key_cache (optional) The key cache to attach
+    #[wasm_bindgen(js_name = "setPersistentKeyCache")]
+    pub fn set_persistent_key_cache(&mut self, key_cache: Option<IndexedDbKeyCache>) {
+        self.key_cache = key_cache;
+    }
+
+    /// Set a callback to receive progress updates during executions and deployments
+    ///
+    /// The callback is invoked as `onProgress(stage, percent)` at the start (0) and end (100) of
+    /// each stage of a transaction. The stages are "authorization", "synthesis" (only when keys
+    /// are not cached), "execution", "inclusion", "proving", "fee" and "verification". Pass
+    /// undefined to remove the callback.
+    ///
+    /// @param on_progress (optional) The callback to invoke
+    #[wasm_bindgen(js_name = "setProgressCallback")]
+    pub fn set_progress_callback(&mut self, on_progress: Option<Function>) {
+        self.progress.on_progress = on_progress;
+    }
+
+    /// Set an AbortSignal which cancels executions, deployments and key synthesis when aborted
+    ///
+    /// WebAssembly cannot interrupt a computation which is already running, so the signal is
+    /// checked at the start and end of each stage reported to the progress callback. Aborting
+    /// will cause the operation to return an error at the next stage boundary. Pass undefined to
+    /// remove the signal.
+    ///
+    /// @param abort_signal (optional) The AbortSignal to observe
+    #[wasm_bindgen(js_name = "setAbortSignal")]
+    pub fn set_abort_signal(&mut self, abort_signal: Option<AbortSignal>) {
+        self.progress.abort_signal = abort_signal;
+    }
+
+    /// Convert a fee to microcredits, checking that the fee record (if one is given) can pay it
+    ///
+    /// Without a fee record the fee is paid from the public balance through
+    /// `credits.aleo/fee_public`, so the network must provide that function.
+    pub(crate) fn validate_fee(credits: f64, fee_record: Option<&RecordPlaintext>) -> Result<u64, String> {
+        if let Some(fee_record) = fee_record {
+            return Self::validate_amount(credits, fee_record, true);
+        }
+        Self::public_fee_function(&ProgramNative::credits().map_err(|e| e.to_string())?)?;
+        if credits <= 0.0 {
+            return Err("Fee must be greater than zero to deploy or execute a program".to_string());
+        }
+        Ok((credits * 1_000_000.0f64) as u64)
+    }
+
+    /// Get the credits.aleo function which pays fees from the public balance
+    ///
+    /// Public fees are only supported when the credits.aleo program bundled with snarkVM provides
+    /// `fee_public`. Otherwise an `invalid_input` error is returned, its message prefixed with the
+    /// error code.
+    pub(crate) fn public_fee_function(credits: &ProgramNative) -> Result<IdentifierNative, String> {
+        let fee_public = IdentifierNative::from_str("fee_public").map_err(|e| e.to_string())?;
+        if !credits.contains_function(&fee_public) {
+            let error = AleoSdkError::invalid_input(
+                "The network does not support paying fees from the public balance, please provide a fee record",
+            );
+            return Err(format!("{}: {error}", error.code()));
+        }
+        Ok(fee_public)
+    }
+
+    /// Format the inputs of a public fee paying for a deployment or execution
+    ///
+    /// `fee_public` takes the fee, then a priority fee on networks which support one, and finally
+    /// the id of the deployment or execution being paid for. No priority fee is paid.
+    pub(crate) fn public_fee_inputs(
+        credits: &ProgramNative,
+        fee_microcredits: u64,
+        id: &FieldNative,
+    ) -> Result<(IdentifierNative, Vec<String>), String> {
+        let function = Self::public_fee_function(credits)?;
+        let inputs = match credits.get_function(&function).map_err(|e| e.to_string())?.inputs().len() {
+            2 => vec![format!("{fee_microcredits}u64"), id.to_string()],
+            3 => vec![format!("{fee_microcredits}u64"), "0u64".to_string(), id.to_string()],
+            _ => return Err(format!("Unsupported signature of credits.aleo/{function}")),
+        };
+        Ok((function, inputs))
     }
 
     /// Validate that an amount being paid from a record is greater than zero and that the record
@@ -135,12 +286,11 @@ impl ProgramManager {
     pub fn get_cached_keypair(&self, program_id: &str, function: &str) -> Result<KeyPair, String> {
         let program_id = ProgramIDNative::from_str(program_id).map_err(|e| e.to_string())?;
         let function_id = IdentifierNative::from_str(function).map_err(|e| e.to_string())?;
-        self.get_keypair(&self.process, &program_id, &function_id)
+        Self::get_keypair(&self.process, &program_id, &function_id)
     }
 
     /// Get a keypair from a process
     pub(crate) fn get_keypair(
-        &self,
         process: &ProcessNative,
         program_id: &ProgramIDNative,
         function_id: &IdentifierNative,
@@ -152,25 +302,42 @@ impl ProgramManager {
         Ok(KeyPair::new(proving_key, verifying_key))
     }
 
-    /// Synthesize a proving and verifying key for a program function. This method should be used
-    /// when there is a need to pre-synthesize keys (i.e. for caching purposes, etc.)
+    /// Synthesize the proving and verifying key for a program function without executing it
+    ///
+    /// This allows applications to pre-warm keys during idle time (i.e. while a user is filling
+    /// out a form) so that later executions of the function can begin proving immediately. If
+    /// `cache` is set to `true` the keys are stored in the ProgramManager's memory and will be used
+    /// by subsequent executions, otherwise they are only returned to the caller.
     ///
     /// @param program The source code of the program containing the desired function
     /// @param function The name of the function to synthesize the key for
-    #[wasm_bindgen(js_name = "synthesizeKeypair")]
-    pub fn synthesize_keypair(&mut self, program: &str, function: &str) -> Result<KeyPair, String> {
-        let mut process = ProcessNative::load_web().map_err(|e| e.to_string())?;
-        let program = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
+    /// @param imports (optional) A javascript object holding the source code of any imported
+    /// programs in the form {"program_name1": "program_source_code", ..}
+    /// @param cache Cache the synthesized keys in the ProgramManager's memory
+    #[wasm_bindgen(js_name = "synthesizeKeyPair")]
+    pub fn synthesize_key_pair(
+        &mut self,
+        program: &str,
+        function: &str,
+        imports: Option<Object>,
+        cache: bool,
+    ) -> Result<KeyPair, String> {
+        let on_progress = self.progress.clone();
+        let mut new_process;
+        let process = get_process!(self, cache, new_process);
+        let program = Self::load_program(process, program, imports.as_ref())?;
         let function_id = IdentifierNative::from_str(function).map_err(|e| e.to_string())?;
         let program_id = program.id();
-        if &program_id.to_string() != "credits.aleo" {
-            process.add_program(&program).map_err(|e| e.to_string())?;
-        }
 
-        process
-            .synthesize_key::<CurrentAleo, _>(program_id, &function_id, &mut StdRng::from_entropy())
-            .map_err(|e| e.to_string())?;
-        self.get_keypair(&process, program_id, &function_id)
+        if !Self::contains_key(process, program_id, &function_id) {
+            log(&format!("Synthesizing keys for {program_id} - {function_id}"));
+            on_progress.report("synthesis", 0.0)?;
+            process
+                .synthesize_key::<CurrentAleo, _>(program_id, &function_id, &mut StdRng::from_entropy())
+                .map_err(|e| e.to_string())?;
+            on_progress.report("synthesis", 100.0)?;
+        }
+        Self::get_keypair(process, program_id, &function_id)
     }
 
     /// Clear key cache in wasm memory.
@@ -178,10 +345,223 @@ impl ProgramManager {
     /// This method will clear the key cache in wasm memory. It is important to note that this will
     /// not DE-allocate the memory assigned to wasm as wasm memory cannot be shrunk. The total
     /// memory allocated to wasm will remain constant but will be available for other usage after
-    /// calling this method.
+    /// calling this method. The credits.aleo fee keys are kept, use `clearFeeKeys` to release them.
+    ///
+    /// @param program_id (optional) Only clear the keys of this program. If not provided, all
+    /// programs and keys are removed from the cache
+    /// @param function_name (optional) Only clear the keys of this function of the program
     #[wasm_bindgen(js_name = "clearKeyCache")]
-    pub fn clear_key_cache(&mut self) {
-        self.process = ProcessNative::load_web().unwrap();
+    pub fn clear_key_cache(&mut self, program_id: Option<String>, function_name: Option<String>) -> Result<(), String> {
+        match program_id {
+            Some(program_id) => self.evict_keys(&program_id, function_name.as_deref()),
+            None if function_name.is_some() => {
+                Err("A program id is required to clear the keys of a function".to_string())
+            }
+            None => {
+                self.process = self.base_process.clone();
+                Ok(())
+            }
+        }
+    }
+
+    /// Reload the ProgramManager's process from scratch
+    ///
+    /// Executions which do not use the cache start from a copy of a process which was loaded when
+    /// the ProgramManager was created, so credits.aleo does not need to be parsed again for every
+    /// execution. This method reloads that process along with the cache, which should only be
+    /// necessary to recover from an unexpected error.
+    #[wasm_bindgen(js_name = "resetProcess")]
+    pub fn reset_process(&mut self) -> Result<(), String> {
+        self.base_process = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        self.process = self.base_process.clone();
+        Ok(())
+    }
+
+    /// Cache the proving and verifying keys of the credits.aleo `fee` function
+    ///
+    /// The fee keys are held separately from the other cached keys and are used by every
+    /// transaction which pays a fee, even when caching is disabled for the transaction. Keys
+    /// synthesized for the fee function are also stored here automatically, so the fee keys are
+    /// only synthesized once per ProgramManager. The keys are checked against the fee verifying key
+    /// bundled with credits.aleo and rejected if they belong to a different function.
+    ///
+    /// @param proving_key The proving key of the fee function
+    /// @param verifying_key The verifying key of the fee function
+    #[wasm_bindgen(js_name = "cacheFeeKeys")]
+    pub fn cache_fee_keys(&mut self, proving_key: ProvingKey, verifying_key: VerifyingKey) -> Result<(), String> {
+        let (credits, fee) = Self::fee_function()?;
+        let proving_key = ProvingKeyNative::from(proving_key);
+        let verifying_key = VerifyingKeyNative::from(verifying_key);
+        let fee_verifying_key = self.base_process.get_verifying_key(&credits, &fee).map_err(|e| e.to_string())?;
+        if **verifying_key != **fee_verifying_key {
+            return Err("The verifying key is not the verifying key of credits.aleo/fee".to_string());
+        }
+        if proving_key.circuit_verifying_key != **fee_verifying_key {
+            return Err("The proving key is not the proving key of credits.aleo/fee".to_string());
+        }
+
+        let fee_keys = MemoryKeyProvider::new();
+        fee_keys.insert(credits, fee, proving_key, verifying_key);
+        self.fee_keys = fee_keys;
+        Ok(())
+    }
+
+    /// Check if the keys of the credits.aleo `fee` function are cached
+    #[wasm_bindgen(js_name = "hasFeeKeys")]
+    pub fn has_fee_keys(&self) -> bool {
+        Self::fee_function().map_or(false, |(credits, fee)| self.fee_keys.contains(&credits, &fee))
+    }
+
+    /// Release the cached keys of the credits.aleo `fee` function
+    #[wasm_bindgen(js_name = "clearFeeKeys")]
+    pub fn clear_fee_keys(&mut self) {
+        self.fee_keys = MemoryKeyProvider::new();
+    }
+
+    /// Insert the fee keys into a process which does not have them yet, preferring the cached fee
+    /// keys over keys passed to the method building the transaction
+    pub(crate) fn load_fee_keys(
+        fee_keys: &MemoryKeyProvider<CurrentNetwork>,
+        process: &mut ProcessNative,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+    ) -> Result<(), String> {
+        let (credits, fee) = Self::fee_function()?;
+        Self::load_provided_keys(fee_keys, process, &credits, &fee)?;
+        let provided_keys = Self::provided_keys(&credits, &fee, proving_key, verifying_key)?;
+        Self::load_provided_keys(&provided_keys, process, &credits, &fee)
+    }
+
+    /// Keep the fee keys of a process if no fee keys are cached yet
+    pub(crate) fn store_fee_keys(
+        fee_keys: &MemoryKeyProvider<CurrentNetwork>,
+        process: &ProcessNative,
+    ) -> Result<(), String> {
+        let (credits, fee) = Self::fee_function()?;
+        if !fee_keys.contains(&credits, &fee) && Self::contains_key(process, &credits, &fee) {
+            let proving_key = process.get_proving_key(&credits, &fee).map_err(|e| e.to_string())?;
+            let verifying_key = process.get_verifying_key(&credits, &fee).map_err(|e| e.to_string())?;
+            fee_keys.insert(credits, fee, proving_key, verifying_key);
+        }
+        Ok(())
+    }
+
+    /// Collect the keys passed to a method into a key provider, ensuring both or neither of the
+    /// proving and verifying key are given
+    pub(crate) fn provided_keys(
+        program_id: &ProgramIDNative,
+        function_id: &IdentifierNative,
+        proving_key: Option<ProvingKey>,
+        verifying_key: Option<VerifyingKey>,
+    ) -> Result<MemoryKeyProvider<CurrentNetwork>, String> {
+        let provided_keys = MemoryKeyProvider::new();
+        match (proving_key, verifying_key) {
+            (Some(proving_key), Some(verifying_key)) => provided_keys.insert(
+                *program_id,
+                *function_id,
+                ProvingKeyNative::from(proving_key),
+                VerifyingKeyNative::from(verifying_key),
+            ),
+            (None, None) => {}
+            _ => {
+                return Err(
+                    "If specifying a key for a program execution, both the proving and verifying key must be specified"
+                        .to_string(),
+                );
+            }
+        }
+        Ok(provided_keys)
+    }
+
+    /// Insert the keys a key provider holds for a function into a process which does not have
+    /// keys for the function yet
+    pub(crate) fn load_provided_keys(
+        key_provider: &dyn KeyProvider<CurrentNetwork>,
+        process: &mut ProcessNative,
+        program_id: &ProgramIDNative,
+        function_id: &IdentifierNative,
+    ) -> Result<(), String> {
+        if Self::contains_key(process, program_id, function_id) {
+            return Ok(());
+        }
+        let program = process.get_program(program_id).map_err(|e| e.to_string())?.clone();
+        let imports = imported_programs(process, &program).map_err(|e| e.to_string())?;
+        if let Some((proving_key, verifying_key)) =
+            key_provider.get_keys(&program, &imports, function_id).map_err(|e| e.to_string())?
+        {
+            log(&format!("Inserting provided proving and verifying keys for {program_id} - {function_id}"));
+            process.insert_proving_key(program_id, function_id, proving_key).map_err(|e| e.to_string())?;
+            process.insert_verifying_key(program_id, function_id, verifying_key).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Get the program and function identifiers of the credits.aleo `fee` function
+    pub(crate) fn fee_function() -> Result<(ProgramIDNative, IdentifierNative), String> {
+        let credits = ProgramIDNative::from_str("credits.aleo").map_err(|e| e.to_string())?;
+        let fee = IdentifierNative::from_str("fee").map_err(|e| e.to_string())?;
+        Ok((credits, fee))
+    }
+
+    /// Prove the execution and the fee of a trace
+    ///
+    /// When the `parallel` feature is enabled the two proofs are generated concurrently on the
+    /// rayon thread pool, otherwise they are generated one after the other.
+    pub(crate) fn prove_execution_and_fee(
+        trace: &TraceNative,
+        locator: &str,
+        on_progress: &ProgressListener,
+    ) -> Result<(ExecutionNative, FeeNative), String> {
+        let prove_execution =
+            || trace.prove_execution::<CurrentAleo, _>(locator, &mut StdRng::from_entropy()).map_err(|e| e.to_string());
+        let prove_fee = || trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e| e.to_string());
+
+        #[cfg(feature = "parallel")]
+        {
+            on_progress.report("proving", 0.0)?;
+            on_progress.report("fee", 0.0)?;
+            let (execution, fee) = rayon::join(prove_execution, prove_fee);
+            on_progress.report("proving", 100.0)?;
+            on_progress.report("fee", 100.0)?;
+            Ok((execution?, fee?))
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            on_progress.report("proving", 0.0)?;
+            let execution = prove_execution()?;
+            on_progress.report("proving", 100.0)?;
+            on_progress.report("fee", 0.0)?;
+            let fee = prove_fee()?;
+            on_progress.report("fee", 100.0)?;
+            Ok((execution, fee))
+        }
+    }
+
+    /// Check that the javascript environment provides the APIs needed to execute programs
+    ///
+    /// Browsers provide everything needed, while NodeJS needs version 18 or later for `fetch` and
+    /// a synchronous `XMLHttpRequest` polyfill, which snarkVM uses to download its parameters. The
+    /// check passes at most once per module, so a polyfill may still be installed after a failure.
+    pub(crate) fn check_environment() -> Result<(), String> {
+        static ENVIRONMENT_CHECKED: AtomicBool = AtomicBool::new(false);
+        if ENVIRONMENT_CHECKED.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let global = js_sys::global();
+        let has = |name: &str| js_sys::Reflect::has(&global, &name.into()).unwrap_or(false);
+        if !has("fetch") {
+            return Err("Program execution requires a global fetch function (NodeJS 18 or later)".to_string());
+        }
+        if !has("XMLHttpRequest") {
+            return Err(
+                "Program execution requires XMLHttpRequest to download the snarkVM parameters, please install \
+                        a polyfill supporting synchronous requests (i.e. xmlhttprequest-ssl) on globalThis when \
+                        running in NodeJS"
+                    .to_string(),
+            );
+        }
+        ENVIRONMENT_CHECKED.store(true, Ordering::Relaxed);
+        Ok(())
     }
 
     /// Check if the cache contains a keypair for a specific function
@@ -208,8 +588,92 @@ impl ProgramManager {
     }
 }
 
+/// The progress callback and abort signal observed during a long running operation
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ProgressListener {
+    on_progress: Option<Function>,
+    abort_signal: Option<AbortSignal>,
+}
+
+impl ProgressListener {
+    /// Report the progress of a stage of a transaction to the progress callback if one is set,
+    /// returning an error if the operation has been aborted
+    pub(crate) fn report(&self, stage: &str, percent: f64) -> Result<(), String> {
+        if self.abort_signal.as_ref().map_or(false, |signal| signal.aborted()) {
+            return Err(format!("Operation aborted during {stage}"));
+        }
+        if let Some(on_progress) = &self.on_progress {
+            if let Err(err) = on_progress.call2(&JsValue::NULL, &JsValue::from_str(stage), &JsValue::from_f64(percent))
+            {
+                log(&format!("Progress callback failed: {err:?}"));
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Default for ProgramManager {
     fn default() -> Self {
         Self::new()
     }
 }
+
+impl Drop for ProgramManager {
+    /// Clear the key cache so the keys held in wasm memory are released along with the
+    /// ProgramManager, including the keys of stacks still shared with other processes
+    fn drop(&mut self) {
+        let program_ids = self.process.program_ids().map(|program_id| program_id.to_string()).collect::<Vec<_>>();
+        for program_id in program_ids {
+            let _ = self.evict_keys(&program_id, None);
+        }
+        self.fee_keys.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const FEE_RECORD: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  microcredits: 1500000u64.private,
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+
+    const PUBLIC_FEE_CREDITS: &str = r"program credits.aleo;
+
+function fee_public:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    input r2 as field.public;
+";
+
+    #[wasm_bindgen_test]
+    fn test_validate_fee() {
+        let fee_record = RecordPlaintext::from_string(FEE_RECORD).unwrap();
+        assert_eq!(ProgramManager::validate_fee(1.5, Some(&fee_record)).unwrap(), 1_500_000);
+        assert!(ProgramManager::validate_fee(2.0, Some(&fee_record)).is_err());
+        assert!(ProgramManager::validate_fee(0.0, Some(&fee_record)).is_err());
+
+        // The credits.aleo program of the pinned network has no fee_public, so a fee record is required
+        let error = ProgramManager::validate_fee(2.0, None).unwrap_err();
+        assert!(error.starts_with("invalid_input: "), "{error}");
+        assert!(ProgramManager::validate_fee(0.0, None).unwrap_err().starts_with("invalid_input: "));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_public_fee_inputs() {
+        let id = FieldNative::from_str("123field").unwrap();
+        let credits = ProgramNative::from_str(PUBLIC_FEE_CREDITS).unwrap();
+        let (function, inputs) = ProgramManager::public_fee_inputs(&credits, 1_500_000, &id).unwrap();
+        assert_eq!(function.to_string(), "fee_public");
+        assert_eq!(inputs, vec!["1500000u64", "0u64", "123field"]);
+
+        // Networks without fee_public require a fee record
+        let credits =
+            ProgramNative::from_str("program credits.aleo;\n\nfunction fee:\n    input r0 as u64.public;\n").unwrap();
+        assert!(ProgramManager::public_fee_inputs(&credits, 1_500_000, &id).is_err());
+    }
+}
@@ -26,7 +26,7 @@ use crate::{
     Transaction,
 };
 
-use js_sys::Array;
+use js_sys::{Array, Object, Reflect};
 use rand::{rngs::StdRng, SeedableRng};
 use std::{ops::Add, str::FromStr};
 
@@ -38,7 +38,8 @@ impl ProgramManager {
     /// @param split_amount The amount of the credit split. This amount will be subtracted from the
     /// value of the record and two new records will be created with the split amount and the remainder
     /// @param amount_record The record to split
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param url (optional) The url of the Aleo network node to send the transaction to. If not
+    /// provided, the node of the ProgramManager's network config is used
     /// @param cache Cache the proving and verifying keys in the ProgramManager memory. If this is
     /// set to `true` the keys synthesized (or passed in as optional parameters via the
     /// `split_proving_key` and `split_verifying_key` arguments) will be stored in the
@@ -53,11 +54,12 @@ impl ProgramManager {
         private_key: PrivateKey,
         split_amount: f64,
         amount_record: RecordPlaintext,
-        url: String,
+        url: Option<String>,
         cache: bool,
         split_proving_key: Option<ProvingKey>,
         split_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
+        let url = self.network.resolve_node_url(url);
         log("Executing split program");
         let amount_microcredits = Self::validate_amount(split_amount, &amount_record, false)?;
 
@@ -67,25 +69,146 @@ impl ProgramManager {
         inputs.set(0u32, wasm_bindgen::JsValue::from_str(&amount_record.to_string()));
         inputs.set(1u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
 
+        let credits = ProgramNative::credits().map_err(|e| e.to_string())?;
+        if cache {
+            self.rehydrate_keys(&credits, "split").await;
+        }
+
+        let on_progress = self.progress.clone();
         let mut new_process;
         let process = get_process!(self, cache, new_process);
 
-        let (_, mut trace) =
-            execute_program!(process, inputs, program, "split", private_key, split_proving_key, split_verifying_key);
+        let (_, mut trace) = execute_program!(
+            process,
+            inputs,
+            program,
+            "split",
+            private_key,
+            split_proving_key,
+            split_verifying_key,
+            &on_progress
+        );
 
         // Prepare the inclusion proofs for the fee & execution
+        on_progress.report("inclusion", 0.0)?;
         trace.prepare_async::<CurrentBlockMemory, _>(&url).await.map_err(|err| err.to_string())?;
+        on_progress.report("inclusion", 100.0)?;
 
         // Prove the execution and fee
+        on_progress.report("proving", 0.0)?;
         let execution = trace
             .prove_execution::<CurrentAleo, _>("credits.aleo/split", &mut StdRng::from_entropy())
             .map_err(|e| e.to_string())?;
+        on_progress.report("proving", 100.0)?;
 
         // Verify the execution and fee
+        on_progress.report("verification", 0.0)?;
         process.verify_execution(&execution).map_err(|err| err.to_string())?;
+        on_progress.report("verification", 100.0)?;
+
+        if cache {
+            self.persist_keys(&credits, "split").await;
+        }
 
         log("Creating execution transaction for split");
         let transaction = TransactionNative::from_execution(execution, None).map_err(|err| err.to_string())?;
         Ok(Transaction::from(transaction))
     }
+
+    /// Split a record into several records of the specified amounts
+    ///
+    /// The split function of credits.aleo only creates two records, and a record created by a split
+    /// can only be split again once the split is confirmed on-chain. This method therefore creates
+    /// one split per call, dividing the amounts into two halves so that the remaining splits of
+    /// both output records can be run independently of each other. If the amounts do not add up
+    /// to the value of the record, the change is appended as an additional amount. The returned
+    /// object has the form {transaction, outputs: [amounts_1, amounts_2]}, where `amounts_1` and
+    /// `amounts_2` are the amounts still to be split from the first and second record created by
+    /// the transaction. Once the transaction is confirmed, call this method again for each output
+    /// record whose amounts contain more than one entry. Splitting a record into n records takes
+    /// n - 1 splits over ceil(log2(n)) rounds.
+    ///
+    /// @param private_key The private key of the owner of the record
+    /// @param record The record to split
+    /// @param amounts A javascript array of the amounts of credits of the records to create
+    /// @param url (optional) The url of the Aleo network node used to build the inclusion proofs
+    /// @param cache Cache the split proving and verifying keys in the ProgramManager memory
+    #[wasm_bindgen(js_name = "splitRecord")]
+    pub async fn split_record(
+        &mut self,
+        private_key: PrivateKey,
+        record: RecordPlaintext,
+        amounts: Array,
+        url: Option<String>,
+        cache: bool,
+    ) -> Result<Object, String> {
+        let amounts = amounts
+            .iter()
+            .map(|amount| {
+                amount
+                    .as_f64()
+                    .filter(|amount| *amount > 0.0)
+                    .map(|amount| (amount * 1_000_000.0f64) as u64)
+                    .ok_or_else(|| "Amounts must be positive numbers".to_string())
+            })
+            .collect::<Result<Vec<u64>, String>>()?;
+        let (first, second) = plan_split(amounts, record.microcredits())?;
+
+        let split_amount = first.iter().sum::<u64>() as f64 / 1_000_000.0f64;
+        let transaction = self.split(private_key, split_amount, record, url, cache, None, None).await?;
+
+        let to_credits = |amounts: Vec<u64>| {
+            amounts.into_iter().map(|amount| JsValue::from_f64(amount as f64 / 1_000_000.0f64)).collect::<Array>()
+        };
+        let outputs = Array::of2(&to_credits(first), &to_credits(second));
+        let result = Object::new();
+        Reflect::set(&result, &"transaction".into(), &transaction.into()).map_err(|_| "Failed to set transaction")?;
+        Reflect::set(&result, &"outputs".into(), &outputs).map_err(|_| "Failed to set outputs")?;
+        Ok(result)
+    }
+}
+
+/// Divide the amounts to split from a record between the two records created by a single split
+fn plan_split(mut amounts: Vec<u64>, available: u64) -> Result<(Vec<u64>, Vec<u64>), String> {
+    let total = amounts.iter().try_fold(0u64, |total, amount| total.checked_add(*amount));
+    match total {
+        Some(total) if total <= available => {
+            if total < available {
+                amounts.push(available - total);
+            }
+        }
+        _ => return Err("The amounts exceed the credits available in the record".to_string()),
+    }
+    if amounts.len() < 2 {
+        return Err("At least two output records are required for a split".to_string());
+    }
+    let second = amounts.split_off(amounts.len() / 2);
+    Ok((amounts, second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_plan_split() {
+        let (first, second) = plan_split(vec![1, 2, 3, 4], 10).unwrap();
+        assert_eq!(first, vec![1, 2]);
+        assert_eq!(second, vec![3, 4]);
+
+        // The change is split off as an additional record
+        let (first, second) = plan_split(vec![5, 2], 10).unwrap();
+        assert_eq!(first, vec![5]);
+        assert_eq!(second, vec![2, 3]);
+
+        let (first, second) = plan_split(vec![4], 10).unwrap();
+        assert_eq!(first, vec![4]);
+        assert_eq!(second, vec![6]);
+
+        assert!(plan_split(vec![10], 10).is_err());
+        assert!(plan_split(vec![6, 5], 10).is_err());
+        assert!(plan_split(vec![u64::MAX, 1], 10).is_err());
+    }
 }
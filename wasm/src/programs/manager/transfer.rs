@@ -21,6 +21,7 @@ use crate::{
     get_process,
     log,
     types::{CurrentAleo, CurrentBlockMemory, IdentifierNative, ProcessNative, ProgramNative, TransactionNative},
+    Address,
     PrivateKey,
     RecordPlaintext,
     Transaction,
@@ -38,10 +39,12 @@ impl ProgramManager {
     /// @param amount_credits The amount of credits to send
     /// @param recipient The recipient of the transaction
     /// @param transfer_type The type of the transfer (options: "private", "public", "private_to_public", "public_to_private")
+    /// or the name of the credits.aleo transfer function to call
     /// @param amount_record The record to fund the amount from
     /// @param fee_credits The amount of credits to pay as a fee
     /// @param fee_record The record to spend the fee from
-    /// @param url The url of the Aleo network node to send the transaction to
+    /// @param url (optional) The url of the Aleo network node to send the transaction to. If not
+    /// provided, the node of the ProgramManager's network config is used
     /// @param cache Cache the proving and verifying keys in the ProgramManager memory. If this is
     /// set to `true` the keys synthesized (or passed in as optional parameters via the
     /// `transfer_proving_key` and `transfer_verifying_key` arguments) will be stored in the
@@ -64,13 +67,14 @@ impl ProgramManager {
         amount_record: Option<RecordPlaintext>,
         fee_credits: f64,
         fee_record: RecordPlaintext,
-        url: String,
+        url: Option<String>,
         cache: bool,
         transfer_proving_key: Option<ProvingKey>,
         transfer_verifying_key: Option<VerifyingKey>,
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
     ) -> Result<Transaction, String> {
+        let url = self.network.resolve_node_url(url);
         log("Executing transfer program");
         let amount_microcredits = if let Some(amount_record) = amount_record.as_ref() {
             Self::validate_amount(amount_credits, amount_record, false)?
@@ -81,62 +85,19 @@ impl ProgramManager {
 
         log("Setup the program and inputs");
         let program = ProgramNative::credits().unwrap().to_string();
-        let inputs = Array::new_with_length(3);
-
-        let transfer_type = match transfer_type.as_str() {
-            "private" => "transfer_".to_string().add("private"),
-            "private_to_public" => "transfer_".to_string().add("private_to_public"),
-            "public" => "transfer_".to_string().add("public"),
-            "public_to_private" => "transfer_".to_string().add("public_to_private"),
-            _ => transfer_type,
-        };
+        let (transfer_type, inputs) =
+            Self::transfer_inputs(&transfer_type, amount_record.as_ref(), &recipient, amount_microcredits)?;
 
-        let transfer_type = match transfer_type.as_str() {
-            "transfer_private" => {
-                if amount_record.is_none() {
-                    return Err("Amount record must be provided for private transfers".to_string());
-                }
-                inputs.set(0u32, wasm_bindgen::JsValue::from_str(&amount_record.unwrap().to_string()));
-                inputs.set(1u32, wasm_bindgen::JsValue::from_str(&recipient));
-                inputs.set(2u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
-                transfer_type
-            }
-            "transfer_private_to_public" => {
-                if amount_record.is_none() {
-                    return Err("Amount record must be provided for private transfers".to_string());
-                }
-                inputs.set(0u32, wasm_bindgen::JsValue::from_str(&amount_record.unwrap().to_string()));
-                inputs.set(1u32, wasm_bindgen::JsValue::from_str(&recipient));
-                inputs.set(2u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
-                transfer_type
-            }
-            "transfer_public" => {
-                inputs.set(0u32, wasm_bindgen::JsValue::from_str(&recipient));
-                inputs.set(1u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
-                transfer_type
-            }
-            "transfer_public_to_private" => {
-                inputs.set(1u32, wasm_bindgen::JsValue::from_str(&recipient));
-                inputs.set(2u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
-                transfer_type
-            }
-            _ => return Err("Invalid transfer type".to_string()),
-        };
+        let credits = ProgramNative::credits().map_err(|e| e.to_string())?;
+        if cache {
+            self.rehydrate_keys(&credits, &transfer_type).await;
+            self.rehydrate_keys(&credits, "fee").await;
+        }
 
+        let on_progress = self.progress.clone();
         let mut new_process;
         let process = get_process!(self, cache, new_process);
-        let fee_identifier = IdentifierNative::from_str("fee").map_err(|e| e.to_string())?;
-        let stack = process.get_stack("credits.aleo").map_err(|e| e.to_string())?;
-        if !stack.contains_proving_key(&fee_identifier) && fee_proving_key.is_some() && fee_verifying_key.is_some() {
-            let fee_proving_key = fee_proving_key.unwrap();
-            let fee_verifying_key = fee_verifying_key.unwrap();
-            stack
-                .insert_proving_key(&fee_identifier, ProvingKeyNative::from(fee_proving_key))
-                .map_err(|e| e.to_string())?;
-            stack
-                .insert_verifying_key(&fee_identifier, VerifyingKeyNative::from(fee_verifying_key))
-                .map_err(|e| e.to_string())?;
-        }
+        Self::load_fee_keys(&self.fee_keys, process, fee_proving_key, fee_verifying_key)?;
 
         let (_, mut trace) = execute_program!(
             process,
@@ -145,25 +106,109 @@ impl ProgramManager {
             &transfer_type,
             private_key,
             transfer_proving_key,
-            transfer_verifying_key
+            transfer_verifying_key,
+            &on_progress
         );
 
         // Prepare the inclusion proofs for the fee & execution
+        on_progress.report("inclusion", 0.0)?;
         trace.prepare_async::<CurrentBlockMemory, _>(&url).await.map_err(|err| err.to_string())?;
+        on_progress.report("inclusion", 100.0)?;
 
         // Prove the execution and fee
-        let execution = trace
-            .prove_execution::<CurrentAleo, _>("credits.aleo/transfer", &mut StdRng::from_entropy())
-            .map_err(|e| e.to_string())?;
-        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e| e.to_string())?;
+        let (execution, fee) = Self::prove_execution_and_fee(&trace, "credits.aleo/transfer", &on_progress)?;
+        Self::store_fee_keys(&self.fee_keys, process)?;
         let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
 
         // Verify the execution and fee
+        on_progress.report("verification", 0.0)?;
         process.verify_execution(&execution).map_err(|err| err.to_string())?;
         process.verify_fee(&fee, execution_id).map_err(|err| err.to_string())?;
+        on_progress.report("verification", 100.0)?;
+
+        if cache {
+            self.persist_keys(&credits, &transfer_type).await;
+            self.persist_keys(&credits, "fee").await;
+        }
 
         log("Creating execution transaction for transfer");
         let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
         Ok(Transaction::from(transaction))
     }
 }
+
+impl ProgramManager {
+    /// Build the credits.aleo function name and inputs for a transfer
+    ///
+    /// The transfer type may be given either as the short form (i.e. "private") or as the name of
+    /// the credits.aleo function (i.e. "transfer_private")
+    pub(crate) fn transfer_inputs(
+        transfer_type: &str,
+        amount_record: Option<&RecordPlaintext>,
+        recipient: &str,
+        amount_microcredits: u64,
+    ) -> Result<(String, Array), String> {
+        Address::validate(recipient)?;
+        let function = match transfer_type.strip_prefix("transfer_").unwrap_or(transfer_type) {
+            function @ ("private" | "private_to_public" | "public" | "public_to_private") => {
+                "transfer_".to_string().add(function)
+            }
+            _ => return Err("Invalid transfer type".to_string()),
+        };
+
+        let inputs = Array::new();
+        if function.starts_with("transfer_private") {
+            let amount_record =
+                amount_record.ok_or_else(|| "Amount record must be provided for private transfers".to_string())?;
+            inputs.push(&wasm_bindgen::JsValue::from_str(&amount_record.to_string()));
+        }
+        inputs.push(&wasm_bindgen::JsValue::from_str(recipient));
+        inputs.push(&wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
+        Ok((function, inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const RECIPIENT: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+    const RECORD: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  microcredits: 1500000000000000u64.private,
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+
+    #[wasm_bindgen_test]
+    fn test_transfer_inputs() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+
+        for transfer_type in ["private", "transfer_private", "private_to_public"] {
+            let (function, inputs) =
+                ProgramManager::transfer_inputs(transfer_type, Some(&record), RECIPIENT, 5).unwrap();
+            assert!(function.starts_with("transfer_private"));
+            assert_eq!(inputs.length(), 3);
+            assert_eq!(inputs.get(0).as_string().unwrap(), RECORD);
+            assert_eq!(inputs.get(1).as_string().unwrap(), RECIPIENT);
+            assert_eq!(inputs.get(2).as_string().unwrap(), "5u64");
+        }
+
+        // Ensure public transfers do not take a record
+        for transfer_type in ["public", "public_to_private"] {
+            let (function, inputs) = ProgramManager::transfer_inputs(transfer_type, None, RECIPIENT, 5).unwrap();
+            assert_eq!(function, format!("transfer_{transfer_type}"));
+            assert_eq!(inputs.length(), 2);
+            assert_eq!(inputs.get(0).as_string().unwrap(), RECIPIENT);
+            assert_eq!(inputs.get(1).as_string().unwrap(), "5u64");
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_transfer_inputs_invalid() {
+        assert!(ProgramManager::transfer_inputs("private", None, RECIPIENT, 5).is_err());
+        assert!(ProgramManager::transfer_inputs("mint", None, RECIPIENT, 5).is_err());
+        assert!(ProgramManager::transfer_inputs("public", None, "aleo1notanaddress", 5).is_err());
+    }
+}
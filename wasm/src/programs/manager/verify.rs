@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{
+    get_process,
+    log,
+    types::{
+        DeploymentNative,
+        ExecutionNative,
+        IdentifierNative,
+        ProcessNative,
+        ProgramIDNative,
+        ProgramNative,
+        TransactionNative,
+    },
+    AleoNetworkClient,
+    NetworkConfig,
+    Transaction,
+};
+
+use js_sys::Object;
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Verify the proof of an execution or of an execution transaction
+    ///
+    /// This allows light clients and explorers to check the proofs of third-party executions
+    /// without trusting a node. Every transition of the execution is verified against the
+    /// verifying key of its own function. Keys which are not provided or cached are synthesized,
+    /// which can take some time for large functions.
+    ///
+    /// @param execution The execution, or the execution transaction, to verify
+    /// @param program The source code of the program containing the executed function
+    /// @param imports (optional) A javascript object holding the source code of any imported
+    /// programs in the form {"program_name1": "program_source_code", ..}
+    /// @param verifying_key (optional) The verifying key of the executed function, the keys of
+    /// the functions it calls are synthesized if they are not cached
+    /// @param cache Cache the program and keys used for verification in the ProgramManager's
+    /// memory
+    /// @returns `true` if the execution proof is valid and `false` otherwise
+    #[wasm_bindgen(js_name = "verifyExecution")]
+    pub fn verify_execution(
+        &mut self,
+        execution: &str,
+        program: String,
+        imports: Option<Object>,
+        verifying_key: Option<VerifyingKey>,
+        cache: bool,
+    ) -> Result<bool, String> {
+        let execution = Self::parse_execution(execution)?;
+        let transition = execution.transitions().last().ok_or_else(|| "The execution is empty".to_string())?;
+        let (program_id, function_name) = (*transition.program_id(), *transition.function_name());
+
+        let mut new_process;
+        let process = get_process!(self, cache, new_process);
+        let program = Self::load_program(process, &program, imports.as_ref())?;
+        if program.id() != &program_id {
+            return Err(format!("The execution runs {program_id} but {} was provided", program.id()));
+        }
+
+        if let Some(verifying_key) = verifying_key {
+            if !Self::contains_verifying_key(process, &program_id, &function_name) {
+                process
+                    .insert_verifying_key(&program_id, &function_name, VerifyingKeyNative::from(verifying_key))
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+        for transition in execution.transitions() {
+            let (program_id, function_name) = (transition.program_id(), transition.function_name());
+            if !Self::contains_verifying_key(process, program_id, function_name) {
+                log(&format!("Synthesizing keys for {program_id} - {function_name}"));
+                process
+                    .synthesize_key::<CurrentAleo, _>(program_id, function_name, &mut StdRng::from_entropy())
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        log("Verifying execution");
+        Ok(process.verify_execution(&execution).is_ok())
+    }
+
+    /// Verify that a program deployed on-chain matches the provided source code
+    ///
+    /// The deployment transaction of the program is fetched from the network and its program is
+    /// compared to the provided source. The certificates of the deployed verifying keys are then
+    /// checked against the program, which proves the keys belong to the functions of the source.
+    /// This requires synthesizing the circuits of every function, so it can take some time for
+    /// large programs.
+    ///
+    /// @param program The source code of the program
+    /// @param imports (optional) A javascript object holding the source code of any imported
+    /// programs in the form {"program_name1": "program_source_code", ..}
+    /// @param url (optional) The url of the Aleo network node to fetch the deployment from. If not
+    /// provided, the node of the ProgramManager's network config is used
+    /// @returns `true` if the deployed program and verifying keys match the source and `false`
+    /// otherwise
+    #[wasm_bindgen(js_name = "verifyOnChainProgram")]
+    pub async fn verify_on_chain_program(
+        &mut self,
+        program: String,
+        imports: Option<Object>,
+        url: Option<String>,
+    ) -> Result<bool, String> {
+        let program = ProgramNative::from_str(&program).map_err(|_| "The program provided was invalid".to_string())?;
+        let network = NetworkConfig::new(self.network.resolve_node_url(url), Some(self.network.network()), None)?;
+        let client = AleoNetworkClient::from_network_config(&network);
+
+        log(&format!("Fetching the deployment of {}", program.id()));
+        let transaction = client.get_deployment_transaction(program.id().to_string()).await?;
+        let deployment = match TransactionNative::from(transaction) {
+            TransactionNative::Deploy(_, _, deployment, _) => deployment,
+            _ => return Err(format!("The node did not return a deployment of {}", program.id())),
+        };
+        self.verify_deployment(&program, &deployment, imports.as_ref())
+    }
+}
+
+impl ProgramManager {
+    // Parse an execution, or the execution of an execution transaction
+    fn parse_execution(execution: &str) -> Result<ExecutionNative, String> {
+        match TransactionNative::from_str(execution) {
+            Ok(TransactionNative::Execute(_, execution, _)) => Ok(execution),
+            Ok(_) => Err("Only executions can be verified with verifyExecution".to_string()),
+            Err(_) => ExecutionNative::from_str(execution)
+                .map_err(|_| "The execution or transaction provided was invalid".to_string()),
+        }
+    }
+
+    // Check that a deployment holds a program and verifying keys matching the program source
+    fn verify_deployment(
+        &mut self,
+        program: &ProgramNative,
+        deployment: &DeploymentNative,
+        imports: Option<&Object>,
+    ) -> Result<bool, String> {
+        if deployment.program() != program {
+            log("The deployed program does not match the provided source");
+            return Ok(false);
+        }
+
+        // Verify the deployment in a process which only holds the imports of the program
+        let mut new_process;
+        let process = get_process!(self, false, new_process);
+        Self::resolve_imports(process, program, imports)?;
+
+        log("Verifying the deployed verifying keys");
+        Ok(process.verify_deployment::<CurrentAleo, _>(deployment, &mut StdRng::from_entropy()).is_ok())
+    }
+
+    // Check if the process holds the verifying key of a function
+    fn contains_verifying_key(
+        process: &ProcessNative,
+        program_id: &ProgramIDNative,
+        function_name: &IdentifierNative,
+    ) -> bool {
+        process.get_stack(program_id).map_or(false, |stack| stack.contains_verifying_key(function_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::programs::transaction::tests::TRANSACTION_STRING;
+
+    use wasm_bindgen_test::*;
+
+    const HELLO_PROGRAM: &str = "program hello.aleo;
+
+function main:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+";
+
+    #[wasm_bindgen_test]
+    fn test_verify_execution() {
+        let mut program_manager = ProgramManager::new();
+        let credits = ProgramNative::credits().unwrap().to_string();
+        let transaction: serde_json::Value = serde_json::from_str(TRANSACTION_STRING).unwrap();
+        let execution = transaction["execution"].to_string();
+
+        // Both the transaction and its bare execution verify
+        assert!(program_manager.verify_execution(TRANSACTION_STRING, credits.clone(), None, None, true).unwrap());
+        assert!(program_manager.verify_execution(&execution, credits.clone(), None, None, true).unwrap());
+
+        // An execution whose transition commitment was altered does not
+        let tcm = transaction["execution"]["transitions"][0]["tcm"].as_str().unwrap();
+        let tampered = execution.replace(tcm, "1field");
+        assert!(!program_manager.verify_execution(&tampered, credits.clone(), None, None, true).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_verify_deployment() {
+        let mut program_manager = ProgramManager::new();
+        let program = ProgramNative::from_str(HELLO_PROGRAM).unwrap();
+        let deployment =
+            ProcessNative::load_web().unwrap().deploy::<CurrentAleo, _>(&program, &mut StdRng::from_entropy()).unwrap();
+
+        // A deployment verifies against the source it was built from
+        assert!(program_manager.verify_deployment(&program, &deployment, None).unwrap());
+
+        // But not against an edited source with the same program id
+        let edited = ProgramNative::from_str(&HELLO_PROGRAM.replace("add r0 r1", "sub r0 r1")).unwrap();
+        assert!(!program_manager.verify_deployment(&edited, &deployment, None).unwrap());
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::{PrivateKey, Transaction};
+
+use js_sys::{Array, Promise, Reflect};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Blob, BlobPropertyBag, MessageEvent, Url, Worker, WorkerOptions, WorkerType};
+
+/// The promise callbacks of requests which are waiting for a response from the worker
+type PendingRequests = Rc<RefCell<HashMap<u32, (Function, Function)>>>;
+
+/// Runs `execute` and `deploy` calls on a web worker
+///
+/// The worker loads its own copy of this module and ProgramManager, so long running proofs do
+/// not block the main thread. Requests are posted to the worker as `{id, method, params}`
+/// messages and each call returns a promise which resolves with the resulting transaction.
+#[wasm_bindgen]
+pub struct ExecutionWorker {
+    worker: Worker,
+    next_id: u32,
+    pending: PendingRequests,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+}
+
+#[wasm_bindgen]
+impl ExecutionWorker {
+    /// Start a new execution worker
+    ///
+    /// @param module_url The url of the javascript entry point of this package (i.e. the url of
+    /// `aleo_wasm.js`), which the worker imports to load its own ProgramManager
+    #[wasm_bindgen(constructor)]
+    pub fn new(module_url: &str) -> Result<ExecutionWorker, String> {
+        let script = Array::of1(&worker_script(module_url).into());
+        let mut blob_options = BlobPropertyBag::new();
+        blob_options.type_("text/javascript");
+        let blob = Blob::new_with_str_sequence_and_options(&script, &blob_options)
+            .map_err(|_| "Failed to create the worker script".to_string())?;
+        let script_url = Url::create_object_url_with_blob(&blob).map_err(|_| "Failed to create the worker url")?;
+
+        let mut worker_options = WorkerOptions::new();
+        worker_options.type_(WorkerType::Module);
+        let worker = Worker::new_with_options(&script_url, &worker_options)
+            .map_err(|_| "Failed to start the execution worker".to_string())?;
+
+        let pending = PendingRequests::default();
+        let on_message = {
+            let pending = pending.clone();
+            Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+                let data = event.data();
+                let id = Reflect::get(&data, &"id".into()).ok().and_then(|id| id.as_f64()).unwrap_or(-1.0) as u32;
+                if let Some((resolve, reject)) = pending.borrow_mut().remove(&id) {
+                    let error = Reflect::get(&data, &"error".into()).unwrap_or(JsValue::UNDEFINED);
+                    let _ = if error.is_undefined() {
+                        let transaction = Reflect::get(&data, &"transaction".into()).unwrap_or(JsValue::UNDEFINED);
+                        resolve.call1(&JsValue::NULL, &transaction)
+                    } else {
+                        reject.call1(&JsValue::NULL, &error)
+                    };
+                }
+            })
+        };
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        Ok(Self { worker, next_id: 0, pending, _on_message: on_message })
+    }
+
+    /// Execute a function on the worker, resolving with the transaction string
+    ///
+    /// @param params An object holding the arguments of `ProgramManager.execute` in the form
+    /// {privateKey, program, function, inputs, feeCredits, feeRecord, url, cache}
+    pub fn execute(&mut self, params: Object) -> Promise {
+        self.request("execute", params)
+    }
+
+    /// Deploy a program on the worker, resolving with the transaction string
+    ///
+    /// @param params An object holding the arguments of `ProgramManager.deploy` in the form
+    /// {privateKey, program, imports, feeCredits, feeRecord, url, cache}
+    pub fn deploy(&mut self, params: Object) -> Promise {
+        self.request("deploy", params)
+    }
+
+    /// Stop the worker, rejecting any requests which have not completed
+    pub fn terminate(&mut self) {
+        self.worker.terminate();
+        for (_, (_, reject)) in self.pending.borrow_mut().drain() {
+            let _ = reject.call1(&JsValue::NULL, &"The execution worker was terminated".into());
+        }
+    }
+}
+
+impl ExecutionWorker {
+    /// Post a request to the worker and return a promise for its result
+    fn request(&mut self, method: &str, params: Object) -> Promise {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        let worker = self.worker.clone();
+        let pending = self.pending.clone();
+        let method = method.to_string();
+        Promise::new(&mut |resolve, reject| {
+            let message = Object::new();
+            let posted = Reflect::set(&message, &"id".into(), &id.into())
+                .and_then(|_| Reflect::set(&message, &"method".into(), &method.as_str().into()))
+                .and_then(|_| Reflect::set(&message, &"params".into(), &params))
+                .and_then(|_| worker.post_message(&message));
+            match posted {
+                Ok(_) => {
+                    pending.borrow_mut().insert(id, (resolve, reject));
+                }
+                Err(error) => {
+                    let _ = reject.call1(&JsValue::NULL, &error);
+                }
+            }
+        })
+    }
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Handle a request posted by an `ExecutionWorker`
+    ///
+    /// This is called by the script running inside the worker and resolves with the response
+    /// message to post back, either `{id, transaction}` or `{id, error}`.
+    ///
+    /// @param message The request message in the form {id, method, params}
+    #[wasm_bindgen(js_name = "handleWorkerMessage")]
+    pub async fn handle_worker_message(&mut self, message: JsValue) -> Result<JsValue, String> {
+        let id = Reflect::get(&message, &"id".into()).map_err(|_| "The message has no id".to_string())?;
+        let method = Reflect::get(&message, &"method".into()).ok().and_then(|method| method.as_string());
+        let params = Reflect::get(&message, &"params".into()).unwrap_or(JsValue::UNDEFINED);
+
+        let result = match method.as_deref() {
+            Some("execute") => self.execute_request(&params).await,
+            Some("deploy") => self.deploy_request(&params).await,
+            _ => Err("Unknown execution worker method".to_string()),
+        };
+
+        let response = Object::new();
+        Reflect::set(&response, &"id".into(), &id).map_err(|_| "Failed to set property")?;
+        match result {
+            Ok(transaction) => Reflect::set(&response, &"transaction".into(), &transaction.to_string().into()),
+            Err(error) => Reflect::set(&response, &"error".into(), &error.into()),
+        }
+        .map_err(|_| "Failed to set property")?;
+        Ok(response.into())
+    }
+}
+
+impl ProgramManager {
+    // Run an execute request received from an execution worker
+    async fn execute_request(&mut self, params: &JsValue) -> Result<Transaction, String> {
+        let inputs = Array::from(&worker_param(params, "inputs"));
+        self.execute(
+            PrivateKey::from_string(&required_string(params, "privateKey")?)?,
+            required_string(params, "program")?,
+            required_string(params, "function")?,
+            inputs,
+            worker_param(params, "feeCredits").as_f64().ok_or_else(|| "Missing parameter feeCredits".to_string())?,
+            optional_record(params, "feeRecord")?,
+            worker_param(params, "url").as_string(),
+            worker_param(params, "cache").as_bool().unwrap_or(false),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    // Run a deploy request received from an execution worker
+    async fn deploy_request(&mut self, params: &JsValue) -> Result<Transaction, String> {
+        let imports = worker_param(params, "imports");
+        self.deploy(
+            PrivateKey::from_string(&required_string(params, "privateKey")?)?,
+            required_string(params, "program")?,
+            imports.dyn_into::<Object>().ok(),
+            worker_param(params, "feeCredits").as_f64().ok_or_else(|| "Missing parameter feeCredits".to_string())?,
+            optional_record(params, "feeRecord")?,
+            worker_param(params, "url").as_string(),
+            worker_param(params, "cache").as_bool().unwrap_or(false),
+            None,
+            None,
+        )
+        .await
+    }
+}
+
+/// Get a parameter of a worker or batch request
+pub(crate) fn worker_param(params: &JsValue, name: &str) -> JsValue {
+    Reflect::get(params, &name.into()).unwrap_or(JsValue::UNDEFINED)
+}
+
+/// Get a required string parameter of a worker or batch request
+pub(crate) fn required_string(params: &JsValue, name: &str) -> Result<String, String> {
+    worker_param(params, name).as_string().ok_or_else(|| format!("Missing parameter {name}"))
+}
+
+/// Get an optional record parameter of a worker or batch request
+pub(crate) fn optional_record(params: &JsValue, name: &str) -> Result<Option<RecordPlaintext>, String> {
+    worker_param(params, name).as_string().map(|record| RecordPlaintext::from_string(&record)).transpose()
+}
+
+/// Generate the script run by an execution worker
+fn worker_script(module_url: &str) -> String {
+    format!(
+        r#"import init, {{ ProgramManager }} from {module_url:?};
+const manager = init().then(() => new ProgramManager());
+self.onmessage = async (event) => {{
+    const response = await (await manager).handleWorkerMessage(event.data).catch((error) => ({{ id: event.data.id, error: String(error) }}));
+    self.postMessage(response);
+}};
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_worker_script() {
+        let script = worker_script("https://example.com/pkg/aleo_wasm.js");
+        assert!(script.starts_with("import init, { ProgramManager } from \"https://example.com/pkg/aleo_wasm.js\";"));
+        assert!(script.contains("handleWorkerMessage(event.data)"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_handle_worker_message() {
+        let mut program_manager = ProgramManager::new();
+        let message = Object::new();
+        Reflect::set(&message, &"id".into(), &7.into()).unwrap();
+        Reflect::set(&message, &"method".into(), &"mint".into()).unwrap();
+
+        let response = program_manager.handle_worker_message(message.into()).await.unwrap();
+        assert_eq!(Reflect::get(&response, &"id".into()).unwrap(), JsValue::from(7));
+        assert_eq!(Reflect::get(&response, &"error".into()).unwrap(), "Unknown execution worker method");
+
+        // Ensure missing parameters are reported back to the caller
+        let message = Object::new();
+        Reflect::set(&message, &"id".into(), &8.into()).unwrap();
+        Reflect::set(&message, &"method".into(), &"execute".into()).unwrap();
+        Reflect::set(&message, &"params".into(), &Object::new()).unwrap();
+        let response = program_manager.handle_worker_message(message.into()).await.unwrap();
+        assert_eq!(Reflect::get(&response, &"error".into()).unwrap(), "Missing parameter privateKey");
+    }
+}
@@ -14,27 +14,64 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "programs")]
 mod macros;
 
+#[cfg(feature = "programs")]
+pub mod authorization;
+#[cfg(feature = "programs")]
+pub use authorization::*;
+
+pub mod ciphertext;
+pub use ciphertext::*;
+
+#[cfg(feature = "programs")]
+pub mod delegated_prover;
+#[cfg(feature = "programs")]
+pub use delegated_prover::*;
+
+#[cfg(feature = "programs")]
+pub mod key_downloader;
+#[cfg(feature = "programs")]
+pub use key_downloader::*;
+
+#[cfg(feature = "programs")]
 pub mod key_pair;
+#[cfg(feature = "programs")]
 pub use key_pair::*;
 
-#[cfg(feature = "browser")]
+#[cfg(any(feature = "browser", feature = "nodejs"))]
 pub mod manager;
-#[cfg(feature = "browser")]
+#[cfg(any(feature = "browser", feature = "nodejs"))]
 pub use manager::*;
 
+#[cfg(feature = "programs")]
 pub mod response;
+#[cfg(feature = "programs")]
 pub use response::*;
 
+pub mod network_config;
+pub use network_config::*;
+
+pub mod plaintext;
+pub use plaintext::*;
+
+#[cfg(feature = "programs")]
 pub mod program;
+#[cfg(feature = "programs")]
 pub use program::*;
 
+#[cfg(feature = "programs")]
 pub mod proving_key;
+#[cfg(feature = "programs")]
 pub use proving_key::*;
 
+#[cfg(feature = "programs")]
 pub mod transaction;
+#[cfg(feature = "programs")]
 pub use transaction::*;
 
+#[cfg(feature = "programs")]
 pub mod verifying_key;
+#[cfg(feature = "programs")]
 pub use verifying_key::*;
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::{AleoNetwork, CurrentNetwork};
+
+use aleo_rust::Endpoint;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The default Aleo node used when no other node is configured
+const DEFAULT_NODE_URL: &str = "https://vm.aleo.org/api";
+
+/// Configuration of the Aleo network a ProgramManager communicates with
+///
+/// This holds the url of the node used to build inclusion proofs and query state, the url
+/// transactions are broadcast to, and the network being targeted. Methods which accept an
+/// optional url fall back to this configuration when the url is not given.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkConfig {
+    node_url: String,
+    network: String,
+    broadcast_url: Option<String>,
+}
+
+#[wasm_bindgen]
+impl NetworkConfig {
+    /// Create a new network configuration
+    ///
+    /// @param node_url The base url of the Aleo node (i.e. "https://vm.aleo.org/api")
+    /// @param network (optional) The network to target (default "testnet3")
+    /// @param broadcast_url (optional) The url transactions are broadcast to (default
+    /// "{node_url}/{network}/transaction/broadcast")
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        node_url: String,
+        network: Option<String>,
+        broadcast_url: Option<String>,
+    ) -> Result<NetworkConfig, String> {
+        // The network must be the network the SDK was built for
+        let network = match network {
+            Some(network) => network.parse::<AleoNetwork>().map_err(|err| err.to_string())?,
+            None => AleoNetwork::of::<CurrentNetwork>().map_err(|err| err.to_string())?,
+        };
+        network.ensure_matches::<CurrentNetwork>().map_err(|err| err.to_string())?;
+        let network = network.name().to_string();
+        Ok(Self { node_url: node_url.trim_end_matches('/').to_string(), network, broadcast_url })
+    }
+
+    /// Get the configuration of the public Aleo testnet3 node
+    pub fn testnet3() -> NetworkConfig {
+        Self { node_url: DEFAULT_NODE_URL.to_string(), network: "testnet3".to_string(), broadcast_url: None }
+    }
+
+    /// Get the base url of the Aleo node
+    #[wasm_bindgen(getter, js_name = "nodeUrl")]
+    pub fn node_url(&self) -> String {
+        self.node_url.clone()
+    }
+
+    /// Get the name of the targeted network
+    #[wasm_bindgen(getter)]
+    pub fn network(&self) -> String {
+        self.network.clone()
+    }
+
+    /// Get the url transactions are broadcast to
+    #[wasm_bindgen(getter, js_name = "broadcastUrl")]
+    pub fn broadcast_url(&self) -> String {
+        self.broadcast_url.clone().unwrap_or_else(|| self.endpoint(&Endpoint::TransactionBroadcast))
+    }
+}
+
+impl NetworkConfig {
+    /// Get the url of an endpoint of the configured network
+    pub(crate) fn endpoint(&self, endpoint: &Endpoint<CurrentNetwork>) -> String {
+        endpoint.url(&self.node_url, &self.network)
+    }
+
+    /// Use the given node url if one is provided, otherwise the configured node url
+    pub(crate) fn resolve_node_url(&self, url: Option<String>) -> String {
+        url.map(|url| url.trim_end_matches('/').to_string()).unwrap_or_else(|| self.node_url.clone())
+    }
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self::testnet3()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_network_config() {
+        let config = NetworkConfig::new("http://localhost:3030/".to_string(), None, None).unwrap();
+        assert_eq!(config.node_url(), "http://localhost:3030");
+        assert_eq!(config.network(), "testnet3");
+        assert_eq!(config.broadcast_url(), "http://localhost:3030/testnet3/transaction/broadcast");
+        assert_eq!(config.endpoint(&Endpoint::LatestHeight), "http://localhost:3030/testnet3/latest/height");
+        assert_eq!(config.resolve_node_url(None), "http://localhost:3030");
+        assert_eq!(config.resolve_node_url(Some("https://node.example/".to_string())), "https://node.example");
+
+        let config =
+            NetworkConfig::new(DEFAULT_NODE_URL.to_string(), None, Some("https://relay.example".to_string())).unwrap();
+        assert_eq!(config.broadcast_url(), "https://relay.example");
+        assert_eq!(NetworkConfig::default().node_url(), DEFAULT_NODE_URL);
+
+        assert!(NetworkConfig::new(DEFAULT_NODE_URL.to_string(), Some("mainnet".to_string()), None).is_err());
+    }
+}
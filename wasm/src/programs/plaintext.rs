@@ -0,0 +1,263 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::PlaintextNative;
+
+use js_sys::{Array, BigInt, Object, Reflect};
+use std::{fmt, ops::Deref, str::FromStr};
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+/// Webassembly Representation of an Aleo plaintext value
+///
+/// Plaintext values can be converted to and from native javascript values so that program inputs
+/// and outputs do not need to be formatted by hand. Integers are represented as BigInts, booleans
+/// as booleans, structs as objects, and all other literals (fields, addresses, etc.) as strings.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Plaintext(PlaintextNative);
+
+#[wasm_bindgen]
+impl Plaintext {
+    /// Create a plaintext from a string (i.e. "{ a: 1u64, b: aleo1... }")
+    #[wasm_bindgen(js_name = "fromString")]
+    pub fn from_string(plaintext: &str) -> Result<Plaintext, String> {
+        Self::from_str(plaintext).map_err(|err| err.to_string())
+    }
+
+    /// Get the plaintext as a string which can be used as a program input
+    #[wasm_bindgen(js_name = "toString")]
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Create a plaintext from a javascript value and a type specification
+    ///
+    /// The type specification is either the name of a literal type (i.e. "u64" or "address") or a
+    /// struct description in the format returned by `Program.getStructMembers` and
+    /// `Program.getFunctionInputs` (i.e. {"type": "struct", "members": [{"name": "a", "type": "u64"}]}).
+    ///
+    /// @param type_spec The type of the value
+    /// @param value The javascript value to convert
+    #[wasm_bindgen(js_name = "fromJsValue")]
+    pub fn from_js_value(type_spec: JsValue, value: JsValue) -> Result<Plaintext, String> {
+        let plaintext = Self::format_value(&type_spec, &value)?;
+        Self::from_string(&plaintext)
+    }
+
+    /// Convert the plaintext into a javascript value
+    #[wasm_bindgen(js_name = "toJsValue")]
+    pub fn to_js_value(&self) -> Result<JsValue, String> {
+        Self::plaintext_to_js(&self.0)
+    }
+}
+
+impl Plaintext {
+    // Format a javascript value as a plaintext string according to a type specification
+    fn format_value(type_spec: &JsValue, value: &JsValue) -> Result<String, String> {
+        if let Some(literal_type) = type_spec.as_string() {
+            return Self::format_literal(&literal_type, value);
+        }
+
+        let get = |object: &JsValue, key: &str| Reflect::get(object, &key.into()).unwrap_or(JsValue::UNDEFINED);
+        let spec_type = get(type_spec, "type").as_string().ok_or_else(|| "Invalid type specification".to_string())?;
+        if spec_type != "struct" {
+            return Self::format_literal(&spec_type, value);
+        }
+        if !value.is_object() {
+            return Err(format!(
+                "Expected an object for struct {}",
+                get(type_spec, "name").as_string().unwrap_or_default()
+            ));
+        }
+
+        let members = Array::from(&get(type_spec, "members"))
+            .iter()
+            .map(|member| {
+                let name =
+                    get(&member, "name").as_string().ok_or_else(|| "Struct members must have a name".to_string())?;
+                let member_value = get(value, &name);
+                if member_value.is_undefined() {
+                    return Err(format!("Missing struct member '{name}'"));
+                }
+                Ok(format!("{name}: {}", Self::format_value(&member, &member_value)?))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(format!("{{ {} }}", members.join(", ")))
+    }
+
+    // Format a javascript value as a literal of the given type
+    fn format_literal(literal_type: &str, value: &JsValue) -> Result<String, String> {
+        let value = if let Some(boolean) = value.as_bool() {
+            boolean.to_string()
+        } else if let Some(number) = value.as_f64() {
+            if number.fract() != 0.0 {
+                return Err(format!("{number} is not a valid {literal_type}"));
+            }
+            format!("{number:.0}")
+        } else if value.is_bigint() {
+            BigInt::from(value.clone())
+                .to_string(10)
+                .map(String::from)
+                .map_err(|_| format!("Invalid BigInt for {literal_type}"))?
+        } else if let Some(string) = value.as_string() {
+            string
+        } else {
+            return Err(format!("Unsupported value for {literal_type}"));
+        };
+
+        // Accept values which already include their type suffix (i.e. "1field")
+        let literal = match PlaintextNative::from_str(&value) {
+            Ok(PlaintextNative::Literal(literal, _)) if literal.to_type().to_string() == literal_type => value,
+            _ => format!("{value}{}", if matches!(literal_type, "address" | "boolean") { "" } else { literal_type }),
+        };
+        match PlaintextNative::from_str(&literal) {
+            Ok(PlaintextNative::Literal(parsed, _)) if parsed.to_type().to_string() == literal_type => Ok(literal),
+            _ => Err(format!("{value} is not a valid {literal_type}")),
+        }
+    }
+
+    // Convert a native plaintext into a javascript value
+    fn plaintext_to_js(plaintext: &PlaintextNative) -> Result<JsValue, String> {
+        match plaintext {
+            PlaintextNative::Literal(literal, _) => {
+                let literal_type = literal.to_type().to_string();
+                let literal_string = literal.to_string();
+                let is_integer = literal_type.len() > 1
+                    && (literal_type.starts_with('u') || literal_type.starts_with('i'))
+                    && literal_type[1..].chars().all(|c| c.is_ascii_digit());
+                if literal_type == "boolean" {
+                    Ok(JsValue::from_bool(literal_string == "true"))
+                } else if is_integer {
+                    let digits = literal_string.trim_end_matches(literal_type.as_str());
+                    BigInt::new(&JsValue::from_str(digits))
+                        .map(JsValue::from)
+                        .map_err(|_| format!("Invalid integer {literal_string}"))
+                } else {
+                    Ok(JsValue::from_str(&literal_string))
+                }
+            }
+            PlaintextNative::Struct(members, _) => {
+                let object = Object::new();
+                for (name, member) in members {
+                    Reflect::set(&object, &name.to_string().into(), &Self::plaintext_to_js(member)?)
+                        .map_err(|_| "Failed to set property")?;
+                }
+                Ok(object.into())
+            }
+        }
+    }
+}
+
+impl Deref for Plaintext {
+    type Target = PlaintextNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<PlaintextNative> for Plaintext {
+    fn from(plaintext: PlaintextNative) -> Self {
+        Self(plaintext)
+    }
+}
+
+impl From<Plaintext> for PlaintextNative {
+    fn from(plaintext: Plaintext) -> Self {
+        plaintext.0
+    }
+}
+
+impl FromStr for Plaintext {
+    type Err = anyhow::Error;
+
+    fn from_str(plaintext: &str) -> Result<Self, Self::Err> {
+        Ok(Self(PlaintextNative::from_str(plaintext)?))
+    }
+}
+
+impl fmt::Display for Plaintext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const ADDRESS: &str = "aleo1t0uer3jgtsgmx5tq6x6f9ecu8tr57rzzfnc2dgmcqldceal0ls9qf6st7a";
+
+    #[wasm_bindgen_test]
+    fn test_literals_from_js_value() {
+        let from_js = |literal_type: &str, value: JsValue| Plaintext::from_js_value(literal_type.into(), value);
+
+        assert_eq!(from_js("u64", JsValue::from_f64(5.0)).unwrap().to_string(), "5u64");
+        assert_eq!(
+            from_js("u128", JsValue::bigint_from_str("340282366920938463463374607431768211455")).unwrap().to_string(),
+            "340282366920938463463374607431768211455u128"
+        );
+        assert_eq!(from_js("i8", JsValue::from_f64(-3.0)).unwrap().to_string(), "-3i8");
+        assert_eq!(from_js("boolean", JsValue::from_bool(true)).unwrap().to_string(), "true");
+        assert_eq!(from_js("field", JsValue::from_str("7")).unwrap().to_string(), "7field");
+        assert_eq!(from_js("field", JsValue::from_str("7field")).unwrap().to_string(), "7field");
+        assert_eq!(from_js("address", JsValue::from_str(ADDRESS)).unwrap().to_string(), ADDRESS);
+
+        // Ensure values which do not fit the type are rejected
+        assert!(from_js("u8", JsValue::from_f64(256.0)).is_err());
+        assert!(from_js("u64", JsValue::from_f64(1.5)).is_err());
+        assert!(from_js("u64", JsValue::from_str("5u32")).is_err());
+    }
+
+    #[cfg(feature = "programs")]
+    #[wasm_bindgen_test]
+    fn test_struct_round_trip() {
+        let program = crate::Program::from_string(
+            "program token.aleo;\n\nstruct metadata:\n    id as u32;\n    owner as address;\n    active as boolean;\n\nfunction main:\n    input r0 as metadata.private;\n    output r0 as metadata.private;\n",
+        )
+        .unwrap();
+        let spec = Object::new();
+        Reflect::set(&spec, &"type".into(), &"struct".into()).unwrap();
+        Reflect::set(&spec, &"members".into(), &program.get_struct_members("metadata".to_string()).unwrap()).unwrap();
+
+        let value = Object::new();
+        Reflect::set(&value, &"id".into(), &JsValue::from_f64(1.0)).unwrap();
+        Reflect::set(&value, &"owner".into(), &JsValue::from_str(ADDRESS)).unwrap();
+        Reflect::set(&value, &"active".into(), &JsValue::from_bool(false)).unwrap();
+
+        let plaintext = Plaintext::from_js_value(spec.clone().into(), value.into()).unwrap();
+        assert_eq!(
+            plaintext,
+            Plaintext::from_string(&format!("{{ id: 1u32, owner: {ADDRESS}, active: false }}")).unwrap()
+        );
+
+        // Ensure the plaintext converts back into the equivalent javascript object
+        let object = plaintext.to_js_value().unwrap();
+        assert_eq!(Reflect::get(&object, &"id".into()).unwrap(), JsValue::bigint_from_str("1"));
+        assert_eq!(Reflect::get(&object, &"owner".into()).unwrap(), JsValue::from_str(ADDRESS));
+        assert_eq!(Reflect::get(&object, &"active".into()).unwrap(), JsValue::from_bool(false));
+        assert_eq!(Plaintext::from_js_value(spec.into(), object).unwrap(), plaintext);
+
+        // Ensure missing members are reported
+        let spec = Object::new();
+        Reflect::set(&spec, &"type".into(), &"struct".into()).unwrap();
+        Reflect::set(&spec, &"members".into(), &program.get_struct_members("metadata".to_string()).unwrap()).unwrap();
+        assert!(Plaintext::from_js_value(spec.into(), Object::new().into()).is_err());
+    }
+}
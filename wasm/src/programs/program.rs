@@ -14,9 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::{CurrentNetwork, IdentifierNative, ProgramNative};
-
-use js_sys::{Array, Object, Reflect};
+use crate::types::{
+    CurrentNetwork,
+    EntryNative,
+    IdentifierNative,
+    PlaintextNative,
+    ProgramNative,
+    RecordPlaintextNative,
+};
+
+use aleo_rust::ProgramAbi;
+use js_sys::{Array, Object, Reflect, JSON};
 use snarkvm_console::program::{EntryType, PlaintextType, ValueType};
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
@@ -99,6 +107,118 @@ impl Program {
         Ok(function_inputs)
     }
 
+    /// Validate a javascript array of inputs against the signature of a function before executing
+    /// it. This checks the number of inputs, the type of each input, and for records the
+    /// visibility of each entry.
+    ///
+    /// @param function_name The name of the function the inputs are for
+    /// @param inputs A javascript array of input strings
+    /// @returns A javascript array of descriptive error messages, which is empty if the inputs are valid
+    #[wasm_bindgen(js_name = "validateInputs")]
+    pub fn validate_inputs(&self, function_name: String, inputs: Array) -> Result<Array, String> {
+        let function_id = IdentifierNative::from_str(&function_name).map_err(|e| e.to_string())?;
+        let function = self
+            .0
+            .functions()
+            .get(&function_id)
+            .ok_or_else(|| format!("function {} not found in {}", function_name, self.0.id()))?;
+
+        let errors = Array::new();
+        if inputs.length() as usize != function.inputs().len() {
+            errors.push(&JsValue::from_str(&format!(
+                "Expected {} inputs for {function_name}, found {}",
+                function.inputs().len(),
+                inputs.length()
+            )));
+        }
+        for (index, (input, expected)) in inputs.iter().zip(function.inputs().iter()).enumerate() {
+            let result = match input.as_string() {
+                Some(input) => self.validate_input(&input, expected.value_type()),
+                None => Err("all inputs must be strings".to_string()),
+            };
+            if let Err(error) = result {
+                errors.push(&JsValue::from_str(&format!("Input {index} ({}): {error}", expected.value_type())));
+            }
+        }
+        Ok(errors)
+    }
+
+    // Check an input string against a function input type
+    fn validate_input(&self, input: &str, value_type: &ValueType<CurrentNetwork>) -> Result<(), String> {
+        match value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => {
+                let plaintext =
+                    PlaintextNative::from_str(input).map_err(|_| format!("'{input}' is not a valid value"))?;
+                self.validate_plaintext(&plaintext, plaintext_type)
+            }
+            ValueType::Record(record_name) => {
+                let record =
+                    RecordPlaintextNative::from_str(input).map_err(|_| "expected a record plaintext".to_string())?;
+                let definition = self.0.get_record(record_name).map_err(|e| e.to_string())?;
+                for (name, entry_type) in definition.entries() {
+                    let entry =
+                        record.data().get(name).ok_or_else(|| format!("record is missing the entry '{name}'"))?;
+                    let (plaintext, visibility_matches) = match entry {
+                        EntryNative::Constant(plaintext) => (plaintext, matches!(entry_type, EntryType::Constant(_))),
+                        EntryNative::Public(plaintext) => (plaintext, matches!(entry_type, EntryType::Public(_))),
+                        EntryNative::Private(plaintext) => (plaintext, matches!(entry_type, EntryType::Private(_))),
+                    };
+                    if !visibility_matches {
+                        return Err(format!("record entry '{name}' must be {entry_type}"));
+                    }
+                    let (EntryType::Constant(plaintext_type)
+                    | EntryType::Public(plaintext_type)
+                    | EntryType::Private(plaintext_type)) = entry_type;
+                    self.validate_plaintext(plaintext, plaintext_type)
+                        .map_err(|error| format!("record entry '{name}': {error}"))?;
+                }
+                Ok(())
+            }
+            ValueType::ExternalRecord(_) => {
+                RecordPlaintextNative::from_str(input).map_err(|_| "expected a record plaintext".to_string())?;
+                Ok(())
+            }
+        }
+    }
+
+    // Check a plaintext value against a plaintext type
+    fn validate_plaintext(
+        &self,
+        plaintext: &PlaintextNative,
+        plaintext_type: &PlaintextType<CurrentNetwork>,
+    ) -> Result<(), String> {
+        match (plaintext, plaintext_type) {
+            (PlaintextNative::Literal(literal, _), PlaintextType::Literal(literal_type)) => {
+                if literal.to_type() != *literal_type {
+                    return Err(format!("expected {literal_type}, found {}", literal.to_type()));
+                }
+                Ok(())
+            }
+            (PlaintextNative::Struct(members, _), PlaintextType::Struct(struct_name)) => {
+                let definition = self.0.get_struct(struct_name).map_err(|e| e.to_string())?;
+                if members.len() != definition.members().len() {
+                    return Err(format!(
+                        "expected {} members for struct {struct_name}, found {}",
+                        definition.members().len(),
+                        members.len()
+                    ));
+                }
+                for (name, member_type) in definition.members() {
+                    let member = members
+                        .get(name)
+                        .ok_or_else(|| format!("struct {struct_name} is missing the member '{name}'"))?;
+                    self.validate_plaintext(member, member_type)
+                        .map_err(|error| format!("member '{name}': {error}"))?;
+                }
+                Ok(())
+            }
+            (_, PlaintextType::Literal(literal_type)) => Err(format!("expected {literal_type}, found a struct")),
+            (_, PlaintextType::Struct(struct_name)) => Err(format!("expected struct {struct_name}, found a literal")),
+        }
+    }
+
     // Get the value of a plaintext input
     fn get_plaintext_input(
         &self,
@@ -185,6 +305,37 @@ impl Program {
         Ok(struct_members)
     }
 
+    /// Get a javascript array of the mappings in the program and their key and value types in the
+    /// form [{"name": "account", "key_type": "address", "value_type": "u64"}, ..]
+    #[wasm_bindgen(js_name = "getMappings")]
+    pub fn get_mappings(&self) -> Result<Array, String> {
+        let mappings = Array::new();
+        for (name, mapping) in self.0.mappings() {
+            let object = Object::new();
+            Reflect::set(&object, &"name".into(), &name.to_string().into()).map_err(|_| "Failed to set property")?;
+            Reflect::set(&object, &"key_type".into(), &mapping.key().plaintext_type().to_string().into())
+                .map_err(|_| "Failed to set property")?;
+            Reflect::set(&object, &"value_type".into(), &mapping.value().plaintext_type().to_string().into())
+                .map_err(|_| "Failed to set property")?;
+            mappings.push(&object);
+        }
+        Ok(mappings)
+    }
+
+    /// Get a javascript object describing the functions, structs, records and mappings of the
+    /// program and their types, in the same form as the `ProgramAbi` of the rust SDK
+    #[wasm_bindgen(js_name = "getAbi")]
+    pub fn get_abi(&self) -> Result<JsValue, String> {
+        let abi = serde_json::to_string(&ProgramAbi::from(&self.0)).map_err(|e| e.to_string())?;
+        JSON::parse(&abi).map_err(|_| "Failed to parse the program abi".to_string())
+    }
+
+    /// Get a javascript array of the ids of the programs imported by the program
+    #[wasm_bindgen(js_name = "getImports")]
+    pub fn get_imports(&self) -> Array {
+        self.0.imports().keys().map(|program_id| JsValue::from_str(&program_id.to_string())).collect()
+    }
+
     /// Get the credits.aleo program
     #[wasm_bindgen(js_name = "getCreditsProgram")]
     pub fn get_credits_program() -> Program {
@@ -266,6 +417,22 @@ function bump_token_version:
     cast r0 r1.microcredits r1.amount r2 into r3 as Token.record;
     output r3 as Token.record;"#;
 
+    const TOKEN_REGISTRY: &str = r#"import token_issue.aleo;
+program token_registry.aleo;
+
+mapping balances:
+    key left as address.public;
+    value right as u64.public;
+
+function register:
+    input r0 as u64.public;
+    finalize self.caller r0;
+
+finalize register:
+    input r0 as address.public;
+    input r1 as u64.public;
+    set r1 into balances[r0];"#;
+
     #[wasm_bindgen_test]
     fn test_get_functions() {
         let program = Program::from(ProgramNative::credits().unwrap());
@@ -306,6 +473,60 @@ function bump_token_version:
         assert_eq!(format!("{:?}", members), expected);
     }
 
+    #[wasm_bindgen_test]
+    fn test_validate_inputs() {
+        let program = Program::from_string(TOKEN_ISSUE).unwrap();
+        let inputs = |values: &[&str]| values.iter().map(|value| JsValue::from_str(value)).collect::<Array>();
+        let address = "aleo1t0uer3jgtsgmx5tq6x6f9ecu8tr57rzzfnc2dgmcqldceal0ls9qf6st7a";
+
+        // Ensure valid inputs produce no errors
+        let valid = inputs(&[address, "5u64", "{ token_id: 1u32, version: 2u32 }"]);
+        assert_eq!(program.validate_inputs("issue".to_string(), valid).unwrap().length(), 0);
+
+        // Ensure the wrong number of inputs is reported
+        let errors = program.validate_inputs("issue".to_string(), inputs(&[address])).unwrap();
+        assert_eq!(errors.get(0).as_string().unwrap(), "Expected 3 inputs for issue, found 1");
+
+        // Ensure mistyped literals and struct members are reported per input
+        let invalid = inputs(&[address, "5u32", "{ token_id: 1u32, version: 2u64 }"]);
+        let errors = program.validate_inputs("issue".to_string(), invalid).unwrap().to_vec();
+        assert_eq!(errors, vec![
+            JsValue::from_str("Input 1 (u64.private): expected u64, found u32"),
+            JsValue::from_str("Input 2 (token_metadata.private): member 'version': expected u32, found u64"),
+        ]);
+
+        // Ensure records with the wrong entry visibility are reported
+        let record = format!(
+            "{{ owner: {address}.private, microcredits: 0u64.private, amount: 5u64.public, token_data: {{ token_id: 1u32.private, version: 2u32.private }}, _nonce: 0group.public }}"
+        );
+        let invalid = inputs(&[address, &record, "{ token_id: 1u32, version: 2u32 }"]);
+        let errors = program.validate_inputs("bump_token_version".to_string(), invalid).unwrap().to_vec();
+        assert_eq!(errors, vec![JsValue::from_str(
+            "Input 1 (Token.record): record entry 'amount' must be u64.private"
+        )]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_mappings_and_imports() {
+        let program = Program::from_string(TOKEN_REGISTRY).unwrap();
+        let mappings = program.get_mappings().unwrap();
+        let expected = r#"Array { obj: Object { obj: JsValue([Object({"name":"balances","key_type":"address","value_type":"u64"})]) } }"#;
+        assert_eq!(format!("{:?}", mappings), expected);
+        assert_eq!(program.get_imports().to_vec(), vec!["token_issue.aleo"]);
+
+        let credits = Program::from(ProgramNative::credits().unwrap());
+        assert_eq!(credits.get_imports().length(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_abi() {
+        let abi = Program::from(ProgramNative::credits().unwrap()).get_abi().unwrap();
+        let program_id = Reflect::get(&abi, &"program_id".into()).unwrap();
+        assert_eq!(program_id.as_string().unwrap(), "credits.aleo");
+        let functions = Array::from(&Reflect::get(&abi, &"functions".into()).unwrap());
+        assert!(functions.length() > 0);
+    }
+
     #[wasm_bindgen_test]
     fn test_program_from_methods() {
         // Test the from_string creates a valid object and to_string matches the source string
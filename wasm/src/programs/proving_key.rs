@@ -26,9 +26,14 @@ pub struct ProvingKey(ProvingKeyNative);
 #[wasm_bindgen]
 impl ProvingKey {
     /// Construct a new proving key from a byte array
+    ///
+    /// This can be used to load keys previously stored with `toBytes` (i.e. from IndexedDB) or
+    /// pre-built keys fetched from a remote server instead of synthesizing them
+    ///
+    /// @param bytes The little-endian byte encoding of the proving key
     #[wasm_bindgen(js_name = "fromBytes")]
     pub fn from_bytes(bytes: &[u8]) -> Result<ProvingKey, String> {
-        Ok(Self(ProvingKeyNative::from_bytes_le(bytes).map_err(|e| e.to_string())?))
+        Ok(Self(ProvingKeyNative::from_bytes_le(bytes).map_err(|e| format!("Invalid proving key bytes: {e}"))?))
     }
 
     /// Create a byte array from a proving key
@@ -78,4 +83,10 @@ mod tests {
         let bytes = fee_proving_key.to_bytes().unwrap();
         assert_eq!(bytes, fee_proving_key_bytes);
     }
+
+    #[wasm_bindgen_test]
+    fn test_proving_key_from_invalid_bytes() {
+        assert!(ProvingKey::from_bytes(&[]).is_err());
+        assert!(ProvingKey::from_bytes(&[0u8; 64]).is_err());
+    }
 }
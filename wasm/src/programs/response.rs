@@ -14,47 +14,118 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::ResponseNative;
+use crate::{
+    types::{IdentifierNative, ProgramIDNative, ProgramNative, ResponseNative, ValueNative},
+    RecordPlaintext,
+};
 
+use js_sys::JSON;
 use std::ops::Deref;
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
 /// Webassembly Representation of an Aleo function execution response
 ///
 /// This object is returned by the execution of an Aleo function off-chain. It provides methods for
-/// retrieving the outputs of the function execution.
+/// retrieving the outputs of the function execution along with their types, the function that
+/// was executed, and any records it created.
 #[wasm_bindgen]
-pub struct ExecutionResponse(ResponseNative);
+pub struct ExecutionResponse {
+    response: ResponseNative,
+    program_id: ProgramIDNative,
+    function_name: IdentifierNative,
+    output_types: Vec<String>,
+    call_trace: Option<Vec<String>>,
+}
 
 #[wasm_bindgen]
 impl ExecutionResponse {
     /// Get the outputs of the executed function
     #[wasm_bindgen(js_name = "getOutputs")]
     pub fn get_outputs(&self) -> js_sys::Array {
-        let array = js_sys::Array::new_with_length(0u32);
-        self.0.outputs().iter().enumerate().for_each(|(i, output)| {
-            array.set(i as u32, JsValue::from_str(&output.to_string()));
-        });
-        array
+        self.response.outputs().iter().map(|output| JsValue::from_str(&output.to_string())).collect()
+    }
+
+    /// Get the types of the outputs of the executed function (i.e. "u32.private" or
+    /// "credits.record") in the same order as the outputs
+    #[wasm_bindgen(js_name = "getOutputTypes")]
+    pub fn get_output_types(&self) -> js_sys::Array {
+        self.output_types.iter().map(|output_type| JsValue::from_str(output_type)).collect()
+    }
+
+    /// Get the id of the program containing the executed function
+    #[wasm_bindgen(js_name = "getProgramId")]
+    pub fn get_program_id(&self) -> String {
+        self.program_id.to_string()
+    }
+
+    /// Get the name of the executed function
+    #[wasm_bindgen(js_name = "getFunctionName")]
+    pub fn get_function_name(&self) -> String {
+        self.function_name.to_string()
+    }
+
+    /// Get the trace of the function calls made during a debug execution
+    ///
+    /// Each entry is an object describing one call (the executed function followed by any
+    /// functions it called in other programs) with its program, function, inputs and outputs.
+    /// Private values are decrypted with the view key of the sender, and values it cannot
+    /// decrypt are null. Returns undefined if the function was not executed in debug mode.
+    #[wasm_bindgen(js_name = "getTrace")]
+    pub fn get_trace(&self) -> Result<Option<js_sys::Array>, String> {
+        self.call_trace
+            .as_ref()
+            .map(|calls| {
+                calls
+                    .iter()
+                    .map(|call| JSON::parse(call).map_err(|_| "Failed to parse the execution trace".to_string()))
+                    .collect()
+            })
+            .transpose()
+    }
+
+    /// Get the records created by the executed function as RecordPlaintext objects
+    #[wasm_bindgen(js_name = "getRecords")]
+    pub fn get_records(&self) -> js_sys::Array {
+        self.response
+            .outputs()
+            .iter()
+            .filter_map(|output| match output {
+                ValueNative::Record(record) => Some(JsValue::from(RecordPlaintext::from(record.clone()))),
+                _ => None,
+            })
+            .collect()
     }
 }
 
-impl Deref for ExecutionResponse {
-    type Target = ResponseNative;
+impl ExecutionResponse {
+    /// Create an execution response, reading the output types from the function in the program
+    pub(crate) fn new(
+        response: ResponseNative,
+        program: &ProgramNative,
+        function_name: IdentifierNative,
+    ) -> Result<Self, String> {
+        let function = program.get_function(&function_name).map_err(|err| err.to_string())?;
+        let output_types = function.outputs().iter().map(|output| output.value_type().to_string()).collect();
+        Ok(Self { response, program_id: *program.id(), function_name, output_types, call_trace: None })
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    /// Attach the trace of the function calls made during the execution
+    pub(crate) fn with_trace(mut self, call_trace: Vec<String>) -> Self {
+        self.call_trace = Some(call_trace);
+        self
     }
 }
 
-impl From<ResponseNative> for ExecutionResponse {
-    fn from(value: ResponseNative) -> Self {
-        Self(value)
+impl Deref for ExecutionResponse {
+    type Target = ResponseNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.response
     }
 }
 
 impl From<ExecutionResponse> for ResponseNative {
     fn from(response: ExecutionResponse) -> Self {
-        response.0
+        response.response
     }
 }
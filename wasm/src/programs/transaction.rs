@@ -14,10 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::TransactionNative;
-
+use crate::{
+    types::{ExecutionNative, FeeNative, FromBytes, ToBytes, TransactionNative},
+    RecordCiphertext,
+    RecordPlaintext,
+    ViewKey,
+};
+
+use js_sys::{Array, JSON};
 use std::str::FromStr;
-use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
 /// Webassembly Representation of an Aleo transaction
 ///
@@ -35,6 +41,32 @@ impl Transaction {
         Transaction::from_str(transaction)
     }
 
+    /// Create a transaction from a byte array
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Transaction, String> {
+        Ok(Self(TransactionNative::from_bytes_le(bytes).map_err(|e| format!("Invalid transaction bytes: {e}"))?))
+    }
+
+    /// Get the transaction as a byte array
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        self.0.to_bytes_le().map_err(|_| "Failed to serialize transaction".to_string())
+    }
+
+    /// Assemble an execute transaction from an execution and an optional fee
+    ///
+    /// This allows an execution proven on one machine to be combined with a fee and broadcast
+    /// from another.
+    ///
+    /// @param execution The execution as a JSON string
+    /// @param fee Optional fee as a JSON string
+    #[wasm_bindgen(js_name = fromExecution)]
+    pub fn from_execution(execution: &str, fee: Option<String>) -> Result<Transaction, String> {
+        let execution = ExecutionNative::from_str(execution).map_err(|e| format!("Invalid execution: {e}"))?;
+        let fee = fee.map(|fee| FeeNative::from_str(&fee)).transpose().map_err(|e| format!("Invalid fee: {e}"))?;
+        Ok(Self(TransactionNative::from_execution(execution, fee).map_err(|e| e.to_string())?))
+    }
+
     /// Get the transaction as a string. If you want to submit this transaction to the Aleo Network
     /// this function will create the string that should be submitted in the `POST` data.
     #[wasm_bindgen(js_name = toString)]
@@ -53,6 +85,63 @@ impl Transaction {
         self.0.id().to_string()
     }
 
+    /// Get the id of the transaction (an alias of `transactionId`)
+    pub fn id(&self) -> String {
+        self.transaction_id()
+    }
+
+    /// Get the fee amount in microcredits paid by the transaction, or undefined if the
+    /// transaction does not include a fee
+    pub fn fee(&self) -> Result<Option<u64>, String> {
+        let fee = match &self.0 {
+            TransactionNative::Deploy(_, _, _, fee) => Some(fee),
+            TransactionNative::Execute(_, _, fee) => fee.as_ref(),
+            TransactionNative::Fee(_, fee) => Some(fee),
+        };
+        fee.map(|fee| fee.amount().map(|amount| *amount).map_err(|err| err.to_string())).transpose()
+    }
+
+    /// Get the transitions contained in the transaction as javascript objects
+    pub fn transitions(&self) -> Result<Array, String> {
+        self.0
+            .transitions()
+            .map(|transition| {
+                JSON::parse(&transition.to_string()).map_err(|_| "Failed to parse transition".to_string())
+            })
+            .collect()
+    }
+
+    /// Get the records created by the transaction
+    ///
+    /// If a view key is provided, only the records owned by the view key are returned and they
+    /// are returned decrypted as RecordPlaintext objects. Otherwise all records are returned as
+    /// RecordCiphertext objects.
+    ///
+    /// @param view_key Optional view key used to find and decrypt the owned records
+    #[wasm_bindgen(js_name = outputRecords)]
+    pub fn output_records(&self, view_key: Option<ViewKey>) -> Result<Array, String> {
+        let records = Array::new();
+        for (_, ciphertext) in self.0.transitions().flat_map(|transition| transition.records()) {
+            match &view_key {
+                Some(view_key) if ciphertext.is_owner(view_key) => {
+                    let record = ciphertext.decrypt(view_key).map_err(|err| err.to_string())?;
+                    records.push(&RecordPlaintext::from(record).into());
+                }
+                Some(_) => {}
+                None => {
+                    records.push(&RecordCiphertext::from(ciphertext.clone()).into());
+                }
+            }
+        }
+        Ok(records)
+    }
+
+    /// Get the transaction as a javascript object
+    #[wasm_bindgen(js_name = toObject)]
+    pub fn to_object(&self) -> Result<JsValue, String> {
+        JSON::parse(&self.0.to_string()).map_err(|_| "Failed to parse transaction".to_string())
+    }
+
     /// Get the type of the transaction (will return "deploy" or "execute")
     #[wasm_bindgen(js_name = transactionType)]
     pub fn transaction_type(&self) -> String {
@@ -85,10 +174,10 @@ impl FromStr for Transaction {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use wasm_bindgen_test::*;
-    const TRANSACTION_STRING: &str = "{\"type\":\"execute\",\"id\":\"at1pkw4ms8yuw29k8lfqdqkcdaffd6hngnzkw3j8f8j0aht0egkz5fq4h652c\",\"execution\":{\"transitions\":[{\"id\":\"as1w46uteuwlm85yp85af0xsd52pt4qa04m2nm20ux6zh78u5d4ecys6v85ch\",\"program\":\"credits.aleo\",\"function\":\"mint\",\"inputs\":[{\"type\":\"public\",\"id\":\"5040908569006131213612149758844338587983081600819749361338731441075240131558field\",\"value\":\"aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8\"},{\"type\":\"public\",\"id\":\"1276789444601847309812664117152425665973038141628909950908919191663021093021field\",\"value\":\"1u64\"}],\"outputs\":[{\"type\":\"record\",\"id\":\"95928634596052979246019218801908083236653595200887025551448057926055637040field\",\"checksum\":\"7583379478720147365928563614237196759288927925463919661327931764780242134350field\",\"value\":\"record1qyqsqw5vumkswscee2ht4yju9el5g02uwv7204sx8nf96ln4mjugmasyqyxx66trwfhkxun9v35hguerqqpqzqpken3n6ghr0mm509pn7sgersrrnxfvcp5zsctd9cr03ay2yncap2khg34cktpumr8mnv7sqa4zjxu9ww78u69nfqxh9mwxxqvv6ssqsepzx3u\"}],\"proof\":\"proof1qqqsqqqqqqqqqqqpqqqqqqqqqqqdxthfnfwmf8htv998y84helwczfhr525qx6fe4d3xfhz9tnz5htjpspzk9l38u4uyxge2hfteva5qc50ht0dklnvqvmukzjvc0q47a3y79e0zaz8mmtl3kzq5rtpeegny8cduhd8v02hg7tfranq7cl7cqlzeth8eak93d2p63nmt5datq6n6v6nv0uxxl0nj9r6lydef228xm34getesxvxchxtt9kj3c4vzqyqcqrnlmzf8mphcwns8wt9js8n67tvrnx8x9dnq66tjl46j2paj75pe0yh057hf9fw9989zacc5y0qq64ekr4cpkyl0jxx6l0l64nt8lgtltlwvfzmad55vh8lz5xsmhhau530n8ttw4h9de7uju2s9wqscrfp8tgfpn5sfyx5k27ue2t5dumfe0w63uq9tf2gjh9j2uegxfn3aqn42j3esmervqa57zms9055lqp09nyv3v7exa3hkqsy9vvu37tve6wrv9xwxf3euk6yf4kecdvf3v6llvjg9ehgtv6a8xqek0vzn7qz6n5sshwnhxn4mel85nqnlz5rp3e0p0tfj0k2cvr4ssw62qgyynr52ckykw84g2an72f6ark9vfzqama0jgalrtrufhet48lew523386xv87p9k57vnvwturq4qclk3yfy8nsfveghjnhuy9znvmu78h5qrzyzhlsnz5fgatex2nqgu8vyvtvrzghxc7dzwspsux93jsn2y64uhecf45r2myk7d7c4cfcfjcmgpp8gp2482ljfhtv3lrjkqhdlu73x7pzzft70xslqk2mypg7ufns2yckh8353ss7frsq4h7etrhdswyytjn0t5gp6m9pzwt4qenznp5p3pq8lhuaj42yhhllzp8huvstmxlcrmjyn09s6vts782rqnu8j6qzpy4p59ujnh9ykunxcyrtm7422jkmus9r7p4ck0fm64fm9ry0rppvhs6p5rh6s05kmpsnrjdx3p8rpqgk8l4m4nhjsxjwr6l7c4mrsxqgqqqqqqqqqqzkfxa64vdye8v2zsjxm8ny3ytmnzd63jpnzdrqys66d3nlay75q30mjd3p7ua2y8fv4rg8xrurzexgh0jptxxt5dxza36qdxxrafys0zx2ckw7662xygr4gynaj2yzzesjlzvs8aw6yr5hn04vdl7ey5q9qyqqqqqqqqqqqqn4ekpaxek52nzpaptxky4cuar5exs9w8spskwn4xu87203y2s8k3l2h0w80zj4m3m39akgytmxcqqtytm0a68pmtwxy8c0rfl09z3mfgxvnprqwmwtq7aa0wmpvg0j0zgc9f35uczk7z2pf54xn2kwp23mrwd6fc4x3cl3lw5khhcl36z0rwsqve9v86newep09epdq7pe8eqqqqq035d6j\",\"tpk\":\"6632149043115422828236144456828746891849538829103670866843594215187740194210group\",\"tcm\":\"4818412099071103605161859576211275541485358629762154526095011715785635126388field\"}],\"global_state_root\":\"ar1jexd2yp8k5lal4rn7khtf0ejgzqcq7rada0chywes7q3hvmcxqgq0u4930\"}}";
+    pub(crate) const TRANSACTION_STRING: &str = "{\"type\":\"execute\",\"id\":\"at1pkw4ms8yuw29k8lfqdqkcdaffd6hngnzkw3j8f8j0aht0egkz5fq4h652c\",\"execution\":{\"transitions\":[{\"id\":\"as1w46uteuwlm85yp85af0xsd52pt4qa04m2nm20ux6zh78u5d4ecys6v85ch\",\"program\":\"credits.aleo\",\"function\":\"mint\",\"inputs\":[{\"type\":\"public\",\"id\":\"5040908569006131213612149758844338587983081600819749361338731441075240131558field\",\"value\":\"aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8\"},{\"type\":\"public\",\"id\":\"1276789444601847309812664117152425665973038141628909950908919191663021093021field\",\"value\":\"1u64\"}],\"outputs\":[{\"type\":\"record\",\"id\":\"95928634596052979246019218801908083236653595200887025551448057926055637040field\",\"checksum\":\"7583379478720147365928563614237196759288927925463919661327931764780242134350field\",\"value\":\"record1qyqsqw5vumkswscee2ht4yju9el5g02uwv7204sx8nf96ln4mjugmasyqyxx66trwfhkxun9v35hguerqqpqzqpken3n6ghr0mm509pn7sgersrrnxfvcp5zsctd9cr03ay2yncap2khg34cktpumr8mnv7sqa4zjxu9ww78u69nfqxh9mwxxqvv6ssqsepzx3u\"}],\"proof\":\"proof1qqqsqqqqqqqqqqqpqqqqqqqqqqqdxthfnfwmf8htv998y84helwczfhr525qx6fe4d3xfhz9tnz5htjpspzk9l38u4uyxge2hfteva5qc50ht0dklnvqvmukzjvc0q47a3y79e0zaz8mmtl3kzq5rtpeegny8cduhd8v02hg7tfranq7cl7cqlzeth8eak93d2p63nmt5datq6n6v6nv0uxxl0nj9r6lydef228xm34getesxvxchxtt9kj3c4vzqyqcqrnlmzf8mphcwns8wt9js8n67tvrnx8x9dnq66tjl46j2paj75pe0yh057hf9fw9989zacc5y0qq64ekr4cpkyl0jxx6l0l64nt8lgtltlwvfzmad55vh8lz5xsmhhau530n8ttw4h9de7uju2s9wqscrfp8tgfpn5sfyx5k27ue2t5dumfe0w63uq9tf2gjh9j2uegxfn3aqn42j3esmervqa57zms9055lqp09nyv3v7exa3hkqsy9vvu37tve6wrv9xwxf3euk6yf4kecdvf3v6llvjg9ehgtv6a8xqek0vzn7qz6n5sshwnhxn4mel85nqnlz5rp3e0p0tfj0k2cvr4ssw62qgyynr52ckykw84g2an72f6ark9vfzqama0jgalrtrufhet48lew523386xv87p9k57vnvwturq4qclk3yfy8nsfveghjnhuy9znvmu78h5qrzyzhlsnz5fgatex2nqgu8vyvtvrzghxc7dzwspsux93jsn2y64uhecf45r2myk7d7c4cfcfjcmgpp8gp2482ljfhtv3lrjkqhdlu73x7pzzft70xslqk2mypg7ufns2yckh8353ss7frsq4h7etrhdswyytjn0t5gp6m9pzwt4qenznp5p3pq8lhuaj42yhhllzp8huvstmxlcrmjyn09s6vts782rqnu8j6qzpy4p59ujnh9ykunxcyrtm7422jkmus9r7p4ck0fm64fm9ry0rppvhs6p5rh6s05kmpsnrjdx3p8rpqgk8l4m4nhjsxjwr6l7c4mrsxqgqqqqqqqqqqzkfxa64vdye8v2zsjxm8ny3ytmnzd63jpnzdrqys66d3nlay75q30mjd3p7ua2y8fv4rg8xrurzexgh0jptxxt5dxza36qdxxrafys0zx2ckw7662xygr4gynaj2yzzesjlzvs8aw6yr5hn04vdl7ey5q9qyqqqqqqqqqqqqn4ekpaxek52nzpaptxky4cuar5exs9w8spskwn4xu87203y2s8k3l2h0w80zj4m3m39akgytmxcqqtytm0a68pmtwxy8c0rfl09z3mfgxvnprqwmwtq7aa0wmpvg0j0zgc9f35uczk7z2pf54xn2kwp23mrwd6fc4x3cl3lw5khhcl36z0rwsqve9v86newep09epdq7pe8eqqqqq035d6j\",\"tpk\":\"6632149043115422828236144456828746891849538829103670866843594215187740194210group\",\"tcm\":\"4818412099071103605161859576211275541485358629762154526095011715785635126388field\"}],\"global_state_root\":\"ar1jexd2yp8k5lal4rn7khtf0ejgzqcq7rada0chywes7q3hvmcxqgq0u4930\"}}";
     const TRANSACTION_ID: &str = "at1pkw4ms8yuw29k8lfqdqkcdaffd6hngnzkw3j8f8j0aht0egkz5fq4h652c";
 
     #[wasm_bindgen_test]
@@ -108,4 +197,41 @@ mod tests {
         let transaction_from_native = Transaction::from(transaction_native);
         assert_eq!(transaction, transaction_from_native);
     }
+
+    #[wasm_bindgen_test]
+    fn test_transaction_bytes_and_parts() {
+        let transaction = Transaction::from_string(TRANSACTION_STRING).unwrap();
+        let bytes = transaction.to_bytes().unwrap();
+        assert_eq!(Transaction::from_bytes(&bytes).unwrap(), transaction);
+        assert!(Transaction::from_bytes(&bytes[1..]).is_err());
+
+        // Reassemble the transaction from its execution
+        let object: serde_json::Value = serde_json::from_str(TRANSACTION_STRING).unwrap();
+        let execution = object["execution"].to_string();
+        let reassembled = Transaction::from_execution(&execution, None).unwrap();
+        assert_eq!(reassembled.transaction_id(), TRANSACTION_ID);
+        assert!(Transaction::from_execution("{}", None).is_err());
+        assert!(Transaction::from_execution(&execution, Some("{}".to_string())).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_transaction_introspection() {
+        let transaction = Transaction::from_string(TRANSACTION_STRING).unwrap();
+        assert_eq!(transaction.id(), TRANSACTION_ID);
+        assert_eq!(transaction.fee().unwrap(), None);
+
+        let transitions = transaction.transitions().unwrap();
+        assert_eq!(transitions.length(), 1);
+        let program = js_sys::Reflect::get(&transitions.get(0), &"program".into()).unwrap();
+        assert_eq!(program.as_string().unwrap(), "credits.aleo");
+
+        // Without a view key every record is returned encrypted
+        assert_eq!(transaction.output_records(None).unwrap().length(), 1);
+        let view_key = ViewKey::from_private_key(&crate::PrivateKey::new());
+        assert_eq!(transaction.output_records(Some(view_key)).unwrap().length(), 0);
+
+        let object = transaction.to_object().unwrap();
+        let id = js_sys::Reflect::get(&object, &"id".into()).unwrap();
+        assert_eq!(id.as_string().unwrap(), TRANSACTION_ID);
+    }
 }
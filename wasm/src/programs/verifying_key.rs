@@ -27,9 +27,14 @@ pub struct VerifyingKey(VerifyingKeyNative);
 #[wasm_bindgen]
 impl VerifyingKey {
     /// Construct a new verifying key from a byte array
+    ///
+    /// This can be used to load keys previously stored with `toBytes` (i.e. from IndexedDB) or
+    /// pre-built keys fetched from a remote server instead of synthesizing them
+    ///
+    /// @param bytes The little-endian byte encoding of the verifying key
     #[wasm_bindgen(js_name = "fromBytes")]
     pub fn from_bytes(bytes: &[u8]) -> Result<VerifyingKey, String> {
-        Ok(Self(VerifyingKeyNative::from_bytes_le(bytes).map_err(|e| e.to_string())?))
+        Ok(Self(VerifyingKeyNative::from_bytes_le(bytes).map_err(|e| format!("Invalid verifying key bytes: {e}"))?))
     }
 
     /// Create a byte array from a verifying key
@@ -73,10 +78,16 @@ mod tests {
     const FEE_VERIFIER_URL: &str = "https://testnet3.parameters.aleo.org/fee.verifier.44783e8";
 
     #[wasm_bindgen_test]
-    async fn test_proving_key_roundtrip() {
+    async fn test_verifying_key_roundtrip() {
         let fee_verifying_key_bytes = reqwest::get(FEE_VERIFIER_URL).await.unwrap().bytes().await.unwrap().to_vec();
         let fee_verifying_key = VerifyingKey::from_bytes(&fee_verifying_key_bytes).unwrap();
         let bytes = fee_verifying_key.to_bytes().unwrap();
         assert_eq!(bytes, fee_verifying_key_bytes);
     }
+
+    #[wasm_bindgen_test]
+    fn test_verifying_key_from_invalid_bytes() {
+        assert!(VerifyingKey::from_bytes(&[]).is_err());
+        assert!(VerifyingKey::from_bytes(&[0u8; 64]).is_err());
+    }
 }
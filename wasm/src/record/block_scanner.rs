@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::RecordPlaintext;
+use crate::{
+    account::ViewKey,
+    types::{BlockNative, ViewKeyNative},
+};
+
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+
+/// A record owned by the scanning account along with where it was found on chain
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct OwnedRecord {
+    record: RecordPlaintext,
+    commitment: String,
+    program_id: String,
+    function_name: String,
+    transition_id: String,
+    block_height: u32,
+}
+
+#[wasm_bindgen]
+impl OwnedRecord {
+    /// Get the decrypted record
+    pub fn record(&self) -> RecordPlaintext {
+        self.record.clone()
+    }
+
+    /// Get the commitment of the record
+    pub fn commitment(&self) -> String {
+        self.commitment.clone()
+    }
+
+    /// Get the id of the program which produced the record
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> String {
+        self.program_id.clone()
+    }
+
+    /// Get the name of the function which produced the record
+    #[wasm_bindgen(js_name = functionName)]
+    pub fn function_name(&self) -> String {
+        self.function_name.clone()
+    }
+
+    /// Get the id of the transition which produced the record
+    #[wasm_bindgen(js_name = transitionId)]
+    pub fn transition_id(&self) -> String {
+        self.transition_id.clone()
+    }
+
+    /// Get the height of the block containing the record
+    #[wasm_bindgen(js_name = blockHeight)]
+    pub fn block_height(&self) -> u32 {
+        self.block_height
+    }
+}
+
+/// Scanner which finds the records owned by a view key within blocks fetched by the host application
+///
+/// Blocks are passed to the scanner as JSON in the format returned by the Aleo node REST API,
+/// either one block at a time or as an array of blocks. Each call returns the records found in
+/// that chunk, so wallets can display discovered records while a scan is still in progress.
+#[wasm_bindgen]
+pub struct BlockScanner {
+    view_key: ViewKeyNative,
+    last_height: Option<u32>,
+    records_found: u32,
+}
+
+#[wasm_bindgen]
+impl BlockScanner {
+    /// Create a new block scanner for the account owning the view key
+    #[wasm_bindgen(constructor)]
+    pub fn new(view_key: &ViewKey) -> Self {
+        Self { view_key: **view_key, last_height: None, records_found: 0 }
+    }
+
+    /// Scan a block, or an array of blocks, in JSON format and return the owned records found
+    #[wasm_bindgen(js_name = scanBlocks)]
+    pub fn scan_blocks(&mut self, blocks: &str) -> Result<Array, String> {
+        let blocks = if blocks.trim_start().starts_with('[') {
+            serde_json::from_str::<Vec<BlockNative>>(blocks)
+        } else {
+            serde_json::from_str::<BlockNative>(blocks).map(|block| vec![block])
+        }
+        .map_err(|error| format!("Invalid block JSON: {error}"))?;
+
+        let records = Array::new();
+        for block in blocks {
+            for record in self.scan_block(&block) {
+                records.push(&record.into());
+            }
+        }
+        Ok(records)
+    }
+
+    /// Get the height of the highest block scanned so far
+    #[wasm_bindgen(js_name = lastHeight)]
+    pub fn last_height(&self) -> Option<u32> {
+        self.last_height
+    }
+
+    /// Get the total number of owned records found so far
+    #[wasm_bindgen(js_name = recordsFound)]
+    pub fn records_found(&self) -> u32 {
+        self.records_found
+    }
+}
+
+impl BlockScanner {
+    // Find the records owned by the view key within a single block
+    fn scan_block(&mut self, block: &BlockNative) -> Vec<OwnedRecord> {
+        let block_height = block.height();
+        let mut owned_records = Vec::new();
+        for transition in block.transitions() {
+            for (commitment, ciphertext) in transition.records() {
+                if !ciphertext.is_owner(&self.view_key) {
+                    continue;
+                }
+                if let Ok(record) = ciphertext.decrypt(&self.view_key) {
+                    owned_records.push(OwnedRecord {
+                        record: RecordPlaintext::from(record),
+                        commitment: commitment.to_string(),
+                        program_id: transition.program_id().to_string(),
+                        function_name: transition.function_name().to_string(),
+                        transition_id: transition.id().to_string(),
+                        block_height,
+                    });
+                }
+            }
+        }
+        self.last_height = Some(self.last_height.map_or(block_height, |height| height.max(block_height)));
+        self.records_found += owned_records.len() as u32;
+        owned_records
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        account::PrivateKey,
+        types::{CurrentNetwork, FromBytes, Network},
+    };
+
+    use wasm_bindgen_test::*;
+
+    const OWNER_VIEW_KEY: &str = "AViewKey1ccEt8A2Ryva5rxnKcAbn7wgTaTsb79tzkKHFpeKsm9NX";
+    // The beacon of snarkOS development nodes, which owns the credits minted in the genesis block
+    const BEACON_PRIVATE_KEY: &str = "APrivateKey1zkp8CZNn3yeCseEtxuVPbDCwSyhGW6yZKUYKfgXmcpoGPWH";
+
+    #[wasm_bindgen_test]
+    fn test_scan_genesis_block() {
+        let genesis = BlockNative::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let view_key = ViewKey::from_private_key(&PrivateKey::from_string(BEACON_PRIVATE_KEY).unwrap());
+
+        // The genesis credits are found along with the transitions which minted them
+        let mut scanner = BlockScanner::new(&view_key);
+        let owned_records = scanner.scan_block(&genesis);
+        assert!(!owned_records.is_empty());
+        for owned_record in &owned_records {
+            let transition = genesis
+                .transitions()
+                .find(|transition| transition.id().to_string() == owned_record.transition_id())
+                .unwrap();
+            assert!(transition.records().any(|(commitment, _)| commitment.to_string() == owned_record.commitment()));
+            assert_eq!(owned_record.program_id(), "credits.aleo");
+            assert_eq!(owned_record.function_name(), transition.function_name().to_string());
+            assert_eq!(owned_record.block_height(), 0);
+            assert!(owned_record.record().microcredits() > 0);
+        }
+        assert_eq!(scanner.last_height(), Some(0));
+        assert_eq!(scanner.records_found(), owned_records.len() as u32);
+
+        // Blocks are scanned from JSON, alone or in an array, and the totals accumulate
+        let genesis_json = serde_json::to_string(&genesis).unwrap();
+        assert_eq!(scanner.scan_blocks(&genesis_json).unwrap().length(), owned_records.len() as u32);
+        assert_eq!(scanner.scan_blocks(&format!("[{genesis_json}]")).unwrap().length(), owned_records.len() as u32);
+        assert_eq!(scanner.records_found(), 3 * owned_records.len() as u32);
+
+        // Other accounts own none of the genesis records
+        let mut scanner = BlockScanner::new(&ViewKey::from_string(OWNER_VIEW_KEY));
+        assert_eq!(scanner.scan_blocks(&genesis_json).unwrap().length(), 0);
+        assert_eq!(scanner.last_height(), Some(0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid_blocks() {
+        let mut scanner = BlockScanner::new(&ViewKey::from_string(OWNER_VIEW_KEY));
+        assert!(scanner.scan_blocks("not json").is_err());
+        assert!(scanner.scan_blocks("[{}]").is_err());
+        assert_eq!(scanner.scan_blocks("[]").unwrap().length(), 0);
+        assert_eq!(scanner.last_height(), None);
+        assert_eq!(scanner.records_found(), 0);
+    }
+}
@@ -14,8 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "programs")]
+pub mod block_scanner;
+#[cfg(feature = "programs")]
+pub use block_scanner::*;
+
 pub mod record_ciphertext;
 pub use record_ciphertext::*;
 
 pub mod record_plaintext;
 pub use record_plaintext::*;
+
+pub mod spent_records;
+pub use spent_records::*;
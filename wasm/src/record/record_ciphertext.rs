@@ -17,12 +17,15 @@
 use super::RecordPlaintext;
 use crate::{account::ViewKey, types::RecordCiphertextNative};
 
-use std::{ops::Deref, str::FromStr};
+use js_sys::Array;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::{fmt, ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
 
 /// Encrypted Aleo record
 #[wasm_bindgen]
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RecordCiphertext(RecordCiphertextNative);
 
 #[wasm_bindgen]
@@ -34,12 +37,17 @@ impl RecordCiphertext {
     }
 
     /// Return the record ciphertext string.
-    #[allow(clippy::inherent_to_string)]
+    #[allow(clippy::inherent_to_string_shadow_display)]
     #[wasm_bindgen(js_name = toString)]
     pub fn to_string(&self) -> String {
         self.0.to_string()
     }
 
+    /// Returns the nonce of the record as a group element string.
+    pub fn nonce(&self) -> String {
+        self.0.nonce().to_string()
+    }
+
     /// Decrypt the record ciphertext into plaintext using the view key.
     pub fn decrypt(&self, view_key: &ViewKey) -> Result<RecordPlaintext, String> {
         Ok(RecordPlaintext::from(
@@ -47,13 +55,55 @@ impl RecordCiphertext {
         ))
     }
 
+    /// Decrypt many record ciphertexts with a view key in a single call.
+    ///
+    /// Returns an array of the same length as the input, containing the decrypted record for each
+    /// ciphertext owned by the view key and `undefined` for every other entry. When the `parallel`
+    /// feature is enabled, decryption is spread across the rayon thread pool.
+    #[wasm_bindgen(js_name = decryptMany)]
+    pub fn decrypt_many(view_key: &ViewKey, ciphertexts: Array) -> Result<Array, String> {
+        let ciphertexts = ciphertexts
+            .iter()
+            .map(|ciphertext| ciphertext.as_string().ok_or_else(|| "Record ciphertexts must be strings".to_string()))
+            .collect::<Result<Vec<String>, String>>()?;
+
+        let view_key = **view_key;
+        let decrypt = |ciphertext: &String| {
+            let record = RecordCiphertextNative::from_str(ciphertext).ok()?;
+            if record.is_owner(&view_key) {
+                record.decrypt(&view_key).ok()
+            } else {
+                None
+            }
+        };
+        #[cfg(feature = "parallel")]
+        let plaintexts = ciphertexts.par_iter().map(decrypt).collect::<Vec<_>>();
+        #[cfg(not(feature = "parallel"))]
+        let plaintexts = ciphertexts.iter().map(decrypt).collect::<Vec<_>>();
+
+        Ok(plaintexts
+            .into_iter()
+            .map(|plaintext| plaintext.map_or(JsValue::UNDEFINED, |plaintext| RecordPlaintext::from(plaintext).into()))
+            .collect())
+    }
+
     /// Returns `true` if the view key can decrypt the record ciphertext.
+    ///
+    /// Only the owner field of the record is decrypted, which makes this check considerably
+    /// cheaper than a full decryption. Wallets scanning many ciphertexts should filter them with
+    /// this method and only decrypt the records they own.
     #[wasm_bindgen(js_name = isOwner)]
     pub fn is_owner(&self, view_key: &ViewKey) -> bool {
         self.0.is_owner(view_key)
     }
 }
 
+impl From<RecordCiphertextNative> for RecordCiphertext {
+    fn from(record: RecordCiphertextNative) -> Self {
+        Self(record)
+    }
+}
+
 impl FromStr for RecordCiphertext {
     type Err = anyhow::Error;
 
@@ -62,6 +112,12 @@ impl FromStr for RecordCiphertext {
     }
 }
 
+impl fmt::Display for RecordCiphertext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl Deref for RecordCiphertext {
     type Target = RecordCiphertextNative;
 
@@ -115,6 +171,38 @@ mod tests {
         assert!(record.decrypt(&incorrect_view_key).is_err());
     }
 
+    #[wasm_bindgen_test]
+    fn test_native_conversion() {
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        let native = RecordCiphertextNative::from_str(OWNER_CIPHERTEXT).unwrap();
+        assert_eq!(RecordCiphertext::from(native), record);
+        assert_eq!(format!("{record}"), OWNER_CIPHERTEXT);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decrypt_many() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let ciphertexts = Array::of3(&OWNER_CIPHERTEXT.into(), &"garbage".into(), &OWNER_CIPHERTEXT.into());
+        let plaintexts = RecordCiphertext::decrypt_many(&view_key, ciphertexts.clone()).unwrap();
+        assert_eq!(plaintexts.length(), 3);
+        assert!(plaintexts.get(0).is_object());
+        assert!(plaintexts.get(1).is_undefined());
+        assert!(plaintexts.get(2).is_object());
+
+        // Ensure nothing is decrypted with another view key and non-string inputs are rejected
+        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY);
+        let plaintexts = RecordCiphertext::decrypt_many(&incorrect_view_key, ciphertexts).unwrap();
+        assert!(plaintexts.iter().all(|plaintext| plaintext.is_undefined()));
+        assert!(RecordCiphertext::decrypt_many(&view_key, Array::of1(&JsValue::from(1))).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_nonce_matches_plaintext() {
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        let plaintext = record.decrypt(&ViewKey::from_string(OWNER_VIEW_KEY)).unwrap();
+        assert_eq!(record.nonce(), plaintext.nonce());
+    }
+
     #[wasm_bindgen_test]
     fn test_is_owner() {
         let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
@@ -123,4 +211,13 @@ mod tests {
         let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY);
         assert!(!record.is_owner(&incorrect_view_key));
     }
+
+    #[wasm_bindgen_test]
+    fn test_is_owner_matches_decryption() {
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        for view_key in [OWNER_VIEW_KEY, NON_OWNER_VIEW_KEY] {
+            let view_key = ViewKey::from_string(view_key);
+            assert_eq!(record.is_owner(&view_key), record.decrypt(&view_key).is_ok());
+        }
+    }
 }
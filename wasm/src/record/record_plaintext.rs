@@ -15,11 +15,11 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::PrivateKey,
-    types::{IdentifierNative, ProgramIDNative, RecordPlaintextNative},
+    account::{GraphKey, PrivateKey},
+    types::{CurrentNetwork, IdentifierNative, ProgramIDNative, RecordPlaintextNative},
 };
 
-use aleo_rust::Credits;
+use aleo_rust::{Credits, Field};
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
 
@@ -48,7 +48,54 @@ impl RecordPlaintext {
         self.0.microcredits().unwrap_or(0)
     }
 
+    /// Returns the nonce of the record as a group element string
+    pub fn nonce(&self) -> String {
+        self.0.nonce().to_string()
+    }
+
+    /// Compute the commitment of the record, which identifies the record among transition outputs
+    ///
+    /// @param program_id The program which defines the record (e.g. credits.aleo)
+    /// @param record_name The name of the record within the program (e.g. credits)
+    pub fn commitment(&self, program_id: &str, record_name: &str) -> Result<String, String> {
+        Ok(self.commitment_field(program_id, record_name)?.to_string())
+    }
+
+    /// Compute the tag of the record, which is published when the record is spent and allows
+    /// light clients holding only a graph key to detect spent records
+    ///
+    /// @param graph_key The graph key of the record owner
+    /// @param program_id The program which defines the record (e.g. credits.aleo)
+    /// @param record_name The name of the record within the program (e.g. credits)
+    pub fn tag(&self, graph_key: &GraphKey, program_id: &str, record_name: &str) -> Result<String, String> {
+        let commitment = self.commitment_field(program_id, record_name)?;
+        let tag = RecordPlaintextNative::tag((**graph_key).sk_tag(), commitment)
+            .map_err(|_| "Tag derivation failed".to_string())?;
+        Ok(tag.to_string())
+    }
+
+    /// Compute the serial number of the record, which can be used to determine whether or not it
+    /// has already been spent on-chain
+    ///
+    /// @param private_key The private key of the record owner
+    /// @param program_id The program which defines the record (e.g. credits.aleo)
+    /// @param record_name The name of the record within the program (e.g. credits)
+    #[wasm_bindgen(js_name = serialNumber)]
+    pub fn serial_number(
+        &self,
+        private_key: &PrivateKey,
+        program_id: &str,
+        record_name: &str,
+    ) -> Result<String, String> {
+        let commitment = self.commitment_field(program_id, record_name)?;
+        let serial_number = RecordPlaintextNative::serial_number(private_key.into(), commitment)
+            .map_err(|_| "Serial number derivation failed".to_string())?;
+        Ok(serial_number.to_string())
+    }
+
     /// Attempt to get the serial number of a record to determine whether or not is has been spent
+    ///
+    /// This is an alias of `serialNumber` kept for existing callers
     #[wasm_bindgen(js_name = serialNumberString)]
     pub fn serial_number_string(
         &self,
@@ -56,17 +103,19 @@ impl RecordPlaintext {
         program_id: &str,
         record_name: &str,
     ) -> Result<String, String> {
+        self.serial_number(private_key, program_id, record_name)
+    }
+}
+
+impl RecordPlaintext {
+    // Compute the commitment of the record for the given program and record name
+    fn commitment_field(&self, program_id: &str, record_name: &str) -> Result<Field<CurrentNetwork>, String> {
         let parsed_program_id =
             ProgramIDNative::from_str(program_id).map_err(|_| "Invalid ProgramID specified".to_string())?;
         let record_identifier = IdentifierNative::from_str(record_name)
             .map_err(|_| "Invalid Identifier specified for record".to_string())?;
-        let commitment = self
-            .to_commitment(&parsed_program_id, &record_identifier)
-            .map_err(|_| "A commitment for this record and program could not be computed".to_string())?;
-
-        let serial_number = RecordPlaintextNative::serial_number(private_key.into(), commitment)
-            .map_err(|_| "Serial number derivation failed".to_string())?;
-        Ok(serial_number.to_string())
+        self.to_commitment(&parsed_program_id, &record_identifier)
+            .map_err(|_| "A commitment for this record and program could not be computed".to_string())
     }
 }
 
@@ -116,6 +165,31 @@ mod tests {
         assert_eq!(record.microcredits(), 1500000000000000);
     }
 
+    #[wasm_bindgen_test]
+    fn test_nonce() {
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        assert_eq!(record.nonce(), "3077450429259593211617823051143573281856129402760267155982965992208217472983group");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_commitment_and_tag() {
+        let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let commitment = record.commitment("credits.aleo", "credits").unwrap();
+        assert!(commitment.ends_with("field"));
+        assert_eq!(commitment, record.commitment("credits.aleo", "credits").unwrap());
+        assert_ne!(commitment, record.commitment("token.aleo", "token").unwrap());
+
+        // Ensure the tag depends on the graph key of the owner
+        let tag = record.tag(&pk.to_graph_key(), "credits.aleo", "credits").unwrap();
+        assert_eq!(tag, record.tag(&pk.to_graph_key(), "credits.aleo", "credits").unwrap());
+        assert_ne!(tag, record.tag(&PrivateKey::new().to_graph_key(), "credits.aleo", "credits").unwrap());
+        assert_eq!(
+            record.commitment("not a real program id", "credits").err(),
+            Some("Invalid ProgramID specified".to_string())
+        );
+    }
+
     #[wasm_bindgen_test]
     fn test_serial_number() {
         let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
@@ -127,6 +201,18 @@ mod tests {
         assert_eq!(expected_sn, result.unwrap());
     }
 
+    #[wasm_bindgen_test]
+    fn test_serial_number_matches_serial_number_string() {
+        let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let expected_sn = "8170619507075647151199239049653235187042661744691458644751012032123701508940field";
+        assert_eq!(expected_sn, record.serial_number(&pk, "credits.aleo", "credits").unwrap());
+        assert_eq!(
+            record.serial_number(&pk, "credits.aleo", "credits"),
+            record.serial_number_string(&pk, "credits.aleo", "credits")
+        );
+    }
+
     #[wasm_bindgen_test]
     fn test_serial_number_can_run_twice_with_same_private_key() {
         let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::RecordPlaintext;
+use crate::{
+    account::PrivateKey,
+    programs::NetworkConfig,
+    types::{CurrentNetwork, IdentifierNative, ProgramIDNative, RecordPlaintextNative, SpentRecordsNative},
+};
+
+use aleo_rust::{Endpoint, Field};
+use js_sys::Array;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Set of spent record serial numbers used to determine which owned records have been spent
+///
+/// Serial numbers can be collected by the host application from transition inputs in scanned
+/// blocks, or checked individually against a node with `isSerialNumberSpent`.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default)]
+pub struct SpentRecords(SpentRecordsNative);
+
+#[wasm_bindgen]
+impl SpentRecords {
+    /// Create a new empty set of spent serial numbers
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self(SpentRecordsNative::new())
+    }
+
+    /// Add the serial number of a spent record
+    #[wasm_bindgen(js_name = addSerialNumber)]
+    pub fn add_serial_number(&mut self, serial_number: &str) -> Result<(), String> {
+        let serial_number = Field::<CurrentNetwork>::from_str(serial_number)
+            .map_err(|_| "Invalid serial number specified".to_string())?;
+        self.0.insert(serial_number);
+        Ok(())
+    }
+
+    /// Add an array of serial number strings of spent records
+    #[wasm_bindgen(js_name = addSerialNumbers)]
+    pub fn add_serial_numbers(&mut self, serial_numbers: Array) -> Result<(), String> {
+        for serial_number in serial_numbers.iter() {
+            let serial_number =
+                serial_number.as_string().ok_or_else(|| "Serial numbers must be strings".to_string())?;
+            self.add_serial_number(&serial_number)?;
+        }
+        Ok(())
+    }
+
+    /// Get the number of spent serial numbers tracked
+    pub fn size(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Determine whether a record owned by the private key has been spent
+    #[wasm_bindgen(js_name = isSpent)]
+    pub fn is_spent(
+        &self,
+        record: &RecordPlaintext,
+        private_key: &PrivateKey,
+        program_id: &str,
+        record_name: &str,
+    ) -> Result<bool, String> {
+        let (program_id, record_name) = Self::parse_record_type(program_id, record_name)?;
+        self.0.is_spent(private_key, record, &program_id, &record_name).map_err(|error| error.to_string())
+    }
+
+    /// Determine which of an array of record plaintext strings owned by the private key have been
+    /// spent, returning an array of booleans in the same order
+    #[wasm_bindgen(js_name = markSpent)]
+    pub fn mark_spent(
+        &self,
+        records: Array,
+        private_key: &PrivateKey,
+        program_id: &str,
+        record_name: &str,
+    ) -> Result<Array, String> {
+        let (program_id, record_name) = Self::parse_record_type(program_id, record_name)?;
+        let records = records
+            .iter()
+            .map(|record| {
+                let record = record.as_string().ok_or_else(|| "Records must be plaintext strings".to_string())?;
+                RecordPlaintextNative::from_str(&record)
+                    .map_err(|_| "The record plaintext string provided was invalid".to_string())
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let spent =
+            self.0.mark_spent(private_key, &records, &program_id, &record_name).map_err(|error| error.to_string())?;
+        Ok(spent.into_iter().map(JsValue::from_bool).collect())
+    }
+
+    /// Query a node to determine whether a serial number has been revealed on chain
+    ///
+    /// @param url The url of the Aleo network node to query
+    /// @param serial_number The serial number of the record, as returned by `RecordPlaintext.serialNumber`
+    #[wasm_bindgen(js_name = isSerialNumberSpent)]
+    pub async fn is_serial_number_spent(url: String, serial_number: String) -> Result<bool, String> {
+        let serial_number = Field::<CurrentNetwork>::from_str(&serial_number)
+            .map_err(|_| "Invalid serial number specified".to_string())?;
+        let url = NetworkConfig::new(url, None, None)?.endpoint(&Endpoint::FindTransitionID(serial_number));
+        let response =
+            reqwest::get(&url).await.map_err(|error| format!("Failed to query the serial number: {error}"))?;
+        // The node responds with 404 when no transition contains the serial number
+        match response.status().as_u16() {
+            404 => Ok(false),
+            _ if response.status().is_success() => Ok(true),
+            status => Err(format!("Failed to query the serial number: {url} responded with status {status}")),
+        }
+    }
+}
+
+impl SpentRecords {
+    // Parse the program id and record name identifying the record type
+    fn parse_record_type(program_id: &str, record_name: &str) -> Result<(ProgramIDNative, IdentifierNative), String> {
+        let program_id =
+            ProgramIDNative::from_str(program_id).map_err(|_| "Invalid ProgramID specified".to_string())?;
+        let record_name = IdentifierNative::from_str(record_name)
+            .map_err(|_| "Invalid Identifier specified for record".to_string())?;
+        Ok((program_id, record_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const OWNER_PRIVATE_KEY: &str = "APrivateKey1zkpJkyYRGYtkeHDaFfwsKtUJzia7csiWhfBWPXWhXJzy9Ls";
+    const RECORD: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  microcredits: 1500000000000000u64.private,
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+
+    #[wasm_bindgen_test]
+    fn test_spent_records() {
+        let private_key = PrivateKey::from_string(OWNER_PRIVATE_KEY).unwrap();
+        let record = RecordPlaintext::from_string(RECORD).unwrap();
+        let mut spent_records = SpentRecords::new();
+        assert!(!spent_records.is_spent(&record, &private_key, "credits.aleo", "credits").unwrap());
+
+        // Mark the record as spent via its serial number
+        let serial_number = record.serial_number(&private_key, "credits.aleo", "credits").unwrap();
+        spent_records.add_serial_number(&serial_number).unwrap();
+        assert_eq!(spent_records.size(), 1);
+        assert!(spent_records.is_spent(&record, &private_key, "credits.aleo", "credits").unwrap());
+
+        let spent =
+            spent_records.mark_spent(Array::of1(&RECORD.into()), &private_key, "credits.aleo", "credits").unwrap();
+        assert_eq!(spent.get(0).as_bool(), Some(true));
+        assert!(spent_records.add_serial_number("not a field").is_err());
+        assert!(spent_records.is_spent(&record, &private_key, "not a program", "credits").is_err());
+    }
+}
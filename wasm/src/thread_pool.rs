@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use js_sys::Reflect;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Get the number of logical processors reported by `navigator.hardwareConcurrency`
+///
+/// Returns 1 when the value is not available (i.e. in environments without a navigator object).
+#[wasm_bindgen(js_name = "hardwareConcurrency")]
+pub fn hardware_concurrency() -> u32 {
+    Reflect::get(&js_sys::global(), &"navigator".into())
+        .and_then(|navigator| Reflect::get(&navigator, &"hardwareConcurrency".into()))
+        .ok()
+        .and_then(|concurrency| concurrency.as_f64())
+        .map_or(1, |concurrency| (concurrency as u32).max(1))
+}
+
+/// Get the recommended size of the proving thread pool
+///
+/// This is the number of logical processors minus the number of cores reserved for other work
+/// (i.e. keeping the UI responsive), and is always at least 1.
+///
+/// @param reserved_cores (optional) The number of cores to leave free (default 1)
+#[wasm_bindgen(js_name = "recommendedThreadCount")]
+pub fn recommended_thread_count(reserved_cores: Option<u32>) -> u32 {
+    thread_count(hardware_concurrency(), reserved_cores.unwrap_or(1))
+}
+
+/// Get the number of worker threads available for proving
+///
+/// This is the size of the initialized thread pool when the module is built with the `parallel`
+/// feature and 1 otherwise.
+#[wasm_bindgen(js_name = "activeThreadCount")]
+pub fn active_thread_count() -> u32 {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::current_num_threads() as u32
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
+}
+
+/// Initialize the thread pool with the recommended number of threads
+///
+/// @param reserved_cores (optional) The number of cores to leave free (default 1)
+#[cfg(feature = "parallel")]
+#[wasm_bindgen(js_name = "initThreadPoolWithDefaults")]
+pub fn init_thread_pool_with_defaults(reserved_cores: Option<u32>) -> js_sys::Promise {
+    wasm_bindgen_rayon::init_thread_pool(recommended_thread_count(reserved_cores) as usize)
+}
+
+/// Compute the size of a thread pool from the available and reserved cores
+fn thread_count(available_cores: u32, reserved_cores: u32) -> u32 {
+    available_cores.saturating_sub(reserved_cores).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_thread_count() {
+        assert_eq!(thread_count(8, 1), 7);
+        assert_eq!(thread_count(8, 0), 8);
+        assert_eq!(thread_count(1, 1), 1);
+        assert_eq!(thread_count(2, 4), 1);
+        assert!(recommended_thread_count(None) >= 1);
+        assert!(active_thread_count() >= 1);
+    }
+}
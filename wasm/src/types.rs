@@ -16,54 +16,111 @@
 
 pub use aleo_rust::{
     Address,
+    AleoNetwork,
     Ciphertext,
     Encryptor,
     Identifier,
+    KeyDerivation,
+    Keystore,
+    Mnemonic,
     Plaintext,
     PrivateKey,
     ProgramID,
     Record,
     Signature,
+    SigningDomain,
+    SpentRecords,
     Testnet3,
     ViewKey,
 };
-use snarkvm_circuit_network::AleoV0;
-use snarkvm_console::program::{ProgramOwner, Response, TransactionLeaf};
-use snarkvm_synthesizer::{
-    helpers::memory::BlockMemory,
-    snark::{ProvingKey, VerifyingKey},
-    Process,
-    Program,
-    Transaction,
+use snarkvm_console::{
+    account::{ComputeKey, GraphKey},
+    types::{Field, Group, Scalar},
 };
 
+pub use snarkvm_console::{
+    network::Network,
+    prelude::{One, ToBits, ToFields, Zero},
+};
 pub use snarkvm_wasm::{network::Environment, FromBytes, PrimeField, ToBytes};
 
 // Account types
 pub type AddressNative = Address<CurrentNetwork>;
+pub type ComputeKeyNative = ComputeKey<CurrentNetwork>;
+pub type GraphKeyNative = GraphKey<CurrentNetwork>;
+pub type KeyDerivationNative = KeyDerivation<CurrentNetwork>;
+pub type MnemonicNative = Mnemonic<CurrentNetwork>;
 pub type PrivateKeyNative = PrivateKey<CurrentNetwork>;
 pub type SignatureNative = Signature<CurrentNetwork>;
+pub type SigningDomainNative = SigningDomain<CurrentNetwork>;
 pub type ViewKeyNative = ViewKey<CurrentNetwork>;
 
-// Network types
+// Network types, the targeted network is selected with a cargo feature
+#[cfg(feature = "testnet3")]
 pub type CurrentNetwork = Testnet3;
-pub type CurrentAleo = AleoV0;
+
+#[cfg(not(feature = "testnet3"))]
+compile_error!("A network feature must be enabled, i.e. \"testnet3\"");
+
+// Primitive types
+pub type FieldNative = Field<CurrentNetwork>;
+pub type GroupNative = Group<CurrentNetwork>;
+pub type ScalarNative = Scalar<CurrentNetwork>;
 
 // Record types
 pub type CiphertextNative = Ciphertext<CurrentNetwork>;
 pub type PlaintextNative = Plaintext<CurrentNetwork>;
 pub type RecordCiphertextNative = Record<CurrentNetwork, CiphertextNative>;
 pub type RecordPlaintextNative = Record<CurrentNetwork, PlaintextNative>;
+pub type SpentRecordsNative = SpentRecords<CurrentNetwork>;
 
 // Program types
-pub type CurrentBlockMemory = BlockMemory<CurrentNetwork>;
 pub type IdentifierNative = Identifier<CurrentNetwork>;
-pub type ProcessNative = Process<CurrentNetwork>;
-pub type ProgramNative = Program<CurrentNetwork>;
 pub type ProgramIDNative = ProgramID<CurrentNetwork>;
-pub type ProgramOwnerNative = ProgramOwner<CurrentNetwork>;
-pub type ProvingKeyNative = ProvingKey<CurrentNetwork>;
-pub type ResponseNative = Response<CurrentNetwork>;
-pub type TransactionLeafNative = TransactionLeaf<CurrentNetwork>;
-pub type TransactionNative = Transaction<CurrentNetwork>;
-pub type VerifyingKeyNative = VerifyingKey<CurrentNetwork>;
+
+// Program execution types, which are excluded from wallet-only builds
+#[cfg(feature = "programs")]
+pub use program_types::*;
+
+#[cfg(feature = "programs")]
+mod program_types {
+    use super::{CurrentNetwork, PlaintextNative};
+
+    use snarkvm_circuit_network::AleoV0;
+    use snarkvm_console::program::{Entry, ProgramOwner, Response, TransactionLeaf, Value};
+    use snarkvm_synthesizer::{
+        helpers::memory::BlockMemory,
+        snark::{ProvingKey, VerifyingKey},
+        Authorization,
+        Block,
+        Deployment,
+        Execution,
+        Fee,
+        Process,
+        Program,
+        Trace,
+        Transaction,
+        Transition,
+    };
+
+    pub type AuthorizationNative = Authorization<CurrentNetwork>;
+    pub type BlockNative = Block<CurrentNetwork>;
+    #[cfg(feature = "testnet3")]
+    pub type CurrentAleo = AleoV0;
+    pub type CurrentBlockMemory = BlockMemory<CurrentNetwork>;
+    pub type DeploymentNative = Deployment<CurrentNetwork>;
+    pub type EntryNative = Entry<CurrentNetwork, PlaintextNative>;
+    pub type ExecutionNative = Execution<CurrentNetwork>;
+    pub type FeeNative = Fee<CurrentNetwork>;
+    pub type ProcessNative = Process<CurrentNetwork>;
+    pub type ProgramNative = Program<CurrentNetwork>;
+    pub type ProgramOwnerNative = ProgramOwner<CurrentNetwork>;
+    pub type ProvingKeyNative = ProvingKey<CurrentNetwork>;
+    pub type ResponseNative = Response<CurrentNetwork>;
+    pub type TraceNative = Trace<CurrentNetwork>;
+    pub type TransactionLeafNative = TransactionLeaf<CurrentNetwork>;
+    pub type TransactionNative = Transaction<CurrentNetwork>;
+    pub type TransitionNative = Transition<CurrentNetwork>;
+    pub type ValueNative = Value<CurrentNetwork>;
+    pub type VerifyingKeyNative = VerifyingKey<CurrentNetwork>;
+}
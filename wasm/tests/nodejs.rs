@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "nodejs")]
+
+use aleo_wasm::{PrivateKey, ProgramManager};
+use js_sys::Array;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+const HELLO_PROGRAM: &str = r#"program hello.aleo;
+function main:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"#;
+
+#[wasm_bindgen_test]
+fn test_execution_requires_xml_http_request() {
+    let global = js_sys::global();
+    assert!(js_sys::Reflect::has(&global, &"fetch".into()).unwrap());
+    assert!(!js_sys::Reflect::has(&global, &"XMLHttpRequest".into()).unwrap());
+
+    let mut program_manager = ProgramManager::new();
+    let inputs = Array::of2(&JsValue::from_str("5u32"), &JsValue::from_str("5u32"));
+    let error = program_manager
+        .execute_local(
+            PrivateKey::new(),
+            HELLO_PROGRAM.to_string(),
+            "main".to_string(),
+            inputs,
+            false,
+            None,
+            None,
+            None,
+        )
+        .err()
+        .expect("execution without XMLHttpRequest should fail");
+    assert!(error.contains("XMLHttpRequest"));
+    assert!(error.contains("synchronous"));
+}
@@ -14,7 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use aleo_wasm::{PrivateKey, Program, ProgramManager, ProvingKey, RecordPlaintext, VerifyingKey};
+#![cfg(feature = "browser")]
+
+use aleo_wasm::{
+    Authorization,
+    IndexedDbKeyCache,
+    PrivateKey,
+    Program,
+    ProgramManager,
+    ProvingKey,
+    RecordPlaintext,
+    VerifyingKey,
+};
 use js_sys::Array;
 use wasm_bindgen::JsValue;
 use wasm_bindgen_test::*;
@@ -87,6 +98,7 @@ async fn test_cache_functionality() {
             true,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -104,7 +116,7 @@ async fn test_cache_functionality() {
     );
 
     // Ensure the cache clears correctly
-    program_manager.clear_key_cache();
+    program_manager.clear_key_cache(None, None).unwrap();
     assert!(program_manager.get_cached_keypair("credits.aleo", "fee").is_err());
     assert!(!program_manager.key_exists("credits.aleo", "fee").unwrap());
 }
@@ -114,7 +126,7 @@ async fn test_key_synthesis() {
     // Synthesize a keypair for the fee program
     let mut program_manager = ProgramManager::new();
     let credits = Program::get_credits_program();
-    let mut key_pair = program_manager.synthesize_keypair(&credits.to_string(), "fee").unwrap();
+    let mut key_pair = program_manager.synthesize_key_pair(&credits.to_string(), "fee", None, false).unwrap();
     let retrieved_proving_key = key_pair.proving_key().unwrap();
     let retreived_verifying_key = key_pair.verifying_key().unwrap();
 
@@ -137,6 +149,7 @@ async fn test_key_synthesis() {
             true,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -163,8 +176,8 @@ async fn test_fee_validation() {
             "fee".to_string(),
             inputs,
             100.0,
-            fee_record.clone(),
-            "https://vm.aleo.org/api".to_string(),
+            Some(fee_record.clone()),
+            Some("https://vm.aleo.org/api".to_string()),
             false,
             None,
             None,
@@ -181,8 +194,8 @@ async fn test_fee_validation() {
             Program::get_credits_program().to_string(),
             None,
             100.0,
-            fee_record.clone(),
-            "https://vm.aleo.org/api".to_string(),
+            Some(fee_record.clone()),
+            Some("https://vm.aleo.org/api".to_string()),
             false,
             None,
             None,
@@ -190,6 +203,25 @@ async fn test_fee_validation() {
         .await;
     assert!(deployment.is_err());
 
+    // Ensure executions without a fee record report that public fees are unavailable
+    let execution = program_manager
+        .execute(
+            private_key.clone(),
+            Program::get_credits_program().to_string(),
+            "fee".to_string(),
+            Array::new(),
+            1.0,
+            None,
+            None,
+            false,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+    assert!(execution.unwrap_err().contains("fee record"));
+
     // Ensure transfer fails when fee amount or amount is greater than the balance available in the record
     let transfer = program_manager
         .transfer(
@@ -200,7 +232,7 @@ async fn test_fee_validation() {
             Some(fee_record.clone()),
             0.9,
             fee_record.clone(),
-            "https://vm.aleo.org/api".to_string(),
+            Some("https://vm.aleo.org/api".to_string()),
             false,
             None,
             None,
@@ -219,7 +251,7 @@ async fn test_fee_validation() {
             Some(fee_record.clone()),
             100.00,
             fee_record.clone(),
-            "https://vm.aleo.org/api".to_string(),
+            Some("https://vm.aleo.org/api".to_string()),
             false,
             None,
             None,
@@ -237,7 +269,7 @@ async fn test_fee_validation() {
             fee_record.clone(),
             100.00,
             fee_record.clone(),
-            "https://vm.aleo.org/api".to_string(),
+            Some("https://vm.aleo.org/api".to_string()),
             false,
             None,
             None,
@@ -257,7 +289,16 @@ async fn test_program_execution_with_cache_and_external_keys() {
     inputs.set(0, wasm_bindgen::JsValue::from_str("5u32"));
     inputs.set(1, wasm_bindgen::JsValue::from_str("5u32"));
     let result = program_manager
-        .execute_local(private_key.clone(), HELLO_PROGRAM.to_string(), "main".to_string(), inputs, true, None, None)
+        .execute_local(
+            private_key.clone(),
+            HELLO_PROGRAM.to_string(),
+            "main".to_string(),
+            inputs,
+            true,
+            None,
+            None,
+            None,
+        )
         .unwrap();
     let outputs = result.get_outputs().to_vec();
     console_log!("outputs: {:?}", outputs);
@@ -272,7 +313,16 @@ async fn test_program_execution_with_cache_and_external_keys() {
     inputs.set(0, wasm_bindgen::JsValue::from_str("15u32"));
     inputs.set(1, wasm_bindgen::JsValue::from_str("5u32"));
     let result = program_manager
-        .execute_local(private_key.clone(), HELLO_PROGRAM.to_string(), "main".to_string(), inputs, true, None, None)
+        .execute_local(
+            private_key.clone(),
+            HELLO_PROGRAM.to_string(),
+            "main".to_string(),
+            inputs,
+            true,
+            None,
+            None,
+            None,
+        )
         .unwrap();
 
     // Ensure the output using cached keys is correct
@@ -303,6 +353,7 @@ async fn test_program_execution_with_cache_and_external_keys() {
             false,
             Some(retrieved_proving_key),
             Some(retrieved_verifying_key),
+            None,
         )
         .unwrap();
 
@@ -320,7 +371,16 @@ async fn test_program_execution_with_cache_and_external_keys() {
     inputs.set(0, wasm_bindgen::JsValue::from_str("20u32"));
     inputs.set(1, wasm_bindgen::JsValue::from_str("20u32"));
     let result = program_manager
-        .execute_local(private_key, HELLO_PROGRAM_EDIT.to_string(), "hello".to_string(), inputs, false, None, None)
+        .execute_local(
+            private_key,
+            HELLO_PROGRAM_EDIT.to_string(),
+            "hello".to_string(),
+            inputs,
+            false,
+            None,
+            None,
+            None,
+        )
         .unwrap();
 
     let outputs = result.get_outputs().to_vec();
@@ -328,3 +388,353 @@ async fn test_program_execution_with_cache_and_external_keys() {
     assert_eq!(outputs.len(), 1);
     assert_eq!(outputs[0], "40u32");
 }
+
+#[wasm_bindgen_test]
+async fn test_fee_estimation() {
+    let mut program_manager = ProgramManager::new();
+    let private_key = PrivateKey::new();
+
+    // Ensure the deployment fee estimate covers at least the program bytecode
+    let deployment_fee = program_manager.estimate_deployment_fee(HELLO_PROGRAM.to_string(), None, false).unwrap();
+    assert!(deployment_fee > HELLO_PROGRAM.len() as u64);
+
+    // Ensure the execution fee can be estimated and that the synthesized keys are cached
+    let inputs = js_sys::Array::new();
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    let execution_fee = program_manager
+        .estimate_execution_fee(private_key, HELLO_PROGRAM.to_string(), "main".to_string(), inputs, true)
+        .unwrap();
+    assert!(execution_fee > 0);
+    assert!(program_manager.key_exists("hello.aleo", "main").unwrap());
+
+    // Ensure estimation fails for a program with missing imports
+    let program = "import hello.aleo;\nprogram hello_import.aleo;\nfunction main:\n    input r0 as u32.public;\n    output r0 as u32.private;\n";
+    assert!(program_manager.estimate_deployment_fee(program.to_string(), None, false).is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_synthesize_key_pair() {
+    let mut program_manager = ProgramManager::new();
+
+    // Ensure keys synthesized without caching are not stored in the program manager
+    program_manager.synthesize_key_pair(HELLO_PROGRAM, "main", None, false).unwrap();
+    assert!(!program_manager.key_exists("hello.aleo", "main").unwrap());
+
+    // Ensure keys synthesized with caching are stored and used in subsequent executions
+    let mut key_pair = program_manager.synthesize_key_pair(HELLO_PROGRAM, "main", None, true).unwrap();
+    assert!(program_manager.key_exists("hello.aleo", "main").unwrap());
+    let mut cached_key_pair = program_manager.get_cached_keypair("hello.aleo", "main").unwrap();
+    assert_eq!(key_pair.proving_key().unwrap(), cached_key_pair.proving_key().unwrap());
+
+    let inputs = js_sys::Array::new();
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    let result = program_manager
+        .execute_local(PrivateKey::new(), HELLO_PROGRAM.to_string(), "main".to_string(), inputs, true, None, None, None)
+        .unwrap();
+    assert_eq!(result.get_outputs().to_vec()[0], "10u32");
+
+    // Ensure a different program with the same name is rejected when the original is cached
+    assert!(program_manager.synthesize_key_pair(HELLO_PROGRAM_EDIT, "hello", None, true).is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_indexed_db_key_cache() {
+    let key_cache = IndexedDbKeyCache::open(Some("aleo-keys-test".to_string())).await.unwrap();
+    key_cache.clear().await.unwrap();
+    assert!(key_cache.load(HELLO_PROGRAM.to_string(), "main".to_string()).await.unwrap().is_none());
+
+    // Ensure stored keys can be loaded back
+    let mut program_manager = ProgramManager::new();
+    let mut key_pair = program_manager.synthesize_key_pair(HELLO_PROGRAM, "main", None, false).unwrap();
+    let proving_key = key_pair.proving_key().unwrap();
+    let verifying_key = key_pair.verifying_key().unwrap();
+    key_cache
+        .store(HELLO_PROGRAM.to_string(), "main".to_string(), proving_key.clone(), verifying_key.clone())
+        .await
+        .unwrap();
+    let mut loaded = key_cache.load(HELLO_PROGRAM.to_string(), "main".to_string()).await.unwrap().unwrap();
+    assert_eq!(loaded.proving_key().unwrap(), proving_key);
+    assert_eq!(loaded.verifying_key().unwrap(), verifying_key);
+
+    // Ensure keys are not returned for an edited program with the same name
+    assert!(key_cache.load(HELLO_PROGRAM_EDIT.to_string(), "main".to_string()).await.unwrap().is_none());
+
+    key_cache.clear().await.unwrap();
+    assert!(key_cache.load(HELLO_PROGRAM.to_string(), "main".to_string()).await.unwrap().is_none());
+}
+
+#[wasm_bindgen_test]
+async fn test_progress_callback() {
+    use std::{cell::RefCell, rc::Rc};
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let stages = Rc::new(RefCell::new(Vec::<(String, f64)>::new()));
+    let stages_clone = stages.clone();
+    let on_progress = Closure::<dyn FnMut(String, f64)>::new(move |stage: String, percent: f64| {
+        stages_clone.borrow_mut().push((stage, percent));
+    });
+
+    let mut program_manager = ProgramManager::new();
+    program_manager.set_progress_callback(Some(on_progress.as_ref().unchecked_ref::<js_sys::Function>().clone()));
+
+    let inputs = js_sys::Array::new();
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    program_manager
+        .execute_local(PrivateKey::new(), HELLO_PROGRAM.to_string(), "main".to_string(), inputs, true, None, None, None)
+        .unwrap();
+
+    // Ensure each stage of a local execution is reported from start to finish
+    let stages = stages.borrow();
+    for stage in ["authorization", "synthesis", "execution"] {
+        assert!(stages.contains(&(stage.to_string(), 0.0)));
+        assert!(stages.contains(&(stage.to_string(), 100.0)));
+    }
+}
+
+#[wasm_bindgen_test]
+async fn test_abort_signal() {
+    let abort_controller = web_sys::AbortController::new().unwrap();
+    let mut program_manager = ProgramManager::new();
+    program_manager.set_abort_signal(Some(abort_controller.signal()));
+
+    // Ensure an aborted signal stops the execution before any work is done
+    abort_controller.abort();
+    let inputs = js_sys::Array::new();
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    let result = program_manager.execute_local(
+        PrivateKey::new(),
+        HELLO_PROGRAM.to_string(),
+        "main".to_string(),
+        inputs.clone(),
+        true,
+        None,
+        None,
+        None,
+    );
+    assert!(result.is_err());
+    assert!(program_manager.synthesize_key_pair(HELLO_PROGRAM, "main", None, true).is_err());
+    assert!(!program_manager.key_exists("hello.aleo", "main").unwrap());
+
+    // Ensure executions succeed once the signal is removed
+    program_manager.set_abort_signal(None);
+    assert!(program_manager
+        .execute_local(PrivateKey::new(), HELLO_PROGRAM.to_string(), "main".to_string(), inputs, true, None, None, None)
+        .is_ok());
+}
+
+#[wasm_bindgen_test]
+async fn test_authorize() {
+    let mut program_manager = ProgramManager::new();
+    let inputs = js_sys::Array::new();
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+
+    // Ensure an authorization can be created without synthesizing keys and survives a string round trip
+    let authorization = program_manager
+        .authorize(PrivateKey::new(), HELLO_PROGRAM.to_string(), "main".to_string(), inputs.clone(), None, false)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+    assert!(!program_manager.key_exists("hello.aleo", "main").unwrap());
+    let recovered = Authorization::from_string(&authorization.to_string()).unwrap();
+    assert_eq!(recovered.to_string(), authorization.to_string());
+
+    // Ensure authorizing a missing function fails
+    let result = program_manager.authorize(
+        PrivateKey::new(),
+        HELLO_PROGRAM.to_string(),
+        "hello".to_string(),
+        inputs,
+        None,
+        false,
+    );
+    assert!(result.is_err());
+}
+
+#[wasm_bindgen_test]
+async fn test_execution_response_metadata() {
+    let mut program_manager = ProgramManager::new();
+    let inputs = js_sys::Array::new();
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    inputs.push(&wasm_bindgen::JsValue::from_str("5u32"));
+    let response = program_manager
+        .execute_local(
+            PrivateKey::new(),
+            HELLO_PROGRAM.to_string(),
+            "main".to_string(),
+            inputs,
+            false,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(response.get_outputs().to_vec(), vec![JsValue::from_str("10u32")]);
+    assert_eq!(response.get_output_types().to_vec(), vec![JsValue::from_str("u32.private")]);
+    assert_eq!(response.get_program_id(), "hello.aleo");
+    assert_eq!(response.get_function_name(), "main");
+    assert_eq!(response.get_records().length(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn test_fee_key_cache() {
+    let mut program_manager = ProgramManager::new();
+    assert!(!program_manager.has_fee_keys());
+
+    // Ensure keys of other functions are rejected
+    let mut key_pair = program_manager.synthesize_key_pair(HELLO_PROGRAM, "main", None, false).unwrap();
+    let hello_proving_key = key_pair.proving_key().unwrap();
+    let hello_verifying_key = key_pair.verifying_key().unwrap();
+    assert!(program_manager.cache_fee_keys(hello_proving_key.clone(), hello_verifying_key).is_err());
+    assert!(!program_manager.has_fee_keys());
+
+    // Ensure a fee verifying key can't be paired with the proving key of another function
+    let fee_proving_key_bytes = reqwest::get(FEE_PROVER_URL).await.unwrap().bytes().await.unwrap().to_vec();
+    let fee_verifying_key_bytes = reqwest::get(FEE_VERIFIER_URL).await.unwrap().bytes().await.unwrap().to_vec();
+    let fee_proving_key = ProvingKey::from_bytes(&fee_proving_key_bytes).unwrap();
+    let fee_verifying_key = VerifyingKey::from_bytes(&fee_verifying_key_bytes).unwrap();
+    assert!(program_manager.cache_fee_keys(hello_proving_key, fee_verifying_key.clone()).is_err());
+    assert!(!program_manager.has_fee_keys());
+
+    // Ensure fee keys are held independently of the rest of the key cache
+    program_manager.cache_fee_keys(fee_proving_key, fee_verifying_key).unwrap();
+    assert!(program_manager.has_fee_keys());
+    program_manager.clear_key_cache(None, None).unwrap();
+    assert!(program_manager.has_fee_keys());
+
+    program_manager.clear_fee_keys();
+    assert!(!program_manager.has_fee_keys());
+}
+
+#[wasm_bindgen_test]
+async fn test_reset_process() {
+    let mut program_manager = ProgramManager::new();
+
+    // Ensure executions without the cache leave the shared process untouched
+    let inputs = js_sys::Array::new();
+    inputs.push(&JsValue::from_str("5u32"));
+    inputs.push(&JsValue::from_str("5u32"));
+    program_manager
+        .execute_local(
+            PrivateKey::new(),
+            HELLO_PROGRAM.to_string(),
+            "main".to_string(),
+            inputs.clone(),
+            false,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    assert!(!program_manager.key_exists("hello.aleo", "main").unwrap());
+
+    // Ensure resetting the process clears the cache
+    program_manager
+        .execute_local(PrivateKey::new(), HELLO_PROGRAM.to_string(), "main".to_string(), inputs, true, None, None, None)
+        .unwrap();
+    assert!(program_manager.key_exists("hello.aleo", "main").unwrap());
+    program_manager.reset_process().unwrap();
+    assert!(!program_manager.key_exists("hello.aleo", "main").unwrap());
+}
+
+#[wasm_bindgen_test]
+async fn test_execution_debug_trace() {
+    let mut program_manager = ProgramManager::new();
+    let inputs = js_sys::Array::new();
+    inputs.push(&JsValue::from_str("5u32"));
+    inputs.push(&JsValue::from_str("5u32"));
+
+    // Ensure the trace is only recorded in debug mode
+    let response = program_manager
+        .execute_local(
+            PrivateKey::new(),
+            HELLO_PROGRAM.to_string(),
+            "main".to_string(),
+            inputs.clone(),
+            true,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    assert!(response.get_trace().unwrap().is_none());
+
+    let response = program_manager
+        .execute_local(
+            PrivateKey::new(),
+            HELLO_PROGRAM.to_string(),
+            "main".to_string(),
+            inputs,
+            true,
+            None,
+            None,
+            Some(true),
+        )
+        .unwrap();
+    let trace = response.get_trace().unwrap().unwrap();
+    assert_eq!(trace.length(), 1);
+    let call = trace.get(0);
+    assert_eq!(js_sys::Reflect::get(&call, &"program".into()).unwrap(), "hello.aleo");
+    assert_eq!(js_sys::Reflect::get(&call, &"function".into()).unwrap(), "main");
+}
+
+#[wasm_bindgen_test]
+async fn test_execute_batch_validation() {
+    let mut program_manager = ProgramManager::new();
+    let private_key = PrivateKey::new();
+
+    let transactions = program_manager.execute_batch(private_key.clone(), Array::new(), None).await.unwrap();
+    assert_eq!(transactions.length(), 0);
+
+    // Ensure malformed requests are rejected before any execution begins
+    let requests = Array::of1(&js_sys::Object::new());
+    let result = program_manager.execute_batch(private_key, requests, None).await;
+    assert_eq!(result.unwrap_err(), "Missing parameter program");
+}
+
+#[wasm_bindgen_test]
+async fn test_join_many_validation() {
+    let mut program_manager = ProgramManager::new();
+    let private_key = PrivateKey::new();
+    let record = JsValue::from_str(RECORD);
+
+    let records = Array::of1(&record);
+    let result = program_manager.join_many(private_key.clone(), records, 0.1, Array::new(), None, false).await;
+    assert_eq!(result.unwrap_err(), "At least two records must be provided to join");
+
+    // Ensure one fee record is required for every pair of records
+    let records = Array::of3(&record, &record, &record);
+    let result = program_manager.join_many(private_key, records, 0.1, Array::new(), None, false).await;
+    assert_eq!(result.unwrap_err(), "1 joins are required but only 0 fee records were provided");
+}
+
+#[wasm_bindgen_test]
+async fn test_split_record_validation() {
+    let mut program_manager = ProgramManager::new();
+    let private_key = PrivateKey::new();
+    let record = RecordPlaintext::from_string(RECORD).unwrap();
+
+    // Ensure the amounts are validated against the record before the split is executed
+    let amounts = Array::of2(&JsValue::from_f64(1.5), &JsValue::from_f64(1.0));
+    let result = program_manager.split_record(private_key.clone(), record.clone(), amounts, None, false).await;
+    assert_eq!(result.unwrap_err(), "The amounts exceed the credits available in the record");
+
+    let amounts = Array::of1(&JsValue::from_f64(2.0));
+    let result = program_manager.split_record(private_key.clone(), record.clone(), amounts, None, false).await;
+    assert_eq!(result.unwrap_err(), "At least two output records are required for a split");
+
+    let amounts = Array::of1(&JsValue::from_str("1"));
+    let result = program_manager.split_record(private_key, record, amounts, None, false).await;
+    assert_eq!(result.unwrap_err(), "Amounts must be positive numbers");
+}
+
+#[wasm_bindgen_test]
+async fn test_verify_on_chain_program_invalid_source() {
+    let mut program_manager = ProgramManager::new();
+    let result = program_manager.verify_on_chain_program("program invalid".to_string(), None, None).await;
+    assert_eq!(result.unwrap_err(), "The program provided was invalid");
+}